@@ -15,4 +15,17 @@ pub enum BuiltinIdentifier {
     ArrayPad,
     CryptoSchnorrSignatureVerify,
     FieldInverse,
+    ArrayBinarySearch,
+    MathMin,
+    MathMax,
+    MathAbs,
+    OpsWrappingAdd,
+    OpsWrappingSub,
+    OpsWrappingMul,
+    OpsSaturatingAdd,
+    OpsSaturatingSub,
+    OpsSaturatingMul,
+    OpsCheckedAdd,
+    OpsCheckedMul,
+    Pow,
 }
@@ -0,0 +1,758 @@
+//!
+//! The static estimation of the number of constraints produced by a program.
+//!
+//! The estimator walks the bytecode once, following the calls, and tracks the types of the
+//! stack and memory values and whether they are constants, since the operations on constants
+//! produce no constraints. The loop bodies are walked once and their cost is multiplied by
+//! the number of iterations. Both branches of a conditional are always synthesized, so both
+//! are counted.
+//!
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::data::types::DataType;
+use crate::instructions::{Add, Mul, Sub};
+use crate::scalar::ScalarType;
+use crate::{Instruction, Program};
+
+/// The bit length of the BN256 scalar field elements.
+const FIELD_BITLENGTH: usize = 254;
+
+/// The maximal depth of the calls, which guards against the malformed recursive bytecode.
+const MAX_CALL_DEPTH: usize = 1024;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CostEstimate {
+    /// The estimated number of constraints.
+    pub constraints: usize,
+    /// The number of the executed instructions, with the loop iterations taken into account.
+    pub instructions: usize,
+    /// The number of the executed instructions with an unknown cost, like the built-in calls,
+    /// which are not included into `constraints`.
+    pub unknown: usize,
+}
+
+/// Estimates the number of constraints produced by running `program`.
+pub fn estimate_cost(program: &Program) -> CostEstimate {
+    let mut estimator = Estimator::new(program);
+    estimator.run();
+    estimator.estimate
+}
+
+/// The cost table, which gives the number of constraints of an operation on witness operands
+/// of `bitlength` bits.
+pub mod table {
+    /// The constraint enforcing `ONE * ONE = ONE`, which is added before the program is run.
+    pub const SETUP: usize = 1;
+
+    /// The decomposition of an integer into bits, which checks that it fits into its type.
+    pub fn type_check(bitlength: usize) -> usize {
+        bitlength + 1
+    }
+
+    /// The addition, subtraction, multiplication and negation, without the type check.
+    pub const ARITHMETIC: usize = 1;
+
+    /// The division with remainder, without the type check of the result.
+    pub fn div_rem(bitlength: usize) -> usize {
+        2 * type_check(bitlength) + 3
+    }
+
+    /// The exponentiation by a witness exponent of `exponent_bits` bits, which decomposes the
+    /// exponent and squares and conditionally multiplies the result for each bit, or by a
+    /// constant one, which only squares and multiplies.
+    pub fn pow(exponent_bits: usize, is_exponent_witness: bool) -> usize {
+        if is_exponent_witness {
+            4 * exponent_bits + 1
+        } else {
+            2 * exponent_bits
+        }
+    }
+
+    /// The ordering comparison, which decomposes the difference of the operands.
+    pub fn ordering(bitlength: usize) -> usize {
+        type_check(bitlength) + 2
+    }
+
+    /// The equality comparison.
+    pub const EQUALITY: usize = 3;
+
+    /// The boolean `!`, `&&`, `||` and `^^`, and the selection between two values.
+    pub const BOOLEAN: usize = 1;
+
+    /// The bitwise `&`, `|` and `^`, which decompose both operands, combine the bits and pack
+    /// them back.
+    pub fn bitwise(bitlength: usize) -> usize {
+        2 * type_check(bitlength) + bitlength + 1
+    }
+
+    /// The shift by a constant amount, or by a witness one of `amount_bitlength` bits.
+    pub fn shift(bitlength: usize, amount_bitlength: Option<usize>) -> usize {
+        type_check(bitlength) + amount_bitlength.map_or(0, |amount| amount * bitlength) + 1
+    }
+
+    /// The access to an array of `len` elements by a witness index.
+    pub fn array_access(len: usize) -> usize {
+        2 * len
+    }
+
+    /// The assertion, which also checks the branch condition.
+    pub const ASSERT: usize = 3;
+
+    /// The public output.
+    pub const OUTPUT: usize = 1;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operand {
+    scalar_type: Option<ScalarType>,
+    is_constant: bool,
+}
+
+impl Operand {
+    const UNKNOWN: Self = Self {
+        scalar_type: None,
+        is_constant: false,
+    };
+
+    fn witness(scalar_type: Option<ScalarType>) -> Self {
+        Self {
+            scalar_type,
+            is_constant: false,
+        }
+    }
+
+    fn bitlength(&self) -> usize {
+        match self.scalar_type {
+            Some(ScalarType::Boolean) => 1,
+            Some(ScalarType::Integer(int_type)) => int_type.bitlength,
+            Some(ScalarType::Field) | None => FIELD_BITLENGTH,
+        }
+    }
+}
+
+struct Frame {
+    memory: HashMap<usize, Operand>,
+    return_address: usize,
+}
+
+struct Branch {
+    stack: Vec<Operand>,
+    then_stack: Option<Vec<Operand>>,
+    is_witness: bool,
+    stores: BTreeSet<usize>,
+}
+
+struct Estimator<'a> {
+    program: &'a Program,
+    pc: usize,
+    multiplier: usize,
+    loops: Vec<usize>,
+    stack: Vec<Operand>,
+    frames: Vec<Frame>,
+    conditions: Vec<bool>,
+    branches: Vec<Branch>,
+    estimate: CostEstimate,
+}
+
+impl<'a> Estimator<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            pc: 0,
+            multiplier: 1,
+            loops: vec![],
+            stack: vec![],
+            frames: vec![],
+            conditions: vec![false],
+            branches: vec![],
+            estimate: CostEstimate::default(),
+        }
+    }
+
+    fn run(&mut self) {
+        self.charge(table::SETUP);
+
+        let mut inputs = vec![];
+        flatten(&self.program.input, &mut inputs);
+        for scalar_type in inputs.into_iter() {
+            let cost = match scalar_type {
+                ScalarType::Field => table::ARITHMETIC,
+                scalar_type => Self::type_check(scalar_type),
+            };
+            self.charge(cost);
+            self.stack.push(Operand::witness(Some(scalar_type)));
+        }
+        self.frames.push(Frame {
+            memory: HashMap::new(),
+            return_address: self.program.bytecode.len(),
+        });
+
+        let program = self.program;
+        while let Some(instruction) = program.bytecode.get(self.pc) {
+            self.pc += 1;
+            self.estimate.instructions += self.multiplier;
+            if !self.step(instruction) {
+                break;
+            }
+        }
+    }
+
+    /// Walks the `instruction` and returns false if the program is finished.
+    fn step(&mut self, instruction: &Instruction) -> bool {
+        match instruction {
+            Instruction::NoOperation(_)
+            | Instruction::FileMarker(_)
+            | Instruction::FunctionMarker(_)
+            | Instruction::LineMarker(_)
+            | Instruction::ColumnMarker(_) => {}
+
+            Instruction::PushConst(instruction) => self.stack.push(Operand {
+                scalar_type: Some(instruction.scalar_type),
+                is_constant: true,
+            }),
+            Instruction::Pop(instruction) => {
+                self.pop_many(instruction.count);
+            }
+            Instruction::Slice(instruction) => {
+                let offset = self.pop();
+                let array = self.pop_many(instruction.array_len);
+                for index in 0..instruction.slice_len {
+                    let value = array.get(index).cloned().unwrap_or(Operand::UNKNOWN);
+                    self.push_selected(value, offset, instruction.array_len);
+                }
+            }
+            Instruction::Swap(_) => {
+                let top = self.pop();
+                let next = self.pop();
+                self.stack.push(top);
+                self.stack.push(next);
+            }
+            Instruction::Tee(_) => {
+                let value = self.pop();
+                self.stack.push(value);
+                self.stack.push(value);
+            }
+            Instruction::Dup(instruction) => {
+                let values = self.pop_many(instruction.depth + 1);
+                let value = values[0];
+                self.stack.extend(values);
+                self.stack.push(value);
+            }
+            Instruction::Drop(instruction) => {
+                self.pop_many(instruction.count);
+            }
+
+            Instruction::Load(instruction) => self.load(instruction.address, 1, false),
+            Instruction::LoadSequence(instruction) => {
+                self.load(instruction.address, instruction.len, false)
+            }
+            Instruction::LoadGlobal(instruction) => self.load(instruction.address, 1, true),
+            Instruction::LoadSequenceGlobal(instruction) => {
+                self.load(instruction.address, instruction.len, true)
+            }
+            Instruction::LoadByIndex(instruction) => {
+                self.load_by_index(instruction.address, instruction.len, 1, false)
+            }
+            Instruction::LoadSequenceByIndex(instruction) => self.load_by_index(
+                instruction.address,
+                instruction.array_len,
+                instruction.value_len,
+                false,
+            ),
+            Instruction::LoadByIndexGlobal(instruction) => {
+                self.load_by_index(instruction.address, instruction.len, 1, true)
+            }
+            Instruction::LoadSequenceByIndexGlobal(instruction) => self.load_by_index(
+                instruction.address,
+                instruction.array_len,
+                instruction.value_len,
+                true,
+            ),
+
+            Instruction::Store(instruction) => self.store(instruction.index, 1, false),
+            Instruction::StoreSequence(instruction) => {
+                self.store(instruction.address, instruction.len, false)
+            }
+            Instruction::StoreGlobal(instruction) => self.store(instruction.address, 1, true),
+            Instruction::StoreSequenceGlobal(instruction) => {
+                self.store(instruction.address, instruction.len, true)
+            }
+            Instruction::StoreByIndex(instruction) => {
+                self.store_by_index(instruction.address, instruction.len, 1)
+            }
+            Instruction::StoreSequenceByIndex(instruction) => self.store_by_index(
+                instruction.address,
+                instruction.array_len,
+                instruction.value_len,
+            ),
+
+            Instruction::Add(Add { is_unchecked })
+            | Instruction::Sub(Sub { is_unchecked })
+            | Instruction::Mul(Mul { is_unchecked }) => {
+                let is_unchecked = *is_unchecked;
+                self.binary(
+                    |operand| {
+                        if is_unchecked {
+                            table::ARITHMETIC
+                        } else {
+                            table::ARITHMETIC + Self::type_check_of(operand)
+                        }
+                    },
+                    None,
+                )
+            }
+            Instruction::Div(_) | Instruction::Rem(_) => self.binary(
+                |operand| table::div_rem(operand.bitlength()) + Self::type_check_of(operand),
+                None,
+            ),
+            Instruction::Pow(instruction) => {
+                let exponent = self.pop();
+                let base = self.pop();
+                let is_constant = base.is_constant && exponent.is_constant;
+                let is_exponent_witness = !exponent.is_constant;
+                self.charge_operation(&base, is_constant, |base| {
+                    table::pow(instruction.exponent_bits, is_exponent_witness)
+                        + Self::type_check_of(base)
+                });
+                self.stack.push(Operand {
+                    scalar_type: base.scalar_type,
+                    is_constant,
+                });
+            }
+            Instruction::Neg(_) => self.unary(
+                |operand| table::ARITHMETIC + Self::type_check_of(operand),
+                None,
+            ),
+
+            Instruction::Not(_) => self.unary(|_| table::BOOLEAN, None),
+            Instruction::And(_) | Instruction::Or(_) | Instruction::Xor(_) => {
+                self.binary(|_| table::BOOLEAN, None)
+            }
+
+            Instruction::Lt(_) | Instruction::Le(_) | Instruction::Ge(_) | Instruction::Gt(_) => {
+                self.binary(
+                    |operand| table::ordering(operand.bitlength()),
+                    Some(ScalarType::Boolean),
+                )
+            }
+            Instruction::Eq(_) | Instruction::Ne(_) => {
+                self.binary(|_| table::EQUALITY, Some(ScalarType::Boolean))
+            }
+
+            Instruction::BitAnd(_) | Instruction::BitOr(_) | Instruction::BitXor(_) => {
+                self.binary(|operand| table::bitwise(operand.bitlength()), None)
+            }
+            Instruction::BitNot(_) => self.unary(|_| table::ARITHMETIC, None),
+            Instruction::BitShiftLeft(_) | Instruction::BitShiftRight(_) => {
+                let amount = self.pop();
+                let value = self.pop();
+                let amount_bitlength = if amount.is_constant {
+                    None
+                } else {
+                    Some(amount.bitlength())
+                };
+                if !value.is_constant || !amount.is_constant {
+                    self.charge(table::shift(value.bitlength(), amount_bitlength));
+                }
+                self.stack.push(Operand {
+                    scalar_type: value.scalar_type,
+                    is_constant: value.is_constant && amount.is_constant,
+                });
+            }
+
+            Instruction::Cast(instruction) => {
+                let value = self.pop();
+                if !value.is_constant {
+                    self.charge(Self::type_check(instruction.scalar_type));
+                }
+                self.stack.push(Operand {
+                    scalar_type: Some(instruction.scalar_type),
+                    is_constant: value.is_constant,
+                });
+            }
+            Instruction::CheckRange(instruction) => {
+                let value = self.pop();
+                if !value.is_constant {
+                    self.charge(table::type_check(instruction.bitlength));
+                }
+                self.stack.push(value);
+            }
+
+            Instruction::ConditionalSelectSequence(instruction) => {
+                let condition = self.pop();
+                let if_false = self.pop_many(instruction.len);
+                let if_true = self.pop_many(instruction.len);
+                if !condition.is_constant {
+                    self.charge(instruction.len * table::BOOLEAN);
+                }
+                for (if_true, if_false) in if_true.into_iter().zip(if_false.into_iter()) {
+                    self.stack.push(Operand {
+                        scalar_type: if_true.scalar_type.or(if_false.scalar_type),
+                        is_constant: condition.is_constant
+                            && if_true.is_constant
+                            && if_false.is_constant,
+                    });
+                }
+            }
+
+            Instruction::If(_) => {
+                let condition = self.pop();
+                let is_witness = !condition.is_constant || self.is_witness_condition();
+                if is_witness {
+                    self.charge(table::BOOLEAN);
+                }
+                self.conditions.push(is_witness);
+                self.branches.push(Branch {
+                    stack: self.stack.clone(),
+                    then_stack: None,
+                    is_witness,
+                    stores: BTreeSet::new(),
+                });
+            }
+            Instruction::Else(_) => {
+                let mut is_witness = false;
+                if let Some(branch) = self.branches.last_mut() {
+                    let stack = std::mem::replace(&mut self.stack, branch.stack.clone());
+                    branch.then_stack = Some(stack);
+                    is_witness = branch.is_witness;
+                }
+                if is_witness {
+                    self.charge(2 * table::BOOLEAN);
+                }
+            }
+            Instruction::EndIf(_) => {
+                self.conditions.pop();
+                if let Some(branch) = self.branches.pop() {
+                    let merged = match branch.then_stack {
+                        Some(then_stack) => then_stack.len().saturating_sub(branch.stack.len()),
+                        None => {
+                            self.stack = branch.stack;
+                            0
+                        }
+                    };
+                    if branch.is_witness {
+                        self.charge((merged + branch.stores.len()) * table::BOOLEAN);
+                    }
+                    if let Some(parent) = self.branches.last_mut() {
+                        parent.stores.extend(branch.stores);
+                    }
+                }
+            }
+            Instruction::LoopBegin(instruction) => {
+                self.loops.push(self.multiplier);
+                self.multiplier = self.multiplier.saturating_mul(instruction.iterations);
+            }
+            Instruction::LoopEnd(_) => {
+                if let Some(multiplier) = self.loops.pop() {
+                    self.multiplier = multiplier;
+                }
+            }
+            Instruction::Call(instruction) => {
+                if self.frames.len() >= MAX_CALL_DEPTH {
+                    return false;
+                }
+                let arguments = self.pop_many(instruction.inputs_count);
+                self.frames.push(Frame {
+                    memory: arguments.into_iter().enumerate().collect(),
+                    return_address: self.pc,
+                });
+                self.pc = instruction.address;
+            }
+            Instruction::Return(instruction) => {
+                let outputs = self.pop_many(instruction.outputs_count);
+                match self.frames.pop() {
+                    Some(frame) => self.pc = frame.return_address,
+                    None => return false,
+                }
+                self.stack.extend(outputs);
+            }
+            Instruction::CallBuiltin(instruction) => {
+                self.pop_many(instruction.inputs_count);
+                for _ in 0..instruction.outputs_count {
+                    self.stack.push(Operand::UNKNOWN);
+                }
+                self.estimate.unknown += self.multiplier;
+            }
+
+            Instruction::Assert(_) => {
+                if !self.pop().is_constant {
+                    self.charge(table::ASSERT);
+                }
+            }
+            Instruction::Dbg(instruction) => {
+                let size = instruction.arg_types.iter().map(DataType::size).sum();
+                self.pop_many(size);
+            }
+            Instruction::Exit(instruction) => {
+                self.charge(instruction.outputs_count * table::OUTPUT);
+                return false;
+            }
+            Instruction::MarkPublic(instruction) => {
+                let values = self.pop_many(instruction.count);
+                self.stack.extend(values);
+                self.charge(instruction.count * table::OUTPUT);
+            }
+        }
+
+        true
+    }
+
+    fn charge(&mut self, constraints: usize) {
+        self.estimate.constraints = self
+            .estimate
+            .constraints
+            .saturating_add(constraints.saturating_mul(self.multiplier));
+    }
+
+    fn is_witness_condition(&self) -> bool {
+        self.conditions.last().cloned().unwrap_or(false)
+    }
+
+    /// The type check of the result, which also selects the checked value in the witness
+    /// branches.
+    fn type_check(scalar_type: ScalarType) -> usize {
+        match scalar_type {
+            ScalarType::Field => 0,
+            ScalarType::Boolean => table::type_check(1),
+            ScalarType::Integer(int_type) => table::type_check(int_type.bitlength),
+        }
+    }
+
+    fn type_check_of(operand: &Operand) -> usize {
+        operand.scalar_type.map_or(0, Self::type_check)
+    }
+
+    fn unary<F>(&mut self, cost: F, result_type: Option<ScalarType>)
+    where
+        F: Fn(&Operand) -> usize,
+    {
+        let operand = self.pop();
+        self.charge_operation(&operand, operand.is_constant, cost);
+        self.stack.push(Operand {
+            scalar_type: result_type.or(operand.scalar_type),
+            is_constant: operand.is_constant,
+        });
+    }
+
+    fn binary<F>(&mut self, cost: F, result_type: Option<ScalarType>)
+    where
+        F: Fn(&Operand) -> usize,
+    {
+        let right = self.pop();
+        let left = self.pop();
+        let is_constant = left.is_constant && right.is_constant;
+        let operand = Operand {
+            scalar_type: left.scalar_type.or(right.scalar_type),
+            is_constant,
+        };
+        self.charge_operation(&operand, is_constant, cost);
+        self.stack.push(Operand {
+            scalar_type: result_type.or(operand.scalar_type),
+            is_constant,
+        });
+    }
+
+    fn charge_operation<F>(&mut self, operand: &Operand, is_constant: bool, cost: F)
+    where
+        F: Fn(&Operand) -> usize,
+    {
+        if is_constant {
+            return;
+        }
+
+        let mut constraints = cost(operand);
+        if self.is_witness_condition() {
+            constraints += table::BOOLEAN;
+        }
+        self.charge(constraints);
+    }
+
+    fn pop(&mut self) -> Operand {
+        self.stack.pop().unwrap_or(Operand::UNKNOWN)
+    }
+
+    /// Pops `count` values and returns them in the order they were pushed.
+    fn pop_many(&mut self, count: usize) -> Vec<Operand> {
+        let mut values: Vec<Operand> = (0..count).map(|_| self.pop()).collect();
+        values.reverse();
+        values
+    }
+
+    fn push_selected(&mut self, value: Operand, index: Operand, len: usize) {
+        if index.is_constant {
+            self.stack.push(value);
+        } else {
+            self.charge(table::array_access(len));
+            self.stack.push(Operand::witness(value.scalar_type));
+        }
+    }
+
+    fn frame(&mut self, is_global: bool) -> Option<&mut Frame> {
+        if is_global {
+            self.frames.first_mut()
+        } else {
+            self.frames.last_mut()
+        }
+    }
+
+    fn load(&mut self, address: usize, len: usize, is_global: bool) {
+        for offset in 0..len {
+            let value = self
+                .frame(is_global)
+                .and_then(|frame| frame.memory.get(&(address + offset)).cloned())
+                .unwrap_or(Operand::UNKNOWN);
+            self.stack.push(value);
+        }
+    }
+
+    fn load_by_index(
+        &mut self,
+        address: usize,
+        array_len: usize,
+        value_len: usize,
+        is_global: bool,
+    ) {
+        let index = self.pop();
+        for offset in 0..value_len {
+            let value = self
+                .frame(is_global)
+                .and_then(|frame| frame.memory.get(&(address + offset)).cloned())
+                .unwrap_or(Operand::UNKNOWN);
+            self.push_selected(value, index, array_len);
+        }
+    }
+
+    fn store(&mut self, address: usize, len: usize, is_global: bool) {
+        let values = self.pop_many(len);
+        if let Some(frame) = self.frame(is_global) {
+            for (offset, value) in values.into_iter().enumerate() {
+                frame.memory.insert(address + offset, value);
+            }
+        }
+
+        // The addresses of different frames are not distinguished, which is enough for the
+        // branches that do not contain calls.
+        if let Some(branch) = self.branches.last_mut() {
+            branch
+                .stores
+                .extend((0..len).map(|offset| address + offset));
+        }
+    }
+
+    fn store_by_index(&mut self, address: usize, array_len: usize, value_len: usize) {
+        let values = self.pop_many(value_len);
+        let index = self.pop();
+        let cost = if index.is_constant {
+            0
+        } else {
+            table::array_access(array_len) + array_len
+        };
+        self.charge(cost * value_len);
+
+        if let Some(frame) = self.frames.last_mut() {
+            for offset in 0..array_len {
+                let value = values
+                    .get(offset % value_len.max(1))
+                    .cloned()
+                    .unwrap_or(Operand::UNKNOWN);
+                let stored = if index.is_constant {
+                    value
+                } else {
+                    Operand::witness(value.scalar_type)
+                };
+                frame.memory.insert(address + offset, stored);
+            }
+        }
+
+        if let Some(branch) = self.branches.last_mut() {
+            branch
+                .stores
+                .extend((0..array_len).map(|offset| address + offset));
+        }
+    }
+}
+
+/// Appends the scalar types of `data_type` to `types` in the order of the flat values.
+fn flatten(data_type: &DataType, types: &mut Vec<ScalarType>) {
+    match data_type {
+        DataType::Unit => {}
+        DataType::Scalar(scalar_type) => types.push(*scalar_type),
+        DataType::Enum => types.push(ScalarType::Field),
+        DataType::Struct(fields) => {
+            for (_, field) in fields.iter() {
+                flatten(field, types);
+            }
+        }
+        DataType::Tuple(elements) => {
+            for element in elements.iter() {
+                flatten(element, types);
+            }
+        }
+        DataType::Array(element, size) => {
+            for _ in 0..*size {
+                flatten(element, types);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::*;
+    use crate::scalar::IntegerType;
+    use crate::InstructionInfo;
+
+    fn u8_type() -> DataType {
+        DataType::Scalar(IntegerType::U8.into())
+    }
+
+    #[test]
+    fn test_constants_are_free() {
+        let program = Program::new(
+            DataType::Unit,
+            u8_type(),
+            vec![
+                Call::new(2, 0).wrap(),
+                Exit::new(1).wrap(),
+                PushConst::new(2.into(), IntegerType::U8.into()).wrap(),
+                PushConst::new(3.into(), IntegerType::U8.into()).wrap(),
+                Mul::default().wrap(),
+                Return::new(1).wrap(),
+            ],
+        );
+
+        let estimate = estimate_cost(&program);
+        assert_eq!(estimate.constraints, table::SETUP + table::OUTPUT);
+        assert_eq!(estimate.instructions, program.bytecode.len());
+    }
+
+    #[test]
+    fn test_loop_body_is_multiplied() {
+        let program = Program::new(
+            u8_type(),
+            u8_type(),
+            vec![
+                Call::new(2, 1).wrap(),
+                Exit::new(1).wrap(),
+                LoopBegin::new(5).wrap(),
+                Load::new(0).wrap(),
+                PushConst::new(1.into(), IntegerType::U8.into()).wrap(),
+                Add::default().wrap(),
+                Store::new(0).wrap(),
+                LoopEnd.wrap(),
+                Load::new(0).wrap(),
+                Return::new(1).wrap(),
+            ],
+        );
+
+        let check = table::type_check(8);
+        let estimate = estimate_cost(&program);
+        assert_eq!(
+            estimate.constraints,
+            table::SETUP + check + 5 * (table::ARITHMETIC + check) + table::OUTPUT
+        );
+        assert_eq!(estimate.instructions, 5 + 5 * 5);
+    }
+}
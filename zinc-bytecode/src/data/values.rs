@@ -29,13 +29,13 @@ where
         .map_err(|_| D::Error::invalid_value(serde::de::Unexpected::Str(&str), &"a decimal number"))
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct StructField {
     pub field: String,
     pub value: Value,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ScalarValue {
     Field(BigInt),
     Bool(bool),
@@ -57,7 +57,11 @@ impl ScalarValue {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A plain data value, which can be used as a map key.
+///
+/// Values are ordered by their variant in the declaration order, then by their contents:
+/// scalars by kind and then by value, structure fields and array elements lexicographically.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Value {
     Unit,
@@ -203,9 +207,13 @@ impl Value {
             DataType::Unit => Self::unit_from_json(value),
             DataType::Scalar(t) => Self::scalar_from_json(value, t),
             DataType::Enum => Self::field_from_json(value),
-            DataType::Struct(fields) => Self::struct_from_json(value, fields),
-            DataType::Tuple(dtype) => Self::tuple_from_json(value, dtype),
-            DataType::Array(dtype, size) => Self::array_from_json(value, dtype, *size),
+            DataType::Struct(fields) => {
+                Self::struct_from_json(value, fields, Self::from_typed_json)
+            }
+            DataType::Tuple(dtype) => Self::tuple_from_json(value, dtype, Self::from_typed_json),
+            DataType::Array(dtype, size) => {
+                Self::array_from_json(value, dtype, *size, Self::from_typed_json)
+            }
         }
     }
 
@@ -234,10 +242,18 @@ impl Value {
     }
 
     fn field_from_json(value: &json::Value) -> Result<Self, JsonValueError> {
+        let bigint = Self::bigint_from_json(value, "field (number string)")?;
+
+        // TODO: overflow check.
+
+        Ok(Value::Scalar(ScalarValue::Field(bigint)))
+    }
+
+    fn bigint_from_json(value: &json::Value, expected: &str) -> Result<BigInt, JsonValueError> {
         let value_string = value
             .as_str()
             .ok_or_else(|| JsonValueErrorType::TypeError {
-                expected: "field (number string)".into(),
+                expected: expected.into(),
                 actual: value.to_string(),
             })?;
 
@@ -247,12 +263,8 @@ impl Value {
             BigInt::from_str_radix(value_string, 10)
         };
 
-        let bigint = bigint_result
-            .map_err(|_| JsonValueErrorType::InvalidNumberFormat(value_string.into()))?;
-
-        // TODO: overflow check.
-
-        Ok(Value::Scalar(ScalarValue::Field(bigint)))
+        bigint_result
+            .map_err(|_| JsonValueErrorType::InvalidNumberFormat(value_string.into()).into())
     }
 
     fn boolean_from_json(value: &json::Value) -> Result<Self, JsonValueError> {
@@ -266,17 +278,30 @@ impl Value {
         Ok(Value::Scalar(ScalarValue::Bool(value_bool)))
     }
 
-    fn integer_from_json(
-        value: &json::Value,
-        _itype: &IntegerType,
-    ) -> Result<Self, JsonValueError> {
-        // TODO: overflow check.
-        Self::field_from_json(value)
+    fn integer_from_json(value: &json::Value, itype: &IntegerType) -> Result<Self, JsonValueError> {
+        let bigint = Self::bigint_from_json(value, "integer (number string)")?;
+
+        let (min, max) = if itype.is_signed {
+            let half = BigInt::from(1) << (itype.bitlength - 1);
+            (-half.clone(), half - 1)
+        } else {
+            (BigInt::from(0), (BigInt::from(1) << itype.bitlength) - 1)
+        };
+        if bigint < min || bigint > max {
+            return Err(JsonValueErrorType::ValueOverflow {
+                value: bigint,
+                scalar_type: ScalarType::Integer(*itype),
+            }
+            .into());
+        }
+
+        Ok(Value::Scalar(ScalarValue::Integer(bigint, *itype)))
     }
 
     fn struct_from_json(
         value: &json::Value,
         field_types: &[(String, DataType)],
+        element_from_json: ElementFromJson,
     ) -> Result<Self, JsonValueError> {
         let object = value
             .as_object()
@@ -291,7 +316,7 @@ impl Value {
                 .get(name)
                 .ok_or_else(|| JsonValueErrorType::MissingField(name.clone()))?;
 
-            let typed_value = Self::from_typed_json(json_value, dtype).in_struct(name.as_str())?;
+            let typed_value = element_from_json(json_value, dtype).in_struct(name.as_str())?;
 
             field_values.push(StructField {
                 field: name.clone(),
@@ -308,7 +333,11 @@ impl Value {
         Ok(Value::Struct(field_values))
     }
 
-    fn tuple_from_json(value: &json::Value, types: &[DataType]) -> Result<Self, JsonValueError> {
+    fn tuple_from_json(
+        value: &json::Value,
+        types: &[DataType],
+        element_from_json: ElementFromJson,
+    ) -> Result<Self, JsonValueError> {
         let array = value
             .as_array()
             .ok_or_else(|| JsonValueErrorType::type_error("tuple (json array)", value))?;
@@ -323,7 +352,7 @@ impl Value {
 
         let mut values = Vec::with_capacity(types.len());
         for (index, (value, dtype)) in array.iter().zip(types).enumerate() {
-            let typed_value = Self::from_typed_json(value, dtype).in_array(index)?;
+            let typed_value = element_from_json(value, dtype).in_array(index)?;
             values.push(typed_value);
         }
 
@@ -334,6 +363,7 @@ impl Value {
         value: &json::Value,
         dtype: &DataType,
         size: usize,
+        element_from_json: ElementFromJson,
     ) -> Result<Self, JsonValueError> {
         let array = value
             .as_array()
@@ -349,7 +379,7 @@ impl Value {
 
         let mut values = Vec::with_capacity(size);
         for (index, value) in array.iter().enumerate() {
-            let typed_value = Self::from_typed_json(value, dtype).in_array(index)?;
+            let typed_value = element_from_json(value, dtype).in_array(index)?;
 
             values.push(typed_value);
         }
@@ -358,6 +388,136 @@ impl Value {
     }
 }
 
+/// Converts a JSON element of a structure, tuple or array, so both JSON forms share the parsing
+/// of composite values.
+type ElementFromJson = fn(&json::Value, &DataType) -> Result<Value, JsonValueError>;
+
+// Witness json de/serialization
+impl Value {
+    /// Converts the value into json, which is converted back by `from_json` with the value type.
+    ///
+    /// Fields and integers are decimal strings, since they may exceed `u64`. Booleans are json
+    /// booleans, the unit is `null`, arrays and tuples are json arrays, and structures are
+    /// objects keyed by the field names. Enumeration values are written as their integer values,
+    /// since `DataType::Enum` does not keep the variant names.
+    pub fn into_json(self) -> json::Value {
+        match self {
+            Value::Unit => json::Value::Null,
+            Value::Scalar(scalar) => match scalar {
+                ScalarValue::Field(value) | ScalarValue::Integer(value, _) => {
+                    json::Value::String(value.to_str_radix(10))
+                }
+                ScalarValue::Bool(value) => json::Value::Bool(value),
+            },
+            Value::Struct(fields) => json::Value::Object(
+                fields
+                    .into_iter()
+                    .map(|field| (field.field, field.value.into_json()))
+                    .collect(),
+            ),
+            Value::Array(values) => {
+                json::Value::Array(values.into_iter().map(Self::into_json).collect())
+            }
+        }
+    }
+
+    /// Converts the json written by `into_json` into the value of `data_type`.
+    ///
+    /// Integers must fit into their types. The error names the json path of the mismatching
+    /// value, e.g. `point.x` or `[1].flag`.
+    pub fn from_json(data_type: &DataType, value: &json::Value) -> Result<Self, JsonValueError> {
+        match data_type {
+            DataType::Unit if value.is_null() => Ok(Value::Unit),
+            DataType::Unit => Err(JsonValueErrorType::type_error("null", value).into()),
+            DataType::Scalar(scalar_type) => Self::scalar_from_json(value, scalar_type),
+            DataType::Enum => Self::field_from_json(value),
+            DataType::Struct(fields) => {
+                Self::struct_from_json(value, fields, |value, data_type| {
+                    Self::from_json(data_type, value)
+                })
+            }
+            DataType::Tuple(types) => Self::tuple_from_json(value, types, |value, data_type| {
+                Self::from_json(data_type, value)
+            }),
+            DataType::Array(data_type, size) => {
+                Self::array_from_json(value, data_type, *size, |value, data_type| {
+                    Self::from_json(data_type, value)
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for ScalarValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ScalarValue::Field(value) => write!(f, "{}: {}", value, ScalarType::Field),
+            ScalarValue::Bool(value) => write!(f, "{}: {}", value, ScalarType::Boolean),
+            ScalarValue::Integer(value, int_type) => {
+                write!(f, "{}: {}", value, ScalarType::Integer(*int_type))
+            }
+        }
+    }
+}
+
+/// Formats the value with the scalar types, e.g. `{ x: 5: u8, flag: true: bool }`.
+///
+/// The alternate form `{:#}` prints each array element and structure field on a separate
+/// line, indenting the nested values.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if f.alternate() {
+            return self.fmt_indented(f, 0);
+        }
+
+        match self {
+            Value::Unit => write!(f, "()"),
+            Value::Scalar(scalar) => write!(f, "{}", scalar),
+            Value::Array(values) => {
+                let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                write!(f, "[{}]", values.join(", "))
+            }
+            Value::Struct(fields) if fields.is_empty() => write!(f, "{{}}"),
+            Value::Struct(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|field| format!("{}: {}", field.field, field.value))
+                    .collect();
+                write!(f, "{{ {} }}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl Value {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> Result<(), fmt::Error> {
+        let indent = "    ".repeat(depth + 1);
+        let closing_indent = "    ".repeat(depth);
+
+        match self {
+            Value::Array(values) if !values.is_empty() => {
+                writeln!(f, "[")?;
+                for value in values.iter() {
+                    write!(f, "{}", indent)?;
+                    value.fmt_indented(f, depth + 1)?;
+                    writeln!(f, ",")?;
+                }
+                write!(f, "{}]", closing_indent)
+            }
+            Value::Struct(fields) if !fields.is_empty() => {
+                writeln!(f, "{{")?;
+                for field in fields.iter() {
+                    write!(f, "{}{}: ", indent, field.field)?;
+                    field.value.fmt_indented(f, depth + 1)?;
+                    writeln!(f, ",")?;
+                }
+                write!(f, "{}}}", closing_indent)
+            }
+            value => write!(f, "{}", value),
+        }
+    }
+}
+
 #[derive(Debug, Fail)]
 pub struct JsonValueError {
     path: Vec<String>,
@@ -422,6 +582,12 @@ pub enum JsonValueErrorType {
     )]
     InvalidNumberFormat(String),
 
+    #[fail(display = "value {} is out of range of type {}", value, scalar_type)]
+    ValueOverflow {
+        value: BigInt,
+        scalar_type: ScalarType,
+    },
+
     #[fail(display = "value for field \"{}\" is missing", _0)]
     MissingField(String),
 
@@ -452,3 +618,343 @@ impl JsonValueErrorType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn round_trip(json: json::Value, dtype: DataType) {
+        let value = Value::from_typed_json(&json, &dtype).expect("valid value");
+        assert_eq!(value.to_json(), json);
+    }
+
+    fn point_type() -> DataType {
+        DataType::Struct(vec![
+            ("x".to_owned(), DataType::Scalar(IntegerType::U8.into())),
+            ("y".to_owned(), DataType::Scalar(IntegerType::I8.into())),
+        ])
+    }
+
+    #[test]
+    fn test_round_trip_scalars() {
+        round_trip(json!("unit"), DataType::Unit);
+        round_trip(json!(true), DataType::Scalar(ScalarType::Boolean));
+        round_trip(json!("42"), DataType::Scalar(ScalarType::Field));
+        round_trip(
+            json!("0xffffffffffffffffff"),
+            DataType::Scalar(ScalarType::Field),
+        );
+        round_trip(json!("255"), DataType::Scalar(IntegerType::U8.into()));
+        round_trip(json!("-128"), DataType::Scalar(IntegerType::I8.into()));
+        round_trip(
+            json!("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+            DataType::Scalar(ScalarType::Integer(IntegerType {
+                is_signed: false,
+                bitlength: 248,
+            })),
+        );
+        round_trip(json!("3"), DataType::Enum);
+    }
+
+    #[test]
+    fn test_round_trip_composites() {
+        round_trip(
+            json!(["1", "2", "3"]),
+            DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 3),
+        );
+        round_trip(
+            json!([true, "-1"]),
+            DataType::Tuple(vec![
+                DataType::Scalar(ScalarType::Boolean),
+                DataType::Scalar(IntegerType::I8.into()),
+            ]),
+        );
+        round_trip(json!({ "x": "1", "y": "-2" }), point_type());
+        round_trip(
+            json!({ "points": [{ "x": "1", "y": "-2" }, { "x": "3", "y": "4" }] }),
+            DataType::Struct(vec![(
+                "points".to_owned(),
+                DataType::Array(Box::new(point_type()), 2),
+            )]),
+        );
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        for (json, dtype) in [
+            (json!("256"), DataType::Scalar(IntegerType::U8.into())),
+            (json!("-1"), DataType::Scalar(IntegerType::U8.into())),
+            (json!("128"), DataType::Scalar(IntegerType::I8.into())),
+            (json!("-129"), DataType::Scalar(IntegerType::I8.into())),
+        ]
+        .iter()
+        {
+            let error = Value::from_typed_json(json, dtype).expect_err("overflow");
+            match error.error {
+                JsonValueErrorType::ValueOverflow { .. } => {}
+                error => panic!("unexpected error: {}", error),
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_path() {
+        let dtype = DataType::Struct(vec![("point".to_owned(), point_type())]);
+
+        let error = Value::from_typed_json(&json!({ "point": { "x": "256", "y": "0" } }), &dtype)
+            .expect_err("overflow");
+        assert_eq!(
+            error.to_string(),
+            "value 256 is out of range of type u8 at point.x"
+        );
+
+        let dtype = DataType::Array(Box::new(dtype), 2);
+        let error = Value::from_typed_json(
+            &json!([{ "point": { "x": "1", "y": "0" } }, { "point": { "x": "1", "y": true } }]),
+            &dtype,
+        )
+        .expect_err("type mismatch");
+        assert_eq!(
+            error.to_string(),
+            "type mismatch: expected integer (number string), got true at [1].point.y"
+        );
+    }
+
+    fn round_trip_witness(json: json::Value, data_type: DataType) {
+        let value = Value::from_json(&data_type, &json).expect("valid value");
+        let written = value.clone().into_json();
+        assert_eq!(written, json);
+        assert_eq!(
+            Value::from_json(&data_type, &written).expect("valid value"),
+            value
+        );
+    }
+
+    #[test]
+    fn test_witness_round_trip_scalars() {
+        round_trip_witness(json!(null), DataType::Unit);
+        round_trip_witness(json!(false), DataType::Scalar(ScalarType::Boolean));
+        round_trip_witness(json!("42"), DataType::Scalar(ScalarType::Field));
+        round_trip_witness(
+            json!("18446744073709551616"),
+            DataType::Scalar(ScalarType::Field),
+        );
+        round_trip_witness(json!("255"), DataType::Scalar(IntegerType::U8.into()));
+        round_trip_witness(json!("-128"), DataType::Scalar(IntegerType::I8.into()));
+        round_trip_witness(
+            json!("340282366920938463463374607431768211455"),
+            DataType::Scalar(ScalarType::Integer(IntegerType {
+                is_signed: false,
+                bitlength: 128,
+            })),
+        );
+        round_trip_witness(json!("3"), DataType::Enum);
+    }
+
+    #[test]
+    fn test_witness_round_trip_composites() {
+        round_trip_witness(
+            json!(["1", "2", "3"]),
+            DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 3),
+        );
+        round_trip_witness(
+            json!([]),
+            DataType::Array(Box::new(DataType::Scalar(ScalarType::Boolean)), 0),
+        );
+        round_trip_witness(
+            json!([null, true, "-1"]),
+            DataType::Tuple(vec![
+                DataType::Unit,
+                DataType::Scalar(ScalarType::Boolean),
+                DataType::Scalar(IntegerType::I8.into()),
+            ]),
+        );
+        round_trip_witness(json!({ "x": "1", "y": "-2" }), point_type());
+        round_trip_witness(
+            json!({ "points": [{ "x": "1", "y": "-2" }, { "x": "3", "y": "4" }] }),
+            DataType::Struct(vec![(
+                "points".to_owned(),
+                DataType::Array(Box::new(point_type()), 2),
+            )]),
+        );
+    }
+
+    #[test]
+    fn test_witness_into_json_decimal() {
+        let value = Value::Scalar(ScalarValue::Field(BigInt::from(1) << 64));
+        assert_eq!(value.clone().to_json(), json!("0x10000000000000000"));
+        assert_eq!(value.into_json(), json!("18446744073709551616"));
+    }
+
+    #[test]
+    fn test_witness_unit_expected_null() {
+        let error = Value::from_json(&DataType::Unit, &json!("unit")).expect_err("type mismatch");
+        assert_eq!(
+            error.to_string(),
+            "type mismatch: expected null, got string (\"unit\")"
+        );
+    }
+
+    #[test]
+    fn test_witness_error_path() {
+        let data_type = DataType::Struct(vec![("point".to_owned(), point_type())]);
+
+        let error = Value::from_json(&data_type, &json!({ "point": { "x": "256", "y": "0" } }))
+            .expect_err("overflow");
+        assert_eq!(
+            error.to_string(),
+            "value 256 is out of range of type u8 at point.x"
+        );
+
+        let data_type = DataType::Array(Box::new(data_type), 2);
+        let error = Value::from_json(
+            &data_type,
+            &json!([{ "point": { "x": "1", "y": "0" } }, { "point": { "x": "1", "y": "-129" } }]),
+        )
+        .expect_err("overflow");
+        assert_eq!(
+            error.to_string(),
+            "value -129 is out of range of type i8 at [1].point.y"
+        );
+    }
+
+    fn nested_value() -> Value {
+        let point = |x: u8, is_visible: bool| {
+            Value::Struct(vec![
+                StructField {
+                    field: "x".to_owned(),
+                    value: Value::Scalar(ScalarValue::Integer(x.into(), IntegerType::U8)),
+                },
+                StructField {
+                    field: "is_visible".to_owned(),
+                    value: Value::Scalar(ScalarValue::Bool(is_visible)),
+                },
+            ])
+        };
+
+        Value::Struct(vec![
+            StructField {
+                field: "points".to_owned(),
+                value: Value::Array(vec![point(5, true), point(12, false)]),
+            },
+            StructField {
+                field: "total".to_owned(),
+                value: Value::Scalar(ScalarValue::Field(17.into())),
+            },
+            StructField {
+                field: "empty".to_owned(),
+                value: Value::Array(vec![]),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_display_compact() {
+        assert_eq!(
+            nested_value().to_string(),
+            "{ points: [{ x: 5: u8, is_visible: true: bool }, \
+             { x: 12: u8, is_visible: false: bool }], total: 17: field, empty: [] }"
+        );
+        assert_eq!(Value::Unit.to_string(), "()");
+        assert_eq!(
+            Value::Scalar(ScalarValue::Integer((-1).into(), IntegerType::I8)).to_string(),
+            "-1: i8"
+        );
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let expected = r#"{
+    points: [
+        {
+            x: 5: u8,
+            is_visible: true: bool,
+        },
+        {
+            x: 12: u8,
+            is_visible: false: bool,
+        },
+    ],
+    total: 17: field,
+    empty: [],
+}"#;
+        assert_eq!(format!("{:#}", nested_value()), expected);
+        assert_eq!(format!("{:#}", Value::Unit), "()");
+    }
+
+    fn samples() -> Vec<Value> {
+        let scalar = |value: ScalarValue| Value::Scalar(value);
+
+        vec![
+            Value::Unit,
+            scalar(ScalarValue::Field(0.into())),
+            scalar(ScalarValue::Field(17.into())),
+            scalar(ScalarValue::Bool(false)),
+            scalar(ScalarValue::Bool(true)),
+            scalar(ScalarValue::Integer((-1).into(), IntegerType::I8)),
+            scalar(ScalarValue::Integer(5.into(), IntegerType::U8)),
+            scalar(ScalarValue::Integer(5.into(), IntegerType::I8)),
+            nested_value(),
+            Value::Array(vec![]),
+            Value::Array(vec![scalar(ScalarValue::Bool(true))]),
+            Value::Array(vec![
+                scalar(ScalarValue::Bool(true)),
+                scalar(ScalarValue::Bool(false)),
+            ]),
+        ]
+    }
+
+    fn hash(value: &Value) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_equal_values_hash_equally() {
+        for value in samples().into_iter() {
+            assert_eq!(value, value.clone());
+            assert_eq!(hash(&value), hash(&value.clone()), "{}", value);
+        }
+
+        let mut map = std::collections::HashMap::new();
+        for (index, value) in samples().into_iter().enumerate() {
+            map.insert(value, index);
+        }
+        for (index, value) in samples().iter().enumerate() {
+            assert_eq!(map.get(value), Some(&index), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_ordering_is_consistent_with_equality() {
+        use std::cmp::Ordering;
+
+        let samples = samples();
+        for (left_index, left) in samples.iter().enumerate() {
+            for (right_index, right) in samples.iter().enumerate() {
+                assert_eq!(
+                    left.cmp(right) == Ordering::Equal,
+                    left == right,
+                    "{} and {}",
+                    left,
+                    right
+                );
+                assert_eq!(left.cmp(right), right.cmp(left).reverse());
+                assert_eq!(left.partial_cmp(right), Some(left.cmp(right)));
+                assert_eq!(
+                    left.cmp(right),
+                    left_index.cmp(&right_index),
+                    "{} and {}",
+                    left,
+                    right
+                );
+            }
+        }
+    }
+}
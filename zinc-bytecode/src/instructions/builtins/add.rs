@@ -2,11 +2,24 @@ use crate::{Instruction, InstructionInfo};
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
-pub struct Add;
+pub struct Add {
+    /// Skips the range check of the result, which is known to fit into the operands type.
+    pub is_unchecked: bool,
+}
+
+impl Add {
+    pub fn new(is_unchecked: bool) -> Self {
+        Self { is_unchecked }
+    }
+}
 
 impl InstructionInfo for Add {
     fn to_assembly(&self) -> String {
-        "add".into()
+        if self.is_unchecked {
+            "add_unchecked".into()
+        } else {
+            "add".into()
+        }
     }
 
     fn wrap(&self) -> Instruction {
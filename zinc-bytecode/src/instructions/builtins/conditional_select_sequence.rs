@@ -0,0 +1,28 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Selects between two sequences of `len` values element-wise.
+///
+/// The stack from the bottom is `true_1 .. true_len, false_1 .. false_len, condition`.
+/// All of them are popped, and the `true` sequence is pushed back if the condition is true,
+/// and the `false` sequence otherwise.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ConditionalSelectSequence {
+    pub len: usize,
+}
+
+impl ConditionalSelectSequence {
+    pub fn new(len: usize) -> Self {
+        Self { len }
+    }
+}
+
+impl InstructionInfo for ConditionalSelectSequence {
+    fn to_assembly(&self) -> String {
+        format!("select_sequence {}", self.len)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::ConditionalSelectSequence((*self).clone())
+    }
+}
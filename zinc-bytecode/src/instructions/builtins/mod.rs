@@ -2,6 +2,7 @@ mod add;
 mod div;
 mod mul;
 mod neg;
+mod pow;
 mod rem;
 mod sub;
 
@@ -17,12 +18,15 @@ mod le;
 mod lt;
 mod ne;
 
+mod conditional_select_sequence;
+
 mod binary;
 
 pub use add::Add;
 pub use div::Div;
 pub use mul::Mul;
 pub use neg::Neg;
+pub use pow::Pow;
 pub use rem::Rem;
 pub use sub::Sub;
 
@@ -38,4 +42,6 @@ pub use le::Le;
 pub use lt::Lt;
 pub use ne::Ne;
 
+pub use conditional_select_sequence::ConditionalSelectSequence;
+
 pub use binary::*;
@@ -2,11 +2,24 @@ use crate::{Instruction, InstructionInfo};
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
-pub struct Mul;
+pub struct Mul {
+    /// Skips the range check of the result, which is known to fit into the operands type.
+    pub is_unchecked: bool,
+}
+
+impl Mul {
+    pub fn new(is_unchecked: bool) -> Self {
+        Self { is_unchecked }
+    }
+}
 
 impl InstructionInfo for Mul {
     fn to_assembly(&self) -> String {
-        "mul".into()
+        if self.is_unchecked {
+            "mul_unchecked".into()
+        } else {
+            "mul".into()
+        }
     }
 
     fn wrap(&self) -> Instruction {
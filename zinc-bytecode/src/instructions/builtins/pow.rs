@@ -0,0 +1,27 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Raises the base to the power of the exponent, which has `exponent_bits` bits.
+///
+/// The stack from the bottom is `base, exponent`. Both are popped, and the power of the base
+/// type is pushed.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Pow {
+    pub exponent_bits: usize,
+}
+
+impl Pow {
+    pub fn new(exponent_bits: usize) -> Self {
+        Self { exponent_bits }
+    }
+}
+
+impl InstructionInfo for Pow {
+    fn to_assembly(&self) -> String {
+        format!("pow {}", self.exponent_bits)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::Pow((*self).clone())
+    }
+}
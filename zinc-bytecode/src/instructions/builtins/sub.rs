@@ -2,11 +2,24 @@ use crate::{Instruction, InstructionInfo};
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
-pub struct Sub;
+pub struct Sub {
+    /// Skips the range check of the result, which is known to fit into the operands type.
+    pub is_unchecked: bool,
+}
+
+impl Sub {
+    pub fn new(is_unchecked: bool) -> Self {
+        Self { is_unchecked }
+    }
+}
 
 impl InstructionInfo for Sub {
     fn to_assembly(&self) -> String {
-        "sub".into()
+        if self.is_unchecked {
+            "sub_unchecked".into()
+        } else {
+            "sub".into()
+        }
     }
 
     fn wrap(&self) -> Instruction {
@@ -0,0 +1,26 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Checks that the value on the top of the stack fits in `bitlength` unsigned bits.
+///
+/// The value is left on the stack with its type unchanged.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CheckRange {
+    pub bitlength: usize,
+}
+
+impl CheckRange {
+    pub fn new(bitlength: usize) -> Self {
+        Self { bitlength }
+    }
+}
+
+impl InstructionInfo for CheckRange {
+    fn to_assembly(&self) -> String {
+        format!("check_range {}", self.bitlength)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::CheckRange((*self).clone())
+    }
+}
@@ -2,6 +2,10 @@ use crate::{Instruction, InstructionInfo};
 
 use serde_derive::{Deserialize, Serialize};
 
+/// Executes the instructions up to the matching `LoopEnd` `iterations` times.
+///
+/// The iterations share the stack frame of the enclosing function, so the loop counter and
+/// the accumulated values are stored in its variables.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct LoopBegin {
     pub iterations: usize,
@@ -0,0 +1,27 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Makes the `count` values on the top of the stack public inputs, leaving them on the stack.
+///
+/// The marked values precede the program output in the public inputs, in the order they are
+/// marked, and their types are described by `Program::public`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MarkPublic {
+    pub count: usize,
+}
+
+impl MarkPublic {
+    pub fn new(count: usize) -> Self {
+        Self { count }
+    }
+}
+
+impl InstructionInfo for MarkPublic {
+    fn to_assembly(&self) -> String {
+        format!("mark_public {}", self.count)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::MarkPublic((*self).clone())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Discards `count` elements from the top of the stack.
+///
+/// It works like `Pop`, but is written by the generator to clean up the elements left after
+/// the stack is reordered with `Swap` and `Dup`, so the reordering reads as a group.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Drop {
+    pub count: usize,
+}
+
+impl Drop {
+    pub fn new(count: usize) -> Self {
+        Self { count }
+    }
+}
+
+impl InstructionInfo for Drop {
+    fn to_assembly(&self) -> String {
+        format!("drop {}", self.count)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::Drop((*self).clone())
+    }
+}
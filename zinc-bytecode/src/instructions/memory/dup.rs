@@ -0,0 +1,27 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Copies the element `depth` positions below the top of the stack to the top.
+///
+/// The depth of the top element is zero, so `Dup::new(0)` is the same as `Tee`. Only single
+/// elements are copied, so a composite value must be copied element by element.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Dup {
+    pub depth: usize,
+}
+
+impl Dup {
+    pub fn new(depth: usize) -> Self {
+        Self { depth }
+    }
+}
+
+impl InstructionInfo for Dup {
+    fn to_assembly(&self) -> String {
+        format!("dup {}", self.depth)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::Dup((*self).clone())
+    }
+}
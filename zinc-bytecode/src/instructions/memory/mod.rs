@@ -1,3 +1,5 @@
+mod drop;
+mod dup;
 mod pop;
 mod push_const;
 mod slice;
@@ -22,6 +24,8 @@ mod load_sequence_global;
 mod store_global;
 mod store_sequence_global;
 
+pub use drop::Drop;
+pub use dup::Dup;
 pub use pop::Pop;
 pub use push_const::PushConst;
 pub use slice::Slice;
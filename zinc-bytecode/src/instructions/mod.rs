@@ -11,11 +11,15 @@ pub use memory::*;
 mod assert;
 mod call_buitin;
 mod cast;
+mod check_range;
 mod dbg;
+mod mark_public;
 mod noop;
 
 pub use assert::Assert;
 pub use call_buitin::CallBuiltin;
 pub use cast::Cast;
+pub use check_range::CheckRange;
 pub use dbg::Dbg;
+pub use mark_public::MarkPublic;
 pub use noop::NoOperation;
@@ -2,11 +2,13 @@ pub mod logger;
 pub mod scalar;
 
 pub mod builtins;
+pub mod cost;
 pub mod data;
 pub mod instructions;
 pub mod program;
 pub mod vlq;
 
+pub use cost::{estimate_cost, CostEstimate};
 pub use instructions::*;
 pub use program::*;
 
@@ -37,6 +39,8 @@ pub enum Instruction {
     Slice(Slice),
     Swap(Swap),
     Tee(Tee),
+    Dup(Dup),
+    Drop(Drop),
 
     // Storage
     Load(Load),
@@ -64,6 +68,7 @@ pub enum Instruction {
     Div(Div),
     Rem(Rem),
     Neg(Neg),
+    Pow(Pow),
 
     // Boolean
     Not(Not),
@@ -88,6 +93,8 @@ pub enum Instruction {
     BitNot(BitNot),
 
     Cast(Cast),
+    CheckRange(CheckRange),
+    ConditionalSelectSequence(ConditionalSelectSequence),
 
     // Flow control
     If(If),
@@ -105,6 +112,7 @@ pub enum Instruction {
     Dbg(Dbg),
 
     Exit(Exit),
+    MarkPublic(MarkPublic),
 
     FileMarker(FileMarker),
     FunctionMarker(FunctionMarker),
@@ -132,6 +140,8 @@ macro_rules! dispatch_instruction {
             Instruction::Slice($pattern) => $expression,
             Instruction::Swap($pattern) => $expression,
             Instruction::Tee($pattern) => $expression,
+            Instruction::Dup($pattern) => $expression,
+            Instruction::Drop($pattern) => $expression,
 
             Instruction::Load($pattern) => $expression,
             Instruction::LoadSequence($pattern) => $expression,
@@ -157,6 +167,7 @@ macro_rules! dispatch_instruction {
             Instruction::Div($pattern) => $expression,
             Instruction::Rem($pattern) => $expression,
             Instruction::Neg($pattern) => $expression,
+            Instruction::Pow($pattern) => $expression,
 
             Instruction::Not($pattern) => $expression,
             Instruction::And($pattern) => $expression,
@@ -178,6 +189,8 @@ macro_rules! dispatch_instruction {
             Instruction::BitNot($pattern) => $expression,
 
             Instruction::Cast($pattern) => $expression,
+            Instruction::CheckRange($pattern) => $expression,
+            Instruction::ConditionalSelectSequence($pattern) => $expression,
 
             Instruction::If($pattern) => $expression,
             Instruction::Else($pattern) => $expression,
@@ -193,6 +206,7 @@ macro_rules! dispatch_instruction {
             Instruction::Dbg($pattern) => $expression,
 
             Instruction::Exit($pattern) => $expression,
+            Instruction::MarkPublic($pattern) => $expression,
             Instruction::FileMarker($pattern) => $expression,
             Instruction::FunctionMarker($pattern) => $expression,
             Instruction::LineMarker($pattern) => $expression,
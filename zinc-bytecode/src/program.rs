@@ -6,6 +6,8 @@ use serde_derive::{Deserialize, Serialize};
 pub struct Program {
     pub input: DataType,
     pub output: DataType,
+    /// The type of the values made public by the `MarkPublic` instructions, in marking order.
+    pub public: DataType,
     pub bytecode: Vec<Instruction>,
 }
 
@@ -14,10 +16,25 @@ impl Program {
         Self {
             input,
             output,
+            public: DataType::Unit,
             bytecode,
         }
     }
 
+    pub fn with_public(mut self, public: DataType) -> Self {
+        self.public = public;
+        self
+    }
+
+    /// The type of the public inputs of the circuit, which are the values marked public
+    /// followed by the output.
+    pub fn public_input_type(&self) -> DataType {
+        match self.public {
+            DataType::Unit => self.output.clone(),
+            ref public => DataType::Tuple(vec![public.clone(), self.output.clone()]),
+        }
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         bincode::serialize(self).expect("Failed to serialize program")
     }
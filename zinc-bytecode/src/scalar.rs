@@ -9,7 +9,7 @@ pub enum ScalarType {
     Integer(IntegerType),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct IntegerType {
     pub is_signed: bool,
     pub bitlength: usize,
@@ -53,6 +53,11 @@ impl IntegerType {
         bitlength: 16,
     };
 
+    pub const U64: Self = IntegerType {
+        is_signed: false,
+        bitlength: 64,
+    };
+
     // Add more if needed for convenience...
 
     pub fn min(&self) -> BigInt {
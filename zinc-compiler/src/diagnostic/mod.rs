@@ -0,0 +1,183 @@
+//!
+//! The Zinc compiler diagnostic.
+//!
+
+mod tests;
+
+pub mod span;
+
+use colored::ColoredString;
+use colored::Colorize;
+use serde_derive::Serialize;
+
+use crate::lexical::token::location::Location;
+
+use self::span::Span;
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+///
+/// The compiler error or warning represented as data, which is useful for the IDE integrations.
+///
+/// The `code` is the stable error or warning code, e.g. `E0402`, whose explanation can be found
+/// with `crate::explain`. The lexical and file errors have no codes.
+/// The `secondary_spans` point to the related code, e.g. the previous declaration of an item.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub span: Option<Span>,
+    pub secondary_spans: Vec<Span>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// Creates a diagnostic without a location.
+    ///
+    pub fn message(message: &str, help: Option<&str>) -> Self {
+        Self {
+            severity: Severity::Error,
+            code: None,
+            message: message.to_owned(),
+            span: None,
+            secondary_spans: vec![],
+            help: help.map(|help| help.to_owned()),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a single location.
+    ///
+    pub fn line(message: &str, location: Location, help: Option<&str>) -> Self {
+        Self {
+            span: Some(Span::from(location)),
+            ..Self::message(message, help)
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to a single location, with an optional reference to the
+    /// related code.
+    ///
+    pub fn line_with_reference(
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            secondary_spans: reference.into_iter().map(Span::from).collect(),
+            ..Self::line(message, location, help)
+        }
+    }
+
+    ///
+    /// Creates a diagnostic pointing to the code between the `start` and `end` locations.
+    ///
+    pub fn range(message: &str, start: Location, end: Location, help: Option<&str>) -> Self {
+        Self {
+            span: Some(Span::new(start, end)),
+            ..Self::message(message, help)
+        }
+    }
+
+    pub fn set_severity(&mut self, severity: Severity) {
+        self.severity = severity;
+    }
+
+    pub fn set_code(&mut self, code: Option<&'static str>) {
+        self.code = code;
+    }
+
+    ///
+    /// Formats the diagnostic for the terminal output, printing the source code lines from the
+    /// `context`.
+    ///
+    pub fn format(&self, context: &[&str]) -> String {
+        let label = match self.severity {
+            Severity::Error => "error".bright_red(),
+            Severity::Warning => "warning".bright_yellow(),
+        };
+        let label = match self.code {
+            Some(code) => format!("{}{}", label, self.paint(format!("[{}]", code).as_str())),
+            None => label.to_string(),
+        };
+
+        let mut strings = Vec::with_capacity(11);
+        strings.push(String::new());
+        strings.push(format!("{}: {}", label, self.message.bright_white()));
+
+        if let Some(ref span) = self.span {
+            for reference in self.secondary_spans.iter() {
+                let line_number_length = reference.start_line.to_string().len();
+                strings.push(format!(
+                    "{}{}",
+                    " ".repeat(line_number_length + 1),
+                    "|".bright_cyan()
+                ));
+                if let Some(line) = context.get(reference.start_line - 1) {
+                    strings.push(format!(
+                        "{}{}",
+                        (reference.start_line.to_string() + " | ").bright_cyan(),
+                        line
+                    ));
+                }
+                strings.push(format!(
+                    "{}{} {}{}",
+                    " ".repeat(line_number_length + 1),
+                    "|".bright_cyan(),
+                    self.paint("_".repeat(reference.start_column - 1).as_str()),
+                    self.paint("^")
+                ));
+            }
+
+            let line_number_length = span.end_line.to_string().len();
+            strings.push(format!(" {} {}", "-->".bright_cyan(), span));
+            strings.push(format!(
+                "{}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan()
+            ));
+            for line_number in span.start_line..=span.end_line {
+                if let Some(line) = context.get(line_number - 1) {
+                    strings.push(format!(
+                        "{}{}",
+                        (line_number.to_string() + " | ").bright_cyan(),
+                        line
+                    ));
+                }
+            }
+            strings.push(format!(
+                "{}{} {}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan(),
+                self.paint("_".repeat(span.end_column - 1).as_str()),
+                self.paint("^")
+            ));
+        }
+
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    fn paint(&self, text: &str) -> ColoredString {
+        match self.severity {
+            Severity::Error => text.bright_red(),
+            Severity::Warning => text.bright_yellow(),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//!
+//! The diagnostic span.
+//!
+
+use std::fmt;
+
+use serde_derive::Serialize;
+
+use crate::lexical::token::location::Location;
+
+///
+/// The source code span a diagnostic points to.
+///
+/// The file is resolved from the global file index, if the location has a file identifier.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Span {
+    pub file: Option<String>,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl Span {
+    pub fn new(start: Location, end: Location) -> Self {
+        let file = start.file_index.map(|file_index| {
+            crate::file::INDEX
+                .read()
+                .expect(crate::PANIC_MUTEX_SYNC)
+                .get(file_index)
+                .expect(crate::PANIC_FILE_INDEX)
+                .to_string_lossy()
+                .to_string()
+        });
+
+        Self {
+            file,
+            start_line: start.line,
+            start_column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+        }
+    }
+
+    ///
+    /// Checks if the span covers several characters, which are underlined up to the end.
+    ///
+    pub fn is_range(&self) -> bool {
+        self.start_line != self.end_line || self.start_column != self.end_column
+    }
+}
+
+impl From<Location> for Span {
+    fn from(location: Location) -> Self {
+        Self::new(location, location)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.start_line, self.start_column) {
+            (0, 0) => write!(f, "<unavailable>"),
+            (line, column) => match self.file {
+                Some(ref file) => write!(f, "{}:{}:{}", file, line, column),
+                None => write!(f, "{}:{}", line, column),
+            },
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//!
+//! The diagnostic tests.
+//!
+
+#![cfg(test)]
+
+use crate::diagnostic::span::Span;
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::lexical::token::location::Location;
+use crate::Parser;
+
+#[test]
+fn ok_lexical() {
+    let input = "/*block comment";
+
+    let expected = Diagnostic {
+        severity: Severity::Error,
+        code: None,
+        message: "unterminated block comment".to_owned(),
+        span: Some(Span::new(Location::new(1, 1), Location::new(1, 16))),
+        secondary_spans: vec![],
+        help: None,
+    };
+
+    let result = Parser::default()
+        .parse(input, None)
+        .expect_err("The comment is not terminated")
+        .diagnostic();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_syntax() {
+    let input = r#"
+fn main() {
+    let = 42;
+}
+"#;
+
+    let result = Parser::default()
+        .parse(input, None)
+        .expect_err("The variable name is missing")
+        .diagnostic();
+
+    assert_eq!(result.severity, Severity::Error);
+    assert_eq!(result.code, Some("E0004"));
+    assert_eq!(result.span, Some(Span::from(Location::new(3, 9))));
+    assert!(result.help.is_some());
+}
+
+#[test]
+fn ok_semantic_with_reference() {
+    let input = r#"
+fn main() {
+    if true { 42 } else { false }
+}
+"#;
+
+    let expected = Diagnostic {
+        severity: Severity::Error,
+        code: Some("E0119"),
+        message: "if and else branches return incompatible types `u8` and `bool`".to_owned(),
+        span: Some(Span::from(Location::new(3, 27))),
+        secondary_spans: vec![Span::from(Location::new(3, 15))],
+        help: None,
+    };
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err("The branch types do not match")
+        .diagnostic();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_semantic_element() {
+    let input = r#"
+fn main() {
+    let result = 42;
+    result = 69;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err("The variable is immutable")
+        .diagnostic();
+
+    assert_eq!(result.code, Some("E0402"));
+    assert_eq!(result.span, Some(Span::from(Location::new(4, 12))));
+    assert_eq!(
+        result.secondary_spans,
+        vec![Span::from(Location::new(3, 9))]
+    );
+}
+
+#[test]
+fn ok_warning() {
+    let input = r#"
+fn main() {
+    let unused = 42;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_warnings(input)
+        .expect("The warnings do not prevent the compilation")
+        .remove(0)
+        .diagnostic();
+
+    assert_eq!(result.severity, Severity::Warning);
+    assert_eq!(result.code, Some("W0001"));
+    assert_eq!(result.span, Some(Span::from(Location::new(3, 9))));
+}
+
+#[test]
+fn ok_serialized() {
+    let input = r#"
+fn main() {
+    if true { 42 } else { false }
+}
+"#;
+
+    let diagnostic = crate::semantic::tests::compile_entry(input)
+        .expect_err("The branch types do not match")
+        .diagnostic();
+
+    let result = serde_json::to_value(&diagnostic).expect("Diagnostics are always serializable");
+
+    assert_eq!(result["severity"], "error");
+    assert_eq!(result["code"], "E0119");
+    assert_eq!(result["span"]["file"], serde_json::Value::Null);
+    assert_eq!(result["span"]["start_line"], 3);
+    assert_eq!(result["span"]["start_column"], 27);
+    assert_eq!(result["secondary_spans"][0]["start_column"], 15);
+    assert_eq!(result["help"], serde_json::Value::Null);
+}
+
+#[test]
+fn ok_formatted_with_code() {
+    let input = r#"
+fn main() {
+    let result = 42;
+    result = 69;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input)
+        .expect_err("The variable is immutable")
+        .diagnostic()
+        .format(input.lines().collect::<Vec<&str>>().as_slice());
+
+    assert!(result.contains("[E0402]"));
+}
@@ -2,16 +2,15 @@
 //! The Zinc compiler error.
 //!
 
-use colored::Colorize;
-
+use crate::diagnostic::Diagnostic;
 use crate::file::error::Error as FileError;
 use crate::lexical::error::Error as LexicalError;
-use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
@@ -38,25 +37,72 @@ impl Error {
     pub fn format(self, context: &[&str]) -> String {
         match self {
             Self::File(inner) => inner.to_string(),
+            error => error.diagnostic().format(context),
+        }
+    }
+
+    ///
+    /// Returns the stable error code, whose explanation can be found with `crate::explain`.
+    ///
+    /// The file and lexical errors have no codes.
+    ///
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            Self::File(_) => None,
+            Self::Lexical(_) => None,
+            Self::Syntax(inner) => Some(inner.code()),
+            Self::Semantic(inner) => Some(inner.code()),
+        }
+    }
+
+    ///
+    /// Converts the error into a diagnostic, which can be formatted or serialized.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let code = self.code();
+
+        let mut diagnostic = match self {
+            Self::File(inner) => Diagnostic::message(inner.to_string().as_str(), None),
 
             Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
-                Self::format_range(context, "unterminated block comment", start, end, None)
+                Diagnostic::range("unterminated block comment", start, end, None)
             }
             Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
-                Self::format_range(
-                    context,
+                Diagnostic::range(
                     "unterminated double quote string",
                     start,
                     end,
                     None,
                 )
             }
+            Self::Lexical(LexicalError::UnterminatedSingleQuoteCharacter { start, end }) => {
+                Diagnostic::range(
+                    "unterminated single quote character",
+                    start,
+                    end,
+                    None,
+                )
+            }
+            Self::Lexical(LexicalError::CharacterEmpty { location }) => Diagnostic::line(
+                "empty character literal",
+                location,
+                None,
+            ),
+            Self::Lexical(LexicalError::CharacterMultiple { location }) => Diagnostic::line(
+                "character literal may only contain one character",
+                location,
+                Some("write every character as a separate literal, e.g. `['A', 'B']`"),
+            ),
+            Self::Lexical(LexicalError::CharacterNotAscii { location, found }) => Diagnostic::line(
+                format!("non-ASCII character `{}` in a character literal", found).as_str(),
+                location,
+                Some("bytes above 0x7F must be written as hexadecimal escapes like `'\\xFF'`"),
+            ),
             Self::Lexical(LexicalError::ExpectedOneOfBinary {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::line(
                 format!(
                     "expected one of binary symbols {} or '_', found `{}`",
                     expected, found
@@ -69,8 +115,7 @@ impl Error {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::line(
                 format!(
                     "expected one of octal symbols {} or '_', found `{}`",
                     expected, found
@@ -83,8 +128,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of decimal symbols {} or '_', found `{}`",
                     expected, found
@@ -97,8 +141,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of hexadecimal symbols {} or '_', found `{}`",
                     expected, found
@@ -107,14 +150,13 @@ impl Error {
                 location,
                 None,
             ),
-            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line(
-                context,
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Diagnostic::line(
                 format!("invalid character `{}`", found).as_str(),
                 location,
                 None,
             ),
             Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
-                Self::format_line(context, "unexpected end of input", location, None)
+                Diagnostic::line("unexpected end of input", location, None)
             }
 
             Self::Syntax(SyntaxError::ExpectedOneOf {
@@ -122,33 +164,38 @@ impl Error {
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+                suggestion,
+            }) => Diagnostic::line(
                 format!("expected one of {}, found `{}`", expected, found).as_str(),
                 location,
-                help,
+                suggestion
+                    .map(|suggestion| format!("did you mean `{}`?", suggestion))
+                    .as_deref()
+                    .or(help),
             ),
             Self::Syntax(SyntaxError::ExpectedOneOfOrOperator {
                 location,
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+                suggestion,
+            }) => Diagnostic::line(
                 format!(
                     "expected one of {} or an operator, found `{}`",
                     expected, found
                 )
                 .as_str(),
                 location,
-                help,
+                suggestion
+                    .map(|suggestion| format!("did you mean `{}`?", suggestion))
+                    .as_deref()
+                    .or(help),
             ),
             Self::Syntax(SyntaxError::ExpectedIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected identifier, found `{}`", found).as_str(),
                 location,
                 help,
@@ -157,8 +204,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected `mut` or identifier, found `{}`", found).as_str(),
                 location,
                 help,
@@ -167,18 +213,29 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected field identifier, found `{}`", found).as_str(),
                 location,
                 help,
             ),
+            Self::Syntax(SyntaxError::ReservedKeyword {
+                location,
+                keyword,
+                usage,
+            }) => Diagnostic::line(
+                format!(
+                    "`{}` is a reserved keyword and cannot be used as a {}",
+                    keyword, usage
+                )
+                .as_str(),
+                location,
+                None,
+            ),
             Self::Syntax(SyntaxError::ExpectedType {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected type, found `{}`", found).as_str(),
                 location,
                 help,
@@ -187,8 +244,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!(
                     "expected `:` with type or `=` with value, found `{}`",
                     found
@@ -201,39 +257,34 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::line(
                 format!("expected `=` with value, found `{}`", found).as_str(),
                 location,
                 help,
             ),
             Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected expression or operand, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected integer literal, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected identifier or `_`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
                         found
@@ -245,8 +296,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -257,8 +307,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `=` expected a value as the second operand, found `{}`",
                         found,
@@ -269,8 +318,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -281,8 +329,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise OR operator `|=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
                         found,
@@ -293,8 +340,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -305,8 +351,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise XOR operator `^=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
                         found,
@@ -317,8 +362,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -329,8 +373,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise AND operator `&=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
                         found,
@@ -341,8 +384,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -353,10 +395,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise shift left operator `<<=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift left operator `<<=` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -365,8 +406,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -377,10 +417,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the assignment bitwise shift right operator `>>=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift right operator `>>=` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -389,8 +428,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -401,8 +439,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `+=` expected a value as the second operand, found `{}`",
                         found,
@@ -413,8 +450,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -425,8 +461,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `-=` expected a value as the second operand, found `{}`",
                         found,
@@ -437,8 +472,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -449,8 +483,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `*=` expected a value as the second operand, found `{}`",
                         found,
@@ -461,8 +494,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -473,8 +505,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `/=` expected a value as the second operand, found `{}`",
                         found,
@@ -485,8 +516,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -497,8 +527,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the assignment operator `%=` expected a value as the second operand, found `{}`",
                         found,
@@ -510,8 +539,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -523,8 +551,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -536,8 +563,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the range operator `..` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -549,8 +575,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the range operator `..` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -563,8 +588,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the OR operator `||` expected a boolean as the first operand, found `{}`",
                         found,
@@ -577,8 +601,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the OR operator `||` expected a boolean as the second operand, found `{}`",
                         found,
@@ -591,8 +614,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
                         found,
@@ -605,8 +627,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
                         found,
@@ -619,8 +640,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the AND operator `&&` expected a boolean as the first operand, found `{}`",
                         found,
@@ -633,8 +653,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the AND operator `&&` expected a boolean as the second operand, found `{}`",
                         found,
@@ -645,10 +664,8 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
                         found,
@@ -658,6 +675,17 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsTypesMismatch{ first, second }))) => {
+                Diagnostic::line(
+                    format!(
+                        "the equals operator `==` expected two operands of the same type, found `{}` and `{}`",
+                        first, second,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
@@ -665,8 +693,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
                         found,
@@ -677,10 +704,8 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
                         found,
@@ -690,6 +715,17 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsTypesMismatch{ first, second }))) => {
+                Diagnostic::line(
+                    format!(
+                        "the not equals operator `!=` expected two operands of the same type, found `{}` and `{}`",
+                        first, second,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
@@ -697,8 +733,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
                         found,
@@ -711,8 +746,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
                         found,
@@ -725,8 +759,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
                         found,
@@ -739,8 +772,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
                         found,
@@ -753,8 +785,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
                         found,
@@ -767,8 +798,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected an integer as the first operand, found `{}`",
                         found,
@@ -781,8 +811,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected an integer as the second operand, found `{}`",
                         found,
@@ -795,8 +824,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected an integer as the first operand, found `{}`",
                         found,
@@ -809,8 +837,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected an integer as the second operand, found `{}`",
                         found,
@@ -823,8 +850,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise OR operator `|` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -837,8 +863,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise OR operator `|` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -851,8 +876,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise XOR operator `^` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -865,8 +889,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise XOR operator `^` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -879,8 +902,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise AND operator `&` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -893,8 +915,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise AND operator `&` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
                         found,
@@ -907,10 +928,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift left operator `<<` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -918,15 +938,14 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift left operator `<<` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -937,10 +956,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift right operator `>>` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -948,15 +966,14 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift right operator `>>` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -967,8 +984,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected an integer as the first operand, found `{}`",
                         found,
@@ -981,8 +997,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected an integer as the second operand, found `{}`",
                         found,
@@ -995,8 +1010,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected an integer as the first operand, found `{}`",
                         found,
@@ -1009,8 +1023,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected an integer as the second operand, found `{}`",
                         found,
@@ -1023,8 +1036,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected an integer as the first operand, found `{}`",
                         found,
@@ -1037,8 +1049,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected an integer as the second operand, found `{}`",
                         found,
@@ -1051,8 +1062,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected an integer as the first operand, found `{}`",
                         found,
@@ -1065,8 +1075,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected an integer as the second operand, found `{}`",
                         found,
@@ -1079,8 +1088,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected an integer as the first operand, found `{}`",
                         found,
@@ -1093,8 +1101,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected an integer as the second operand, found `{}`",
                         found,
@@ -1104,9 +1111,36 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorExponentiationFirstOperandExpectedEvaluable{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorExponentiationFirstOperandExpectedInteger{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorExponentiationFirstOperandExpectedInteger{ found }))) => {
+                Diagnostic::line(
+                    format!(
+                        "the exponentiation operator `**` expected an integer as the first operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorExponentiationSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorExponentiationSecondOperandExpectedInteger{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorExponentiationSecondOperatorExpectedUnsigned { found })))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorExponentiationSecondOperandExpectedInteger{ found }))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorExponentiationSecondOperatorExpectedUnsigned { found })))) => {
+                Diagnostic::line(
+                    format!(
+                        "the exponentiation operator `**` expected an unsigned integer constant as the second operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingFirstOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the casting operator `as` expected a value as the first operand, found `{}`",
                         found,
@@ -1117,8 +1151,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingSecondOperandExpectedType{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the casting operator `as` expected a type as the second operand, found `{}`",
                         found,
@@ -1132,8 +1165,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingToInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingToInvalidType { from, to })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "cannot cast from `{}` to `{}`",
                         from, to,
@@ -1143,11 +1175,22 @@ impl Error {
                     Some("only integer values can be casted to greater or equal bitlength"),
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingToNarrowerType { from, to })))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingToNarrowerType { from, to })))) => {
+                Diagnostic::line(
+                    format!(
+                        "cannot cast from `{}` to `{}`",
+                        from, to,
+                    )
+                        .as_str(),
+                    location,
+                    Some("the integer type must be wide enough to hold every enumeration variant"),
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the NOT operator `!` expected a boolean, found `{}`",
                         found,
@@ -1160,22 +1203,20 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseNotExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseNotExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise NOT operator `~` expected an integer, found `{}`",
                         found,
                     )
                         .as_str(),
                     location,
-                    None,
+                    Some("use the logical NOT operator `!` to invert a boolean value"),
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNegationExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNegationExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNegationExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the negation operator `-` expected an integer, found `{}`",
                         found,
@@ -1188,8 +1229,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexFirstOperandExpectedArray{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the index operator `[]` expected an array as the first operand, found `{}`",
                         found,
@@ -1202,8 +1242,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the index operator `[]` expected an integer or range as the second operand, found `{}`",
                         found,
@@ -1218,8 +1257,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedStructure{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the field access operator `.` expected a tuple or structure as the first operand, found `{}`",
                         found,
@@ -1230,8 +1268,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the field access operator `.` expected a tuple or structure field identifier as the second operand, found `{}`",
                         found,
@@ -1242,8 +1279,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathFirstOperandExpectedPath{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
                         found,
@@ -1254,8 +1290,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
                         found,
@@ -1267,21 +1302,31 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType { expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected `{}`, found `{}`",
                         expected, found,
                     )
                         .as_str(),
                     location,
-                    None,
+                    Some("all the array elements must have the type of the first one"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::IndexOutOfRange { index, size })))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArrayIndexOutOfRange { index, size }))) => {
+                Diagnostic::line(
+                    format!(
+                        "index `{}` is out of range of the array of size {}",
+                        index, size,
+                    )
+                        .as_str(),
+                    location,
+                    Some("array index must be within the array size"),
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceStartOutOfRange { start })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "left slice bound `{}` is negative",
                         start,
@@ -1293,8 +1338,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndOutOfRange { end, size })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "right slice bound `{}` is out of range of the array of size {}",
                         end, size,
@@ -1306,8 +1350,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndLesserThanStart { start, end })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "left slice bound `{}` is greater than right slice bound `{}`",
                         start, end,
@@ -1318,24 +1361,22 @@ impl Error {
                 )
             }
 
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist { type_identifier, field_index })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index }))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist { type_identifier, field_index, field_count })))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index, field_count }))) => {
+                Diagnostic::line(
                     format!(
                         "tuple `{}` has no field with index `{}`",
                         type_identifier, field_index,
                     )
                         .as_str(),
                     location,
-                    None,
+                    Some(format!("the tuple has {} fields, so the index must be lesser than {}", field_count, field_count).as_str()),
                 )
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldDoesNotExist { type_identifier, field_name })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::StructureFieldDoesNotExist { type_identifier, field_name }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "field `{}` does not exist in structure `{}`",
                         field_name, type_identifier,
@@ -1346,28 +1387,33 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingWithDifferentType { expected, found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingImmutableMemory { name, reference }))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("cannot assign twice to immutable variable `{}`", name).as_str(),
                     location,
                     reference,
                     Some(format!("make this variable mutable: `mut {}`", name).as_str()),
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected { type_identifier, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingImmutableElement { name, reference }))) => {
+                Diagnostic::line_with_reference(
+                    format!("cannot assign to an element of immutable variable `{}`", name).as_str(),
+                    location,
+                    reference,
+                    Some(format!("make this variable mutable: `mut {}`", name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldInitializedTwice { type_identifier, field_name })))) => {
+                Diagnostic::line(
                     format!(
-                        "structure `{}` expected field `{}` at position {}, found `{}`",
-                        type_identifier, expected, position, found,
+                        "field `{}` of structure `{}` is initialized more than once",
+                        field_name, type_identifier,
                     )
                         .as_str(),
                     location,
@@ -1375,8 +1421,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldInvalidType { type_identifier, field_name, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "field `{}` of structure `{}` expected type `{}`, found `{}`",
                         field_name, type_identifier, expected, found,
@@ -1386,22 +1431,20 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldOutOfRange { type_identifier, expected, found })))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldMissing { type_identifier, field_name })))) => {
+                Diagnostic::line(
                     format!(
-                        "structure `{}` expected {} fields, found {}",
-                        type_identifier, expected, found,
+                        "field `{}` of structure `{}` is not initialized",
+                        field_name, type_identifier,
                     )
                         .as_str(),
                     location,
-                    None,
+                    Some(format!("add the `{}` field to the structure literal", field_name).as_str()),
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1413,8 +1456,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchNotEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1426,8 +1468,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreaterEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1439,8 +1480,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesserEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1452,8 +1492,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreater{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1465,8 +1504,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesser{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1478,8 +1516,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseOr{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1491,8 +1528,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseXor{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1504,8 +1540,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseAnd{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1517,8 +1552,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchAddition{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchAddition{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1530,8 +1564,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchSubtraction{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1543,8 +1576,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchMultiplication{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1556,8 +1588,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchDivision{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchDivision{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1569,8 +1600,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchRemainder{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1581,10 +1611,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowAddition { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the addition operator `+` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1593,10 +1622,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowSubtraction { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the subtraction operator `-` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1605,10 +1633,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowMultiplication { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the multiplication operator `*` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1617,10 +1644,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowDivision { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the division operator `/` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1629,10 +1655,20 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowRemainder { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the remainder operator `%` overflow, as the value `{}` cannot be represented by type `{}`",
+                        value, r#type,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowExponentiation { value, r#type })))) => {
+                Diagnostic::line(
+                    format!(
+                        "the exponentiation operator `**` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1641,10 +1677,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowCasting { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the casting operator `as` overflow, as the value `{}` cannot be represented by type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1653,10 +1688,31 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowNegation { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
-                        "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
+                        "the negation operator `-` overflow, as the value `{}` cannot be represented by type `{}`",
+                        value, r#type,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowLiteral { value, r#type })))) => {
+                Diagnostic::line(
+                    format!(
+                        "the integer literal `{}` cannot be represented by its suffix type `{}`",
+                        value, r#type,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowInference { value, r#type })))) => {
+                Diagnostic::line(
+                    format!(
+                        "the integer literal `{}` cannot be represented by the inferred type `{}`",
                         value, r#type,
                     )
                         .as_str(),
@@ -1666,8 +1722,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldDivision)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the division operator `/` is forbidden for the `field` type",
                     location,
                     Some("for inversion consider using `std::ff::invert`"),
@@ -1675,8 +1730,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldRemainder)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the remainder operator `%` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be used to get a remainder"),
@@ -1684,57 +1738,58 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldBitwise)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the bitwise operators are forbidden for the `field` type",
                     location,
                     None,
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenSignedBitwiseNot)))) |
+            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenSignedBitwiseNot)))) => {
+                Diagnostic::line(
+                    "the bitwise NOT operator `~` is forbidden for signed integers",
+                    location,
+                    Some("consider casting the value to an unsigned type, e.g. `~(value as u8)`"),
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldNegation)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "the negation operator `-` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be negative"),
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "division by zero",
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "remainder of division by zero",
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::IntegerTooLarge { value, bitlength })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("integer `{}` is larger than `{}` bits", value, bitlength).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::UnsignedNegative { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("found a negative value `{}` of unsigned type `{}`", value, r#type).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToType { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected type, found `{}`",
                         found
@@ -1745,8 +1800,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToStructure { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "expected structure type, found `{}`",
                         found
@@ -1758,8 +1812,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemRedeclared { name, reference })) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!(
                         "item `{}` already declared here",
                         name
@@ -1770,21 +1823,31 @@ impl Error {
                     Some("consider giving the latter item another name"),
                 )
             }
-            Self::Semantic(SemanticError::Scope(location, ScopeError::ItemUndeclared { name })) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Scope(_location, ScopeError::ItemRedefined { name, first, second })) => {
+                Diagnostic::line_with_reference(
+                    format!(
+                        "item `{}` is defined more than once",
+                        name
+                    )
+                        .as_str(),
+                    second,
+                    Some(first),
+                    Some("items in the same scope must have different names"),
+                )
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::ItemUndeclared { name, suggestion })) => {
+                Diagnostic::line(
                     format!(
                         "cannot find item `{}` in this scope",
                         name
                     )
                         .as_str(),
                     location,
-                    None,
+                    suggestion.map(|suggestion| format!("did you mean `{}`?", suggestion)).as_deref(),
                 )
             }
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemIsNotNamespace { name })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "item `{}` is not a namespace",
                         name
@@ -1794,10 +1857,44 @@ impl Error {
                     Some("only modules, structures, and enumerations can contain items within their namespaces"),
                 )
             }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::VariableUninitialized { name, reference })) => {
+                Diagnostic::line_with_reference(
+                    format!(
+                        "use of possibly uninitialized variable `{}`",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    reference,
+                    Some("assign the variable on every control path before using it"),
+                )
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::VariableCaptured { name, reference })) => {
+                Diagnostic::line_with_reference(
+                    format!(
+                        "variable `{}` cannot be captured by a nested function",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    reference,
+                    Some("only constants can be used from the enclosing function, consider passing the variable as an argument"),
+                )
+            }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::EnumerationVariantUnqualified { name, enumeration })) => {
+                Diagnostic::line(
+                    format!(
+                        "unqualified enumeration variant `{}`",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("qualify the variant with its enumeration: `{}::{}`", enumeration, name).as_str()),
+                )
+            }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentCount { function, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected {} arguments, found {}",
                         function, expected, found
@@ -1808,8 +1905,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentType { function, name, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
                         function, expected, name, position, found
@@ -1820,8 +1916,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentConstantness { function, name, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
                         function, name, position, found
@@ -1832,8 +1927,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentNotEvaluable { function, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "function `{}` expected a value as the argument #{}, found `{}`",
                         function, position, found
@@ -1844,8 +1938,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ReturnType { function, expected, found, reference })))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!(
                         "function `{}` must return a value of type `{}`, found `{}`",
                         function, expected, found
@@ -1857,8 +1950,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::NonCallable { name })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a non-callable item `{}`",
                         name
@@ -1868,9 +1960,19 @@ impl Error {
                     Some("only functions may be called"),
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ReturnTypeNotInferred { function })))) => {
+                Diagnostic::line(
+                    format!(
+                        "function `{}` is called before its return type is inferred",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("the function calls itself through a nested function, so specify its return type explicitly"),
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::Unknown { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a non-builtin function `{}` with `!` specifier",
                         function
@@ -1881,8 +1983,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::SpecifierMissing { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to call a builtin function `{}` without `!` specifier",
                         function
@@ -1893,8 +1994,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::DebugArgumentCount { expected, found }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "the `dbg!` function expected {} arguments, but got {}",
                         expected, found,
@@ -1905,8 +2005,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayTruncatingToBiggerSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to truncate an array from size `{}` to bigger size `{}`",
                         from, to,
@@ -1917,8 +2016,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayPaddingToLesserSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "attempt to pad an array from size `{}` to lesser size `{}`",
                         from, to,
@@ -1929,8 +2027,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayNewLengthInvalid { value }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "new array length `{}` cannot act as an index",
                         value,
@@ -1940,62 +2037,79 @@ impl Error {
                     Some("array indexes cannot be greater than maximum of `u64`"),
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { type_identifier, field_name })))) => {
-                Self::format_line(
-                    context,
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayNotSorted { index, previous, next }))))) => {
+                Diagnostic::line(
+                    format!(
+                        "the array is not sorted: element `[{}]` = `{}` is greater than element `[{}]` = `{}`",
+                        index, previous, index + 1, next,
+                    )
+                        .as_str(),
+                    location,
+                    Some("binary search requires the array to be sorted in ascending order"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { type_identifier, field_name, reference })))) => {
+                Diagnostic::line_with_reference(
                     format!(
                         "structure `{}` has a duplicate field `{}`",
                         type_identifier, field_name,
                     )
                         .as_str(),
                     location,
+                    Some(reference),
                     Some("consider giving the field a unique name"),
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::DuplicateVariant { type_identifier, variant_name, reference })))) => {
+                Diagnostic::line_with_reference(
+                    format!(
+                        "enumeration `{}` has a duplicate variant `{}`",
+                        type_identifier, variant_name,
+                    )
+                        .as_str(),
+                    location,
+                    Some(reference),
+                    Some("consider giving the variant a unique name"),
+                )
+            }
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::MatchNotExhausted { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "match expression must be exhaustive",
                     location,
                     Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
                 )
             }
             Self::Semantic(SemanticError::MatchLessThanTwoBranches { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "match expression must have at least two branches",
                     location,
                     Some("consider adding some branches to make the expression useful"),
                 )
             }
             Self::Semantic(SemanticError::MatchBranchUnreachable { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     "match expression branch is unreachable",
                     location,
                     Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
                 )
             }
             Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected path to a constant, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::MatchBranchPatternInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
@@ -2003,8 +2117,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::MatchBranchExpressionInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
@@ -2012,58 +2125,176 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::MatchBranchDuplicate { location, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::line_with_reference(
                     "match expression contains a duplicate branch pattern",
                     location,
                     Some(reference),
                     Some("each pattern may occur only once"),
                 )
             }
+            Self::Semantic(SemanticError::MatchBranchGuardExpectedBoolean { location, found }) => {
+                Diagnostic::line(
+                    format!("expected `bool`, found `{}`", found).as_str(),
+                    location,
+                    Some("the match branch guard must be a boolean expression"),
+                )
+            }
 
             Self::Semantic(SemanticError::LoopWhileExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected a constant range expression, found `{}`", found).as_str(),
                     location,
                     Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
                 )
             }
+            Self::Semantic(SemanticError::LoopAttributeUnknown { location, found }) => {
+                Diagnostic::line(
+                    format!("unknown loop attribute `{}`", found).as_str(),
+                    location,
+                    Some("only `#[unroll]` and `#[no_unroll]` are allowed for loops"),
+                )
+            }
+            Self::Semantic(SemanticError::LoopAttributeConflict { location, reference }) => {
+                Diagnostic::line_with_reference(
+                    "the loop attribute conflicts with the previous one",
+                    location,
+                    Some(reference),
+                    Some("a loop can be either unrolled or not, so remove one of the attributes"),
+                )
+            }
+            Self::Semantic(SemanticError::LoopIterationsLimitExceeded { location, found, limit }) => {
+                Diagnostic::line(
+                    format!("the loop has {} iterations including the enclosing loops, which exceeds the limit of {}", found, limit).as_str(),
+                    location,
+                    Some("loops are unrolled, so the number of iterations must be reasonably small, or the limit can be raised with `--loop-iterations-limit`"),
+                )
+            }
+
+            Self::Semantic(SemanticError::LetPatternInvalidType { location, expected, found }) => {
+                Diagnostic::line(
+                    format!("expected a value of structure `{}`, found `{}`", expected, found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::LetPatternFieldDoesNotExist { location, type_identifier, field_name, suggestion }) => {
+                Diagnostic::line(
+                    format!(
+                        "field `{}` does not exist in structure `{}`",
+                        field_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    suggestion.map(|suggestion| format!("did you mean `{}`?", suggestion)).as_deref(),
+                )
+            }
+            Self::Semantic(SemanticError::LetPatternFieldMissing { location, type_identifier, field_name }) => {
+                Diagnostic::line(
+                    format!(
+                        "pattern does not mention field `{}` of structure `{}`",
+                        field_name, type_identifier,
+                    )
+                        .as_str(),
+                    location,
+                    Some("bind the field or ignore the rest with `..`"),
+                )
+            }
 
             Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
-            Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+            Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference, difference }) => {
+                Diagnostic::line_with_reference(
                     format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
-                    None,
+                    difference.as_deref(),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionRecursion { location, cycle }) => {
+                Diagnostic::line(
+                    format!("recursive function call `{}`", cycle).as_str(),
+                    location,
+                    Some("the recursion cannot be compiled into a circuit, so use a loop instead"),
+                )
+            }
+            Self::Semantic(SemanticError::RangeUsedAsValue { location, found }) => {
+                Diagnostic::line(
+                    format!("the {} cannot be used as a runtime value", found).as_str(),
+                    location,
+                    Some("ranges exist only at compile time, so they can be bound with `let` and used as loop bounds or array slices"),
+                )
+            }
+            Self::Semantic(SemanticError::RangeUsedAsType { location }) => {
+                Diagnostic::line(
+                    "ranges are not first-class values, so there is no `range` type",
+                    location,
+                    Some("ranges exist only at compile time, so they cannot be passed to functions, but can be bound with `let` and used as loop bounds or array slices"),
                 )
             }
             Self::Semantic(SemanticError::EntryPointMissing) => {
-                Self::format_message(
+                Diagnostic::message(
                     "function `main` is missing",
                     Some("create the `main` function in the entry point file `main.zn`"),
                 )
             }
+            Self::Semantic(SemanticError::EntryPointMisnamed { location, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "function `main` is missing, but `{}` is declared",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("the entry point name is case-sensitive, consider renaming the function to `main`"),
+                )
+            }
+            Self::Semantic(SemanticError::EntryPointNotFunction { location, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "the entry point `main` must be a function, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("declare the entry point as `fn main(...) -> ... { ... }`"),
+                )
+            }
+            Self::Semantic(SemanticError::EntryPointArgumentInvalidType { location, name, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "the entry point argument `{}` has type `{}`, which cannot be a circuit input",
+                        name, found,
+                    )
+                        .as_str(),
+                    location,
+                    Some("the circuit inputs may only consist of booleans, integers, fields, enumerations, arrays, tuples, and structures"),
+                )
+            }
+            Self::Semantic(SemanticError::EntryPointReturnTypeInvalid { location, found }) => {
+                Diagnostic::line(
+                    format!(
+                        "the entry point return type `{}` cannot be a circuit output",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    Some("the circuit output may only consist of booleans, integers, fields, enumerations, arrays, tuples, and structures"),
+                )
+            }
             Self::Semantic(SemanticError::ModuleNotFound { location, name }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "file not found for module `{}`",
                         name
@@ -2074,8 +2305,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UseExpectedPath { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "`use` expected an item path, but got `{}`",
                         found
@@ -2086,8 +2316,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!(
                         "`impl` expected a type with namespace, found `{}`",
                         found
@@ -2098,185 +2327,37 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::line(
                     format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
                     location,
                     None,
                 )
             }
-        }
-    }
-
-    fn format_message(message: &str, help: Option<&str>) -> String {
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_line(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_line_with_reference(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        reference: Option<Location>,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(11);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-
-        if let Some(reference) = reference {
-            let line_number_length = reference.line.to_string().len();
-            strings.push(format!(
-                "{}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan()
-            ));
-            if let Some(line) = context.get(reference.line - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (reference.line.to_string() + " | ").bright_cyan(),
-                    line
-                ));
-            }
-            strings.push(format!(
-                "{}{} {}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan(),
-                "_".repeat(reference.column - 1).bright_red(),
-                "^".bright_red()
-            ));
-        }
-
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_range(
-        context: &[&str],
-        message: &'static str,
-        start: Location,
-        end: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = end.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(8 + end.line - start.line);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        for line_number in start.line..=end.line {
-            if let Some(line) = context.get(line_number - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (line_number.to_string() + " | ").bright_cyan(),
-                    line
-                ));
+            Self::Semantic(SemanticError::IntegerLiteralOutOfRange { location, value, r#type }) => {
+                Diagnostic::line(
+                    format!("integer literal `{}` is out of range of type `{}`", value, r#type).as_str(),
+                    location,
+                    Some("use a wider type or a value within the type range"),
+                )
             }
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(end.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
+            Self::Semantic(SemanticError::ArrayEmptyWithoutType { location }) => {
+                Diagnostic::line(
+                    "cannot infer the element type of an empty array",
+                    location,
+                    Some("consider giving the variable a type annotation, e.g. `let array: [u8; 0] = [];`"),
+                )
+            }
+            Self::Semantic(SemanticError::StatementResultDiscarded { location, found }) => {
+                Diagnostic::line(
+                    format!("the expression statement result of type `{}` is not used", found).as_str(),
+                    location,
+                    Some("consider discarding the result explicitly, e.g. `let _ = hash(value);`"),
+                )
+            }
+        };
+
+        diagnostic.set_code(code);
+        diagnostic
     }
 }
 
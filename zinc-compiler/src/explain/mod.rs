@@ -0,0 +1,4606 @@
+//!
+//! The Zinc compiler error explanations.
+//!
+
+mod tests;
+
+///
+/// The error codes are allocated in blocks of a hundred, one block per error enum:
+///
+/// E00xx - syntax
+/// E01xx - semantic
+/// E02xx - scope
+/// E03xx - element operators
+/// E04xx - place
+/// E05xx - value operators
+/// E06xx - integer value
+/// E07xx - array value
+/// E08xx - tuple value
+/// E09xx - structure value
+/// E10xx - constant operators
+/// E11xx - integer constant
+/// E12xx - casting
+/// E13xx - type
+/// E14xx - structure type
+/// E15xx - function type
+/// E16xx - built-in function type
+/// E17xx - standard library function type
+/// E18xx - enumeration type
+///
+/// The warnings have their own block:
+///
+/// W00xx - warnings
+///
+/// The codes are never reused, so a removed error leaves a gap in its block.
+///
+static EXPLANATIONS: &[(&str, &str)] = &[
+    (
+        "E0001",
+        r#"An unexpected token was found where one of the listed tokens is expected.
+
+If the token looks like a misspelled keyword, the closest keyword is
+suggested.
+
+Erroneous code example:
+
+    fnn main() {}"#,
+    ),
+    (
+        "E0002",
+        r#"An unexpected token was found where one of the listed tokens or an operator is
+expected.
+
+The error usually means that an operator or a separator is missing between two
+operands.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 43;
+    }"#,
+    ),
+    (
+        "E0003",
+        r#"An identifier was expected.
+
+The functions, structures, enumerations, constants, types and modules must be
+named.
+
+Erroneous code example:
+
+    fn (value: u8) -> u8 {
+        value
+    }"#,
+    ),
+    (
+        "E0004",
+        r#"The `let` keyword is not followed by `mut` or a variable name.
+
+Erroneous code example:
+
+    fn main() {
+        let = 42;
+    }"#,
+    ),
+    (
+        "E0005",
+        r#"The field access operator is not followed by a field name or a tuple index.
+
+The structure fields are accessed by their names, e.g. `point.x`, and the
+tuple fields are accessed by their indexes, e.g. `pair.0`.
+
+Erroneous code example:
+
+    fn main() {
+        let pair = (1, 2);
+        let value = pair.*;
+    }"#,
+    ),
+    (
+        "E0006",
+        r#"A reserved keyword was used as an identifier.
+
+Some keywords are reserved for the future versions of the language and cannot
+be used as names of variables, functions or types.
+
+Erroneous code example:
+
+    fn main() {
+        let match = 42;
+    }
+
+Choose another name for the item."#,
+    ),
+    (
+        "E0007",
+        r#"A type was expected.
+
+Erroneous code example:
+
+    fn main() {
+        let value: = 42;
+    }"#,
+    ),
+    (
+        "E0008",
+        r#"An expression or an operand was expected.
+
+The error usually means that an operand of a binary operator is missing.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 5 + ;
+    }"#,
+    ),
+    (
+        "E0009",
+        r#"The variable declaration has neither a type nor a value.
+
+An immutable variable must be initialized when it is declared. A mutable
+variable may be declared without a value, but then it must have a type
+annotation, since the type cannot be inferred.
+
+Erroneous code example:
+
+    fn main() {
+        let value;
+    }"#,
+    ),
+    (
+        "E0010",
+        r#"The constant or immutable variable declaration has no value.
+
+The constants and immutable variables cannot be assigned later, so they must
+be initialized when they are declared.
+
+Erroneous code example:
+
+    const VALUE: u8;
+
+    fn main() {}"#,
+    ),
+    (
+        "E0011",
+        r#"An integer literal was expected.
+
+The enumeration variant values and the negative match patterns must be integer
+literals.
+
+Erroneous code example:
+
+    enum Direction {
+        Up = UP,
+    }"#,
+    ),
+    (
+        "E0012",
+        r#"An identifier or `_` was expected in a binding pattern.
+
+The function parameters and the `let` bindings must be named, or ignored with
+`_`.
+
+Erroneous code example:
+
+    fn sum(, b: u8) -> u8 {
+        b
+    }"#,
+    ),
+    (
+        "E0013",
+        r#"The match pattern is not valid.
+
+A match pattern can be a boolean or integer literal, a path to a constant or
+an enumeration variant, a binding, or the wildcard `_`.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            [1] => 10,
+            _ => 20,
+        }
+    }"#,
+    ),
+    (
+        "E0101",
+        r#"The match scrutinee has a type which cannot be matched.
+
+Only the boolean, integer and enumeration values can be matched against the
+constant patterns.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3];
+        match array {
+            _ => 42,
+        };
+    }"#,
+    ),
+    (
+        "E0102",
+        r#"The match expression does not cover all the possible values.
+
+Since a circuit must produce a value for every input, the match branches must
+cover every value of the scrutinee type.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            1 => 10,
+            2 => 20,
+        }
+    }
+
+Add a wildcard branch `_ => ...` or a binding branch to cover the rest."#,
+    ),
+    (
+        "E0103",
+        r#"The match expression has less than two branches.
+
+A match with a single branch is equivalent to its expression and is most
+likely a mistake.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            _ => 42,
+        }
+    }"#,
+    ),
+    (
+        "E0104",
+        r#"The match branch can never be reached.
+
+A branch after a wildcard or a binding pattern is never matched, since the
+previous branch matches every value.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            _ => 10,
+            1 => 20,
+        }
+    }
+
+Remove the branch or move it above the irrefutable one."#,
+    ),
+    (
+        "E0105",
+        r#"The match pattern path does not point to a constant.
+
+The paths used as match patterns must be resolved at compile time.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        let value = 1;
+        match witness {
+            value => 10,
+            _ => 20,
+        }
+    }
+
+Declare the pattern with `const` instead of `let`."#,
+    ),
+    (
+        "E0106",
+        r#"The match pattern type does not match the scrutinee type.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            false => 10,
+            _ => 20,
+        }
+    }"#,
+    ),
+    (
+        "E0107",
+        r#"The match branches return values of different types.
+
+Every branch must return the type returned by the first one.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            1 => 10,
+            _ => false,
+        }
+    }"#,
+    ),
+    (
+        "E0108",
+        r#"The match expression contains two branches with the same pattern.
+
+The second branch is never matched.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            1 => 10,
+            1 => 20,
+            _ => 30,
+        }
+    }"#,
+    ),
+    (
+        "E0109",
+        r#"The match branch guard is not a boolean expression.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            value if value + 1 => 10,
+            _ => 20,
+        }
+    }"#,
+    ),
+    (
+        "E0110",
+        r#"The `while` condition of a loop is not a boolean expression.
+
+Erroneous code example:
+
+    fn main() {
+        let mut sum = 0;
+        for i in 0..10 while i {
+            sum += i;
+        }
+    }"#,
+    ),
+    (
+        "E0111",
+        r#"The loop bounds are not a constant range.
+
+The loops are unrolled at compile time, so the number of iterations must be
+known in advance.
+
+Erroneous code example:
+
+    fn main(witness: u8) {
+        for i in 0..witness {}
+    }
+
+Use a constant range and stop the loop early with a `while` condition."#,
+    ),
+    (
+        "E0112",
+        r#"The loop attribute is unknown.
+
+Only `#[unroll]` and `#[no_unroll]` are allowed for loops.
+
+Erroneous code example:
+
+    fn main() {
+        #[fast]
+        for i in 0..10 {}
+    }"#,
+    ),
+    (
+        "E0113",
+        r#"The loop attributes conflict with each other.
+
+Erroneous code example:
+
+    fn main() {
+        #[unroll]
+        #[no_unroll]
+        for i in 0..10 {}
+    }"#,
+    ),
+    (
+        "E0114",
+        r#"The loop has too many iterations.
+
+The loops are unrolled, so the number of iterations must be reasonably small.
+The iterations of a nested loop are multiplied by the ones of the enclosing
+loops. The limit can be raised with the `--loop-iterations-limit` option.
+
+Erroneous code example:
+
+    fn main() {
+        for i in 0..1000 {
+            for j in 0..1000 {}
+        }
+    }"#,
+    ),
+    (
+        "E0115",
+        r#"The `let` pattern does not match the type of the bound value.
+
+Erroneous code example:
+
+    struct Point { x: u8, y: u8 }
+
+    fn main() {
+        let Point { x, y } = (1, 2);
+    }"#,
+    ),
+    (
+        "E0116",
+        r#"The `let` pattern mentions a field which does not exist in the structure.
+
+Erroneous code example:
+
+    struct Point { x: u8, y: u8 }
+
+    fn main() {
+        let Point { x, z } = Point { x: 1, y: 2 };
+    }"#,
+    ),
+    (
+        "E0117",
+        r#"The `let` pattern does not mention a field of the structure.
+
+Every field must be bound, so the pattern is exhaustive.
+
+Erroneous code example:
+
+    struct Point { x: u8, y: u8 }
+
+    fn main() {
+        let Point { x } = Point { x: 1, y: 2 };
+    }"#,
+    ),
+    (
+        "E0118",
+        r#"The `if` condition is not a boolean expression.
+
+There are no implicit conversions to `bool`.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        if witness { 1 } else { 2 }
+    }
+
+Compare the value explicitly, e.g. `if witness != 0 { ... }`."#,
+    ),
+    (
+        "E0119",
+        r#"The `if` and `else` branches return values of different types.
+
+Erroneous code example:
+
+    fn main(witness: bool) -> u8 {
+        if witness { 42 } else { false }
+    }"#,
+    ),
+    (
+        "E0120",
+        r#"The function calls itself directly or through other functions.
+
+The functions are inlined into the circuit, so the recursion cannot be
+compiled.
+
+Erroneous code example:
+
+    fn factorial(n: u8) -> u8 {
+        if n == 0 { 1 } else { n * factorial(n - 1) }
+    }
+
+    fn main() -> u8 {
+        factorial(5)
+    }
+
+Use a loop instead."#,
+    ),
+    (
+        "E0121",
+        r#"A range was used as a runtime value.
+
+Ranges exist only at compile time, so they can be bound with `let` and used
+as loop bounds or array slices, but cannot be passed or returned.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [0..10];
+    }"#,
+    ),
+    (
+        "E0122",
+        r#"The entry point file does not declare the `main` function.
+
+The `main` function is where the circuit execution starts.
+
+Erroneous code example:
+
+    fn helper() {}"#,
+    ),
+    (
+        "E0123",
+        r#"The module file cannot be found.
+
+Every `mod name;` declaration must have the `name.zn` file in the `src`
+directory.
+
+Erroneous code example:
+
+    mod missing;
+
+    fn main() {}"#,
+    ),
+    (
+        "E0124",
+        r#"The `use` statement does not point to an item path.
+
+Erroneous code example:
+
+    use 42;
+
+    fn main() {}"#,
+    ),
+    (
+        "E0125",
+        r#"The `impl` statement does not point to a structure or enumeration.
+
+Only the types with a namespace can have an implementation.
+
+Erroneous code example:
+
+    type Alias = u8;
+
+    impl Alias {}
+
+    fn main() {}"#,
+    ),
+    (
+        "E0126",
+        r#"A non-constant value was used in a constant expression.
+
+Erroneous code example:
+
+    fn main(witness: u8) {
+        const VALUE: u8 = witness;
+    }"#,
+    ),
+    (
+        "E0127",
+        r#"The integer literal does not fit into the type of the variable.
+
+The unsigned types of bitlength N hold the values from 0 to 2^N - 1, whereas
+the signed ones hold the values from -2^(N-1) to 2^(N-1) - 1, e.g. `i8` holds
+the values from -128 to 127.
+
+Erroneous code example:
+
+    fn main() {
+        let value: u8 = 300;
+    }
+
+Annotate the variable with a wider type, e.g. `u16`."#,
+    ),
+    (
+        "E0128",
+        r#"The entry point function is declared with another letter case.
+
+The names are case-sensitive, and the circuit execution starts at the function
+called exactly `main`.
+
+Erroneous code example:
+
+    fn Main() -> u8 {
+        42
+    }"#,
+    ),
+    (
+        "E0129",
+        r#"The entry point `main` is declared, but it is not a function.
+
+Erroneous code example:
+
+    const main: u8 = 42;"#,
+    ),
+    (
+        "E0130",
+        r#"The entry point argument type cannot be a circuit input.
+
+The `main` arguments become the circuit witness, so they may only consist of
+booleans, integers, fields, enumerations, arrays, tuples, and structures.
+The compile-time only strings, ranges, and functions are not allowed.
+
+Erroneous code example:
+
+    fn helper() {}
+
+    fn main(value: helper) {}"#,
+    ),
+    (
+        "E0131",
+        r#"The entry point return type cannot be a circuit output.
+
+The `main` result becomes the circuit public output, so it may only consist of
+booleans, integers, fields, enumerations, arrays, tuples, and structures.
+
+Erroneous code example:
+
+    fn main() -> _ {
+        0..10
+    }"#,
+    ),
+    (
+        "E0132",
+        r#"The empty array element type cannot be inferred.
+
+The element type of an array literal is taken from its first element, so an
+empty array is only allowed where its type is annotated.
+
+Erroneous code example:
+
+    fn main() {
+        let array = []; // must be `let array: [u8; 0] = [];`
+    }"#,
+    ),
+    (
+        "E0133",
+        r#"The expression statement result is not a unit.
+
+Only the expressions returning `()`, e.g. assignments and calls of functions
+without a result, can be used as statements. Other values must be either bound
+to a variable, or discarded explicitly with the wildcard binding.
+
+Erroneous code example:
+
+    fn hash(value: u8) -> u8 {
+        value * 2
+    }
+
+    fn main() {
+        hash(42); // must be `let _ = hash(42);`
+    }"#,
+    ),
+    (
+        "E0134",
+        r#"The `range` type was used, but the ranges are not first-class values.
+
+Ranges exist only at compile time, so they can be bound with `let` and used
+as loop bounds or array slices, but cannot be passed to functions or stored
+in variables of an explicit type.
+
+Erroneous code example:
+
+    fn sum(range: range) -> u64 {
+        0
+    }
+
+Bind the range with `let` and use it as the loop bound in place."#,
+    ),
+    (
+        "E0201",
+        r#"The item is not declared in this scope or any of its parents.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        value
+    }"#,
+    ),
+    (
+        "E0202",
+        r#"The item with the same name is already declared.
+
+A variable cannot shadow another variable, including the nested blocks and
+branches.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 1;
+        let value = 2;
+    }"#,
+    ),
+    (
+        "E0203",
+        r#"The path goes through an item which is not a namespace.
+
+Only the modules, structures and enumerations have namespaces.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 1;
+        let result = value::field;
+    }"#,
+    ),
+    (
+        "E0204",
+        r#"The variable is used before it is initialized.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        let value: u8;
+        value
+    }"#,
+    ),
+    (
+        "E0205",
+        r#"The variable of the outer function is used by a nested function.
+
+The functions cannot capture the variables of their environment, so pass the
+value as an argument instead.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        let value = 42;
+        fn nested() -> u8 { value }
+        nested()
+    }"#,
+    ),
+    (
+        "E0206",
+        r#"The enumeration variant is used without its enumeration name.
+
+The variants live in the namespace of their enumeration, so different
+enumerations may have variants with the same names. Qualify the variant with
+its enumeration both in expressions and in match patterns.
+
+Erroneous code example:
+
+    enum Color {
+        Red = 1,
+    }
+
+    fn main() -> Color {
+        Red // must be `Color::Red`
+    }"#,
+    ),
+    (
+        "E0207",
+        r#"The item is defined twice in the same scope.
+
+Functions, constants, types, and modules declared in the same scope must have
+different names, even if they are items of different kinds. Variables may
+shadow the items declared in outer scopes.
+
+Erroneous code example:
+
+    struct Data {
+        value: u8,
+    }
+
+    enum Data {
+        Value = 1,
+    }"#,
+    ),
+    (
+        "E0301",
+        r#"The left side of the `=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 = 5;
+    }"#,
+    ),
+    (
+        "E0302",
+        r#"The right side of the `=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value = X;
+    }"#,
+    ),
+    (
+        "E0303",
+        r#"The left side of the `|=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 |= 5;
+    }"#,
+    ),
+    (
+        "E0304",
+        r#"The right side of the `|=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value |= X;
+    }"#,
+    ),
+    (
+        "E0305",
+        r#"The left side of the `^=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 ^= 5;
+    }"#,
+    ),
+    (
+        "E0306",
+        r#"The right side of the `^=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value ^= X;
+    }"#,
+    ),
+    (
+        "E0307",
+        r#"The left side of the `&=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 &= 5;
+    }"#,
+    ),
+    (
+        "E0308",
+        r#"The right side of the `&=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value &= X;
+    }"#,
+    ),
+    (
+        "E0309",
+        r#"The left side of the `<<=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 <<= 5;
+    }"#,
+    ),
+    (
+        "E0310",
+        r#"The right side of the `<<=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value <<= X;
+    }"#,
+    ),
+    (
+        "E0311",
+        r#"The left side of the `>>=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 >>= 5;
+    }"#,
+    ),
+    (
+        "E0312",
+        r#"The right side of the `>>=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value >>= X;
+    }"#,
+    ),
+    (
+        "E0313",
+        r#"The left side of the `+=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 += 5;
+    }"#,
+    ),
+    (
+        "E0314",
+        r#"The right side of the `+=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value += X;
+    }"#,
+    ),
+    (
+        "E0315",
+        r#"The left side of the `-=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 -= 5;
+    }"#,
+    ),
+    (
+        "E0316",
+        r#"The right side of the `-=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value -= X;
+    }"#,
+    ),
+    (
+        "E0317",
+        r#"The left side of the `*=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 *= 5;
+    }"#,
+    ),
+    (
+        "E0318",
+        r#"The right side of the `*=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value *= X;
+    }"#,
+    ),
+    (
+        "E0319",
+        r#"The left side of the `/=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 /= 5;
+    }"#,
+    ),
+    (
+        "E0320",
+        r#"The right side of the `/=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value /= X;
+    }"#,
+    ),
+    (
+        "E0321",
+        r#"The left side of the `%=` assignment is not a memory place.
+
+Only the variables, their array elements, and tuple and structure fields can
+be assigned.
+
+Erroneous code example:
+
+    fn main() {
+        5 %= 5;
+    }"#,
+    ),
+    (
+        "E0322",
+        r#"The right side of the `%=` assignment is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let mut value = 0;
+        value %= X;
+    }"#,
+    ),
+    (
+        "E0323",
+        r#"The start of the `..=` range is not a constant.
+
+The ranges exist only at compile time, so their bounds must be constant
+expressions.
+
+Erroneous code example:
+
+    fn main(start: u8) {
+        let range = start ..= 10;
+    }"#,
+    ),
+    (
+        "E0324",
+        r#"The end of the `..=` range is not a constant.
+
+The ranges exist only at compile time, so their bounds must be constant
+expressions.
+
+Erroneous code example:
+
+    fn main(end: u8) {
+        let range = 0 ..= end;
+    }"#,
+    ),
+    (
+        "E0325",
+        r#"The start of the `..` range is not a constant.
+
+The ranges exist only at compile time, so their bounds must be constant
+expressions.
+
+Erroneous code example:
+
+    fn main(start: u8) {
+        let range = start .. 10;
+    }"#,
+    ),
+    (
+        "E0326",
+        r#"The end of the `..` range is not a constant.
+
+The ranges exist only at compile time, so their bounds must be constant
+expressions.
+
+Erroneous code example:
+
+    fn main(end: u8) {
+        let range = 0 .. end;
+    }"#,
+    ),
+    (
+        "E0327",
+        r#"The first operand of the `||` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X || true;
+    }"#,
+    ),
+    (
+        "E0328",
+        r#"The second operand of the `||` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = true || X;
+    }"#,
+    ),
+    (
+        "E0329",
+        r#"The first operand of the `^^` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X ^^ true;
+    }"#,
+    ),
+    (
+        "E0330",
+        r#"The second operand of the `^^` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = true ^^ X;
+    }"#,
+    ),
+    (
+        "E0331",
+        r#"The first operand of the `&&` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X && true;
+    }"#,
+    ),
+    (
+        "E0332",
+        r#"The second operand of the `&&` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = true && X;
+    }"#,
+    ),
+    (
+        "E0333",
+        r#"The first operand of the `==` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X == true;
+    }"#,
+    ),
+    (
+        "E0334",
+        r#"The second operand of the `==` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = true == X;
+    }"#,
+    ),
+    (
+        "E0335",
+        r#"The first operand of the `!=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X != true;
+    }"#,
+    ),
+    (
+        "E0336",
+        r#"The second operand of the `!=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = true != X;
+    }"#,
+    ),
+    (
+        "E0337",
+        r#"The first operand of the `>=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X >= 42;
+    }"#,
+    ),
+    (
+        "E0338",
+        r#"The second operand of the `>=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 >= X;
+    }"#,
+    ),
+    (
+        "E0339",
+        r#"The first operand of the `<=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X <= 42;
+    }"#,
+    ),
+    (
+        "E0340",
+        r#"The second operand of the `<=` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 <= X;
+    }"#,
+    ),
+    (
+        "E0341",
+        r#"The first operand of the `>` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X > 42;
+    }"#,
+    ),
+    (
+        "E0342",
+        r#"The second operand of the `>` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 > X;
+    }"#,
+    ),
+    (
+        "E0343",
+        r#"The first operand of the `<` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X < 42;
+    }"#,
+    ),
+    (
+        "E0344",
+        r#"The second operand of the `<` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 < X;
+    }"#,
+    ),
+    (
+        "E0345",
+        r#"The first operand of the `|` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X | 42;
+    }"#,
+    ),
+    (
+        "E0346",
+        r#"The second operand of the `|` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 | X;
+    }"#,
+    ),
+    (
+        "E0347",
+        r#"The first operand of the `^` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X ^ 42;
+    }"#,
+    ),
+    (
+        "E0348",
+        r#"The second operand of the `^` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 ^ X;
+    }"#,
+    ),
+    (
+        "E0349",
+        r#"The first operand of the `&` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X & 42;
+    }"#,
+    ),
+    (
+        "E0350",
+        r#"The second operand of the `&` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 & X;
+    }"#,
+    ),
+    (
+        "E0351",
+        r#"The first operand of the `<<` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X << 42;
+    }"#,
+    ),
+    (
+        "E0352",
+        r#"The second operand of the `<<` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 << X;
+    }"#,
+    ),
+    (
+        "E0353",
+        r#"The first operand of the `>>` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X >> 42;
+    }"#,
+    ),
+    (
+        "E0354",
+        r#"The second operand of the `>>` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 >> X;
+    }"#,
+    ),
+    (
+        "E0355",
+        r#"The first operand of the `+` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X + 42;
+    }"#,
+    ),
+    (
+        "E0356",
+        r#"The second operand of the `+` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 + X;
+    }"#,
+    ),
+    (
+        "E0357",
+        r#"The first operand of the `-` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X - 42;
+    }"#,
+    ),
+    (
+        "E0358",
+        r#"The second operand of the `-` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 - X;
+    }"#,
+    ),
+    (
+        "E0359",
+        r#"The first operand of the `*` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X * 42;
+    }"#,
+    ),
+    (
+        "E0360",
+        r#"The second operand of the `*` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 * X;
+    }"#,
+    ),
+    (
+        "E0361",
+        r#"The first operand of the `/` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X / 42;
+    }"#,
+    ),
+    (
+        "E0362",
+        r#"The second operand of the `/` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 / X;
+    }"#,
+    ),
+    (
+        "E0363",
+        r#"The first operand of the `%` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X % 42;
+    }"#,
+    ),
+    (
+        "E0364",
+        r#"The second operand of the `%` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = 42 % X;
+    }"#,
+    ),
+    (
+        "E0365",
+        r#"The base of the `**` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X ** 2;
+    }"#,
+    ),
+    (
+        "E0366",
+        r#"The exponent of the `**` operator is not a constant.
+
+The exponentiation is unrolled into multiplications at compile time, so the
+exponent must be known in advance.
+
+Erroneous code example:
+
+    fn main(base: u8, exponent: u8) -> u8 {
+        base ** exponent
+    }"#,
+    ),
+    (
+        "E0367",
+        r#"The operand of the `as` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = X as field;
+    }"#,
+    ),
+    (
+        "E0368",
+        r#"The right side of the `as` operator is not a type.
+
+The parser only accepts a type after `as`, and the type aliases pointing to
+something other than a type are reported as E1301, so the error is not
+expected in practice."#,
+    ),
+    (
+        "E0369",
+        r#"The operand of the `!` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = bool;
+
+    fn main() {
+        let value = !X;
+    }"#,
+    ),
+    (
+        "E0370",
+        r#"The operand of the `~` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = ~X;
+    }"#,
+    ),
+    (
+        "E0371",
+        r#"The operand of the unary `-` operator is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let value = -X;
+    }"#,
+    ),
+    (
+        "E0372",
+        r#"The indexed expression is neither a memory place nor a value.
+
+Only the arrays can be indexed.
+
+Erroneous code example:
+
+    fn main() {
+        5[42];
+    }"#,
+    ),
+    (
+        "E0373",
+        r#"The array index is not a value.
+
+The operands must be values or constants, whereas the types, functions,
+modules and other items existing only at compile time have no value.
+
+Erroneous code example:
+
+    type X = u8;
+
+    fn main() {
+        let array = [1, 2, 3, 4, 5];
+        let value = array[X];
+    }"#,
+    ),
+    (
+        "E0374",
+        r#"The accessed expression is neither a memory place nor a value.
+
+Only the tuples and structures have fields.
+
+Erroneous code example:
+
+    fn main() {
+        5.data;
+    }"#,
+    ),
+    (
+        "E0375",
+        r#"The field access operator is not followed by a field name or a tuple index.
+
+The structure fields are accessed by their names, e.g. `point.x`, and the
+tuple fields are accessed by their indexes, e.g. `pair.0`.
+
+Erroneous code example:
+
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    fn main() {
+        let point = Point { x: 1, y: 2 };
+        let value = point.(1 + 1);
+    }"#,
+    ),
+    (
+        "E0376",
+        r#"The left side of the `::` operator is not a path.
+
+The path operator resolves the items in the namespaces of modules, structures
+and enumerations, so its left side must be an item name.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 5::UNDEFINED;
+    }"#,
+    ),
+    (
+        "E0377",
+        r#"The right side of the `::` operator is not an identifier.
+
+Erroneous code example:
+
+    enum Value {
+        First = 1,
+    }
+
+    fn main() {
+        let value = Value::5;
+    }"#,
+    ),
+    (
+        "E0401",
+        r#"A value of another type was assigned to the variable.
+
+Erroneous code example:
+
+    fn main() {
+        let mut value = 42;
+        value = false;
+    }"#,
+    ),
+    (
+        "E0402",
+        r#"An immutable variable was assigned twice.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42;
+        value = 69;
+    }
+
+Declare the variable as mutable: `let mut value = 42;`."#,
+    ),
+    (
+        "E0403",
+        r#"An element or field of an immutable variable was assigned.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3];
+        array[0] = 42;
+    }
+
+Declare the variable as mutable: `let mut array = [1, 2, 3];`."#,
+    ),
+    (
+        "E0404",
+        r#"The indexed variable is not an array.
+
+Only the arrays can be indexed. The tuple fields are accessed with the dot
+operator, e.g. `tuple.1`.
+
+Erroneous code example:
+
+    fn main() {
+        let tuple = (true, false, true);
+        let value = tuple[1];
+    }"#,
+    ),
+    (
+        "E0405",
+        r#"The array index is neither an integer nor a range.
+
+An array is indexed with an integer to get an element, or with a constant
+range to get a slice.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3];
+        let value = array[true];
+    }"#,
+    ),
+    (
+        "E0406",
+        r#"The field of a variable is accessed with an index, but the variable is not a
+tuple.
+
+Only the tuple fields are accessed with indexes. The structure fields are
+accessed by their names.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+    }
+
+    fn main() {
+        let data = Data { a: 0 };
+        let value = data.0;
+    }"#,
+    ),
+    (
+        "E0407",
+        r#"The field of a variable is accessed with a name, but the variable is not a
+structure.
+
+Only the structure fields are accessed by their names. The tuple fields are
+accessed with indexes, e.g. `tuple.0`.
+
+Erroneous code example:
+
+    fn main() {
+        let tuple = (true, true, false);
+        let value = tuple.first;
+    }"#,
+    ),
+    (
+        "E0408",
+        r#"The constant index is out of range of the array.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        let array = [1, 2, 3];
+        array[3]
+    }"#,
+    ),
+    (
+        "E0409",
+        r#"The start of the array slice range is out of the array bounds.
+
+The slice range bounds must be within the array size.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = array[-1 .. 1 as i8];
+    }"#,
+    ),
+    (
+        "E0410",
+        r#"The end of the array slice range is out of the array bounds.
+
+The slice range end must not exceed the array size.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = array[0 .. 6];
+    }"#,
+    ),
+    (
+        "E0411",
+        r#"The end of the array slice range is lesser than its start.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = array[2 .. 1];
+    }"#,
+    ),
+    (
+        "E0412",
+        r#"The tuple field does not exist.
+
+The tuple fields are numbered from zero, so the index must be lesser than the
+number of fields.
+
+Erroneous code example:
+
+    fn main() {
+        let tuple = (1, 2, 3);
+        let value = tuple.3;
+    }"#,
+    ),
+    (
+        "E0413",
+        r#"The structure field does not exist.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+    }
+
+    fn main() {
+        let data = Data { a: 0 };
+        let value = data.b;
+    }"#,
+    ),
+    (
+        "E0501",
+        r#"The first operand of the `||` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) -> bool {
+        value || flag
+    }"#,
+    ),
+    (
+        "E0502",
+        r#"The second operand of the `||` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) -> bool {
+        flag || value
+    }"#,
+    ),
+    (
+        "E0503",
+        r#"The first operand of the `^^` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) -> bool {
+        value ^^ flag
+    }"#,
+    ),
+    (
+        "E0504",
+        r#"The second operand of the `^^` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) -> bool {
+        flag ^^ value
+    }"#,
+    ),
+    (
+        "E0505",
+        r#"The first operand of the `&&` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) -> bool {
+        value && flag
+    }"#,
+    ),
+    (
+        "E0506",
+        r#"The second operand of the `&&` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) -> bool {
+        flag && value
+    }"#,
+    ),
+    (
+        "E0507",
+        r#"The unit value is compared with a value of another type with `==`.
+
+The values of different types cannot be compared.
+
+Erroneous code example:
+
+    fn main(value: u8) -> bool {
+        let unit = ();
+        unit == value
+    }"#,
+    ),
+    (
+        "E0508",
+        r#"The boolean value is compared with a value of another type with `==`.
+
+The values of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) -> bool {
+        flag == value
+    }"#,
+    ),
+    (
+        "E0509",
+        r#"The integer value is compared with a value of another type with `==`.
+
+The values of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) -> bool {
+        value == flag
+    }"#,
+    ),
+    (
+        "E0510",
+        r#"The values compared with `==` have different types.
+
+The arrays, tuples and structures are compared element by element, so they
+must have the same type, including the array sizes.
+
+Erroneous code example:
+
+    fn main(array: [u8; 3], value: u8) -> bool {
+        array == value
+    }"#,
+    ),
+    (
+        "E0511",
+        r#"The unit value is compared with a value of another type with `!=`.
+
+The values of different types cannot be compared.
+
+Erroneous code example:
+
+    fn main(value: u8) -> bool {
+        let unit = ();
+        unit != value
+    }"#,
+    ),
+    (
+        "E0512",
+        r#"The boolean value is compared with a value of another type with `!=`.
+
+The values of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) -> bool {
+        flag != value
+    }"#,
+    ),
+    (
+        "E0513",
+        r#"The integer value is compared with a value of another type with `!=`.
+
+The values of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) -> bool {
+        value != flag
+    }"#,
+    ),
+    (
+        "E0514",
+        r#"The values compared with `!=` have different types.
+
+The arrays, tuples and structures are compared element by element, so they
+must have the same type, including the array sizes.
+
+Erroneous code example:
+
+    fn main(array: [u8; 3], value: u8) -> bool {
+        array != value
+    }"#,
+    ),
+    (
+        "E0515",
+        r#"The first operand of the `>=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag >= value;
+    }"#,
+    ),
+    (
+        "E0516",
+        r#"The second operand of the `>=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value >= flag;
+    }"#,
+    ),
+    (
+        "E0517",
+        r#"The first operand of the `<=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag <= value;
+    }"#,
+    ),
+    (
+        "E0518",
+        r#"The second operand of the `<=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value <= flag;
+    }"#,
+    ),
+    (
+        "E0519",
+        r#"The first operand of the `>` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag > value;
+    }"#,
+    ),
+    (
+        "E0520",
+        r#"The second operand of the `>` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value > flag;
+    }"#,
+    ),
+    (
+        "E0521",
+        r#"The first operand of the `<` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag < value;
+    }"#,
+    ),
+    (
+        "E0522",
+        r#"The second operand of the `<` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value < flag;
+    }"#,
+    ),
+    (
+        "E0523",
+        r#"The first operand of the `|` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag | value;
+    }"#,
+    ),
+    (
+        "E0524",
+        r#"The second operand of the `|` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value | flag;
+    }"#,
+    ),
+    (
+        "E0525",
+        r#"The first operand of the `^` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag ^ value;
+    }"#,
+    ),
+    (
+        "E0526",
+        r#"The second operand of the `^` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value ^ flag;
+    }"#,
+    ),
+    (
+        "E0527",
+        r#"The first operand of the `&` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag & value;
+    }"#,
+    ),
+    (
+        "E0528",
+        r#"The second operand of the `&` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value & flag;
+    }"#,
+    ),
+    (
+        "E0529",
+        r#"The first operand of the `<<` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag << value;
+    }"#,
+    ),
+    (
+        "E0530",
+        r#"The second operand of the `<<` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value << flag;
+    }"#,
+    ),
+    (
+        "E0531",
+        r#"The first operand of the `>>` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag >> value;
+    }"#,
+    ),
+    (
+        "E0532",
+        r#"The second operand of the `>>` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value >> flag;
+    }"#,
+    ),
+    (
+        "E0533",
+        r#"The first operand of the `+` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag + value;
+    }"#,
+    ),
+    (
+        "E0534",
+        r#"The second operand of the `+` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value + flag;
+    }"#,
+    ),
+    (
+        "E0535",
+        r#"The first operand of the `-` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag - value;
+    }"#,
+    ),
+    (
+        "E0536",
+        r#"The second operand of the `-` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value - flag;
+    }"#,
+    ),
+    (
+        "E0537",
+        r#"The first operand of the `*` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag * value;
+    }"#,
+    ),
+    (
+        "E0538",
+        r#"The second operand of the `*` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value * flag;
+    }"#,
+    ),
+    (
+        "E0539",
+        r#"The first operand of the `/` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag / value;
+    }"#,
+    ),
+    (
+        "E0540",
+        r#"The second operand of the `/` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value / flag;
+    }"#,
+    ),
+    (
+        "E0541",
+        r#"The first operand of the `%` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(flag: bool, value: u8) {
+        let result = flag % value;
+    }"#,
+    ),
+    (
+        "E0542",
+        r#"The second operand of the `%` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main(value: u8, flag: bool) {
+        let result = value % flag;
+    }"#,
+    ),
+    (
+        "E0543",
+        r#"The first operand of the `**` operator is not an integer.
+
+The exponentiation is only defined for integers.
+
+Erroneous code example:
+
+    fn main(flag: bool) {
+        let result = flag ** 2;
+    }"#,
+    ),
+    (
+        "E0544",
+        r#"The second operand of the `**` operator is not an integer.
+
+The exponentiation is only defined for integers.
+
+Erroneous code example:
+
+    fn main(value: u8) {
+        let result = value ** true;
+    }"#,
+    ),
+    (
+        "E0545",
+        r#"The operand of the `!` operator is not a boolean.
+
+The logical negation is only defined for booleans. The integer bits are
+inverted with the `~` operator.
+
+Erroneous code example:
+
+    fn main(value: u8) -> bool {
+        !value
+    }"#,
+    ),
+    (
+        "E0546",
+        r#"The operand of the `~` operator is not an integer.
+
+The bitwise negation is only defined for integers. The booleans are negated
+with the `!` operator.
+
+Erroneous code example:
+
+    fn main(flag: bool) -> bool {
+        ~flag
+    }"#,
+    ),
+    (
+        "E0547",
+        r#"The operand of the unary `-` operator is not an integer.
+
+Erroneous code example:
+
+    fn main(flag: bool) {
+        let value = -flag;
+    }"#,
+    ),
+    (
+        "E0548",
+        r#"The indexed value is not an array.
+
+Only the arrays can be indexed. The tuple fields are accessed with the dot
+operator, e.g. `tuple.1`.
+
+Erroneous code example:
+
+    fn main() {
+        let value = (true, false, true)[1];
+    }"#,
+    ),
+    (
+        "E0549",
+        r#"The array index is neither an integer nor a range.
+
+An array is indexed with an integer to get an element, or with a constant
+range to get a slice.
+
+Erroneous code example:
+
+    fn main() {
+        let value = [1, 2, 3][true];
+    }"#,
+    ),
+    (
+        "E0550",
+        r#"The field of a value is accessed with an index, but the value is not a tuple.
+
+Only the tuple fields are accessed with indexes. The structure fields are
+accessed by their names.
+
+Erroneous code example:
+
+    fn main() {
+        let value = [true, true, false].1;
+    }"#,
+    ),
+    (
+        "E0551",
+        r#"The field of a value is accessed with a name, but the value is not a
+structure.
+
+Only the structure fields are accessed by their names. The tuple fields are
+accessed with indexes, e.g. `tuple.0`.
+
+Erroneous code example:
+
+    fn main() {
+        let value = [true, true, false].first;
+    }"#,
+    ),
+    (
+        "E0601",
+        r#"The operands of the `==` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first == second;
+    }"#,
+    ),
+    (
+        "E0602",
+        r#"The operands of the `!=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first != second;
+    }"#,
+    ),
+    (
+        "E0603",
+        r#"The operands of the `>=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first >= second;
+    }"#,
+    ),
+    (
+        "E0604",
+        r#"The operands of the `<=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first <= second;
+    }"#,
+    ),
+    (
+        "E0605",
+        r#"The operands of the `>` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first > second;
+    }"#,
+    ),
+    (
+        "E0606",
+        r#"The operands of the `<` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first < second;
+    }"#,
+    ),
+    (
+        "E0607",
+        r#"The operands of the `|` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first | second;
+    }"#,
+    ),
+    (
+        "E0608",
+        r#"The operands of the `^` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first ^ second;
+    }"#,
+    ),
+    (
+        "E0609",
+        r#"The operands of the `&` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first & second;
+    }"#,
+    ),
+    (
+        "E0610",
+        r#"The operands of the `+` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first + second;
+    }"#,
+    ),
+    (
+        "E0611",
+        r#"The operands of the `-` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first - second;
+    }"#,
+    ),
+    (
+        "E0612",
+        r#"The operands of the `*` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first * second;
+    }"#,
+    ),
+    (
+        "E0613",
+        r#"The operands of the `/` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first / second;
+    }"#,
+    ),
+    (
+        "E0614",
+        r#"The operands of the `%` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. Cast the narrower operand with `as` to the type of the wider one.
+
+Erroneous code example:
+
+    fn main(first: u64, second: u128) {
+        let value = first % second;
+    }"#,
+    ),
+    (
+        "E0615",
+        r#"The shift amount of the `<<` operator is signed.
+
+The shift amount cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main(value: u8, amount: i8) -> u8 {
+        value << amount
+    }"#,
+    ),
+    (
+        "E0616",
+        r#"The shift amount of the `>>` operator is signed.
+
+The shift amount cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main(value: u8, amount: i8) -> u8 {
+        value >> amount
+    }"#,
+    ),
+    (
+        "E0617",
+        r#"The exponent of the `**` operator is signed.
+
+The exponent cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main(base: u8) -> u8 {
+        base ** -2
+    }"#,
+    ),
+    (
+        "E0618",
+        r#"The `field` values cannot be divided.
+
+The `field` values are the elements of a prime field, where the division and
+remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Use the `std::ff::invert` function to get the multiplicative inverse.
+
+Erroneous code example:
+
+    fn main(first: field, second: field) -> field {
+        first / second
+    }"#,
+    ),
+    (
+        "E0619",
+        r#"The remainder of the `field` values cannot be taken.
+
+The `field` values are the elements of a prime field, where the division and
+remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Erroneous code example:
+
+    fn main(first: field, second: field) -> field {
+        first % second
+    }"#,
+    ),
+    (
+        "E0620",
+        r#"The bitwise operators are not defined for the `field` values.
+
+The `field` values are the elements of a prime field, where the division and
+remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Erroneous code example:
+
+    fn main(first: field, second: field) -> field {
+        first | second
+    }
+
+Convert the value to bits with `std::convert::to_bits` or cast it to an
+integer type."#,
+    ),
+    (
+        "E0621",
+        r#"The bitwise negation is not defined for the signed integers.
+
+The signed integers are not stored in the two's complement form, so their bits
+cannot be inverted.
+
+Erroneous code example:
+
+    fn main(value: i8) -> i8 {
+        ~value
+    }"#,
+    ),
+    (
+        "E0622",
+        r#"The `field` values cannot be negated.
+
+The `field` type is unsigned, so it has no negative values.
+
+Erroneous code example:
+
+    fn main(value: field) -> field {
+        -value
+    }"#,
+    ),
+    (
+        "E0701",
+        r#"The array element has a type different from the previous elements.
+
+All the array elements must have the type of the first one.
+
+Erroneous code example:
+
+    fn main() {
+        let array = [1, false];
+    }"#,
+    ),
+    (
+        "E0702",
+        r#"The constant index is out of range of the array.
+
+The arrays are indexed from zero, so the index must be lesser than the array
+size.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        [1, 2, 3, 4, 5][5]
+    }"#,
+    ),
+    (
+        "E0703",
+        r#"The start of the array slice range is out of the array bounds.
+
+The slice range bounds must be within the array size.
+
+Erroneous code example:
+
+    fn main() {
+        let slice = [1, 2, 3, 4, 5][-1 .. 1];
+    }"#,
+    ),
+    (
+        "E0704",
+        r#"The end of the array slice range is out of the array bounds.
+
+The slice range end must not exceed the array size.
+
+Erroneous code example:
+
+    fn main() {
+        let slice = [1, 2, 3, 4, 5][0 .. 6];
+    }"#,
+    ),
+    (
+        "E0705",
+        r#"The end of the array slice range is lesser than its start.
+
+Erroneous code example:
+
+    fn main() {
+        let slice = [1, 2, 3, 4, 5][2 .. 1];
+    }"#,
+    ),
+    (
+        "E0801",
+        r#"The tuple field does not exist.
+
+The tuple fields are numbered from zero, so the index must be lesser than the
+number of fields.
+
+Erroneous code example:
+
+    fn main() {
+        let value = (true, true, false).5;
+    }"#,
+    ),
+    (
+        "E0901",
+        r#"The structure field does not exist.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+    }
+
+    fn main() {
+        let value = Data { a: 0 }.b;
+    }"#,
+    ),
+    (
+        "E0902",
+        r#"The structure field is initialized twice.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+        b: u8,
+    }
+
+    fn main() {
+        let data = Data {
+            a: 25,
+            b: 42,
+            a: 69,
+        };
+    }"#,
+    ),
+    (
+        "E0903",
+        r#"The structure field is initialized with a value of another type.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+    }
+
+    fn main() {
+        let data = Data { a: true };
+    }"#,
+    ),
+    (
+        "E0904",
+        r#"The structure field is not initialized.
+
+Every field must be initialized, since there are no default values.
+
+Erroneous code example:
+
+    struct Data {
+        a: u8,
+        b: u8,
+    }
+
+    fn main() {
+        let data = Data { b: 25 };
+    }"#,
+    ),
+    (
+        "E1001",
+        r#"The first operand of the `..=` operator is not an integer.
+
+The range bounds must be integers.
+
+Erroneous code example:
+
+    fn main() {
+        let range = true ..= 42;
+    }"#,
+    ),
+    (
+        "E1002",
+        r#"The second operand of the `..=` operator is not an integer.
+
+The range bounds must be integers.
+
+Erroneous code example:
+
+    fn main() {
+        let range = 42 ..= true;
+    }"#,
+    ),
+    (
+        "E1003",
+        r#"The first operand of the `..` operator is not an integer.
+
+The range bounds must be integers.
+
+Erroneous code example:
+
+    fn main() {
+        let range = true .. 42;
+    }"#,
+    ),
+    (
+        "E1004",
+        r#"The second operand of the `..` operator is not an integer.
+
+The range bounds must be integers.
+
+Erroneous code example:
+
+    fn main() {
+        let range = 42 .. true;
+    }"#,
+    ),
+    (
+        "E1005",
+        r#"The first operand of the `||` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 || true;
+    }"#,
+    ),
+    (
+        "E1006",
+        r#"The second operand of the `||` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true || 42;
+    }"#,
+    ),
+    (
+        "E1007",
+        r#"The first operand of the `^^` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 ^^ true;
+    }"#,
+    ),
+    (
+        "E1008",
+        r#"The second operand of the `^^` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true ^^ 42;
+    }"#,
+    ),
+    (
+        "E1009",
+        r#"The first operand of the `&&` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 && true;
+    }"#,
+    ),
+    (
+        "E1010",
+        r#"The second operand of the `&&` operator is not a boolean.
+
+The logical operators are only defined for booleans, and the integers are not
+converted to booleans implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true && 42;
+    }"#,
+    ),
+    (
+        "E1011",
+        r#"The unit constant is compared with a constant of another type with `==`.
+
+The constants of different types cannot be compared.
+
+Erroneous code example:
+
+    fn main() {
+        let value = () == 42;
+    }"#,
+    ),
+    (
+        "E1012",
+        r#"The boolean constant is compared with a constant of another type with `==`.
+
+The constants of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true == 42;
+    }"#,
+    ),
+    (
+        "E1013",
+        r#"The integer constant is compared with a constant of another type with `==`.
+
+The constants of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 == true;
+    }"#,
+    ),
+    (
+        "E1014",
+        r#"The first operand of the `==` operator is not a primitive constant.
+
+Only the unit, boolean and integer constants can be compared at compile time.
+
+Erroneous code example:
+
+    fn main() {
+        let value = "string" == 42;
+    }"#,
+    ),
+    (
+        "E1015",
+        r#"The unit constant is compared with a constant of another type with `!=`.
+
+The constants of different types cannot be compared.
+
+Erroneous code example:
+
+    fn main() {
+        let value = () != 42;
+    }"#,
+    ),
+    (
+        "E1016",
+        r#"The boolean constant is compared with a constant of another type with `!=`.
+
+The constants of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true != 42;
+    }"#,
+    ),
+    (
+        "E1017",
+        r#"The integer constant is compared with a constant of another type with `!=`.
+
+The constants of different types cannot be compared, and the booleans are not
+converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 != true;
+    }"#,
+    ),
+    (
+        "E1018",
+        r#"The first operand of the `!=` operator is not a primitive constant.
+
+Only the unit, boolean and integer constants can be compared at compile time.
+
+Erroneous code example:
+
+    fn main() {
+        let value = "string" != 42;
+    }"#,
+    ),
+    (
+        "E1019",
+        r#"The first operand of the `>=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true >= 42;
+    }"#,
+    ),
+    (
+        "E1020",
+        r#"The second operand of the `>=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 >= true;
+    }"#,
+    ),
+    (
+        "E1021",
+        r#"The first operand of the `<=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true <= 42;
+    }"#,
+    ),
+    (
+        "E1022",
+        r#"The second operand of the `<=` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 <= true;
+    }"#,
+    ),
+    (
+        "E1023",
+        r#"The first operand of the `>` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true > 42;
+    }"#,
+    ),
+    (
+        "E1024",
+        r#"The second operand of the `>` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 > true;
+    }"#,
+    ),
+    (
+        "E1025",
+        r#"The first operand of the `<` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true < 42;
+    }"#,
+    ),
+    (
+        "E1026",
+        r#"The second operand of the `<` operator is not an integer.
+
+The ordering operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 < true;
+    }"#,
+    ),
+    (
+        "E1027",
+        r#"The first operand of the `|` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true | 42;
+    }"#,
+    ),
+    (
+        "E1028",
+        r#"The second operand of the `|` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 | true;
+    }"#,
+    ),
+    (
+        "E1029",
+        r#"The first operand of the `^` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true ^ 42;
+    }"#,
+    ),
+    (
+        "E1030",
+        r#"The second operand of the `^` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 ^ true;
+    }"#,
+    ),
+    (
+        "E1031",
+        r#"The first operand of the `&` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true & 42;
+    }"#,
+    ),
+    (
+        "E1032",
+        r#"The second operand of the `&` operator is not an integer.
+
+The bitwise operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 & true;
+    }"#,
+    ),
+    (
+        "E1033",
+        r#"The first operand of the `<<` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true << 42;
+    }"#,
+    ),
+    (
+        "E1034",
+        r#"The second operand of the `<<` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 << true;
+    }"#,
+    ),
+    (
+        "E1035",
+        r#"The first operand of the `>>` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true >> 42;
+    }"#,
+    ),
+    (
+        "E1036",
+        r#"The second operand of the `>>` operator is not an integer.
+
+The shift operators are only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 >> true;
+    }"#,
+    ),
+    (
+        "E1037",
+        r#"The first operand of the `+` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true + 42;
+    }"#,
+    ),
+    (
+        "E1038",
+        r#"The second operand of the `+` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 + true;
+    }"#,
+    ),
+    (
+        "E1039",
+        r#"The first operand of the `-` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true - 42;
+    }"#,
+    ),
+    (
+        "E1040",
+        r#"The second operand of the `-` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 - true;
+    }"#,
+    ),
+    (
+        "E1041",
+        r#"The first operand of the `*` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true * 42;
+    }"#,
+    ),
+    (
+        "E1042",
+        r#"The second operand of the `*` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 * true;
+    }"#,
+    ),
+    (
+        "E1043",
+        r#"The first operand of the `/` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true / 42;
+    }"#,
+    ),
+    (
+        "E1044",
+        r#"The second operand of the `/` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 / true;
+    }"#,
+    ),
+    (
+        "E1045",
+        r#"The first operand of the `%` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true % 42;
+    }"#,
+    ),
+    (
+        "E1046",
+        r#"The second operand of the `%` operator is not an integer.
+
+The arithmetic operators are only defined for integers, and the booleans are
+not converted to integers implicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 % true;
+    }"#,
+    ),
+    (
+        "E1047",
+        r#"The first operand of the `**` operator is not an integer.
+
+The exponentiation is only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = true ** 2;
+    }"#,
+    ),
+    (
+        "E1048",
+        r#"The second operand of the `**` operator is not an integer.
+
+The exponentiation is only defined for integers.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 2 ** true;
+    }"#,
+    ),
+    (
+        "E1049",
+        r#"The operand of the `!` operator is not a boolean.
+
+The logical negation is only defined for booleans. The integer bits are
+inverted with the `~` operator.
+
+Erroneous code example:
+
+    fn main() {
+        let value = !42;
+    }"#,
+    ),
+    (
+        "E1050",
+        r#"The operand of the `~` operator is not an integer.
+
+The bitwise negation is only defined for integers. The booleans are negated
+with the `!` operator.
+
+Erroneous code example:
+
+    fn main() {
+        let value = ~true;
+    }"#,
+    ),
+    (
+        "E1051",
+        r#"The operand of the unary `-` operator is not an integer.
+
+Erroneous code example:
+
+    fn main() {
+        let value = -true;
+    }"#,
+    ),
+    (
+        "E1101",
+        r#"The constant operands of the `==` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 == 69 as u128;
+    }"#,
+    ),
+    (
+        "E1102",
+        r#"The constant operands of the `!=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 != 69 as u128;
+    }"#,
+    ),
+    (
+        "E1103",
+        r#"The constant operands of the `>=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 >= 69 as u128;
+    }"#,
+    ),
+    (
+        "E1104",
+        r#"The constant operands of the `<=` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 <= 69 as u128;
+    }"#,
+    ),
+    (
+        "E1105",
+        r#"The constant operands of the `>` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 > 69 as u128;
+    }"#,
+    ),
+    (
+        "E1106",
+        r#"The constant operands of the `<` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 < 69 as u128;
+    }"#,
+    ),
+    (
+        "E1107",
+        r#"The constant operands of the `|` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 | 69 as u128;
+    }"#,
+    ),
+    (
+        "E1108",
+        r#"The constant operands of the `^` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 ^ 69 as u128;
+    }"#,
+    ),
+    (
+        "E1109",
+        r#"The constant operands of the `&` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 & 69 as u128;
+    }"#,
+    ),
+    (
+        "E1110",
+        r#"The constant operands of the `+` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 + 69 as u128;
+    }"#,
+    ),
+    (
+        "E1111",
+        r#"The constant operands of the `-` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 - 69 as u128;
+    }"#,
+    ),
+    (
+        "E1112",
+        r#"The constant operands of the `*` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 * 69 as u128;
+    }"#,
+    ),
+    (
+        "E1113",
+        r#"The constant operands of the `/` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 / 69 as u128;
+    }"#,
+    ),
+    (
+        "E1114",
+        r#"The constant operands of the `%` operator have different integer types.
+
+The integer operands must have the same type, and the integers are not widened
+implicitly. The enumeration variants have their enumeration types, so they
+must be cast to integers explicitly.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as u64 % 69 as u128;
+    }"#,
+    ),
+    (
+        "E1115",
+        r#"The constant shift amount of the `<<` operator is signed.
+
+The shift amount cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 << -2;
+    }"#,
+    ),
+    (
+        "E1116",
+        r#"The constant shift amount of the `>>` operator is signed.
+
+The shift amount cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 >> -2;
+    }"#,
+    ),
+    (
+        "E1117",
+        r#"The constant exponent of the `**` operator is signed.
+
+The exponent cannot be negative, so it must have an unsigned integer type.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 2 ** -2;
+    }"#,
+    ),
+    (
+        "E1118",
+        r#"The constant addition result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        200 as u8 + 100 as u8
+    }
+
+Cast the operands to a wider type."#,
+    ),
+    (
+        "E1119",
+        r#"The constant subtraction result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+The result of an unsigned subtraction cannot be negative.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 - 255;
+    }
+
+Cast the operands to a signed type."#,
+    ),
+    (
+        "E1120",
+        r#"The constant multiplication result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 100 as i8 * 2 as i8;
+    }
+
+Cast the operands to a wider type."#,
+    ),
+    (
+        "E1121",
+        r#"The constant division result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+The only such case is the division of the minimal signed value by -1.
+
+Erroneous code example:
+
+    fn main() {
+        let value = -128 / (-1);
+    }"#,
+    ),
+    (
+        "E1122",
+        r#"The constant remainder does not fit into its type.
+
+The Euclidean remainder is always lesser than the divisor by absolute value,
+so the check only guards the compiler invariants, and the error is not
+expected in practice."#,
+    ),
+    (
+        "E1123",
+        r#"The constant exponentiation result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 2 ** 8;
+    }
+
+Cast the base to a wider type, e.g. `2 as u16 ** 8`."#,
+    ),
+    (
+        "E1124",
+        r#"The constant does not fit into the type it is cast to.
+
+The constants can be cast to any integer type, provided the value fits into
+it.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 300 as i8;
+    }"#,
+    ),
+    (
+        "E1125",
+        r#"The constant negation result does not fit into its type.
+
+The constant expressions are evaluated at compile time, so the overflow is
+detected before the circuit is run.
+
+Erroneous code example:
+
+    fn main() {
+        let value = -(200);
+    }"#,
+    ),
+    (
+        "E1126",
+        r#"The integer literal does not fit into the type of its suffix.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 300u8;
+    }"#,
+    ),
+    (
+        "E1127",
+        r#"The integer literal does not fit into the type inferred from the other
+operand.
+
+An integer literal without a type suffix gets the type of the other operand of
+a binary operator, the type of the function parameter it is passed to, or the
+type of the variable it is assigned to.
+
+Erroneous code example:
+
+    fn main(value: u8) -> u8 {
+        value + 300
+    }"#,
+    ),
+    (
+        "E1128",
+        r#"The `field` constants cannot be divided.
+
+The `field` constants are the elements of a prime field, where the division
+and remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as field / 1 as field;
+    }"#,
+    ),
+    (
+        "E1129",
+        r#"The remainder of the `field` constants cannot be taken.
+
+The `field` constants are the elements of a prime field, where the division
+and remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as field % 1 as field;
+    }"#,
+    ),
+    (
+        "E1130",
+        r#"The bitwise operators are not defined for the `field` constants.
+
+The `field` constants are the elements of a prime field, where the division
+and remainder are not the integer ones, and the bitwise operations are not
+defined.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 42 as field | 1 as field;
+    }"#,
+    ),
+    (
+        "E1131",
+        r#"The bitwise negation is not defined for the signed integer constants.
+
+The signed integers are not stored in the two's complement form, so their bits
+cannot be inverted.
+
+Erroneous code example:
+
+    fn main() {
+        let value = ~(42 as i8);
+    }"#,
+    ),
+    (
+        "E1132",
+        r#"The `field` constants cannot be negated.
+
+The `field` type is unsigned, so it has no negative values.
+
+Erroneous code example:
+
+    fn main() {
+        let value = -(42 as field);
+    }"#,
+    ),
+    (
+        "E1133",
+        r#"The expression divides by a constant zero.
+
+The divisor is checked after the constant folding, so a constant expression like
+`2 - 2` is rejected as well, even if the dividend is only known at runtime.
+
+Erroneous code example:
+
+    fn main(value: u8) -> u8 {
+        value / (2 - 2)
+    }"#,
+    ),
+    (
+        "E1134",
+        r#"The expression takes the remainder of a division by a constant zero.
+
+Erroneous code example:
+
+    fn main(value: u8) -> u8 {
+        value % 0
+    }"#,
+    ),
+    (
+        "E1135",
+        r#"The integer literal does not fit into the `field` type.
+
+The largest integer type is `field`, whose bitlength is 254.
+
+Erroneous code example:
+
+    fn main() {
+        let value = 0xffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff;
+    }"#,
+    ),
+    (
+        "E1136",
+        r#"A negative integer was given an unsigned type.
+
+The error usually means that a negative match pattern is used with an unsigned
+scrutinee.
+
+Erroneous code example:
+
+    fn main(witness: u8) -> u8 {
+        match witness {
+            -1 => 10,
+            _ => 20,
+        }
+    }"#,
+    ),
+    (
+        "E1201",
+        r#"The value cannot be cast from its type.
+
+Only the integer values can be cast.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        true as u8
+    }"#,
+    ),
+    (
+        "E1202",
+        r#"The value cannot be cast to the type.
+
+Only the integer types can be the casting target.
+
+Erroneous code example:
+
+    fn main() -> bool {
+        42 as bool
+    }"#,
+    ),
+    (
+        "E1203",
+        r#"The value cannot be cast to a narrower type.
+
+The narrowing casts would lose data, so they are forbidden.
+
+Erroneous code example:
+
+    fn main(witness: u16) -> u8 {
+        witness as u8
+    }"#,
+    ),
+    (
+        "E1301",
+        r#"The type alias does not point to a type.
+
+The paths used as types must point to the types, structures, enumerations or
+type aliases.
+
+Erroneous code example:
+
+    fn main() {
+        let unknown = 0;
+        let value = 42 as unknown;
+    }"#,
+    ),
+    (
+        "E1302",
+        r#"The structure literal type does not point to a structure.
+
+Erroneous code example:
+
+    type X = field;
+
+    fn main() {
+        let data = X {
+            value: 42,
+        };
+    }"#,
+    ),
+    (
+        "E1401",
+        r#"The structure has two fields with the same name.
+
+Erroneous code example:
+
+    struct Point {
+        x: u8,
+        x: u8,
+    }"#,
+    ),
+    (
+        "E1501",
+        r#"The function was called with a wrong number of arguments.
+
+Erroneous code example:
+
+    fn sum(a: u8, b: u8) -> u8 { a + b }
+
+    fn main() -> u8 {
+        sum(1)
+    }"#,
+    ),
+    (
+        "E1502",
+        r#"The function argument has a wrong type.
+
+Erroneous code example:
+
+    fn sum(a: u8, b: u8) -> u8 { a + b }
+
+    fn main() -> u8 {
+        sum(1, false)
+    }"#,
+    ),
+    (
+        "E1503",
+        r#"The function argument must be a constant.
+
+Some built-in and standard library function arguments, like array sizes,
+must be known at compile time.
+
+Erroneous code example:
+
+    fn main(witness: u8) {
+        let array = std::array::pad([1, 2, 3], witness, 0);
+    }"#,
+    ),
+    (
+        "E1504",
+        r#"The function argument is not a value.
+
+Erroneous code example:
+
+    fn sum(a: u8, b: u8) -> u8 { a + b }
+
+    fn main() -> u8 {
+        sum(1, u8)
+    }"#,
+    ),
+    (
+        "E1505",
+        r#"The function returns a value of a type different from its signature.
+
+Erroneous code example:
+
+    fn get() -> u8 {
+        false
+    }
+
+    fn main() -> u8 {
+        get()
+    }"#,
+    ),
+    (
+        "E1506",
+        r#"An item which is not a function was called.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        let value = 42;
+        value()
+    }"#,
+    ),
+    (
+        "E1507",
+        r#"The function is called before its return type is inferred.
+
+The omitted or `_` return type is inferred from the function block, so it is
+unknown while the block is being analyzed. A function declared inside the block
+cannot call the enclosing one until then.
+
+Erroneous code example:
+
+    fn another() -> _ {
+        fn nested() -> u8 {
+            another()
+        }
+        nested()
+    }
+
+Specify the return type of the enclosing function explicitly."#,
+    ),
+    (
+        "E1601",
+        r#"The built-in function does not exist.
+
+The `!` specifier is only used with the built-in functions `dbg!` and
+`assert!`.
+
+Erroneous code example:
+
+    fn unknown() {}
+
+    fn main() {
+        unknown!();
+    }
+
+Remove the `!` to call the user-defined function."#,
+    ),
+    (
+        "E1602",
+        r#"The built-in function is called without the `!` specifier.
+
+The built-in functions `dbg!` and `assert!` must be called with the `!`
+specifier, which distinguishes them from the user-defined functions.
+
+Erroneous code example:
+
+    fn main() {
+        assert(true);
+    }"#,
+    ),
+    (
+        "E1603",
+        r#"The number of the `dbg!` arguments does not match its format string.
+
+Every `{}` placeholder in the format string must have an argument.
+
+Erroneous code example:
+
+    fn main() {
+        dbg!("{} {}", 42);
+    }"#,
+    ),
+    (
+        "E1701",
+        r#"The array cannot be truncated to a bigger size.
+
+Use `std::array::pad` to make the array bigger.
+
+Erroneous code example:
+
+    fn main() -> [u8; 4] {
+        std::array::truncate([1, 2], 4)
+    }"#,
+    ),
+    (
+        "E1702",
+        r#"The array cannot be padded to a lesser size.
+
+Use `std::array::truncate` to make the array smaller.
+
+Erroneous code example:
+
+    fn main() -> [u8; 2] {
+        std::array::pad([1, 2, 3, 4], 2, 0)
+    }"#,
+    ),
+    (
+        "E1703",
+        r#"The new array length is not a valid array size.
+
+The array size must fit into the `usize` type of the compiler platform.
+
+Erroneous code example:
+
+    fn main() -> [u8; 4] {
+        std::array::truncate([1], 0x1_00000000_00000000)
+    }"#,
+    ),
+    (
+        "E1704",
+        r#"The array passed to `std::array::binary_search` is not sorted.
+
+The binary search only works with the arrays sorted in the ascending order,
+which is checked at compile time.
+
+Erroneous code example:
+
+    const TABLE: [u8; 5] = [1, 2, 5, 4, 8];
+
+    fn main(key: u8) -> bool {
+        std::array::binary_search(TABLE, key).0
+    }"#,
+    ),
+    (
+        "E1801",
+        r#"The enumeration has two variants with the same name.
+
+Erroneous code example:
+
+    enum Direction {
+        Up = 1,
+        Down = 2,
+        Up = 3,
+    }"#,
+    ),
+    (
+        "W0001",
+        r#"The variable is declared, but never used.
+
+If the variable is unused intentionally, prefix its name with an underscore.
+
+Erroneous code example:
+
+    fn main() -> u8 {
+        let unused = 42;
+        0
+    }"#,
+    ),
+    (
+        "W0002",
+        r#"The function is never called from the `main` function.
+
+The function is not compiled into the circuit, so it is most likely a mistake.
+
+Erroneous code example:
+
+    fn unused() -> u8 {
+        42
+    }
+
+    fn main() -> u8 {
+        0
+    }"#,
+    ),
+    (
+        "W0003",
+        r#"The imported item is never used.
+
+Erroneous code example:
+
+    enum Default {
+        First = 1,
+        Second = 2,
+    }
+
+    use Default::First;
+
+    fn main() -> u8 {
+        0
+    }"#,
+    ),
+    (
+        "W0004",
+        r#"The index type may exceed the array size.
+
+The runtime index is checked to be within the array at the proving time, so
+the proof fails if the index is too big. Use an index type whose maximal value
+is lesser than the array size, or mask the index with the remainder operator.
+
+Erroneous code example:
+
+    fn main(array: [u8; 4], index: u16) -> u8 {
+        array[index]
+    }"#,
+    ),
+];
+
+///
+/// Returns the explanation with an example program for the error `code`, e.g. `E0402`.
+///
+/// Returns `None` if the code does not exist or has not been explained yet.
+///
+pub fn explain(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(key, _explanation)| *key == code)
+        .map(|(_key, explanation)| *explanation)
+}
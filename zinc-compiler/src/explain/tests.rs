@@ -0,0 +1,180 @@
+//!
+//! The error explanation tests.
+//!
+
+#![cfg(test)]
+
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+use crate::syntax::error::Error as SyntaxError;
+use crate::Parser;
+
+///
+/// The files with the `code` methods of the error enums.
+///
+/// Every variant is covered by the exhaustive matches, so the codes are collected from the
+/// sources to check that they are not repeated.
+///
+static SOURCES: &[&str] = &[
+    include_str!("../syntax/error.rs"),
+    include_str!("../semantic/error.rs"),
+    include_str!("../semantic/scope/error.rs"),
+    include_str!("../semantic/element/error.rs"),
+    include_str!("../semantic/element/place/error.rs"),
+    include_str!("../semantic/element/value/error.rs"),
+    include_str!("../semantic/element/value/integer/error.rs"),
+    include_str!("../semantic/element/value/array/error.rs"),
+    include_str!("../semantic/element/value/tuple/error.rs"),
+    include_str!("../semantic/element/value/structure/error.rs"),
+    include_str!("../semantic/element/constant/error.rs"),
+    include_str!("../semantic/element/constant/integer/error.rs"),
+    include_str!("../semantic/casting/error.rs"),
+    include_str!("../semantic/element/type/error.rs"),
+    include_str!("../semantic/element/type/structure/error.rs"),
+    include_str!("../semantic/element/type/function/error.rs"),
+    include_str!("../semantic/element/type/function/builtin/error.rs"),
+    include_str!("../semantic/element/type/function/stdlib/error.rs"),
+    include_str!("../semantic/element/type/enumeration/error.rs"),
+    include_str!("../warning.rs"),
+];
+
+fn codes() -> Vec<&'static str> {
+    let mut codes = Vec::new();
+    for source in SOURCES.iter() {
+        let mut rest = *source;
+        while let Some(start) = rest
+            .match_indices('"')
+            .map(|(index, _)| index)
+            .find(|index| rest[index + 1..].starts_with(|c| c == 'E' || c == 'W'))
+        {
+            rest = &rest[start + 1..];
+            let end = rest[1..].find('"').map(|end| end + 1).unwrap_or(0);
+            codes.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    codes
+}
+
+#[test]
+fn ok_codes_unique() {
+    let codes = codes();
+
+    let mut unique = HashSet::with_capacity(codes.len());
+    for code in codes.iter() {
+        assert!(unique.insert(code), "The code {} is repeated", code);
+    }
+}
+
+#[test]
+fn ok_codes_well_formed() {
+    for code in codes().into_iter() {
+        assert!(
+            code.len() == 5 && code[1..].chars().all(|c| c.is_ascii_digit()),
+            "The code {} is malformed",
+            code
+        );
+    }
+}
+
+#[test]
+fn ok_explanations_refer_to_existing_codes() {
+    let codes: HashSet<&str> = codes().into_iter().collect();
+
+    let mut unique = HashSet::new();
+    for (code, explanation) in super::EXPLANATIONS.iter() {
+        assert!(codes.contains(code), "The code {} does not exist", code);
+        assert!(unique.insert(code), "The code {} is explained twice", code);
+        assert!(
+            explanation.contains("Erroneous code example:")
+                || explanation.contains("is not expected in practice"),
+            "The code {} explanation has no example",
+            code
+        );
+    }
+}
+
+#[test]
+fn ok_codes_explained() {
+    for code in codes().into_iter() {
+        assert!(
+            crate::explain(code).is_some(),
+            "The code {} is not explained",
+            code
+        );
+    }
+}
+
+#[test]
+fn ok_syntax() {
+    let input = r#"
+fn main() {
+    let = 42;
+}
+"#;
+
+    let result = Parser::default().parse(input, None);
+
+    match result {
+        Err(Error::Syntax(error @ SyntaxError::ExpectedMutOrIdentifier { .. })) => {
+            assert_eq!(error.code(), "E0004")
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn ok_syntax_or_operator() {
+    let input = r#"
+fn main() {
+    let value = 42 43;
+}
+"#;
+
+    let result = Parser::default().parse(input, None);
+
+    match result {
+        Err(Error::Syntax(error @ SyntaxError::ExpectedOneOfOrOperator { .. })) => {
+            assert_eq!(error.code(), "E0002")
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn ok_semantic() {
+    let input = r#"
+fn main() {
+    let value = 42;
+    value = 69;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    match result {
+        Err(Error::Semantic(error)) => {
+            assert_eq!(error.code(), "E0402");
+            assert!(crate::explain(error.code()).is_some());
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn ok_semantic_top_level() {
+    let error = SemanticError::EntryPointMissing;
+
+    assert_eq!(error.code(), "E0122");
+    assert!(crate::explain(error.code())
+        .expect("The code is explained")
+        .contains("`main`"));
+}
+
+#[test]
+fn error_unknown_code() {
+    assert_eq!(crate::explain("E9999"), None);
+    assert_eq!(crate::explain("E0402x"), None);
+}
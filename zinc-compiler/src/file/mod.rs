@@ -14,17 +14,23 @@ use std::sync::RwLock;
 
 use lazy_static::lazy_static;
 
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
 use crate::generator::bytecode::Bytecode;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::scope::Scope;
 use crate::syntax::parser::Parser;
+use crate::syntax::tree::Tree;
+use crate::warning::Warning;
 
 use self::error::Error;
 
 pub struct File {
     path: PathBuf,
     code: String,
+    deny_warnings: bool,
+    loop_iterations_limit: usize,
 }
 
 lazy_static! {
@@ -32,11 +38,28 @@ lazy_static! {
 }
 
 impl File {
+    ///
+    /// Makes the compiler fail if there are any warnings, which is useful for CI.
+    ///
+    pub fn set_deny_warnings(&mut self, value: bool) {
+        self.deny_warnings = value;
+    }
+
+    ///
+    /// Sets the maximal number of loop iterations, including the ones of the enclosing loops.
+    ///
+    pub fn set_loop_iterations_limit(&mut self, value: usize) {
+        self.loop_iterations_limit = value;
+    }
+
+    ///
+    /// Compiles the circuit entry, returning the formatted warnings.
+    ///
     pub fn try_into_entry(
         self,
         bytecode: Rc<RefCell<Bytecode>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<(), String> {
+    ) -> Result<Vec<String>, String> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
         let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
@@ -45,22 +68,27 @@ impl File {
             .expect(crate::PANIC_MUTEX_SYNC)
             .push(self.path);
 
-        let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+        let syntax_tree = Self::parse(&self.code, next_file_id, &lines)?;
 
-        EntryAnalyzer::new()
+        let mut analyzer = EntryAnalyzer::new();
+        analyzer.set_loop_iterations_limit(self.loop_iterations_limit);
+        let (intermediate, warnings) = analyzer
             .compile(syntax_tree, dependencies)
-            .map_err(|error| error.format(&lines))?
-            .write_all_to_bytecode(bytecode);
+            .map_err(|error| error.format(&lines))?;
+        let warnings = Self::format_warnings(warnings, self.deny_warnings, &lines)?;
+
+        intermediate.write_all_to_bytecode(bytecode);
 
-        Ok(())
+        Ok(warnings)
     }
 
+    ///
+    /// Compiles the circuit module, returning its scope and the formatted warnings.
+    ///
     pub fn try_into_module(
         self,
         bytecode: Rc<RefCell<Bytecode>>,
-    ) -> Result<Rc<RefCell<Scope>>, String> {
+    ) -> Result<(Rc<RefCell<Scope>>, Vec<String>), String> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
         let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
@@ -69,17 +97,135 @@ impl File {
             .expect(crate::PANIC_MUTEX_SYNC)
             .push(self.path);
 
-        let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+        let syntax_tree = Self::parse(&self.code, next_file_id, &lines)?;
 
-        let (scope, intermediate) = ModuleAnalyzer::new()
+        let mut analyzer = ModuleAnalyzer::new();
+        analyzer.set_loop_iterations_limit(self.loop_iterations_limit);
+        let (scope, intermediate, warnings) = analyzer
             .compile(syntax_tree)
             .map_err(|error| error.format(&lines))?;
+        let warnings = Self::format_warnings(warnings, self.deny_warnings, &lines)?;
 
         intermediate.write_all_to_bytecode(bytecode);
 
-        Ok(scope)
+        Ok((scope, warnings))
+    }
+
+    ///
+    /// Checks the circuit entry without generating the bytecode, returning the errors and
+    /// warnings as data, which is useful for the IDE integrations.
+    ///
+    pub fn diagnose_entry(
+        self,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Vec<Diagnostic> {
+        let deny_warnings = self.deny_warnings;
+        let loop_iterations_limit = self.loop_iterations_limit;
+        let syntax_tree = match self.parse_diagnosed() {
+            Ok(syntax_tree) => syntax_tree,
+            Err(diagnostics) => return diagnostics,
+        };
+
+        let mut analyzer = EntryAnalyzer::new();
+        analyzer.set_loop_iterations_limit(loop_iterations_limit);
+        match analyzer.compile(syntax_tree, dependencies) {
+            Ok((_intermediate, warnings)) => Self::diagnose_warnings(warnings, deny_warnings),
+            Err(error) => vec![error.diagnostic()],
+        }
+    }
+
+    ///
+    /// Checks the circuit module without generating the bytecode, returning the errors and
+    /// warnings as data, which is useful for the IDE integrations.
+    ///
+    pub fn diagnose_module(self) -> Vec<Diagnostic> {
+        let deny_warnings = self.deny_warnings;
+        let loop_iterations_limit = self.loop_iterations_limit;
+        let syntax_tree = match self.parse_diagnosed() {
+            Ok(syntax_tree) => syntax_tree,
+            Err(diagnostics) => return diagnostics,
+        };
+
+        let mut analyzer = ModuleAnalyzer::new();
+        analyzer.set_loop_iterations_limit(loop_iterations_limit);
+        match analyzer.compile(syntax_tree) {
+            Ok((_scope, _intermediate, warnings)) => {
+                Self::diagnose_warnings(warnings, deny_warnings)
+            }
+            Err(error) => vec![error.diagnostic()],
+        }
+    }
+
+    ///
+    /// Parses the file code, reporting all the syntax errors at once.
+    ///
+    fn parse(code: &str, file_id: usize, lines: &[&str]) -> Result<Tree, String> {
+        let (syntax_tree, errors) = Parser::default().parse_recovering(code, Some(file_id));
+        if errors.is_empty() {
+            return Ok(syntax_tree);
+        }
+
+        Err(errors
+            .into_iter()
+            .map(|error| error.format(lines))
+            .collect::<Vec<String>>()
+            .join("\n"))
+    }
+
+    ///
+    /// Registers the file in the global index and parses it, returning the syntax errors as
+    /// diagnostics.
+    ///
+    fn parse_diagnosed(self) -> Result<Tree, Vec<Diagnostic>> {
+        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        INDEX
+            .write()
+            .expect(crate::PANIC_MUTEX_SYNC)
+            .push(self.path);
+
+        let (syntax_tree, errors) =
+            Parser::default().parse_recovering(&self.code, Some(next_file_id));
+        if errors.is_empty() {
+            return Ok(syntax_tree);
+        }
+
+        Err(errors.into_iter().map(|error| error.diagnostic()).collect())
+    }
+
+    ///
+    /// Converts the warnings into diagnostics, which are errors if the warnings are denied.
+    ///
+    fn diagnose_warnings(warnings: Vec<Warning>, deny_warnings: bool) -> Vec<Diagnostic> {
+        warnings
+            .into_iter()
+            .map(|warning| {
+                let mut diagnostic = warning.diagnostic();
+                if deny_warnings {
+                    diagnostic.set_severity(Severity::Error);
+                }
+                diagnostic
+            })
+            .collect()
+    }
+
+    ///
+    /// Formats the warnings, which are turned into an error if they are denied.
+    ///
+    fn format_warnings(
+        warnings: Vec<Warning>,
+        deny_warnings: bool,
+        lines: &[&str],
+    ) -> Result<Vec<String>, String> {
+        let warnings: Vec<String> = warnings
+            .into_iter()
+            .map(|warning| warning.format(lines))
+            .collect();
+
+        if deny_warnings && !warnings.is_empty() {
+            return Err(warnings.join("\n"));
+        }
+
+        Ok(warnings)
     }
 }
 
@@ -102,6 +248,11 @@ impl TryFrom<PathBuf> for File {
             .map_err(Error::Reading)
             .map_err(|error| error.to_string())?;
 
-        Ok(Self { path, code })
+        Ok(Self {
+            path,
+            code,
+            deny_warnings: false,
+            loop_iterations_limit: crate::LIMIT_LOOP_ITERATIONS,
+        })
     }
 }
@@ -10,6 +10,7 @@ use zinc_bytecode::Instruction;
 use zinc_bytecode::Program;
 
 use crate::generator::r#type::Type;
+use crate::generator::statement::function::Statement as FunctionStatement;
 use crate::lexical::token::location::Location;
 
 static PANIC_JSON_TEMPLATE_SERIALIZATION: &str =
@@ -18,7 +19,7 @@ static PANIC_JSON_TEMPLATE_SERIALIZATION: &str =
 ///
 /// The Zinc VM bytecode, generated by the compiler target code generator.
 ///
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Bytecode {
     input_fields: Vec<(String, Type)>,
     output_type: Type,
@@ -27,6 +28,10 @@ pub struct Bytecode {
     data_stack_pointer: usize,
     variable_addresses: HashMap<String, usize>,
     function_addresses: HashMap<usize, usize>,
+    forward_calls: Vec<(usize, usize)>,
+
+    is_function_open: bool,
+    deferred_functions: Vec<FunctionStatement>,
 
     current_file: String,
     current_location: Location,
@@ -60,6 +65,10 @@ impl Bytecode {
             function_addresses: HashMap::with_capacity(
                 Self::FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            forward_calls: vec![],
+
+            is_function_open: false,
+            deferred_functions: vec![],
 
             current_file: String::new(),
             current_location: Location::new_beginning(None),
@@ -73,7 +82,9 @@ impl Bytecode {
     pub fn start_function(&mut self, unique_id: usize, identifier: String) {
         let address = self.instructions.len();
         self.function_addresses.insert(unique_id, address);
+        self.resolve_forward_calls(unique_id, address);
         self.data_stack_pointer = 0;
+        self.is_function_open = true;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -103,9 +114,11 @@ impl Bytecode {
 
         let address = self.instructions.len();
         self.function_addresses.insert(unique_id, address);
+        self.resolve_forward_calls(unique_id, address);
         self.instructions[0] = Instruction::Call(zinc_bytecode::Call::new(address, input_size));
         self.instructions[1] = Instruction::Exit(zinc_bytecode::Exit::new(output_size));
         self.data_stack_pointer = 0;
+        self.is_function_open = true;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -118,6 +131,26 @@ impl Bytecode {
         ));
     }
 
+    ///
+    /// Closes the function being written and returns the functions nested into it,
+    /// which must be written right after it.
+    ///
+    pub fn end_function(&mut self) -> Vec<FunctionStatement> {
+        self.is_function_open = false;
+        std::mem::take(&mut self.deferred_functions)
+    }
+
+    pub fn is_function_open(&self) -> bool {
+        self.is_function_open
+    }
+
+    ///
+    /// Postpones writing a nested function until its enclosing function is written.
+    ///
+    pub fn defer_function(&mut self, function: FunctionStatement) {
+        self.deferred_functions.push(function);
+    }
+
     pub fn declare_variable(&mut self, identifier: Option<String>, r#type: Type) -> usize {
         let start_address = self.data_stack_pointer;
         if let Some(identifier) = identifier {
@@ -148,6 +181,29 @@ impl Bytecode {
         self.instructions.push(instruction)
     }
 
+    ///
+    /// Pushes a call to the function with `unique_id`.
+    ///
+    /// If the function has not been written yet, which is the case for nested functions,
+    /// the address is patched as soon as the function is started.
+    ///
+    pub fn push_call(&mut self, unique_id: usize, input_size: usize, location: Location) {
+        let address = self.get_function_address(unique_id);
+
+        self.push_instruction(
+            Instruction::Call(zinc_bytecode::Call::new(
+                address.unwrap_or_default(),
+                input_size,
+            )),
+            Some(location),
+        );
+
+        if address.is_none() {
+            self.forward_calls
+                .push((self.instructions.len() - 1, unique_id));
+        }
+    }
+
     pub fn get_function_address(&self, unique_id: usize) -> Option<usize> {
         self.function_addresses.get(&unique_id).copied()
     }
@@ -192,6 +248,19 @@ impl Bytecode {
         program.to_bytes()
     }
 
+    fn resolve_forward_calls(&mut self, unique_id: usize, address: usize) {
+        let instructions = &mut self.instructions;
+        self.forward_calls.retain(|(index, id)| {
+            if *id != unique_id {
+                return true;
+            }
+            if let Instruction::Call(ref mut call) = instructions[*index] {
+                call.address = address;
+            }
+            false
+        });
+    }
+
     fn input_types_as_struct(&self) -> DataType {
         DataType::Struct(
             self.input_fields
@@ -10,6 +10,8 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Zero;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 use zinc_bytecode::data::types::DataType;
@@ -19,6 +21,7 @@ use zinc_bytecode::Instruction;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::operand::place::Place;
+use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
 
 use self::element::Element;
@@ -50,6 +53,76 @@ impl Expression {
         self.elements.push(Element::Operator { location, operator })
     }
 
+    ///
+    /// The index the next pushed element will get.
+    ///
+    pub fn next_index(&self) -> usize {
+        self.elements.len()
+    }
+
+    ///
+    /// Replaces the operand at `index`, e.g. a literal constant whose type has been inferred.
+    ///
+    pub fn replace_operand(&mut self, index: usize, operand: Operand) {
+        self.elements[index] = Element::Operand(operand);
+    }
+
+    ///
+    /// Replaces the elements starting from `start` with the `constant` evaluated at compile time,
+    /// so a constant operation like `3 * 4 + 1` is written to the bytecode as a single push.
+    ///
+    /// The elements are left as is, if some of them are not constants or pure operators, since
+    /// they may have side effects, e.g. a block with an assignment evaluated to a constant.
+    ///
+    pub fn fold(&mut self, start: usize, constant: Constant) {
+        let elements = &self.elements[start..];
+        if elements.is_empty() || !elements.iter().all(Self::is_element_pure) {
+            return;
+        }
+
+        self.elements.truncate(start);
+        self.push_operand(Operand::Constant(constant));
+    }
+
+    ///
+    /// Checks if the expression consists of constants and pure operators only.
+    ///
+    pub fn is_pure(&self) -> bool {
+        self.elements.iter().all(Self::is_element_pure)
+    }
+
+    fn is_element_pure(element: &Element) -> bool {
+        match element {
+            Element::Operand(Operand::Constant(_)) => true,
+            Element::Operand(_) => false,
+            Element::Operator { operator, .. } => operator.is_pure(),
+        }
+    }
+
+    ///
+    /// Checks if the expression is a single constant or a whole variable, which can be loaded
+    /// regardless of the branch condition, since the load has no side effects and cannot fail.
+    ///
+    pub fn is_load(&self) -> bool {
+        match self.elements.as_slice() {
+            [Element::Operand(Operand::Constant(_))] => true,
+            [Element::Operand(Operand::Place(place))] => place.elements.is_empty(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the expression consists of constants, variables and pure operators only, so
+    /// it does not change any variable.
+    ///
+    pub fn is_side_effect_free(&self) -> bool {
+        self.elements.iter().all(|element| match element {
+            Element::Operand(Operand::Constant(_)) | Element::Operand(Operand::Place(_)) => true,
+            Element::Operand(_) => false,
+            Element::Operator { operator, .. } => operator.is_pure(),
+        })
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for element in self.elements.into_iter() {
             match element {
@@ -111,7 +184,7 @@ impl Expression {
                             bytecode.clone(),
                             place,
                             expression,
-                            Instruction::Add(zinc_bytecode::Add),
+                            Instruction::Add(zinc_bytecode::Add::default()),
                             location,
                         )
                     }
@@ -120,7 +193,7 @@ impl Expression {
                             bytecode.clone(),
                             place,
                             expression,
-                            Instruction::Sub(zinc_bytecode::Sub),
+                            Instruction::Sub(zinc_bytecode::Sub::default()),
                             location,
                         )
                     }
@@ -129,7 +202,7 @@ impl Expression {
                             bytecode.clone(),
                             place,
                             expression,
-                            Instruction::Mul(zinc_bytecode::Mul),
+                            Instruction::Mul(zinc_bytecode::Mul::default()),
                             location,
                         )
                     }
@@ -152,21 +225,15 @@ impl Expression {
                         )
                     }
 
-                    Operator::Or => Self::binary(
-                        bytecode.clone(),
-                        Instruction::Or(zinc_bytecode::Or),
-                        location,
-                    ),
+                    Operator::Or { expression } => Self::or(bytecode.clone(), expression, location),
                     Operator::Xor => Self::binary(
                         bytecode.clone(),
                         Instruction::Xor(zinc_bytecode::Xor),
                         location,
                     ),
-                    Operator::And => Self::binary(
-                        bytecode.clone(),
-                        Instruction::And(zinc_bytecode::And),
-                        location,
-                    ),
+                    Operator::And { expression } => {
+                        Self::and(bytecode.clone(), expression, location)
+                    }
 
                     Operator::Equals => Self::binary(
                         bytecode.clone(),
@@ -178,6 +245,17 @@ impl Expression {
                         Instruction::Ne(zinc_bytecode::Ne),
                         location,
                     ),
+                    Operator::EqualsComposite { r#type } => {
+                        Self::equals_composite(bytecode.clone(), r#type, location)
+                    }
+                    Operator::NotEqualsComposite { r#type } => {
+                        Self::equals_composite(bytecode.clone(), r#type, location);
+                        Self::unary(
+                            bytecode.clone(),
+                            Instruction::Not(zinc_bytecode::Not),
+                            location,
+                        );
+                    }
                     Operator::GreaterEquals => Self::binary(
                         bytecode.clone(),
                         Instruction::Ge(zinc_bytecode::Ge),
@@ -225,19 +303,19 @@ impl Expression {
                         location,
                     ),
 
-                    Operator::Addition => Self::binary(
+                    Operator::Addition { is_unchecked } => Self::binary(
                         bytecode.clone(),
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::new(is_unchecked)),
                         location,
                     ),
                     Operator::Subtraction => Self::binary(
                         bytecode.clone(),
-                        Instruction::Sub(zinc_bytecode::Sub),
+                        Instruction::Sub(zinc_bytecode::Sub::default()),
                         location,
                     ),
-                    Operator::Multiplication => Self::binary(
+                    Operator::Multiplication { is_unchecked } => Self::binary(
                         bytecode.clone(),
-                        Instruction::Mul(zinc_bytecode::Mul),
+                        Instruction::Mul(zinc_bytecode::Mul::new(is_unchecked)),
                         location,
                     ),
                     Operator::Division => Self::binary(
@@ -250,8 +328,29 @@ impl Expression {
                         Instruction::Rem(zinc_bytecode::Rem),
                         location,
                     ),
+                    Operator::Exponentiation {
+                        exponent,
+                        is_signed,
+                        bitlength,
+                    } => Self::exponentiation(
+                        bytecode.clone(),
+                        exponent,
+                        is_signed,
+                        bitlength,
+                        location,
+                    ),
 
-                    Operator::Casting { r#type } => {
+                    Operator::Casting {
+                        r#type,
+                        check_range_bitlength,
+                    } => {
+                        if let Some(bitlength) = check_range_bitlength {
+                            Self::unary(
+                                bytecode.clone(),
+                                Instruction::CheckRange(zinc_bytecode::CheckRange::new(bitlength)),
+                                location,
+                            );
+                        }
                         if let Some(scalar_type) = r#type.into() {
                             Self::unary(
                                 bytecode.clone(),
@@ -337,6 +436,11 @@ impl Expression {
                         output_size,
                         location,
                     ),
+                    Operator::CallPow { exponent_bits } => Self::binary(
+                        bytecode.clone(),
+                        Instruction::Pow(zinc_bytecode::Pow::new(exponent_bits)),
+                        location,
+                    ),
                 },
             }
         }
@@ -437,6 +541,149 @@ impl Expression {
             .push_instruction(instruction, Some(location));
     }
 
+    ///
+    /// Lowers the element-wise equality of two operands of `r#type`, which are already on the
+    /// evaluation stack.
+    ///
+    /// The pairs of scalar elements are compared from the last one, and the results are
+    /// conjuncted. Each comparison consumes the top element of the second operand, so the
+    /// matching element of the first operand is always at the same depth and is copied with
+    /// `Dup`, while the result is swapped above the next element of the second operand. The
+    /// elements of the first operand are dropped from under the result in the end. Operands
+    /// without elements, like the unit, are always equal.
+    ///
+    fn equals_composite(bytecode: Rc<RefCell<Bytecode>>, r#type: Type, location: Location) {
+        let size = r#type.size();
+        if size == 0 {
+            Constant::new_boolean(true).write_all_to_bytecode(bytecode);
+            return;
+        }
+
+        for index in 0..size {
+            if index > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Swap(zinc_bytecode::Swap), Some(location));
+            }
+
+            let depth = if index > 0 { size + 1 } else { size };
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Dup(zinc_bytecode::Dup::new(depth)),
+                Some(location),
+            );
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(location));
+
+            if index > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_bytecode::And), Some(location));
+            }
+        }
+
+        for _ in 0..size {
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Swap(zinc_bytecode::Swap), Some(location));
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Drop(zinc_bytecode::Drop::new(1)),
+                Some(location),
+            );
+        }
+    }
+
+    ///
+    /// Lowers the logical OR, whose first operand is already on the evaluation stack.
+    ///
+    /// The second operand is evaluated in the branch where the first one is `false`, so its
+    /// witness-time failures, like division by zero, are masked by the VM condition stack.
+    ///
+    fn or(bytecode: Rc<RefCell<Bytecode>>, expression: Expression, location: Location) {
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+        Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(location));
+        expression.write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+    }
+
+    ///
+    /// Lowers the logical AND, whose first operand is already on the evaluation stack.
+    ///
+    /// The second operand is evaluated in the branch where the first one is `true`, so its
+    /// witness-time failures, like division by zero, are masked by the VM condition stack.
+    ///
+    fn and(bytecode: Rc<RefCell<Bytecode>>, expression: Expression, location: Location) {
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+        expression.write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(location));
+        Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+    }
+
+    ///
+    /// Lowers the exponentiation with a constant exponent via the square-and-multiply method.
+    ///
+    /// The base is already on the evaluation stack. It is duplicated once for each multiplication
+    /// by the base, and the duplicates are consumed while the exponent bits are traversed from the
+    /// most significant one, so the intermediate values are the same as with repeated
+    /// multiplication and overflow at the same point.
+    ///
+    fn exponentiation(
+        bytecode: Rc<RefCell<Bytecode>>,
+        exponent: BigInt,
+        is_signed: bool,
+        bitlength: usize,
+        location: Location,
+    ) {
+        if exponent.is_zero() {
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Pop(zinc_bytecode::Pop::new(1)), Some(location));
+            Constant::new_integer(BigInt::one(), is_signed, bitlength)
+                .write_all_to_bytecode(bytecode);
+            return;
+        }
+
+        let exponent_bits = exponent.to_str_radix(crate::BASE_BINARY as u32);
+        let base_copies_count = exponent_bits.chars().filter(|bit| *bit == '1').count() - 1;
+
+        for _ in 0..base_copies_count {
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Tee(zinc_bytecode::Tee), Some(location));
+        }
+
+        for bit in exponent_bits.chars().skip(1) {
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Tee(zinc_bytecode::Tee), Some(location));
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Mul(zinc_bytecode::Mul::default()),
+                Some(location),
+            );
+
+            if bit == '1' {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Mul(zinc_bytecode::Mul::default()),
+                    Some(location),
+                );
+            }
+        }
+    }
+
     fn unary(bytecode: Rc<RefCell<Bytecode>>, instruction: Instruction, location: Location) {
         bytecode
             .borrow_mut()
@@ -449,15 +696,9 @@ impl Expression {
         input_size: usize,
         location: Location,
     ) {
-        let address = bytecode
-            .borrow()
-            .get_function_address(unique_id)
-            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
-
-        bytecode.borrow_mut().push_instruction(
-            Instruction::Call(zinc_bytecode::Call::new(address, input_size)),
-            Some(location),
-        );
+        bytecode
+            .borrow_mut()
+            .push_call(unique_id, input_size, location);
     }
 
     fn call_debug(
@@ -28,6 +28,17 @@ impl Expression {
         }
     }
 
+    ///
+    /// Checks if the block has no statements and its result is a constant or a whole variable.
+    ///
+    pub fn is_load(&self) -> bool {
+        self.statements.is_empty()
+            && self
+                .expression
+                .as_ref()
+                .map_or(false, GeneratorExpression::is_load)
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for statement in self.statements.into_iter() {
             statement.write_all_to_bytecode(bytecode.clone());
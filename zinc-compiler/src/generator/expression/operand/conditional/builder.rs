@@ -10,9 +10,9 @@ use crate::lexical::token::location::Location;
 #[derive(Debug, Default, Clone)]
 pub struct Builder {
     location: Option<Location>,
-    condition: Option<GeneratorExpression>,
-    main_block: Option<BlockExpression>,
+    branches: Vec<(GeneratorExpression, BlockExpression)>,
     else_block: Option<BlockExpression>,
+    result_size: usize,
 }
 
 impl Builder {
@@ -20,36 +20,26 @@ impl Builder {
         self.location = Some(location);
     }
 
-    pub fn set_condition(&mut self, value: GeneratorExpression) {
-        self.condition = Some(value);
-    }
-
-    pub fn set_main_block(&mut self, value: BlockExpression) {
-        self.main_block = Some(value);
+    pub fn push_branch(&mut self, condition: GeneratorExpression, block: BlockExpression) {
+        self.branches.push((condition, block));
     }
 
     pub fn set_else_block(&mut self, value: BlockExpression) {
         self.else_block = Some(value);
     }
 
+    pub fn set_result_size(&mut self, value: usize) {
+        self.result_size = value;
+    }
+
     pub fn finish(mut self) -> ConditionalExpression {
         let location = self
             .location
             .take()
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
 
-        let condition = self
-            .condition
-            .take()
-            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "condition"));
-
-        let main_block = self
-            .main_block
-            .take()
-            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "main_block"));
-
         let else_block = self.else_block.take();
 
-        ConditionalExpression::new(location, condition, main_block, else_block)
+        ConditionalExpression::new(location, self.branches, else_block, self.result_size)
     }
 }
@@ -17,46 +17,107 @@ use crate::lexical::token::location::Location;
 ///
 /// The conditional expression which is translated to a Zinc VM conditional.
 ///
+/// The `else if` branches are written as conditionals nested into the `else` blocks.
+///
+/// The branches may be empty, if their conditions are constant and have been resolved at
+/// compile time. In such case, only the `else` block is written, if any.
+///
+/// A conditional choosing between two variables or constants is written as a single element-wise
+/// selection instead, since both branches are evaluated by the Zinc VM anyway.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     location: Location,
-    condition: GeneratorExpression,
-    main_block: BlockExpression,
+    branches: Vec<(GeneratorExpression, BlockExpression)>,
     else_block: Option<BlockExpression>,
+    result_size: usize,
 }
 
 impl Expression {
     pub fn new(
         location: Location,
-        condition: GeneratorExpression,
-        main_block: BlockExpression,
+        branches: Vec<(GeneratorExpression, BlockExpression)>,
         else_block: Option<BlockExpression>,
+        result_size: usize,
     ) -> Self {
         Self {
             location,
-            condition,
-            main_block,
+            branches,
             else_block,
+            result_size,
         }
     }
 
-    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
-        self.condition.write_all_to_bytecode(bytecode.clone());
-        bytecode
-            .borrow_mut()
-            .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
-        self.main_block.write_all_to_bytecode(bytecode.clone());
+    pub fn write_all_to_bytecode(mut self, bytecode: Rc<RefCell<Bytecode>>) {
+        if self.is_selection() {
+            let (condition, block) = self
+                .branches
+                .pop()
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+            let else_block = self
+                .else_block
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
 
-        if let Some(else_block) = self.else_block {
+            block.write_all_to_bytecode(bytecode.clone());
+            else_block.write_all_to_bytecode(bytecode.clone());
+            condition.write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::ConditionalSelectSequence(
+                    zinc_bytecode::ConditionalSelectSequence::new(self.result_size),
+                ),
+                Some(self.location),
+            );
+            return;
+        }
+
+        let branches_count = self.branches.len();
+
+        for (index, (condition, block)) in self.branches.into_iter().enumerate() {
+            if index > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
+            }
+
+            condition.write_all_to_bytecode(bytecode.clone());
             bytecode
                 .borrow_mut()
-                .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
+                .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
+            block.write_all_to_bytecode(bytecode.clone());
+        }
+
+        if let Some(else_block) = self.else_block {
+            if branches_count > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
+            }
             else_block.write_all_to_bytecode(bytecode.clone());
         }
 
-        bytecode.borrow_mut().push_instruction(
-            Instruction::EndIf(zinc_bytecode::EndIf),
-            Some(self.location),
-        );
+        for _ in 0..branches_count {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::EndIf(zinc_bytecode::EndIf),
+                Some(self.location),
+            );
+        }
+    }
+
+    ///
+    /// Checks if the expression is a single `if`-`else` with a non-unit result, whose branches
+    /// only load a variable or a constant.
+    ///
+    /// The branches are evaluated before the condition, so the condition must not change them.
+    ///
+    fn is_selection(&self) -> bool {
+        match (self.branches.as_slice(), self.else_block.as_ref()) {
+            ([(condition, block)], Some(else_block)) => {
+                self.result_size > 0
+                    && condition.is_side_effect_free()
+                    && block.is_load()
+                    && else_block.is_load()
+            }
+            _ => false,
+        }
     }
 }
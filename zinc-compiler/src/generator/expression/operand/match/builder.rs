@@ -2,8 +2,8 @@
 //! The generator expression match operand builder.
 //!
 
-use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
+use crate::generator::expression::operand::r#match::Pattern;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
@@ -13,7 +13,7 @@ pub struct Builder {
     location: Option<Location>,
     scrutinee: Option<GeneratorExpression>,
     scrutinee_type: Option<Type>,
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Pattern, GeneratorExpression)>,
     binding_branch: Option<(GeneratorExpression, String)>,
     wildcard_branch: Option<GeneratorExpression>,
 }
@@ -28,7 +28,7 @@ impl Builder {
         self.scrutinee_type = Some(r#type);
     }
 
-    pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
+    pub fn push_branch(&mut self, pattern: Pattern, expression: GeneratorExpression) {
         self.branches.push((pattern, expression));
     }
 
@@ -15,6 +15,20 @@ use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
 
+///
+/// The match expression branch pattern, which is checked before the branch expression.
+///
+/// The binding and wildcard patterns get here only if they are guarded, since otherwise they
+/// are the last branch of the match expression.
+///
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Constant(Constant),
+    GuardedConstant(Constant, GeneratorExpression),
+    GuardedBinding(String, GeneratorExpression),
+    GuardedWildcard(GeneratorExpression),
+}
+
 ///
 /// The match expression which is translated to Zinc VM conditional series.
 ///
@@ -23,7 +37,7 @@ pub struct Expression {
     location: Location,
     scrutinee: GeneratorExpression,
     scrutinee_type: Type,
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Pattern, GeneratorExpression)>,
     binding_branch: Option<(GeneratorExpression, String)>,
     wildcard_branch: Option<GeneratorExpression>,
 }
@@ -33,7 +47,7 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
+        branches: Vec<(Pattern, GeneratorExpression)>,
         binding_branch: Option<(GeneratorExpression, String)>,
         wildcard_branch: Option<GeneratorExpression>,
     ) -> Self {
@@ -58,7 +72,7 @@ impl Expression {
 
         let scrutinee_address = bytecode
             .borrow_mut()
-            .declare_variable(binding_name, self.scrutinee_type);
+            .declare_variable(binding_name, self.scrutinee_type.clone());
 
         self.scrutinee.write_all_to_bytecode(bytecode.clone());
         bytecode.borrow_mut().push_instruction(
@@ -70,17 +84,66 @@ impl Expression {
         );
 
         for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            bytecode.borrow_mut().push_instruction(
-                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    scrutinee_address,
-                    scrutinee_size,
-                )),
-                Some(self.location),
-            );
-            branch_pattern.write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(self.location));
+            match branch_pattern {
+                Pattern::Constant(constant) => {
+                    Self::write_pattern(
+                        bytecode.clone(),
+                        constant,
+                        scrutinee_address,
+                        scrutinee_size,
+                        self.location,
+                    );
+                }
+                Pattern::GuardedConstant(constant, guard) => {
+                    Self::write_pattern(
+                        bytecode.clone(),
+                        constant,
+                        scrutinee_address,
+                        scrutinee_size,
+                        self.location,
+                    );
+
+                    // the guard is lowered like the logical AND, so it is evaluated only
+                    // if the pattern matches
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
+                    guard.write_all_to_bytecode(bytecode.clone());
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::Else(zinc_bytecode::Else),
+                        Some(self.location),
+                    );
+                    Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::EndIf(zinc_bytecode::EndIf),
+                        Some(self.location),
+                    );
+                }
+                Pattern::GuardedBinding(name, guard) => {
+                    let binding_address = bytecode
+                        .borrow_mut()
+                        .declare_variable(Some(name), self.scrutinee_type.clone());
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                            scrutinee_address,
+                            scrutinee_size,
+                        )),
+                        Some(self.location),
+                    );
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                            binding_address,
+                            scrutinee_size,
+                        )),
+                        Some(self.location),
+                    );
+                    guard.write_all_to_bytecode(bytecode.clone());
+                }
+                Pattern::GuardedWildcard(guard) => {
+                    guard.write_all_to_bytecode(bytecode.clone());
+                }
+            }
+
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
@@ -96,15 +159,34 @@ impl Expression {
             wildcard_branch.write_all_to_bytecode(bytecode.clone());
         }
 
-        bytecode.borrow_mut().push_instruction(
-            Instruction::EndIf(zinc_bytecode::EndIf),
-            Some(self.location),
-        );
-        for _ in 0..branch_count - 1 {
+        for _ in 0..branch_count {
             bytecode.borrow_mut().push_instruction(
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
         }
     }
+
+    ///
+    /// Writes the comparison of the scrutinee with the constant pattern.
+    ///
+    fn write_pattern(
+        bytecode: Rc<RefCell<Bytecode>>,
+        constant: Constant,
+        scrutinee_address: usize,
+        scrutinee_size: usize,
+        location: Location,
+    ) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                scrutinee_address,
+                scrutinee_size,
+            )),
+            Some(location),
+        );
+        constant.write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(location));
+    }
 }
@@ -50,11 +50,11 @@ impl Place {
                     )
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Mul(zinc_bytecode::Mul),
+                        Instruction::Mul(zinc_bytecode::Mul::default()),
                         Some(self.location),
                     );
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::default()),
                         Some(self.location),
                     );
                 }
@@ -71,11 +71,11 @@ impl Place {
                     )
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Mul(zinc_bytecode::Mul),
+                        Instruction::Mul(zinc_bytecode::Mul::default()),
                         Some(self.location),
                     );
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::default()),
                         Some(self.location),
                     );
                 }
@@ -87,7 +87,7 @@ impl Place {
                     )
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::default()),
                         Some(self.location),
                     );
                 }
@@ -99,7 +99,7 @@ impl Place {
                     )
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::default()),
                         Some(self.location),
                     );
                 }
@@ -111,7 +111,7 @@ impl Place {
                     )
                     .write_all_to_bytecode(bytecode.clone());
                     bytecode.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_bytecode::Add),
+                        Instruction::Add(zinc_bytecode::Add::default()),
                         Some(self.location),
                     );
                 }
@@ -2,6 +2,8 @@
 //! The generator expression operator.
 //!
 
+use num_bigint::BigInt;
+
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
 use crate::generator::expression::operand::place::Place;
@@ -63,13 +65,23 @@ pub enum Operator {
     },
 
     // binary logical
-    Or,
+    Or {
+        expression: Expression,
+    },
     Xor,
-    And,
+    And {
+        expression: Expression,
+    },
 
     // binary comparison
     Equals,
     NotEquals,
+    EqualsComposite {
+        r#type: Type,
+    },
+    NotEqualsComposite {
+        r#type: Type,
+    },
     GreaterEquals,
     LesserEquals,
     Greater,
@@ -83,15 +95,25 @@ pub enum Operator {
     BitwiseShiftRight,
 
     // binary arithmetic
-    Addition,
+    Addition {
+        is_unchecked: bool,
+    },
     Subtraction,
-    Multiplication,
+    Multiplication {
+        is_unchecked: bool,
+    },
     Division,
     Remainder,
+    Exponentiation {
+        exponent: BigInt,
+        is_signed: bool,
+        bitlength: usize,
+    },
 
     // type casting
     Casting {
         r#type: Type,
+        check_range_bitlength: Option<usize>,
     },
 
     // unary logical
@@ -131,11 +153,75 @@ pub enum Operator {
         input_size: usize,
         output_size: usize,
     },
+    CallPow {
+        exponent_bits: usize,
+    },
 }
 
 impl Operator {
-    pub fn casting(r#type: &SemanticType) -> Option<Self> {
-        Type::try_from_semantic(r#type).map(|r#type| Self::Casting { r#type })
+    pub fn exponentiation(exponent: BigInt, is_signed: bool, bitlength: usize) -> Self {
+        Self::Exponentiation {
+            exponent,
+            is_signed,
+            bitlength,
+        }
+    }
+
+    ///
+    /// The equals operator for the operands of `r#type`.
+    ///
+    /// The scalar operands are compared with a single instruction, whereas the unit, arrays,
+    /// tuples, and structures are compared element by element.
+    ///
+    pub fn equals(r#type: &SemanticType) -> Self {
+        if r#type.is_scalar() {
+            Self::Equals
+        } else {
+            Self::EqualsComposite {
+                r#type: Type::try_from_semantic(r#type).unwrap_or_else(Type::unit),
+            }
+        }
+    }
+
+    ///
+    /// The not equals operator for the operands of `r#type`.
+    ///
+    pub fn not_equals(r#type: &SemanticType) -> Self {
+        if r#type.is_scalar() {
+            Self::NotEquals
+        } else {
+            Self::NotEqualsComposite {
+                r#type: Type::try_from_semantic(r#type).unwrap_or_else(Type::unit),
+            }
+        }
+    }
+
+    ///
+    /// The casting operator from `from` to `to`.
+    ///
+    /// If the value is an unsigned integer, which may not fit into the target type, its bits are
+    /// range-checked before the cast. It covers the narrowing casts and the sign reinterpretations,
+    /// which are not checked by the cast itself. The signed values are not range-checked, since
+    /// their negative values are stored as big field elements.
+    ///
+    pub fn casting(from: &SemanticType, to: &SemanticType) -> Option<Self> {
+        let check_range_bitlength = match (from, to) {
+            (SemanticType::IntegerUnsigned { .. }, SemanticType::Boolean) => Some(1),
+            (
+                SemanticType::IntegerUnsigned { bitlength: from },
+                SemanticType::IntegerUnsigned { bitlength: to },
+            ) if from > to => Some(*to),
+            (
+                SemanticType::IntegerUnsigned { bitlength: from },
+                SemanticType::IntegerSigned { bitlength: to },
+            ) if from >= to => Some(*to),
+            _ => None,
+        };
+
+        Type::try_from_semantic(to).map(|r#type| Self::Casting {
+            r#type,
+            check_range_bitlength,
+        })
     }
 
     pub fn index(expression: Expression, access: IndexAccess) -> Self {
@@ -175,4 +261,42 @@ impl Operator {
             output_size,
         }
     }
+
+    pub fn call_pow(exponent_bits: usize) -> Self {
+        Self::CallPow { exponent_bits }
+    }
+
+    ///
+    /// Checks if the operator has no side effects and can be evaluated at compile time, if its
+    /// operands are constants.
+    ///
+    pub fn is_pure(&self) -> bool {
+        match self {
+            Self::Or { expression } => expression.is_pure(),
+            Self::And { expression } => expression.is_pure(),
+            Self::Xor => true,
+            Self::Equals
+            | Self::NotEquals
+            | Self::EqualsComposite { .. }
+            | Self::NotEqualsComposite { .. }
+            | Self::GreaterEquals
+            | Self::LesserEquals
+            | Self::Greater
+            | Self::Lesser => true,
+            Self::BitwiseOr
+            | Self::BitwiseXor
+            | Self::BitwiseAnd
+            | Self::BitwiseShiftLeft
+            | Self::BitwiseShiftRight => true,
+            Self::Addition { .. }
+            | Self::Subtraction
+            | Self::Multiplication { .. }
+            | Self::Division
+            | Self::Remainder
+            | Self::Exponentiation { .. } => true,
+            Self::Casting { .. } => true,
+            Self::Not | Self::BitwiseNot | Self::Negation => true,
+            _ => false,
+        }
+    }
 }
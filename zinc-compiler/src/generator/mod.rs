@@ -2,6 +2,8 @@
 //! The intermediate representation for Zinc VM bytecode generating.
 //!
 
+mod tests;
+
 pub mod bytecode;
 pub mod expression;
 pub mod statement;
@@ -16,12 +16,15 @@ use crate::semantic::element::r#type::Type as SemanticType;
 ///
 /// The Zinc VM storage memory allocating statement.
 ///
+/// If there is no expression, the memory is only allocated and is written by a later
+/// assignment.
+///
 #[derive(Debug, Clone)]
 pub struct Statement {
     pub location: Location,
     pub name: String,
     pub r#type: Type,
-    pub expression: Expression,
+    pub expression: Option<Expression>,
 }
 
 impl Statement {
@@ -29,7 +32,7 @@ impl Statement {
         location: Location,
         name: String,
         r#type: SemanticType,
-        expression: Expression,
+        expression: Option<Expression>,
     ) -> Option<Self> {
         Type::try_from_semantic(&r#type).map(|r#type| Self {
             location,
@@ -45,7 +48,11 @@ impl Statement {
             .borrow_mut()
             .declare_variable(Some(self.name), self.r#type.clone());
 
-        self.expression.write_all_to_bytecode(bytecode.clone());
+        let expression = match self.expression {
+            Some(expression) => expression,
+            None => return,
+        };
+        expression.write_all_to_bytecode(bytecode.clone());
 
         if let Some(scalar_type) = self.r#type.into() {
             bytecode.borrow_mut().push_instruction(
@@ -0,0 +1,95 @@
+//!
+//! The generator destructuring statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::Expression;
+use crate::generator::r#type::Type;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::Type as SemanticType;
+
+///
+/// The Zinc VM storage memory allocating statement, which binds parts of a value to variables.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+    pub r#type: Type,
+    pub expression: Expression,
+    pub bindings: Vec<Binding>,
+}
+
+///
+/// The variable bound to the `size` items starting at `offset` of the destructured value.
+///
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub name: String,
+    pub r#type: Type,
+    pub offset: usize,
+}
+
+impl Statement {
+    pub fn new(
+        location: Location,
+        r#type: SemanticType,
+        expression: Expression,
+        bindings: Vec<(String, SemanticType, usize)>,
+    ) -> Option<Self> {
+        Type::try_from_semantic(&r#type).map(|r#type| Self {
+            location,
+            r#type,
+            expression,
+            bindings: bindings
+                .into_iter()
+                .filter_map(|(name, r#type, offset)| {
+                    Type::try_from_semantic(&r#type).map(|r#type| Binding {
+                        name,
+                        r#type,
+                        offset,
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let size = self.r#type.size();
+        let address = bytecode
+            .borrow_mut()
+            .declare_variable(None, self.r#type.clone());
+
+        self.expression.write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, size)),
+            Some(self.location),
+        );
+
+        for binding in self.bindings.into_iter() {
+            let size = binding.r#type.size();
+            let binding_address = bytecode
+                .borrow_mut()
+                .declare_variable(Some(binding.name), binding.r#type);
+
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                    address + binding.offset,
+                    size,
+                )),
+                Some(self.location),
+            );
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    binding_address,
+                    size,
+                )),
+                Some(self.location),
+            );
+        }
+    }
+}
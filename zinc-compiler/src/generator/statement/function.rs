@@ -59,6 +59,11 @@ impl Statement {
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        if bytecode.borrow().is_function_open() {
+            bytecode.borrow_mut().defer_function(self);
+            return;
+        }
+
         let output_size = self
             .output_type
             .as_ref()
@@ -89,5 +94,10 @@ impl Statement {
             Instruction::Return(zinc_bytecode::Return::new(output_size)),
             Some(self.location),
         );
+
+        let nested_functions = bytecode.borrow_mut().end_function();
+        for function in nested_functions.into_iter() {
+            function.write_all_to_bytecode(bytecode.clone());
+        }
     }
 }
@@ -20,6 +20,9 @@ use num_traits::One;
 ///
 /// The Zinc VM loop statement.
 ///
+/// The loop is written either as the Zinc VM loop, which repeats its body at runtime, or as
+/// the unrolled sequence of bodies, if the `#[unroll]` attribute is specified.
+///
 #[derive(Debug, Clone)]
 pub struct Statement {
     pub location: Location,
@@ -31,6 +34,7 @@ pub struct Statement {
     pub index_variable_bitlength: usize,
     pub while_condition: Option<GeneratorExpression>,
     pub body: BlockExpression,
+    pub is_unrolled: bool,
 }
 
 impl Statement {
@@ -44,6 +48,7 @@ impl Statement {
         index_variable_bitlength: usize,
         while_condition: Option<GeneratorExpression>,
         body: BlockExpression,
+        is_unrolled: bool,
     ) -> Self {
         Self {
             location,
@@ -55,29 +60,16 @@ impl Statement {
             index_variable_bitlength,
             while_condition,
             body,
+            is_unrolled,
         }
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         let index_type =
             Type::integer(self.index_variable_is_signed, self.index_variable_bitlength);
-        let index_size = index_type.size();
         let index_address = bytecode
             .borrow_mut()
-            .declare_variable(Some(self.index_variable_name), index_type);
-        Constant::new_integer(
-            self.initial_value.clone(),
-            self.index_variable_is_signed,
-            self.index_variable_bitlength,
-        )
-        .write_all_to_bytecode(bytecode.clone());
-        bytecode.borrow_mut().push_instruction(
-            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
-                index_address,
-                index_size,
-            )),
-            Some(self.location),
-        );
+            .declare_variable(Some(self.index_variable_name.clone()), index_type);
 
         let while_allowed_address = if self.while_condition.is_some() {
             let while_allowed = Constant::new_boolean(true);
@@ -94,52 +86,63 @@ impl Statement {
             None
         };
 
+        if self.is_unrolled {
+            self.write_unrolled(bytecode, index_address, while_allowed_address);
+        } else {
+            self.write_looped(bytecode, index_address, while_allowed_address);
+        }
+    }
+
+    ///
+    /// Writes the body once for each iteration, setting the index to a constant before it.
+    ///
+    fn write_unrolled(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        index_address: usize,
+        while_allowed_address: Option<usize>,
+    ) {
+        for iteration in 0..self.iterations_count {
+            let index = if self.is_reversed {
+                self.initial_value.clone() - BigInt::from(iteration)
+            } else {
+                self.initial_value.clone() + BigInt::from(iteration)
+            };
+            self.write_index(bytecode.clone(), index, index_address);
+
+            Self::write_body(
+                bytecode.clone(),
+                self.location,
+                self.while_condition.clone(),
+                while_allowed_address,
+                self.body.clone(),
+            );
+        }
+    }
+
+    ///
+    /// Writes the body once inside the Zinc VM loop, which repeats it `iterations_count` times.
+    ///
+    fn write_looped(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        index_address: usize,
+        while_allowed_address: Option<usize>,
+    ) {
+        self.write_index(bytecode.clone(), self.initial_value.clone(), index_address);
+
         bytecode.borrow_mut().push_instruction(
             Instruction::LoopBegin(zinc_bytecode::LoopBegin::new(self.iterations_count)),
             Some(self.location),
         );
 
-        if let (Some(while_condition), Some(while_allowed_address)) =
-            (self.while_condition, while_allowed_address)
-        {
-            while_condition.write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Not(zinc_bytecode::Not), Some(self.location));
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
-            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
-            bytecode.borrow_mut().push_instruction(
-                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
-                    while_allowed_address,
-                    Type::boolean().size(),
-                )),
-                Some(self.location),
-            );
-            bytecode.borrow_mut().push_instruction(
-                Instruction::EndIf(zinc_bytecode::EndIf),
-                Some(self.location),
-            );
-
-            bytecode.borrow_mut().push_instruction(
-                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    while_allowed_address,
-                    Type::boolean().size(),
-                )),
-                Some(self.location),
-            );
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
-            self.body.write_all_to_bytecode(bytecode.clone());
-            bytecode.borrow_mut().push_instruction(
-                Instruction::EndIf(zinc_bytecode::EndIf),
-                Some(self.location),
-            );
-        } else {
-            self.body.write_all_to_bytecode(bytecode.clone());
-        }
+        Self::write_body(
+            bytecode.clone(),
+            self.location,
+            self.while_condition,
+            while_allowed_address,
+            self.body,
+        );
 
         if self.is_reversed {
             bytecode.borrow_mut().push_instruction(
@@ -164,9 +167,10 @@ impl Statement {
                 self.index_variable_bitlength,
             )
             .write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Sub(zinc_bytecode::Sub), Some(self.location));
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Sub(zinc_bytecode::Sub::default()),
+                Some(self.location),
+            );
             bytecode.borrow_mut().push_instruction(
                 Instruction::Store(zinc_bytecode::Store::new(index_address)),
                 Some(self.location),
@@ -198,9 +202,10 @@ impl Statement {
                 self.index_variable_bitlength,
             )
             .write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Add(zinc_bytecode::Add), Some(self.location));
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Add(zinc_bytecode::Add::default()),
+                Some(self.location),
+            );
             bytecode.borrow_mut().push_instruction(
                 Instruction::Store(zinc_bytecode::Store::new(index_address)),
                 Some(self.location),
@@ -215,4 +220,75 @@ impl Statement {
             Some(self.location),
         );
     }
+
+    ///
+    /// Writes the index variable value.
+    ///
+    fn write_index(&self, bytecode: Rc<RefCell<Bytecode>>, value: BigInt, index_address: usize) {
+        Constant::new_integer(
+            value,
+            self.index_variable_is_signed,
+            self.index_variable_bitlength,
+        )
+        .write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                index_address,
+                Type::integer(self.index_variable_is_signed, self.index_variable_bitlength).size(),
+            )),
+            Some(self.location),
+        );
+    }
+
+    ///
+    /// Writes the body with the optional `while` condition, which disables the body after
+    /// it has become false once.
+    ///
+    fn write_body(
+        bytecode: Rc<RefCell<Bytecode>>,
+        location: Location,
+        while_condition: Option<GeneratorExpression>,
+        while_allowed_address: Option<usize>,
+        body: BlockExpression,
+    ) {
+        if let (Some(while_condition), Some(while_allowed_address)) =
+            (while_condition, while_allowed_address)
+        {
+            while_condition.write_all_to_bytecode(bytecode.clone());
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Not(zinc_bytecode::Not), Some(location));
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    while_allowed_address,
+                    Type::boolean().size(),
+                )),
+                Some(location),
+            );
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                    while_allowed_address,
+                    Type::boolean().size(),
+                )),
+                Some(location),
+            );
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+            body.write_all_to_bytecode(bytecode.clone());
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+        } else {
+            body.write_all_to_bytecode(bytecode.clone());
+        }
+    }
 }
@@ -3,6 +3,7 @@
 //!
 
 pub mod declaration;
+pub mod destructuring;
 pub mod function;
 pub mod loop_for;
 
@@ -12,6 +13,7 @@ use std::rc::Rc;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::Expression;
 use crate::generator::statement::declaration::Statement as DeclarationStatement;
+use crate::generator::statement::destructuring::Statement as DestructuringStatement;
 use crate::generator::statement::function::Statement as FunctionStatement;
 use crate::generator::statement::loop_for::Statement as ForLoopStatement;
 
@@ -22,6 +24,7 @@ use crate::generator::statement::loop_for::Statement as ForLoopStatement;
 pub enum Statement {
     Expression(Expression),
     Declaration(DeclarationStatement),
+    Destructuring(DestructuringStatement),
     Loop(ForLoopStatement),
     Function(FunctionStatement),
     Implementation(Vec<Self>),
@@ -32,6 +35,7 @@ impl Statement {
         match self {
             Self::Expression(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Declaration(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Destructuring(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Loop(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Function(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Implementation(inner) => {
@@ -0,0 +1,412 @@
+//!
+//! The generator tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::Parser;
+
+static PANIC_COMPILE_ERROR: &str = "Compile errors must be eliminated at this point";
+
+fn compile_instructions(input: &str) -> Vec<Instruction> {
+    let (intermediate, _warnings) = EntryAnalyzer::default()
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(PANIC_COMPILE_ERROR),
+            HashMap::new(),
+        )
+        .expect(PANIC_COMPILE_ERROR);
+
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    intermediate.write_all_to_bytecode(bytecode.clone());
+    Rc::try_unwrap(bytecode)
+        .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+        .into_inner()
+        .into()
+}
+
+fn accumulator_loop(attribute: &str, iterations_count: usize) -> String {
+    format!(
+        r#"
+fn main() -> u64 {{
+    let mut sum: u64 = 0;
+    {}
+    for i in 0..{} {{
+        sum += i as u64;
+    }}
+    sum
+}}
+"#,
+        attribute, iterations_count
+    )
+}
+
+#[test]
+fn ok_loop_size_independent_of_iterations_count() {
+    let small = compile_instructions(accumulator_loop("", 2).as_str()).len();
+    let large = compile_instructions(accumulator_loop("", 1024).as_str()).len();
+
+    assert_eq!(small, large);
+}
+
+#[test]
+fn ok_loop_no_unroll_is_default() {
+    let default = compile_instructions(accumulator_loop("", 1024).as_str()).len();
+    let looped = compile_instructions(accumulator_loop("#[no_unroll]", 1024).as_str()).len();
+
+    assert_eq!(default, looped);
+}
+
+#[test]
+fn ok_loop_unrolled_size_proportional_to_iterations_count() {
+    let two = compile_instructions(accumulator_loop("#[unroll]", 2).as_str()).len();
+    let three = compile_instructions(accumulator_loop("#[unroll]", 3).as_str()).len();
+    let large = compile_instructions(accumulator_loop("#[unroll]", 1024).as_str()).len();
+    let looped = compile_instructions(accumulator_loop("", 1024).as_str()).len();
+
+    let iteration_size = three - two;
+    assert_eq!(large - two, iteration_size * 1022);
+    assert!(looped * 32 < large);
+}
+
+#[test]
+fn ok_constant_expression_folded() {
+    let folded = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let value = 3 * 4 + 1;
+    witness + value
+}
+"#,
+    );
+    let literal = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let value = 13;
+    witness + value
+}
+"#,
+    );
+    let variable = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let three = 3;
+    let value = three * 4 + 1;
+    witness + value
+}
+"#,
+    );
+
+    assert_eq!(folded.len(), literal.len());
+    assert!(folded.len() < variable.len());
+}
+
+#[test]
+fn ok_constant_false_branch_eliminated() {
+    let eliminated = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let mut sum = witness;
+    if false {
+        for i in 0..10 {
+            sum += i;
+        }
+    };
+    sum
+}
+"#,
+    );
+    let absent = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let mut sum = witness;
+    sum
+}
+"#,
+    );
+    let runtime = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    let mut sum = witness;
+    if witness == 0 {
+        for i in 0..10 {
+            sum += i;
+        }
+    };
+    sum
+}
+"#,
+    );
+
+    assert_eq!(eliminated.len(), absent.len());
+    assert!(eliminated.len() < runtime.len());
+}
+
+#[test]
+fn ok_constant_true_branch_unconditional() {
+    let resolved = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    if true {
+        witness + 1
+    } else {
+        witness * 2
+    }
+}
+"#,
+    );
+    let unconditional = compile_instructions(
+        r#"
+fn main(witness: u8) -> u8 {
+    witness + 1
+}
+"#,
+    );
+
+    assert_eq!(resolved.len(), unconditional.len());
+}
+
+fn array_selection(size: usize) -> String {
+    format!(
+        r#"
+fn main(condition: bool, a: [u8; {0}], b: [u8; {0}]) -> [u8; {0}] {{
+    if condition {{
+        a
+    }} else {{
+        b
+    }}
+}}
+"#,
+        size
+    )
+}
+
+#[test]
+fn ok_conditional_selection() {
+    for size in vec![1, 4] {
+        let instructions = compile_instructions(array_selection(size).as_str());
+
+        assert!(
+            instructions.contains(&Instruction::ConditionalSelectSequence(
+                zinc_bytecode::ConditionalSelectSequence::new(size)
+            ))
+        );
+        assert!(!instructions.contains(&Instruction::If(zinc_bytecode::If)));
+    }
+}
+
+#[test]
+fn ok_conditional_with_statements_not_selection() {
+    let instructions = compile_instructions(
+        r#"
+fn main(condition: bool, a: [u8; 4], b: [u8; 4]) -> [u8; 4] {
+    if condition {
+        let c = a;
+        c
+    } else {
+        b
+    }
+}
+"#,
+    );
+
+    assert!(instructions.contains(&Instruction::If(zinc_bytecode::If)));
+    assert!(!instructions.iter().any(|instruction| match instruction {
+        Instruction::ConditionalSelectSequence(_) => true,
+        _ => false,
+    }));
+}
+
+fn additions(instructions: &[Instruction]) -> Vec<bool> {
+    instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Add(add) => Some(add.is_unchecked),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn ok_bounded_addition_unchecked() {
+    let instructions = compile_instructions(
+        r#"
+fn main(a: u8, b: u8, c: u8, d: u8) -> u16 {
+    a as u16 + b as u16 + c as u16 + d as u16
+}
+"#,
+    );
+
+    assert_eq!(additions(&instructions), vec![true, true, true]);
+}
+
+#[test]
+fn ok_unbounded_addition_checked() {
+    let instructions = compile_instructions(
+        r#"
+fn main(a: u8, b: u8, c: u16) -> u16 {
+    (a + b) as u16 + c
+}
+"#,
+    );
+
+    assert_eq!(additions(&instructions), vec![false, false]);
+}
+
+#[test]
+fn ok_pow_witness_exponent() {
+    let instructions = compile_instructions(
+        r#"
+fn main(base: u64, exponent: u16) -> u64 {
+    std::pow(base, exponent)
+}
+"#,
+    );
+
+    assert!(instructions.contains(&Instruction::Pow(zinc_bytecode::Pow::new(16))));
+    assert!(!instructions.iter().any(|instruction| match instruction {
+        Instruction::CallBuiltin(_) => true,
+        _ => false,
+    }));
+}
+
+#[test]
+fn ok_conditional_addition_checked_if_any_branch_unbounded() {
+    let instructions = compile_instructions(
+        r#"
+fn main(c: bool, a: u8, w: u16) -> u16 {
+    (if c { a as u16 } else { w }) + (a as u16)
+}
+"#,
+    );
+
+    assert_eq!(additions(&instructions), vec![false]);
+}
+
+#[test]
+fn ok_conditional_addition_unchecked_if_every_branch_bounded() {
+    let instructions = compile_instructions(
+        r#"
+fn main(c: bool, a: u8, b: u8) -> u16 {
+    (if c { a as u16 } else { b as u16 * 2 }) + (a as u16)
+}
+"#,
+    );
+
+    assert_eq!(additions(&instructions), vec![true]);
+}
+
+#[test]
+fn ok_match_addition_checked_if_any_branch_unbounded() {
+    let instructions = compile_instructions(
+        r#"
+fn main(s: u8, a: u8, w: u16) -> u16 {
+    (match s {
+        0 => a as u16,
+        _ => w,
+    }) + (a as u16)
+}
+"#,
+    );
+
+    assert_eq!(additions(&instructions), vec![false]);
+}
+
+#[test]
+fn ok_composite_equality_reordered_on_stack() {
+    let instructions = compile_instructions(
+        r#"
+fn main(a: (u8, bool, u8), b: (u8, bool, u8)) -> bool {
+    a == b
+}
+"#,
+    );
+
+    assert_eq!(
+        instructions
+            .iter()
+            .filter(|instruction| match instruction {
+                Instruction::Dup(_) => true,
+                _ => false,
+            })
+            .count(),
+        3
+    );
+    assert!(instructions.contains(&Instruction::Drop(zinc_bytecode::Drop::new(1))));
+    assert!(!instructions.iter().any(|instruction| match instruction {
+        Instruction::StoreSequence(_) => true,
+        _ => false,
+    }));
+}
+
+fn range_checks(from: &str, to: &str) -> Vec<usize> {
+    compile_instructions(
+        format!(
+            r#"
+fn main(value: {}) -> {} {{
+    value as {}
+}}
+"#,
+            from, to, to
+        )
+        .as_str(),
+    )
+    .into_iter()
+    .filter_map(|instruction| match instruction {
+        Instruction::CheckRange(check_range) => Some(check_range.bitlength),
+        _ => None,
+    })
+    .collect()
+}
+
+#[test]
+fn ok_casting_range_checked_if_narrowing() {
+    assert_eq!(range_checks("u16", "u8"), vec![8]);
+    assert_eq!(range_checks("u64", "i32"), vec![32]);
+    assert_eq!(range_checks("u8", "bool"), vec![1]);
+}
+
+#[test]
+fn ok_casting_range_checked_if_sign_reinterpreted() {
+    assert_eq!(range_checks("u8", "i8"), vec![8]);
+}
+
+#[test]
+fn ok_casting_not_range_checked_if_widening_or_signed() {
+    assert!(range_checks("u8", "u16").is_empty());
+    assert!(range_checks("u8", "field").is_empty());
+    assert!(range_checks("i16", "i8").is_empty());
+    assert!(range_checks("i8", "u8").is_empty());
+}
+
+#[test]
+fn ok_casting_range_checked_before_cast() {
+    let instructions = compile_instructions(
+        r#"
+fn main(value: u16) -> u8 {
+    value as u8
+}
+"#,
+    );
+
+    let check_range = instructions
+        .iter()
+        .position(|instruction| match instruction {
+            Instruction::CheckRange(_) => true,
+            _ => false,
+        })
+        .expect("The range check is emitted");
+    match instructions.get(check_range + 1) {
+        Some(Instruction::Cast(_)) => {}
+        instruction => panic!("Expected the cast, found {:?}", instruction),
+    }
+}
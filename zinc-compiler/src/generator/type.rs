@@ -114,7 +114,7 @@ impl Type {
                 }
             }
             SemanticType::Enumeration(enumeration) => {
-                Some(Self::integer_unsigned(enumeration.bitlength))
+                Some(Self::integer(enumeration.is_signed, enumeration.bitlength))
             }
             _ => None,
         }
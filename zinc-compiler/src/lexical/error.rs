@@ -15,6 +15,20 @@ pub enum Error {
         start: Location,
         end: Location,
     },
+    UnterminatedSingleQuoteCharacter {
+        start: Location,
+        end: Location,
+    },
+    CharacterEmpty {
+        location: Location,
+    },
+    CharacterMultiple {
+        location: Location,
+    },
+    CharacterNotAscii {
+        location: Location,
+        found: char,
+    },
     ExpectedOneOfBinary {
         location: Location,
         expected: String,
@@ -53,6 +67,22 @@ impl Error {
         Self::UnterminatedDoubleQuoteString { start, end }
     }
 
+    pub fn unterminated_single_quote_character(start: Location, end: Location) -> Self {
+        Self::UnterminatedSingleQuoteCharacter { start, end }
+    }
+
+    pub fn character_empty(location: Location) -> Self {
+        Self::CharacterEmpty { location }
+    }
+
+    pub fn character_multiple(location: Location) -> Self {
+        Self::CharacterMultiple { location }
+    }
+
+    pub fn character_not_ascii(location: Location, found: char) -> Self {
+        Self::CharacterNotAscii { location, found }
+    }
+
     pub fn expected_one_of_binary(location: Location, found: char) -> Self {
         Self::ExpectedOneOfBinary {
             location,
@@ -0,0 +1,198 @@
+//!
+//! The lexical character parser.
+//!
+
+pub enum State {
+    SingleQuoteOpen,
+    Character,
+    EscapedCharacter,
+    HexadecimalFirst,
+    HexadecimalSecond,
+    SingleQuoteClose,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    NotAChar,
+    UnterminatedSingleQuote { offset: usize },
+    Empty { offset: usize },
+    MultipleCharacters { offset: usize },
+    NotAscii { found: char, offset: usize },
+    ExpectedOneOfHexadecimal { found: char, offset: usize },
+}
+
+///
+/// Parses a character literal into its ASCII byte value.
+///
+/// The escapes are the same as in string literals, that is, the escaped character is taken as is.
+/// The bytes above 0x7F can be only written with the hexadecimal escape `\xNN`.
+///
+/// Example:
+/// ''A''
+/// ''\'''
+/// ''\xFF''
+///
+pub fn parse(input: &str) -> Result<(usize, u8), Error> {
+    let mut state = State::SingleQuoteOpen;
+    let mut size = 0;
+    let mut value = 0u32;
+
+    loop {
+        let character = input.chars().nth(size);
+        match state {
+            State::SingleQuoteOpen => match character {
+                Some('\'') => {
+                    size += 1;
+                    state = State::Character;
+                }
+                _ => return Err(Error::NotAChar),
+            },
+            State::Character => match character {
+                Some('\'') => return Err(Error::Empty { offset: size }),
+                Some('\\') => {
+                    size += 1;
+                    state = State::EscapedCharacter;
+                }
+                Some('\n') | None => return Err(Error::UnterminatedSingleQuote { offset: size }),
+                Some(character) if !character.is_ascii() => {
+                    return Err(Error::NotAscii {
+                        found: character,
+                        offset: size,
+                    })
+                }
+                Some(character) => {
+                    value = character as u32;
+                    size += 1;
+                    state = State::SingleQuoteClose;
+                }
+            },
+            State::EscapedCharacter => match character {
+                Some('x') => {
+                    size += 1;
+                    state = State::HexadecimalFirst;
+                }
+                Some('\n') | None => return Err(Error::UnterminatedSingleQuote { offset: size }),
+                Some(character) if !character.is_ascii() => {
+                    return Err(Error::NotAscii {
+                        found: character,
+                        offset: size,
+                    })
+                }
+                Some(character) => {
+                    value = character as u32;
+                    size += 1;
+                    state = State::SingleQuoteClose;
+                }
+            },
+            State::HexadecimalFirst | State::HexadecimalSecond => match character {
+                Some(character) if character.is_ascii_hexdigit() => {
+                    value = value * 16 + character.to_digit(16).unwrap_or_default();
+                    size += 1;
+                    state = match state {
+                        State::HexadecimalFirst => State::HexadecimalSecond,
+                        _ => State::SingleQuoteClose,
+                    };
+                }
+                Some('\n') | None => return Err(Error::UnterminatedSingleQuote { offset: size }),
+                Some(character) => {
+                    return Err(Error::ExpectedOneOfHexadecimal {
+                        found: character,
+                        offset: size,
+                    })
+                }
+            },
+            State::SingleQuoteClose => match character {
+                Some('\'') => {
+                    size += 1;
+                    return Ok((size, value as u8));
+                }
+                Some('\n') | None => return Err(Error::UnterminatedSingleQuote { offset: size }),
+                Some(_) => return Err(Error::MultipleCharacters { offset: size }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use super::Error;
+
+    #[test]
+    fn ok() {
+        let input = "'A'";
+        let expected = Ok((input.len(), b'A'));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_escaped() {
+        let input = r#"'\''"#;
+        let expected = Ok((input.len(), b'\''));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_hexadecimal() {
+        let input = r#"'\xFF'"#;
+        let expected = Ok((input.len(), 0xff));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_not_a_char() {
+        let input = "no single quote here";
+        let expected = Err(Error::NotAChar);
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_unterminated_single_quote() {
+        let input = "'A";
+        let expected = Err(Error::UnterminatedSingleQuote { offset: 2 });
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_empty() {
+        let input = "''";
+        let expected = Err(Error::Empty { offset: 1 });
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_multiple_characters() {
+        let input = "'AB'";
+        let expected = Err(Error::MultipleCharacters { offset: 2 });
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_not_ascii() {
+        let input = "'Ж'";
+        let expected = Err(Error::NotAscii {
+            found: 'Ж',
+            offset: 1,
+        });
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_one_of_hexadecimal() {
+        let input = r#"'\xFG'"#;
+        let expected = Err(Error::ExpectedOneOfHexadecimal {
+            found: 'G',
+            offset: 4,
+        });
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+}
@@ -2,8 +2,10 @@
 //! The lexical integer literal parser.
 //!
 
+use std::convert::TryFrom;
 use std::str;
 
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::literal::integer::Integer;
 
 pub enum State {
@@ -45,6 +47,9 @@ pub enum Error {
 /// 4. Hexadecimal
 /// '2a'
 ///
+/// Each of them may be followed by an integer type suffix, e.g. '42u64' or '0xff_i16'.
+/// The 'field' suffix is not available for hexadecimal literals, where 'f' is a digit.
+///
 pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
     let mut state = State::Start;
     let mut size = 0;
@@ -78,6 +83,11 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
                     size += 1;
                     value.clear();
                     state = State::Hexadecimal;
+                } else if let Some((length, suffix)) = parse_suffix(input, size) {
+                    return Ok((
+                        size + length,
+                        Integer::new_decimal(value).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -93,6 +103,13 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((length, suffix)) =
+                    parse_suffix(input, size).filter(|_| !value.is_empty())
+                {
+                    return Ok((
+                        size + length,
+                        Integer::new_binary(value).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() || size <= 2 {
                     return Err(Error::ExpectedOneOfBinary {
                         found: character,
@@ -108,6 +125,10 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((length, suffix)) =
+                    parse_suffix(input, size).filter(|_| !value.is_empty())
+                {
+                    return Ok((size + length, Integer::new_octal(value).with_suffix(suffix)));
                 } else if character.is_ascii_alphanumeric() || size <= 2 {
                     return Err(Error::ExpectedOneOfOctal {
                         found: character,
@@ -123,6 +144,11 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((length, suffix)) = parse_suffix(input, size) {
+                    return Ok((
+                        size + length,
+                        Integer::new_decimal(value).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -138,6 +164,13 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((length, suffix)) =
+                    parse_suffix(input, size).filter(|_| !value.is_empty())
+                {
+                    return Ok((
+                        size + length,
+                        Integer::new_hexadecimal(value).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() || size <= 2 {
                     return Err(Error::ExpectedOneOfHexadecimal {
                         found: character,
@@ -178,10 +211,29 @@ pub fn parse(input: &str) -> Result<(usize, Integer), Error> {
     }
 }
 
+///
+/// Parses the integer type suffix at `offset`, returning its size and type keyword.
+///
+fn parse_suffix(input: &str, offset: usize) -> Option<(usize, Keyword)> {
+    let suffix: String = input
+        .chars()
+        .skip(offset)
+        .take_while(char::is_ascii_alphanumeric)
+        .collect();
+
+    match Keyword::try_from(suffix.as_str()) {
+        Ok(keyword @ Keyword::IntegerUnsigned { .. })
+        | Ok(keyword @ Keyword::IntegerSigned { .. })
+        | Ok(keyword @ Keyword::Field) => Some((suffix.len(), keyword)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::parse;
     use super::Error;
+    use crate::lexical::token::lexeme::keyword::Keyword;
     use crate::lexical::token::lexeme::literal::integer::Integer;
 
     #[test]
@@ -245,6 +297,51 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_decimal_suffix() {
+        let input = "42u64";
+        let expected = Ok((
+            input.len(),
+            Integer::new_decimal("42".to_owned()).with_suffix(Keyword::new_integer_unsigned(64)),
+        ));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_decimal_zero_suffix() {
+        let input = "0field";
+        let expected = Ok((
+            input.len(),
+            Integer::new_decimal("0".to_owned()).with_suffix(Keyword::Field),
+        ));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_binary_suffix_delimited() {
+        let input = "0b1010_i8";
+        let expected = Ok((
+            input.len(),
+            Integer::new_binary("1010".to_owned()).with_suffix(Keyword::new_integer_signed(8)),
+        ));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_hexadecimal_suffix() {
+        let input = "0xff_ffu16";
+        let expected = Ok((
+            input.len(),
+            Integer::new_hexadecimal("ffff".to_owned())
+                .with_suffix(Keyword::new_integer_unsigned(16)),
+        ));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_not_an_integer() {
         let input = "xyz";
@@ -2,6 +2,7 @@
 //! The lexical token stream.
 //!
 
+pub mod char;
 pub mod comment;
 pub mod integer;
 pub mod string;
@@ -12,12 +13,15 @@ use std::collections::VecDeque;
 
 use crate::lexical::error::Error;
 use crate::lexical::token::lexeme::identifier::Identifier;
+use crate::lexical::token::lexeme::literal::char::Char as CharLiteral;
 use crate::lexical::token::lexeme::literal::string::String as StringLiteral;
 use crate::lexical::token::lexeme::literal::Literal;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::location::Location;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 
+use self::char::Error as CharParserError;
 use self::comment::Error as CommentParserError;
 use self::integer::Error as IntegerParserError;
 use self::string::Error as StringParserError;
@@ -28,6 +32,7 @@ pub struct TokenStream<'a> {
     offset: usize,
     location: Location,
     look_ahead: VecDeque<Token>,
+    recovered_errors: Vec<SyntaxError>,
 }
 
 impl<'a> TokenStream<'a> {
@@ -43,6 +48,7 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(None),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            recovered_errors: Vec::new(),
         }
     }
 
@@ -56,6 +62,7 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(Some(file)),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            recovered_errors: Vec::new(),
         }
     }
 
@@ -87,15 +94,32 @@ impl<'a> TokenStream<'a> {
             .ok_or_else(|| Error::unexpected_end(self.location))
     }
 
+    ///
+    /// Keeps a syntax error, after which the parser has skipped the erroneous statement.
+    /// The errors are taken by the top-level parser, since the statement parsers cannot
+    /// return both a result and an error.
+    ///
+    pub fn push_recovered_error(&mut self, error: SyntaxError) {
+        self.recovered_errors.push(error);
+    }
+
+    ///
+    /// Takes the syntax errors kept since the last call.
+    ///
+    pub fn take_recovered_errors(&mut self) -> Vec<SyntaxError> {
+        std::mem::take(&mut self.recovered_errors)
+    }
+
     ///
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
     /// 2. Starts a comment -> start the comment subparser
     /// 3. Starts a string literal -> start the string subparser
-    /// 4. Starts a number -> start the number subparser
-    /// 5. Starts a word -> start the word subparser
-    /// 6. Starts a symbol -> start the operand subparser
-    /// 7. Is unknown -> yield an 'invalid character' error
+    /// 4. Starts a character literal -> start the character subparser
+    /// 5. Starts a number -> start the number subparser
+    /// 6. Starts a word -> start the word subparser
+    /// 7. Starts a symbol -> start the operand subparser
+    /// 8. Is unknown -> yield an 'invalid character' error
     ///
     /// If the end of input has been reached, an 'EOF' token is returned for consequent calls.
     ///
@@ -151,6 +175,47 @@ impl<'a> TokenStream<'a> {
                 }
             }
 
+            if character == '\'' {
+                match self::char::parse(&self.input[self.offset..]) {
+                    Ok((size, value)) => {
+                        let location = self.location;
+                        self.location.column += size;
+                        self.offset += size;
+                        return Ok(Token::new(
+                            Lexeme::Literal(Literal::Char(CharLiteral::new(value))),
+                            location,
+                        ));
+                    }
+                    Err(CharParserError::NotAChar) => {}
+                    Err(CharParserError::UnterminatedSingleQuote { offset }) => {
+                        return Err(Error::unterminated_single_quote_character(
+                            self.location,
+                            self.location.shifted_right(offset),
+                        ));
+                    }
+                    Err(CharParserError::Empty { offset }) => {
+                        return Err(Error::character_empty(self.location.shifted_right(offset)));
+                    }
+                    Err(CharParserError::MultipleCharacters { offset }) => {
+                        return Err(Error::character_multiple(
+                            self.location.shifted_right(offset),
+                        ));
+                    }
+                    Err(CharParserError::NotAscii { found, offset }) => {
+                        return Err(Error::character_not_ascii(
+                            self.location.shifted_right(offset),
+                            found,
+                        ));
+                    }
+                    Err(CharParserError::ExpectedOneOfHexadecimal { found, offset }) => {
+                        return Err(Error::expected_one_of_hexadecimal(
+                            self.location.shifted_right(offset),
+                            found,
+                        ));
+                    }
+                }
+            }
+
             if character.is_ascii_digit() {
                 match self::integer::parse(&self.input[self.offset..]) {
                     Ok((size, integer)) => {
@@ -52,6 +52,7 @@ pub fn parse(input: &str) -> Result<(usize, Symbol), Error> {
                 Some(',') => return Ok((size + 1, Symbol::Comma)),
 
                 Some('~') => return Ok((size + 1, Symbol::Tilde)),
+                Some('#') => return Ok((size + 1, Symbol::Number)),
 
                 Some('+') => {
                     size += 1;
@@ -162,6 +163,7 @@ pub fn parse(input: &str) -> Result<(usize, Symbol), Error> {
             State::Asterisk => {
                 return match character {
                     Some('=') => Ok((size + 1, Symbol::AsteriskEquals)),
+                    Some('*') => Ok((size + 1, Symbol::DoubleAsterisk)),
                     _ => Ok((size, Symbol::Asterisk)),
                 }
             }
@@ -248,10 +250,26 @@ mod tests {
     }
 
     #[test]
-    fn error_invalid_character() {
+    fn ok_double_asterisk() {
+        let input = "**";
+        let expected = Ok((input.len(), Symbol::DoubleAsterisk));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_number() {
         let input = "#";
+        let expected = Ok((input.len(), Symbol::Number));
+        let result = parse(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_invalid_character() {
+        let input = "$";
         let expected = Err(Error::InvalidCharacter {
-            found: '#',
+            found: '$',
             offset: 0,
         });
         let result = parse(input);
@@ -8,6 +8,7 @@ use crate::lexical::error::Error;
 use crate::lexical::stream::TokenStream;
 use crate::lexical::token::lexeme::identifier::Identifier;
 use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::literal::char::Char;
 use crate::lexical::token::lexeme::literal::integer::Integer;
 use crate::lexical::token::lexeme::literal::Literal;
 use crate::lexical::token::lexeme::symbol::Symbol;
@@ -114,6 +115,75 @@ fn error_unterminated_double_quote_string() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_char() {
+    let input = r#"'A' '\x7F'"#;
+
+    let expected: Result<Vec<Token>, Error> = Ok(vec![
+        Token::new(
+            Lexeme::Literal(Literal::Char(Char::new(b'A'))),
+            Location::new(1, 1),
+        ),
+        Token::new(
+            Lexeme::Literal(Literal::Char(Char::new(0x7f))),
+            Location::new(1, 5),
+        ),
+    ]);
+
+    let mut stream = TokenStream::new(input);
+    let result: Result<Vec<Token>, Error> =
+        vec![stream.next(), stream.next()].into_iter().collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unterminated_single_quote_character() {
+    let input = "'A";
+
+    let expected: Result<Token, Error> = Err(Error::unterminated_single_quote_character(
+        Location::new(1, 1),
+        Location::new(1, 3),
+    ));
+
+    let result = TokenStream::new(input).next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_character_empty() {
+    let input = "''";
+
+    let expected: Result<Token, Error> = Err(Error::character_empty(Location::new(1, 2)));
+
+    let result = TokenStream::new(input).next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_character_multiple() {
+    let input = "'AB'";
+
+    let expected: Result<Token, Error> = Err(Error::character_multiple(Location::new(1, 3)));
+
+    let result = TokenStream::new(input).next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_character_not_ascii() {
+    let input = "'Ж'";
+
+    let expected: Result<Token, Error> = Err(Error::character_not_ascii(Location::new(1, 2), 'Ж'));
+
+    let result = TokenStream::new(input).next();
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_expected_one_of_binary() {
     let input = "0b102";
@@ -164,9 +234,9 @@ fn error_expected_one_of_hexadecimal() {
 
 #[test]
 fn error_invalid_character() {
-    let input = "#";
+    let input = "$";
 
-    let expected: Result<Token, Error> = Err(Error::invalid_character(Location::new(1, 1), '#'));
+    let expected: Result<Token, Error> = Err(Error::invalid_character(Location::new(1, 1), '$'));
 
     let result = TokenStream::new(input).next();
 
@@ -54,6 +54,12 @@ pub enum Keyword {
     Loop,
     Break,
     Continue,
+    Static,
+    Contract,
+    Trait,
+    Where,
+    Crate,
+    Super,
 }
 
 impl Keyword {
@@ -121,6 +127,12 @@ impl TryFrom<&str> for Keyword {
             "loop" => return Ok(Self::Loop),
             "break" => return Ok(Self::Break),
             "continue" => return Ok(Self::Continue),
+            "static" => return Ok(Self::Static),
+            "contract" => return Ok(Self::Contract),
+            "trait" => return Ok(Self::Trait),
+            "where" => return Ok(Self::Where),
+            "crate" => return Ok(Self::Crate),
+            "super" => return Ok(Self::Super),
 
             _ => {}
         }
@@ -219,6 +231,12 @@ impl fmt::Display for Keyword {
             Self::Loop => write!(f, "loop"),
             Self::Break => write!(f, "break"),
             Self::Continue => write!(f, "continue"),
+            Self::Static => write!(f, "static"),
+            Self::Contract => write!(f, "contract"),
+            Self::Trait => write!(f, "trait"),
+            Self::Where => write!(f, "where"),
+            Self::Crate => write!(f, "crate"),
+            Self::Super => write!(f, "super"),
         }
     }
 }
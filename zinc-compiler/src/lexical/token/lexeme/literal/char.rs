@@ -0,0 +1,35 @@
+//!
+//! The lexical token character literal lexeme.
+//!
+
+use std::fmt;
+
+///
+/// The character literal, which is an ASCII byte like `'A'` or an arbitrary byte like `'\xFF'`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Char {
+    pub inner: u8,
+}
+
+impl Char {
+    pub fn new(inner: u8) -> Self {
+        Self { inner }
+    }
+}
+
+impl Into<u8> for Char {
+    fn into(self) -> u8 {
+        self.inner
+    }
+}
+
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.inner.is_ascii_graphic() || self.inner == b' ' {
+            write!(f, "'{}'", self.inner as char)
+        } else {
+            write!(f, "'\\x{:02X}'", self.inner)
+        }
+    }
+}
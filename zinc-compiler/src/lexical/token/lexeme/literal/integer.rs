@@ -4,12 +4,32 @@
 
 use std::fmt;
 
+use crate::lexical::token::lexeme::keyword::Keyword;
+
+///
+/// The integer literal lexeme.
+///
+/// The optional `suffix` is the integer type keyword written right after the digits,
+/// e.g. `u64` in `42u64` or `i8` in `0x7f_i8`.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub enum Integer {
-    Binary { inner: String },
-    Octal { inner: String },
-    Decimal { inner: String },
-    Hexadecimal { inner: String },
+    Binary {
+        inner: String,
+        suffix: Option<Keyword>,
+    },
+    Octal {
+        inner: String,
+        suffix: Option<Keyword>,
+    },
+    Decimal {
+        inner: String,
+        suffix: Option<Keyword>,
+    },
+    Hexadecimal {
+        inner: String,
+        suffix: Option<Keyword>,
+    },
 }
 
 impl Integer {
@@ -28,29 +48,63 @@ impl Integer {
     pub const CHARACTER_DELIMITER: char = '_';
 
     pub fn new_binary(inner: String) -> Self {
-        Self::Binary { inner }
+        Self::Binary {
+            inner,
+            suffix: None,
+        }
     }
 
     pub fn new_octal(inner: String) -> Self {
-        Self::Octal { inner }
+        Self::Octal {
+            inner,
+            suffix: None,
+        }
     }
 
     pub fn new_decimal(inner: String) -> Self {
-        Self::Decimal { inner }
+        Self::Decimal {
+            inner,
+            suffix: None,
+        }
     }
 
     pub fn new_hexadecimal(inner: String) -> Self {
-        Self::Hexadecimal { inner }
+        Self::Hexadecimal {
+            inner,
+            suffix: None,
+        }
+    }
+
+    ///
+    /// Sets the type suffix, which must be an integer or `field` type keyword.
+    ///
+    pub fn with_suffix(mut self, keyword: Keyword) -> Self {
+        match self {
+            Self::Binary { ref mut suffix, .. } => *suffix = Some(keyword),
+            Self::Octal { ref mut suffix, .. } => *suffix = Some(keyword),
+            Self::Decimal { ref mut suffix, .. } => *suffix = Some(keyword),
+            Self::Hexadecimal { ref mut suffix, .. } => *suffix = Some(keyword),
+        }
+        self
+    }
+
+    pub fn suffix(&self) -> Option<&Keyword> {
+        match self {
+            Self::Binary { suffix, .. } => suffix.as_ref(),
+            Self::Octal { suffix, .. } => suffix.as_ref(),
+            Self::Decimal { suffix, .. } => suffix.as_ref(),
+            Self::Hexadecimal { suffix, .. } => suffix.as_ref(),
+        }
     }
 }
 
 impl Into<String> for Integer {
     fn into(self) -> String {
         match self {
-            Self::Binary { inner } => inner,
-            Self::Octal { inner } => inner,
-            Self::Decimal { inner } => inner,
-            Self::Hexadecimal { inner } => inner,
+            Self::Binary { inner, .. } => inner,
+            Self::Octal { inner, .. } => inner,
+            Self::Decimal { inner, .. } => inner,
+            Self::Hexadecimal { inner, .. } => inner,
         }
     }
 }
@@ -58,10 +112,14 @@ impl Into<String> for Integer {
 impl fmt::Display for Integer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::Binary { inner } => write!(f, "{}", inner),
-            Self::Octal { inner } => write!(f, "{}", inner),
-            Self::Decimal { inner } => write!(f, "{}", inner),
-            Self::Hexadecimal { inner } => write!(f, "{}", inner),
+            Self::Binary { inner, .. } => write!(f, "{}", inner)?,
+            Self::Octal { inner, .. } => write!(f, "{}", inner)?,
+            Self::Decimal { inner, .. } => write!(f, "{}", inner)?,
+            Self::Hexadecimal { inner, .. } => write!(f, "{}", inner)?,
+        }
+        match self.suffix() {
+            Some(suffix) => write!(f, "{}", suffix),
+            None => Ok(()),
         }
     }
 }
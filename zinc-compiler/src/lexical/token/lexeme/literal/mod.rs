@@ -3,12 +3,14 @@
 //!
 
 pub mod boolean;
+pub mod char;
 pub mod integer;
 pub mod string;
 
 use std::fmt;
 
 use self::boolean::Boolean;
+use self::char::Char;
 use self::integer::Integer;
 use self::string::String;
 
@@ -16,6 +18,7 @@ use self::string::String;
 pub enum Literal {
     Boolean(Boolean),
     Integer(Integer),
+    Char(Char),
     String(String),
 }
 
@@ -24,6 +27,7 @@ impl fmt::Display for Literal {
         match self {
             Self::Boolean(inner) => write!(f, "{}", inner),
             Self::Integer(inner) => write!(f, "{}", inner),
+            Self::Char(inner) => write!(f, "{}", inner),
             Self::String(inner) => write!(f, "{}", inner),
         }
     }
@@ -29,6 +29,7 @@ pub enum Symbol {
     Circumflex,
     Tilde,
     ExclamationMark,
+    Number,
     Lesser,
     Greater,
 
@@ -54,6 +55,7 @@ pub enum Symbol {
     DoubleDot,
     EqualsGreater,
     MinusGreater,
+    DoubleAsterisk,
 
     // three chars
     DoubleDotEquals,
@@ -86,6 +88,7 @@ impl fmt::Display for Symbol {
             Self::Circumflex => write!(f, "^"),
             Self::Tilde => write!(f, "~"),
             Self::ExclamationMark => write!(f, "!"),
+            Self::Number => write!(f, "#"),
             Self::Lesser => write!(f, "<"),
             Self::Greater => write!(f, ">"),
 
@@ -110,6 +113,7 @@ impl fmt::Display for Symbol {
             Self::DoubleDot => write!(f, ".."),
             Self::EqualsGreater => write!(f, "=>"),
             Self::MinusGreater => write!(f, "->"),
+            Self::DoubleAsterisk => write!(f, "**"),
 
             Self::DoubleDotEquals => write!(f, "..="),
             Self::DoubleLesserEquals => write!(f, "<<="),
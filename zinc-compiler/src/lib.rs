@@ -7,21 +7,32 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::too_many_arguments)]
 
+pub(crate) mod diagnostic;
 pub(crate) mod error;
+pub(crate) mod explain;
 pub(crate) mod file;
 pub(crate) mod generator;
 pub(crate) mod lexical;
 pub(crate) mod semantic;
 pub(crate) mod syntax;
+pub(crate) mod warning;
 
+pub use self::diagnostic::span::Span as DiagnosticSpan;
+pub use self::diagnostic::Diagnostic;
+pub use self::diagnostic::Severity as DiagnosticSeverity;
 pub use self::error::Error;
+pub use self::explain::explain;
 pub use self::file::File;
 pub use self::generator::bytecode::Bytecode;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 pub use self::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
+pub use self::semantic::scope::snapshot::symbol::Kind as ScopeSymbolKind;
+pub use self::semantic::scope::snapshot::symbol::Symbol as ScopeSymbol;
+pub use self::semantic::scope::snapshot::Snapshot as ScopeSnapshot;
 pub use self::semantic::scope::Scope;
 pub use self::syntax::parser::Parser;
 pub use self::syntax::tree::Tree;
+pub use self::warning::Warning;
 
 pub const BASE_BINARY: usize = 2;
 pub const BASE_OCTAL: usize = 8;
@@ -38,6 +49,7 @@ pub const BITLENGTH_SHA256_HASH: usize = 256;
 pub const LIMIT_PEDERSEN_HASH_INPUT_BITS: usize = 512;
 pub const LIMIT_SCHNORR_MESSAGE_BYTES: usize = 31;
 pub const LIMIT_SCHNORR_MESSAGE_BITS: usize = LIMIT_SCHNORR_MESSAGE_BYTES * BITLENGTH_BYTE;
+pub const LIMIT_LOOP_ITERATIONS: usize = 1 << 16;
 
 pub static PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS: &str = "Validated during lexical analysis";
 pub static PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS: &str = "Validated during syntax analysis";
@@ -33,6 +33,16 @@ struct Arguments {
         help = "Shows verbose logs, use multiple times for more verbosity"
     )]
     verbosity: usize,
+    #[structopt(
+        long = "deny-warnings",
+        help = "Turns the compiler warnings into errors"
+    )]
+    deny_warnings: bool,
+    #[structopt(
+        long = "loop-iterations-limit",
+        help = "The maximal number of loop iterations, including the ones of the enclosing loops"
+    )]
+    loop_iterations_limit: Option<usize>,
     #[structopt(
         long = "witness",
         parse(from_os_str),
@@ -137,10 +147,17 @@ fn main_inner(args: Arguments) -> Result<(), Error> {
             .start_new_file(source_file_path.to_string_lossy().as_ref());
 
         log::info!("Compiling {:?}", source_file_path);
-        let module = ZincFile::try_from(source_file_path)
-            .map_err(Error::Compiler)?
+        let mut file = ZincFile::try_from(source_file_path).map_err(Error::Compiler)?;
+        file.set_deny_warnings(args.deny_warnings);
+        if let Some(limit) = args.loop_iterations_limit {
+            file.set_loop_iterations_limit(limit);
+        }
+        let (module, warnings) = file
             .try_into_module(bytecode.clone())
             .map_err(Error::Compiler)?;
+        for warning in warnings.into_iter() {
+            eprintln!("{}", warning);
+        }
 
         modules.insert(module_name, module);
     }
@@ -152,10 +169,17 @@ fn main_inner(args: Arguments) -> Result<(), Error> {
                 .start_new_file(entry_file_path.to_string_lossy().as_ref());
 
             log::info!("Compiling {:?}", entry_file_path);
-            ZincFile::try_from(entry_file_path)
-                .map_err(Error::Compiler)?
+            let mut file = ZincFile::try_from(entry_file_path).map_err(Error::Compiler)?;
+            file.set_deny_warnings(args.deny_warnings);
+            if let Some(limit) = args.loop_iterations_limit {
+                file.set_loop_iterations_limit(limit);
+            }
+            let warnings = file
                 .try_into_entry(bytecode.clone(), modules)
                 .map_err(Error::Compiler)?;
+            for warning in warnings.into_iter() {
+                eprintln!("{}", warning);
+            }
         }
         None => return Err(Error::EntrySourceFileNotFound),
     }
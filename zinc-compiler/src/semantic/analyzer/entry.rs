@@ -9,10 +9,17 @@ use std::rc::Rc;
 use crate::error::Error as CompilerError;
 use crate::generator::Tree;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::item::Item;
+use crate::semantic::scope::snapshot::Snapshot as ScopeSnapshot;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes the circuit entry, which must be located in the `main.zn` file.
@@ -36,10 +43,52 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Sets the maximal number of loop iterations, including the ones of the enclosing loops.
+    ///
+    pub fn set_loop_iterations_limit(&mut self, limit: usize) {
+        Scope::set_loop_iterations_limit(self.scope_stack.top(), limit);
+    }
+
+    ///
+    /// Analyzes the entry `program`, returning the IR tree and the warnings.
+    ///
     pub fn compile(
         self,
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Result<(Tree, Vec<Warning>), CompilerError> {
+        let intermediate = self.analyze(program, dependencies)?;
+
+        Ok((intermediate, Scope::take_warnings(self.scope_stack.top())))
+    }
+
+    ///
+    /// Analyzes the entry `program`, returning the snapshots of its scopes with the symbols
+    /// declared in them, and the error, if the analysis has failed.
+    ///
+    /// The snapshots of the scopes analyzed before the error are returned as well, which is
+    /// useful for the IDE integrations, e.g. autocompletion.
+    ///
+    pub fn symbols(
+        self,
+        program: SyntaxTree,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> (Vec<ScopeSnapshot>, Option<CompilerError>) {
+        Scope::enable_snapshots(self.scope_stack.top());
+        let error = self.analyze(program, dependencies).err();
+        Scope::snapshot(self.scope_stack.top(), None);
+
+        (Scope::take_snapshots(self.scope_stack.top()), error)
+    }
+
+    ///
+    /// Analyzes the entry `program` statements and checks the entry point.
+    ///
+    fn analyze(
+        &self,
+        program: SyntaxTree,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> Result<Tree, CompilerError> {
         let mut intermediate = Tree::new();
 
@@ -53,20 +102,51 @@ impl Analyzer {
             }
         }
 
-        Scope::resolve_item(
-            self.scope_stack.top(),
-            crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER,
-        )
-        .map_err(|_| Error::EntryPointMissing)
-        .map_err(CompilerError::Semantic)?;
+        analyzer.check_unused_imports();
+
+        let main = Scope::resolve_item(self.scope_stack.top(), FUNCTION_MAIN_IDENTIFIER)
+            .map_err(|_| self.entry_point_missing())
+            .map_err(CompilerError::Semantic)?;
+        match main.variant {
+            ScopeItemVariant::Type(Type::Function(FunctionType::UserDefined(function))) => {
+                Scope::check_unused_functions(self.scope_stack.top(), function.unique_id());
+            }
+            variant => {
+                return Err(CompilerError::Semantic(Error::EntryPointNotFunction {
+                    location: main.location.unwrap_or_default(),
+                    found: variant.to_string(),
+                }))
+            }
+        }
 
         Ok(intermediate)
     }
+
+    ///
+    /// Checks if the entry point is declared with another letter case, e.g. `Main`.
+    ///
+    fn entry_point_missing(&self) -> Error {
+        match Scope::resolve_item_ignoring_case(self.scope_stack.top(), FUNCTION_MAIN_IDENTIFIER) {
+            Some((
+                name,
+                Item {
+                    variant: ScopeItemVariant::Type(Type::Function(_)),
+                    location: Some(location),
+                    ..
+                },
+            )) => Error::EntryPointMisnamed {
+                location,
+                found: name,
+            },
+            _ => Error::EntryPointMissing,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
+    use crate::lexical::token::location::Location;
     use crate::semantic::error::Error as SemanticError;
 
     #[test]
@@ -83,4 +163,79 @@ fn another() -> u8 {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_misnamed() {
+        let input = r#"
+fn Main() -> u8 {
+    42
+}
+"#;
+
+        let expected = Err(Error::Semantic(SemanticError::EntryPointMisnamed {
+            location: Location::new(2, 4),
+            found: "Main".to_owned(),
+        }));
+
+        let result = crate::semantic::tests::compile_entry(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_not_function() {
+        let input = r#"
+const main: u8 = 42;
+"#;
+
+        let expected = Err(Error::Semantic(SemanticError::EntryPointNotFunction {
+            location: Location::new(2, 7),
+            found: "constant integer '42' of type 'u8'".to_owned(),
+        }));
+
+        let result = crate::semantic::tests::compile_entry(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_argument_invalid_type() {
+        let input = r#"
+fn helper() {}
+
+fn main(value: helper) {}
+"#;
+
+        let expected = Err(Error::Semantic(
+            SemanticError::EntryPointArgumentInvalidType {
+                location: Location::new(4, 16),
+                name: "value".to_owned(),
+                found: "fn helper() -> ()".to_owned(),
+            },
+        ));
+
+        let result = crate::semantic::tests::compile_entry(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_return_type_invalid() {
+        let input = r#"
+fn main() -> _ {
+    0..10
+}
+"#;
+
+        let expected = Err(Error::Semantic(
+            SemanticError::EntryPointReturnTypeInvalid {
+                location: Location::new(2, 4),
+                found: "u8..u8".to_owned(),
+            },
+        ));
+
+        let result = crate::semantic::tests::compile_entry(input);
+
+        assert_eq!(result, expected);
+    }
 }
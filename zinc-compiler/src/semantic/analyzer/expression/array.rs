@@ -28,6 +28,12 @@ impl Analyzer {
     ///
     /// Analyzes the array literal expression.
     ///
+    /// The untyped integer literal elements get the type of the first typed element, so
+    /// `[x, 1, 2]` is an array of the `x` type.
+    ///
+    /// The empty list element type cannot be inferred, so it is only allowed in a `let` statement
+    /// with a type annotation, which is handled by the statement analyzer.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
@@ -35,15 +41,51 @@ impl Analyzer {
         array: ArrayExpression,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let mut result = Array::default();
+        let mut constants = Some(Vec::new());
         let mut builder = GeneratorArrayExpressionBuilder::default();
 
         match array.variant {
             ArrayExpressionVariant::List { elements } => {
+                if elements.is_empty() {
+                    return Err(Error::ArrayEmptyWithoutType {
+                        location: array.location,
+                    });
+                }
+
+                let mut analyzed = Vec::with_capacity(elements.len());
                 for expression in elements.into_iter() {
                     let expression_location = expression.location;
+                    let is_untyped_literal = expression.is_untyped_integer_literal();
 
                     let (element, expression) = ExpressionAnalyzer::new(scope.clone())
                         .analyze(expression, TranslationHint::Value)?;
+                    analyzed.push((expression_location, is_untyped_literal, element, expression));
+                }
+
+                let inferred_type = match analyzed.iter().find(
+                    |(_location, is_untyped_literal, _element, _expression)| !is_untyped_literal,
+                ) {
+                    Some((_location, _is_untyped_literal, element, _expression)) => {
+                        Some(Type::from_element(element, scope.clone())?)
+                    }
+                    None => None,
+                };
+
+                for (expression_location, is_untyped_literal, element, expression) in
+                    analyzed.into_iter()
+                {
+                    let (element, expression) = match inferred_type {
+                        Some(ref r#type) if is_untyped_literal => {
+                            ExpressionAnalyzer::infer_literal_expression(
+                                element,
+                                expression,
+                                r#type,
+                                expression_location,
+                            )?
+                        }
+                        _ => (element, expression),
+                    };
+
                     let element_type = Type::from_element(&element, scope.clone())?;
                     result.push(element_type).map_err(|error| {
                         Error::Element(
@@ -52,6 +94,14 @@ impl Analyzer {
                         )
                     })?;
 
+                    constants = match (constants, Self::scalar_constant(element)) {
+                        (Some(mut constants), Some(constant)) => {
+                            constants.push(constant);
+                            Some(constants)
+                        }
+                        _ => None,
+                    };
+
                     builder.push_expression(expression);
                 }
             }
@@ -63,7 +113,7 @@ impl Analyzer {
                 let size_expression_location = size_expression.location;
 
                 let size = match ExpressionAnalyzer::new(scope.clone())
-                    .analyze(size_expression, TranslationHint::Value)?
+                    .analyze(size_expression, TranslationHint::Constant)?
                 {
                     (Element::Constant(Constant::Integer(integer)), _intermediate) => {
                         integer.to_usize().map_err(|error| {
@@ -91,14 +141,33 @@ impl Analyzer {
                     )
                 })?;
 
+                constants = Self::scalar_constant(element).map(|constant| vec![constant; size]);
+
                 builder.push_expression(expression);
                 builder.set_size(size);
             }
         }
 
+        if let Some(constants) = constants {
+            if !constants.is_empty() {
+                result.set_constants(constants);
+            }
+        }
+
         let intermediate = GeneratorExpressionOperand::Array(builder.finish());
         let element = Element::Value(Value::Array(result));
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Returns the constant, if the array element is a scalar constant.
+    ///
+    fn scalar_constant(element: Element) -> Option<Constant> {
+        match element {
+            Element::Constant(constant @ Constant::Boolean(_)) => Some(constant),
+            Element::Constant(constant @ Constant::Integer(_)) => Some(constant),
+            _ => None,
+        }
+    }
 }
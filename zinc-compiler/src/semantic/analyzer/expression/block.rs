@@ -31,6 +31,7 @@ impl Analyzer {
         block: BlockExpression,
     ) -> Result<(Element, GeneratorBlockExpression), Error> {
         let mut builder = GeneratorBlockExpressionBuilder::default();
+        let span = (block.location, block.end_location);
 
         let mut scope_stack = ScopeStack::new(scope);
         scope_stack.push();
@@ -53,6 +54,7 @@ impl Analyzer {
             None => Element::Value(Value::Unit),
         };
 
+        Scope::snapshot(scope_stack.top(), Some(span));
         scope_stack.pop();
 
         Ok((element, builder.finish()))
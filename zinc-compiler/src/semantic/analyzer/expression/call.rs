@@ -13,6 +13,7 @@ use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::builtin::Function as BuiltInFunctionType;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::stdlib::Function as StandardLibraryFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
@@ -27,6 +28,9 @@ impl Analyzer {
     ///
     /// Analyzes the function call.
     ///
+    /// The `argument_locations` are used to point at the argument whose type does not match the
+    /// user-defined function parameter.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
@@ -35,6 +39,7 @@ impl Analyzer {
         operand_2: Element,
         is_call_builtin: bool,
         location: Location,
+        argument_locations: Vec<Location>,
     ) -> Result<(Element, GeneratorExpressionOperator), Error> {
         let function = match operand_1 {
             Element::Type(Type::Function(function)) => function,
@@ -65,6 +70,15 @@ impl Analyzer {
         };
         let mut input_size = 0;
         for element in argument_elements.iter() {
+            if let Element::Constant(constant) = element {
+                if constant.is_range() {
+                    return Err(Error::RangeUsedAsValue {
+                        location,
+                        found: constant.to_string(),
+                    });
+                }
+            }
+
             input_size += Type::from_element(element, scope.clone())?.size();
         }
 
@@ -121,17 +135,35 @@ impl Analyzer {
 
                 let builtin_identifier = function.builtin_identifier();
 
-                let return_type = function.call(argument_elements).map_err(|error| {
-                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
-                })?;
+                match function {
+                    StandardLibraryFunctionType::Pow(function) => {
+                        let (return_type, exponent_bitlength) =
+                            function.call(argument_elements).map_err(|error| {
+                                Error::Element(
+                                    location,
+                                    ElementError::Type(TypeError::Function(error)),
+                                )
+                            })?;
 
-                let intermediate = GeneratorExpressionOperator::call_std(
-                    builtin_identifier,
-                    input_size,
-                    return_type.size(),
-                );
+                        let intermediate =
+                            GeneratorExpressionOperator::call_pow(exponent_bitlength);
 
-                (return_type, intermediate)
+                        (return_type, intermediate)
+                    }
+                    function => {
+                        let return_type = function.call(argument_elements).map_err(|error| {
+                            Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                        })?;
+
+                        let intermediate = GeneratorExpressionOperator::call_std(
+                            builtin_identifier,
+                            input_size,
+                            return_type.size(),
+                        );
+
+                        (return_type, intermediate)
+                    }
+                }
             }
             FunctionType::UserDefined(function) => {
                 if is_call_builtin {
@@ -144,8 +176,17 @@ impl Analyzer {
                 }
 
                 let unique_id = function.unique_id();
+                Scope::add_call(scope.clone(), unique_id)
+                    .map_err(|cycle| Error::FunctionRecursion { location, cycle })?;
 
                 let return_type = function.call(argument_elements).map_err(|error| {
+                    let location = match error {
+                        FunctionTypeError::ArgumentType { position, .. } => argument_locations
+                            .get(position - 1)
+                            .copied()
+                            .unwrap_or(location),
+                        _ => location,
+                    };
                     Error::Element(location, ElementError::Type(TypeError::Function(error)))
                 })?;
 
@@ -5,18 +5,22 @@
 mod tests;
 
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::lexical::token::location::Location;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
 
 pub struct Analyzer {}
@@ -25,83 +29,151 @@ impl Analyzer {
     ///
     /// Analyzes the conditional expression.
     ///
+    /// The `else if` chain is analyzed as a flat sequence of branches, where every branch is
+    /// checked against the type of the first one.
+    ///
+    /// The result element is joined from the results of all the branches, so it is bounded only
+    /// if every branch is.
+    ///
+    /// The branches with constant conditions are resolved at compile time. A branch with the
+    /// `false` condition and the branches following the `true` one are analyzed, but not written
+    /// to the IR. The branch with the `true` condition becomes the unconditional `else` block.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         conditional: ConditionalExpression,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
-        let condition_location = conditional.condition.location;
-
-        let main_expression_location = conditional
-            .main_block
-            .expression
-            .as_ref()
-            .map(|expression| expression.location)
-            .unwrap_or(conditional.main_block.location);
-        let else_expression_location = conditional
-            .else_block
-            .as_ref()
-            .map(|block| {
-                block
-                    .expression
-                    .as_ref()
-                    .map(|expression| expression.location)
-                    .unwrap_or(block.location)
-            })
-            .unwrap_or(conditional.location);
-
         let mut builder = GeneratorConditionalExpressionBuilder::default();
 
         builder.set_location(conditional.location);
 
         let mut scope_stack = ScopeStack::new(scope);
 
-        let (condition_result, condition) = ExpressionAnalyzer::new(scope_stack.top())
-            .analyze(*conditional.condition, TranslationHint::Value)?;
-        match Type::from_element(&condition_result, scope_stack.top())? {
-            Type::Boolean => {}
-            r#type => {
-                return Err(Error::ConditionalExpectedBooleanCondition {
-                    location: condition_location,
-                    found: r#type.to_string(),
-                });
+        let uninitialized = Scope::uninitialized_variables(scope_stack.top());
+        let mut branches_uninitialized = HashSet::new();
+
+        let mut is_resolved = false;
+        let mut main: Option<(Element, Type, Location)> = None;
+        for (condition, block) in conditional.branches.into_iter() {
+            let condition_location = condition.location;
+            let block_expression_location = Self::block_expression_location(&block);
+
+            let (condition_result, condition) = ExpressionAnalyzer::new(scope_stack.top())
+                .analyze(condition, TranslationHint::Value)?;
+            match Type::from_element(&condition_result, scope_stack.top())? {
+                Type::Boolean => {}
+                r#type => {
+                    return Err(Error::ConditionalExpectedBooleanCondition {
+                        location: condition_location,
+                        found: r#type.to_string(),
+                    });
+                }
+            }
+
+            let constant = match condition_result {
+                Element::Constant(Constant::Boolean(ref boolean)) if condition.is_pure() => {
+                    Some(boolean.inner)
+                }
+                _ => None,
+            };
+
+            scope_stack.push();
+            let (result, block) = BlockAnalyzer::analyze(scope_stack.top(), block)?;
+            let r#type = Type::from_element(&result, scope_stack.top())?;
+            scope_stack.pop();
+            match constant {
+                _ if is_resolved => {}
+                Some(true) => {
+                    builder.set_else_block(block);
+                    is_resolved = true;
+                }
+                Some(false) => {}
+                None => builder.push_branch(condition, block),
+            }
+
+            branches_uninitialized.extend(Scope::uninitialized_variables(scope_stack.top()));
+            Scope::set_uninitialized_variables(scope_stack.top(), &uninitialized);
+
+            match main {
+                Some((ref mut main_result, ref main_type, main_location)) => {
+                    Self::check_branch_type(
+                        main_type,
+                        main_location,
+                        r#type,
+                        block_expression_location,
+                    )?;
+                    main_result.join_branch(&result);
+                }
+                None => main = Some((result, r#type, block_expression_location)),
             }
         }
-        builder.set_condition(condition);
+        let (mut main_result, main_type, main_location) =
+            main.expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+        builder.set_result_size(main_type.size());
 
-        scope_stack.push();
-        let (main_result, main_block) =
-            BlockAnalyzer::analyze(scope_stack.top(), conditional.main_block)?;
-        let main_type = Type::from_element(&main_result, scope_stack.top())?;
-        scope_stack.pop();
-        builder.set_main_block(main_block);
+        let (else_type, else_location) = if let Some(else_block) = conditional.else_block {
+            let else_location = Self::block_expression_location(&else_block);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
             scope_stack.push();
             let (else_result, else_block) = BlockAnalyzer::analyze(scope_stack.top(), else_block)?;
             let else_type = Type::from_element(&else_result, scope_stack.top())?;
             scope_stack.pop();
-            builder.set_else_block(else_block);
+            if !is_resolved {
+                builder.set_else_block(else_block);
+            }
+            main_result.join_branch(&else_result);
 
-            else_type
+            (else_type, else_location)
         } else {
-            Type::Unit
+            (Type::Unit, conditional.location)
         };
 
-        // check if the two branches return equals types
-        if main_type != else_type {
+        // a variable is initialized after the expression only if every branch initializes it
+        branches_uninitialized.extend(Scope::uninitialized_variables(scope_stack.top()));
+        Scope::set_uninitialized_variables(scope_stack.top(), &branches_uninitialized);
+
+        Self::check_branch_type(&main_type, main_location, else_type, else_location)?;
+
+        let element = main_result;
+        let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Checks if the branch returns the same type as the first one.
+    ///
+    /// The first branch is used as the reference for every subsequent branch in the chain.
+    ///
+    fn check_branch_type(
+        main_type: &Type,
+        main_location: Location,
+        r#type: Type,
+        location: Location,
+    ) -> Result<(), Error> {
+        if main_type != &r#type {
             return Err(Error::ConditionalBranchTypesMismatch {
-                location: main_expression_location,
+                location,
                 expected: main_type.to_string(),
-                found: else_type.to_string(),
-                reference: else_expression_location,
+                found: r#type.to_string(),
+                reference: main_location,
+                difference: main_type.difference(&r#type),
             });
         }
 
-        let element = main_result;
-        let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
+        Ok(())
+    }
 
-        Ok((element, intermediate))
+    ///
+    /// Returns the location of the block result expression, or of the block itself.
+    ///
+    fn block_expression_location(block: &BlockExpression) -> Location {
+        block
+            .expression
+            .as_ref()
+            .map(|expression| expression.location)
+            .unwrap_or(block.location)
     }
 }
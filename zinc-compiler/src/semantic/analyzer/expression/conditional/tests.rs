@@ -8,6 +8,7 @@ use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
 
 #[test]
 fn error_conditional_branch_types_mismatch() {
@@ -19,10 +20,136 @@ fn main() {
 
     let expected = Err(Error::Semantic(
         SemanticError::ConditionalBranchTypesMismatch {
-            location: Location::new(3, 15),
+            location: Location::new(3, 27),
             expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
             found: Type::boolean().to_string(),
+            reference: Location::new(3, 15),
+            difference: None,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_conditional_branch_types_mismatch_else_if_chain() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 2;
+    if value == 0 {
+        1
+    } else if value == 1 {
+        2
+    } else if value == 2 {
+        false
+    } else {
+        4
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::new(9, 9),
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::boolean().to_string(),
+            reference: Location::new(5, 9),
+            difference: None,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_conditional_branch_types_mismatch_array_length() {
+    let input = r#"
+fn main(flag: bool) {
+    let value = if flag { [1, 2, 3, 4] } else { [1, 2, 3, 4, 5, 6, 7, 8] };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::new(3, 49),
+            expected: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 4).to_string(),
+            found: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 8).to_string(),
             reference: Location::new(3, 27),
+            difference: Some("array length: expected 4, found 8".to_owned()),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_conditional_branch_types_mismatch_tuple_element() {
+    let input = r#"
+fn main(flag: bool) {
+    let value = if flag { (1, (2, 3)) } else { (1, (2, false)) };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::new(3, 48),
+            expected: Type::tuple(vec![
+                Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                Type::tuple(vec![
+                    Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                    Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                ]),
+            ])
+            .to_string(),
+            found: Type::tuple(vec![
+                Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                Type::tuple(vec![
+                    Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                    Type::boolean(),
+                ]),
+            ])
+            .to_string(),
+            reference: Location::new(3, 27),
+            difference: Some("tuple element 1.1: expected `u8`, found `bool`".to_owned()),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_conditional_branch_types_mismatch_structure_field() {
+    let input = r#"
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Pixel {
+    x: u8,
+    y: u64,
+}
+
+fn main(flag: bool, point: Point, pixel: Pixel) {
+    let value = if flag { point } else { pixel };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::new(13, 42),
+            expected: "struct Point".to_owned(),
+            found: "struct Pixel".to_owned(),
+            reference: Location::new(13, 27),
+            difference: Some("field x: expected `u64`, found `u8`".to_owned()),
         },
     ));
 
@@ -50,3 +177,28 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_dead_branch_is_analyzed() {
+    let input = r#"
+fn main() -> u8 {
+    if false {
+        undeclared
+    } else {
+        42
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 9),
+        ScopeError::ItemUndeclared {
+            name: "undeclared".to_owned(),
+            suggestion: None,
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
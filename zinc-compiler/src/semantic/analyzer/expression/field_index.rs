@@ -2,13 +2,20 @@
 //! The tuple index semantic analyzer.
 //!
 
+use std::cell::RefCell;
 use std::convert::TryFrom;
+use std::rc::Rc;
 
+use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::tuple_index::TupleIndex;
 
 ///
@@ -42,4 +49,30 @@ impl Analyzer {
 
         Ok(Element::TupleIndex(integer))
     }
+
+    ///
+    /// Analyzes the parenthesized constant expression, which is a tuple field index.
+    ///
+    pub fn expression(
+        scope: Rc<RefCell<Scope>>,
+        expression: ExpressionTree,
+    ) -> Result<Element, Error> {
+        let location = expression.location;
+
+        match ExpressionAnalyzer::new(scope).analyze(expression, TranslationHint::Constant)? {
+            (Element::Constant(Constant::Integer(integer)), _intermediate) => integer
+                .to_usize()
+                .map(Element::TupleIndex)
+                .map_err(|error| {
+                    Error::Element(
+                        location,
+                        ElementError::Constant(ConstantError::Integer(error)),
+                    )
+                }),
+            (element, _intermediate) => Err(Error::ConstantExpressionHasNonConstantElement {
+                location,
+                found: element.to_string(),
+            }),
+        }
+    }
 }
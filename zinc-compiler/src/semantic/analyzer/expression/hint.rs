@@ -16,6 +16,9 @@ pub enum Hint {
     Place,
     /// describes a runtime or compile-time value (will be separated later)
     Value,
+    /// describes a compile-time value, e.g. an array size, where variables with known constant
+    /// values are turned into constants
+    Constant,
     /// describes a compile-time type
     Type,
     /// describes a compile-time path
@@ -67,6 +70,7 @@ impl Hint {
             ExpressionOperator::Multiplication => Self::Value,
             ExpressionOperator::Division => Self::Value,
             ExpressionOperator::Remainder => Self::Value,
+            ExpressionOperator::Exponentiation => Self::Value,
 
             ExpressionOperator::Casting => Self::Value,
 
@@ -126,6 +130,7 @@ impl Hint {
             ExpressionOperator::Multiplication => Self::Value,
             ExpressionOperator::Division => Self::Value,
             ExpressionOperator::Remainder => Self::Value,
+            ExpressionOperator::Exponentiation => Self::Value,
 
             ExpressionOperator::Casting => Self::Type,
 
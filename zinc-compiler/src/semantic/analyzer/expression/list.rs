@@ -9,6 +9,7 @@ use crate::generator::expression::operand::list::builder::Builder as GeneratorLi
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -20,18 +21,31 @@ impl Analyzer {
     ///
     /// Analyzes the function argument list.
     ///
+    /// The untyped integer literal arguments get the `parameter_types` at the same positions,
+    /// if they are known.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         list: ListExpression,
+        parameter_types: &[Type],
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
         let mut expressions = Vec::with_capacity(list.len());
         let mut builder = GeneratorListExpressionBuilder::default();
 
-        for expression in list.elements.into_iter() {
+        for (index, expression) in list.elements.into_iter().enumerate() {
+            let location = expression.location;
+            let is_untyped_literal = expression.is_untyped_integer_literal();
+
             let (element, expression) = ExpressionAnalyzer::new(scope.clone())
                 .analyze(expression, TranslationHint::Value)?;
+            let (element, expression) = match parameter_types.get(index) {
+                Some(r#type) if is_untyped_literal => ExpressionAnalyzer::infer_literal_expression(
+                    element, expression, r#type, location,
+                )?,
+                _ => (element, expression),
+            };
             expressions.push(element);
 
             builder.push_expression(expression);
@@ -4,6 +4,8 @@
 
 use std::convert::TryFrom;
 
+use num_bigint::BigInt;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -14,6 +16,7 @@ use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
+use crate::syntax::tree::literal::char::Literal as CharLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::literal::string::Literal as StringLiteral;
 
@@ -63,6 +66,27 @@ impl Analyzer {
         Ok((element, intermediate))
     }
 
+    ///
+    /// Analyzes the character literal, which is an unsigned 8-bit integer constant.
+    ///
+    /// Returns the semantic element and the intermediate representation if it is available.
+    ///
+    pub fn char(
+        literal: CharLiteral,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let constant = Constant::Integer(IntegerConstant::new(
+            BigInt::from(literal.inner.inner),
+            false,
+            crate::BITLENGTH_BYTE,
+        ));
+
+        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+            .map(GeneratorExpressionOperand::Constant);
+        let element = Element::Constant(constant);
+
+        Ok((element, intermediate))
+    }
+
     ///
     /// Converts the syntax string literal to a semantic string literal.
     ///
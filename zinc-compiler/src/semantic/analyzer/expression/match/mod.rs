@@ -7,27 +7,35 @@ mod tests;
 pub mod exhausting;
 
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use num_traits::Signed;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
+use crate::generator::expression::operand::r#match::Pattern as GeneratorMatchPattern;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
 
 use self::exhausting::Data as ExhaustingData;
@@ -38,6 +46,9 @@ impl Analyzer {
     ///
     /// Analyzes the match expression.
     ///
+    /// The result element is joined from the results of all the branches, so it is bounded only
+    /// if every branch is.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
@@ -74,15 +85,20 @@ impl Analyzer {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
-        let first_branch_expression_location = r#match.branches[0].1.location;
+        let first_branch_expression_location = r#match.branches[0].2.location;
         let mut is_exhausted = false;
         let mut exhausting_data = ExhaustingData::new();
         let mut branch_results = Vec::with_capacity(r#match.branches.len());
 
-        for (pattern, expression) in r#match.branches.into_iter() {
+        let uninitialized = Scope::uninitialized_variables(scope_stack.top());
+        let mut branches_uninitialized = HashSet::new();
+
+        for (pattern, guard, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
 
+            Scope::set_uninitialized_variables(scope_stack.top(), &uninitialized);
+
             if is_exhausted {
                 return Err(Error::MatchBranchUnreachable {
                     location: pattern.location,
@@ -104,26 +120,37 @@ impl Analyzer {
                         });
                     }
 
-                    if let Some(duplicate) =
-                        exhausting_data.insert_boolean(constant.inner, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
+                    if guard.is_none() {
+                        if let Some(duplicate) =
+                            exhausting_data.insert_boolean(constant.inner, location)
+                        {
+                            return Err(Error::MatchBranchDuplicate {
+                                location,
+                                reference: duplicate,
+                            });
+                        }
                     }
 
                     let constant =
                         GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
                             .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let guard = match guard {
+                        Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                        None => None,
+                    };
                     let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
                         .analyze(expression, TranslationHint::Value)?;
 
-                    if exhausting_data.is_exhausted_boolean() {
+                    if let Some(guard) = guard {
+                        builder.push_branch(
+                            GeneratorMatchPattern::GuardedConstant(constant, guard),
+                            branch,
+                        );
+                    } else if exhausting_data.is_exhausted_boolean() {
                         is_exhausted = true;
                         builder.set_wildcard_branch(branch);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(GeneratorMatchPattern::Constant(constant), branch);
                     }
 
                     result
@@ -137,6 +164,17 @@ impl Analyzer {
                             ElementError::Constant(ConstantError::Integer(error)),
                         )
                     })?;
+                    if constant.value.is_negative() && scrutinee_type.is_scalar_unsigned() {
+                        return Err(Error::Element(
+                            location,
+                            ElementError::Constant(ConstantError::Integer(
+                                IntegerConstantError::UnsignedNegative {
+                                    value: constant.value,
+                                    r#type: scrutinee_type.to_string(),
+                                },
+                            )),
+                        ));
+                    }
                     let pattern_type = constant.r#type();
                     if pattern_type != scrutinee_type {
                         return Err(Error::MatchBranchPatternInvalidType {
@@ -147,26 +185,37 @@ impl Analyzer {
                         });
                     }
 
-                    if let Some(duplicate) =
-                        exhausting_data.insert_integer(constant.value.clone(), None, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
+                    if guard.is_none() {
+                        if let Some(duplicate) =
+                            exhausting_data.insert_integer(constant.value.clone(), None, location)
+                        {
+                            return Err(Error::MatchBranchDuplicate {
+                                location,
+                                reference: duplicate,
+                            });
+                        }
                     }
 
                     let constant =
                         GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
                             .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let guard = match guard {
+                        Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                        None => None,
+                    };
                     let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
                         .analyze(expression, TranslationHint::Value)?;
 
-                    if exhausting_data.is_exhausted_integer() {
+                    if let Some(guard) = guard {
+                        builder.push_branch(
+                            GeneratorMatchPattern::GuardedConstant(constant, guard),
+                            branch,
+                        );
+                    } else if exhausting_data.is_exhausted_integer() {
                         is_exhausted = true;
                         builder.set_wildcard_branch(branch);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(GeneratorMatchPattern::Constant(constant), branch);
                     }
 
                     result
@@ -178,7 +227,7 @@ impl Analyzer {
                         .analyze(path, TranslationHint::Value)?
                     {
                         (Element::Constant(constant), _intermediate) => {
-                            if let Constant::Integer(ref integer) = constant {
+                            if let (Constant::Integer(ref integer), None) = (&constant, &guard) {
                                 if let Some(duplicate) = exhausting_data.insert_integer(
                                     integer.value.to_owned(),
                                     integer.enumeration.to_owned(),
@@ -202,21 +251,41 @@ impl Analyzer {
 
                     let constant = GeneratorConstant::try_from_semantic(&constant)
                         .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let guard = match guard {
+                        Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                        None => None,
+                    };
                     let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
                         .analyze(expression, TranslationHint::Value)?;
 
-                    if exhausting_data.is_exhausted_integer() {
+                    if let Some(guard) = guard {
+                        builder.push_branch(
+                            GeneratorMatchPattern::GuardedConstant(constant, guard),
+                            branch,
+                        );
+                    } else if exhausting_data.is_exhausted_integer() {
                         is_exhausted = true;
                         builder.set_wildcard_branch(branch);
                     } else {
-                        builder.push_branch(constant, branch);
+                        builder.push_branch(GeneratorMatchPattern::Constant(constant), branch);
                     }
 
                     result
                 }
                 MatchPatternVariant::Binding(identifier) => {
                     let location = identifier.location;
-                    is_exhausted = true;
+
+                    if let Type::Enumeration(ref enumeration) = scrutinee_type {
+                        if Scope::is_variant_declared(enumeration.scope.clone(), &identifier.name) {
+                            return Err(Error::Scope(
+                                location,
+                                ScopeError::EnumerationVariantUnqualified {
+                                    name: identifier.name,
+                                    enumeration: enumeration.identifier.to_owned(),
+                                },
+                            ));
+                        }
+                    }
 
                     scope_stack.push();
                     Scope::declare_variable(
@@ -225,20 +294,40 @@ impl Analyzer {
                         ScopeVariableItem::new(false, scrutinee_type.clone()),
                     )
                     .map_err(|error| Error::Scope(location, error))?;
+                    let guard = match guard {
+                        Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                        None => None,
+                    };
                     let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
                         .analyze(expression, TranslationHint::Value)?;
                     scope_stack.pop();
 
-                    builder.set_binding_branch(branch, identifier.name);
+                    if let Some(guard) = guard {
+                        builder.push_branch(
+                            GeneratorMatchPattern::GuardedBinding(identifier.name, guard),
+                            branch,
+                        );
+                    } else {
+                        is_exhausted = true;
+                        builder.set_binding_branch(branch, identifier.name);
+                    }
 
                     result
                 }
                 MatchPatternVariant::Wildcard => {
-                    is_exhausted = true;
+                    let guard = match guard {
+                        Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                        None => None,
+                    };
                     let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
                         .analyze(expression, TranslationHint::Value)?;
 
-                    builder.set_wildcard_branch(branch);
+                    if let Some(guard) = guard {
+                        builder.push_branch(GeneratorMatchPattern::GuardedWildcard(guard), branch);
+                    } else {
+                        is_exhausted = true;
+                        builder.set_wildcard_branch(branch);
+                    }
 
                     result
                 }
@@ -259,18 +348,51 @@ impl Analyzer {
             }
 
             branch_results.push(result);
+            branches_uninitialized.extend(Scope::uninitialized_variables(scope_stack.top()));
         }
 
+        // a variable is initialized after the expression only if every branch initializes it
+        Scope::set_uninitialized_variables(scope_stack.top(), &branches_uninitialized);
+
         if !is_exhausted {
             return Err(Error::MatchNotExhausted { location });
         }
 
-        let element = match branch_results.pop() {
-            Some(result) => result,
+        let mut branch_results = branch_results.into_iter();
+        let element = match branch_results.next() {
+            Some(mut element) => {
+                for result in branch_results {
+                    element.join_branch(&result);
+                }
+                element
+            }
             None => Element::Constant(Constant::Unit),
         };
         let intermediate = GeneratorExpressionOperand::Match(builder.finish());
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Analyzes the match branch guard, which must be a boolean expression.
+    ///
+    /// Guarded branches are not taken into account by the exhaustiveness checking, since the
+    /// guard value is generally unknown at compile time.
+    ///
+    fn guard(
+        scope: Rc<RefCell<Scope>>,
+        guard: ExpressionTree,
+    ) -> Result<GeneratorExpression, Error> {
+        let location = guard.location;
+
+        let (result, expression) =
+            ExpressionAnalyzer::new(scope.clone()).analyze(guard, TranslationHint::Value)?;
+        match Type::from_element(&result, scope)? {
+            Type::Boolean => Ok(expression),
+            r#type => Err(Error::MatchBranchGuardExpectedBoolean {
+                location,
+                found: r#type.to_string(),
+            }),
+        }
+    }
 }
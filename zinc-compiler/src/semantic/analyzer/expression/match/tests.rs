@@ -8,10 +8,16 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::Scope;
 
 static PANIC_COMPILE_DEPENDENCY: &str = "Dependencies must be successfully compiled";
@@ -59,6 +65,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_match_not_exhausted_guarded() {
+    let input = r#"
+fn main() {
+    let scrutinee = true;
+    let result = match scrutinee {
+        false => 10,
+        true if scrutinee => 20,
+        value if !value => 30,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::new(4, 18),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_match_less_than_two_branches() {
     let input = r#"
@@ -152,6 +180,36 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_match_branch_pattern_variant_unqualified() {
+    let input = r#"
+enum List {
+    One = 1,
+    Two = 2,
+}
+
+fn main() {
+    let scrutinee = List::One;
+    let result = match scrutinee {
+        One => 1,
+        _ => 2,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(10, 9),
+        ScopeError::EnumerationVariantUnqualified {
+            name: "One".to_owned(),
+            enumeration: "List".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_match_branch_pattern_path_expected_constant() {
     let module_1 = r#"
@@ -215,6 +273,33 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_match_branch_pattern_unsigned_negative() {
+    let input = r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        -42 => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 9),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::UnsignedNegative {
+                value: BigInt::from(-42),
+                r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_match_branch_expression_invalid_type() {
     let input = r#"
@@ -284,3 +369,27 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_match_branch_guard_expected_boolean() {
+    let input = r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        value if value + 1 => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchGuardExpectedBoolean {
+            location: Location::new(5, 24),
+            found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
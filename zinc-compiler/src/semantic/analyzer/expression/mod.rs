@@ -22,28 +22,39 @@ pub mod tuple;
 pub mod r#type;
 
 use std::cell::RefCell;
+use std::convert::TryFrom;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Zero;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorExpressionConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::error::Error as PlaceError;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::warning::Warning;
 
 use self::array::Analyzer as ArrayAnalyzer;
 use self::block::Analyzer as BlockAnalyzer;
@@ -73,6 +84,8 @@ pub struct Analyzer {
     evaluation_stack: EvaluationStack,
     intermediate: GeneratorExpression,
     is_next_call_builtin: bool,
+    argument_locations: Vec<Location>,
+    is_constant: bool,
 }
 
 impl Analyzer {
@@ -85,6 +98,8 @@ impl Analyzer {
             evaluation_stack: EvaluationStack::new(),
             intermediate: GeneratorExpression::new(),
             is_next_call_builtin: false,
+            argument_locations: Vec::new(),
+            is_constant: false,
         }
     }
 
@@ -97,19 +112,29 @@ impl Analyzer {
     /// Is used either as an outer interface method, or for getting the array index 2nd operand
     /// subtree, assignment operands and so on.
     ///
+    /// The `Constant` hint makes the whole expression a constant one, so the variables with known
+    /// constant values are turned into constants, e.g. in `[0; size * 2]`.
+    ///
     pub fn analyze(
         mut self,
         tree: ExpressionTree,
         hint: TranslationHint,
     ) -> Result<(Element, GeneratorExpression), Error> {
+        if let TranslationHint::Constant = hint {
+            self.is_constant = true;
+        }
+
         let (element, intermediate) = self.traverse(tree, hint)?;
         if let Some(intermediate) = intermediate {
             self.intermediate.push_operand(intermediate)
         }
 
-        if let (Element::Place(place), TranslationHint::Value) = (&element, hint) {
-            self.intermediate
-                .push_operand(GeneratorExpressionOperand::Place(place.to_owned().into()))
+        match (&element, hint) {
+            (Element::Place(place), TranslationHint::Value)
+            | (Element::Place(place), TranslationHint::Constant) => self
+                .intermediate
+                .push_operand(GeneratorExpressionOperand::Place(place.to_owned().into())),
+            _ => {}
         }
 
         Ok((element, self.intermediate))
@@ -130,6 +155,8 @@ impl Analyzer {
         tree: ExpressionTree,
         hint: TranslationHint,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let start = self.intermediate.next_index();
+
         match *tree.value {
             ExpressionTreeNode::Operand(operand) => {
                 return Self::evaluate(
@@ -142,7 +169,9 @@ impl Analyzer {
                 ExpressionOperator::Assignment => {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
-                    let place = self.assignment(Element::assign, tree.location)?.into();
+                    let place = self
+                        .assignment(Element::assign, operator, tree.location)?
+                        .into();
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::Assignment { place, expression },
@@ -152,7 +181,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_or, tree.location)?
+                        .assignment(Element::assign_bitwise_or, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -163,7 +192,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_xor, tree.location)?
+                        .assignment(Element::assign_bitwise_xor, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -174,7 +203,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_and, tree.location)?
+                        .assignment(Element::assign_bitwise_and, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -185,7 +214,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_shift_left, tree.location)?
+                        .assignment(Element::assign_bitwise_shift_left, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -199,7 +228,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_shift_right, tree.location)?
+                        .assignment(Element::assign_bitwise_shift_right, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -212,7 +241,9 @@ impl Analyzer {
                 ExpressionOperator::AssignmentAddition => {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
-                    let place = self.assignment(Element::assign_add, tree.location)?.into();
+                    let place = self
+                        .assignment(Element::assign_add, operator, tree.location)?
+                        .into();
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentAddition { place, expression },
@@ -222,7 +253,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_subtract, tree.location)?
+                        .assignment(Element::assign_subtract, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -233,7 +264,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_multiply, tree.location)?
+                        .assignment(Element::assign_multiply, operator, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -241,10 +272,12 @@ impl Analyzer {
                     );
                 }
                 ExpressionOperator::AssignmentDivision => {
+                    let divisor_location = Self::divisor_location(&tree);
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_divide, tree.location)?
+                        .assignment(Element::assign_divide, operator, tree.location)
+                        .map_err(|error| Self::divisor_error(error, divisor_location))?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -252,10 +285,12 @@ impl Analyzer {
                     );
                 }
                 ExpressionOperator::AssignmentRemainder => {
+                    let divisor_location = Self::divisor_location(&tree);
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_remainder, tree.location)?
+                        .assignment(Element::assign_remainder, operator, tree.location)
+                        .map_err(|error| Self::divisor_error(error, divisor_location))?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -287,10 +322,14 @@ impl Analyzer {
 
                 ExpressionOperator::Or => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
+                    let uninitialized = Scope::uninitialized_variables(self.scope_stack.top());
+                    let expression = self.right_global(tree.right, operator)?;
+                    Scope::set_uninitialized_variables(self.scope_stack.top(), &uninitialized);
                     self.binary(Element::or, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Or);
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::Or { expression },
+                    );
                 }
                 ExpressionOperator::Xor => {
                     self.left_local(tree.left, operator)?;
@@ -301,29 +340,38 @@ impl Analyzer {
                 }
                 ExpressionOperator::And => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
+                    let uninitialized = Scope::uninitialized_variables(self.scope_stack.top());
+                    let expression = self.right_global(tree.right, operator)?;
+                    Scope::set_uninitialized_variables(self.scope_stack.top(), &uninitialized);
                     self.binary(Element::and, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::And);
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::And { expression },
+                    );
                 }
 
                 ExpressionOperator::Equals => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::equals, tree.location)?;
+                    self.infer_literal_types(tree.location)?;
+                    let r#type = self.equality(Element::equals, tree.location)?;
                     self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Equals);
+                        .push_operator(tree.location, GeneratorExpressionOperator::equals(&r#type));
                 }
                 ExpressionOperator::NotEquals => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::not_equals, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::NotEquals);
+                    self.infer_literal_types(tree.location)?;
+                    let r#type = self.equality(Element::not_equals, tree.location)?;
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::not_equals(&r#type),
+                    );
                 }
                 ExpressionOperator::GreaterEquals => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::greater_equals, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::GreaterEquals);
@@ -331,6 +379,7 @@ impl Analyzer {
                 ExpressionOperator::LesserEquals => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::lesser_equals, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::LesserEquals);
@@ -338,6 +387,7 @@ impl Analyzer {
                 ExpressionOperator::Greater => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::greater, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Greater);
@@ -345,6 +395,7 @@ impl Analyzer {
                 ExpressionOperator::Lesser => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::lesser, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Lesser);
@@ -353,6 +404,7 @@ impl Analyzer {
                 ExpressionOperator::BitwiseOr => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::bitwise_or, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseOr);
@@ -360,6 +412,7 @@ impl Analyzer {
                 ExpressionOperator::BitwiseXor => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::bitwise_xor, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseXor);
@@ -367,13 +420,14 @@ impl Analyzer {
                 ExpressionOperator::BitwiseAnd => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::bitwise_and, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseAnd);
                 }
                 ExpressionOperator::BitwiseShiftLeft => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
+                    self.right_constant(tree.right, operator)?;
                     self.binary(Element::bitwise_shift_left, tree.location)?;
                     self.intermediate.push_operator(
                         tree.location,
@@ -382,7 +436,7 @@ impl Analyzer {
                 }
                 ExpressionOperator::BitwiseShiftRight => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
+                    self.right_constant(tree.right, operator)?;
                     self.binary(Element::bitwise_shift_right, tree.location)?;
                     self.intermediate.push_operator(
                         tree.location,
@@ -393,13 +447,18 @@ impl Analyzer {
                 ExpressionOperator::Addition => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::add, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Addition);
+                    let is_unchecked = self.is_result_bounded();
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::Addition { is_unchecked },
+                    );
                 }
                 ExpressionOperator::Subtraction => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::subtract, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Subtraction);
@@ -407,24 +466,40 @@ impl Analyzer {
                 ExpressionOperator::Multiplication => {
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
+                    self.infer_literal_types(tree.location)?;
                     self.binary(Element::multiply, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Multiplication);
+                    let is_unchecked = self.is_result_bounded();
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::Multiplication { is_unchecked },
+                    );
                 }
                 ExpressionOperator::Division => {
+                    let divisor_location = Self::divisor_location(&tree);
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::divide, tree.location)?;
+                    self.infer_literal_types(tree.location)?;
+                    self.binary(Element::divide, tree.location)
+                        .map_err(|error| Self::divisor_error(error, divisor_location))?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Division);
                 }
                 ExpressionOperator::Remainder => {
+                    let divisor_location = Self::divisor_location(&tree);
                     self.left_local(tree.left, operator)?;
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::remainder, tree.location)?;
+                    self.infer_literal_types(tree.location)?;
+                    self.binary(Element::remainder, tree.location)
+                        .map_err(|error| Self::divisor_error(error, divisor_location))?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Remainder);
                 }
+                ExpressionOperator::Exponentiation => {
+                    self.left_local(tree.left, operator)?;
+                    self.right_global(tree.right, operator)?;
+                    let operator = self.exponentiation(tree.location)?;
+                    self.intermediate.push_operator(tree.location, operator);
+                }
 
                 ExpressionOperator::Casting => {
                     self.left_local(tree.left, operator)?;
@@ -457,8 +532,9 @@ impl Analyzer {
 
                 ExpressionOperator::Index => {
                     self.left_local(tree.left, operator)?;
+                    let index = tree.right.clone();
                     let intermediate = self.right_global(tree.right, operator)?;
-                    let intermediate = self.index(tree.location, intermediate)?;
+                    let intermediate = self.index(tree.location, intermediate, index)?;
                     if let Some(intermediate) = intermediate {
                         self.intermediate.push_operator(tree.location, intermediate);
                     }
@@ -475,7 +551,7 @@ impl Analyzer {
 
                 ExpressionOperator::Call => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
+                    self.arguments(tree.right)?;
                     let operator = self.call(tree.location)?;
                     self.intermediate.push_operator(tree.location, operator);
                 }
@@ -492,6 +568,8 @@ impl Analyzer {
             },
         }
 
+        self.fold(start);
+
         Self::evaluate(self.scope_stack.top(), self.evaluation_stack.pop(), hint)
     }
 
@@ -507,10 +585,15 @@ impl Analyzer {
     ) -> Result<(), Error> {
         match left {
             Some(left) => {
-                let hint = TranslationHint::first(operator);
+                let hint = self.operand_hint(TranslationHint::first(operator));
+                let is_untyped_literal = left.is_untyped_integer_literal();
                 let (element, intermediate) = self.traverse(*left, hint)?;
 
-                self.evaluation_stack.push(StackElement::Evaluated(element));
+                self.evaluation_stack.push(if is_untyped_literal {
+                    StackElement::Literal(element, self.intermediate.next_index())
+                } else {
+                    StackElement::Evaluated(element)
+                });
                 if let Some(intermediate) = intermediate {
                     self.intermediate.push_operand(intermediate);
                 }
@@ -532,10 +615,15 @@ impl Analyzer {
     ) -> Result<(), Error> {
         match right {
             Some(right) => {
-                let hint = TranslationHint::second(operator);
+                let hint = self.operand_hint(TranslationHint::second(operator));
+                let is_untyped_literal = right.is_untyped_integer_literal();
                 let (element, intermediate) = self.traverse(*right, hint)?;
 
-                self.evaluation_stack.push(StackElement::Evaluated(element));
+                self.evaluation_stack.push(if is_untyped_literal {
+                    StackElement::Literal(element, self.intermediate.next_index())
+                } else {
+                    StackElement::Evaluated(element)
+                });
                 if let Some(intermediate) = intermediate {
                     self.intermediate.push_operand(intermediate);
                 }
@@ -545,6 +633,24 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Analyzes the right operand of a binary operand, which must be a constant expression,
+    /// e.g. the bitwise shift amount.
+    ///
+    /// Writes results to the current evaluation stack and IR instance.
+    ///
+    pub fn right_constant(
+        &mut self,
+        right: Option<Box<ExpressionTree>>,
+        operator: ExpressionOperator,
+    ) -> Result<(), Error> {
+        let is_constant = self.is_constant;
+        self.is_constant = true;
+        let result = self.right_local(right, operator);
+        self.is_constant = is_constant;
+        result
+    }
+
     ///
     /// Analyzes the left operand of a binary operand.
     ///
@@ -555,7 +661,7 @@ impl Analyzer {
         left: Option<Box<ExpressionTree>>,
         operator: ExpressionOperator,
     ) -> Result<GeneratorExpression, Error> {
-        let hint = TranslationHint::first(operator);
+        let hint = self.operand_hint(TranslationHint::first(operator));
         let (element, intermediate) = match left {
             Some(left) => Self::new(self.scope_stack.top()).analyze(*left, hint)?,
             None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
@@ -569,24 +675,70 @@ impl Analyzer {
     ///
     /// Creates a new analyzer to avoid writing to the current evaluation stack and IR instance.
     ///
+    /// The untyped integer literal gets the type of the left operand, so `1` in `value += 1`
+    /// gets the type of `value`. The shift amount type is independent, so it is left as is.
+    ///
     pub fn right_global(
         &mut self,
         right: Option<Box<ExpressionTree>>,
         operator: ExpressionOperator,
     ) -> Result<GeneratorExpression, Error> {
-        let hint = TranslationHint::second(operator);
+        let hint = self.operand_hint(TranslationHint::second(operator));
         let (element, intermediate) = match right {
-            Some(left) => Self::new(self.scope_stack.top()).analyze(*left, hint)?,
+            Some(right) => {
+                let location = right.location;
+                let is_untyped_literal = right.is_untyped_integer_literal();
+                let (element, intermediate) =
+                    Self::new(self.scope_stack.top()).analyze(*right, hint)?;
+
+                match Self::operand_type(self.evaluation_stack.top()) {
+                    Some(r#type) if is_untyped_literal && !Self::is_shift(operator) => {
+                        Self::infer_literal_expression(element, intermediate, &r#type, location)?
+                    }
+                    _ => (element, intermediate),
+                }
+            }
             None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
         self.evaluation_stack.push(StackElement::Evaluated(element));
         Ok(intermediate)
     }
 
+    ///
+    /// Checks if the operator is a shift one, whose second operand type is independent.
+    ///
+    fn is_shift(operator: ExpressionOperator) -> bool {
+        match operator {
+            ExpressionOperator::BitwiseShiftLeft
+            | ExpressionOperator::BitwiseShiftRight
+            | ExpressionOperator::AssignmentBitwiseShiftLeft
+            | ExpressionOperator::AssignmentBitwiseShiftRight => true,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Turns the operand value hint into the constant one, if the expression is a constant one.
+    ///
+    fn operand_hint(&self, hint: TranslationHint) -> TranslationHint {
+        match (hint, self.is_constant) {
+            (TranslationHint::Value, true) => TranslationHint::Constant,
+            (hint, _) => hint,
+        }
+    }
+
     ///
     /// Analyzes the assignment operation.
     ///
-    fn assignment<F>(&mut self, callback: F, location: Location) -> Result<Place, Error>
+    /// The simple assignment to a whole variable initializes it. Other assignments read the
+    /// variable, so it must be already initialized.
+    ///
+    fn assignment<F>(
+        &mut self,
+        callback: F,
+        operator: ExpressionOperator,
+        location: Location,
+    ) -> Result<Place, Error>
     where
         F: FnOnce(Element, Element) -> Result<Place, ElementError>,
     {
@@ -610,15 +762,20 @@ impl Analyzer {
                 Scope::resolve_item(self.scope_stack.top(), place.identifier.as_str())
                     .map_err(|error| Error::Scope(place.location, error))?
                     .location;
-            return Err(Error::Element(
-                location,
-                ElementError::Place(PlaceError::MutatingImmutableMemory {
-                    name: place.to_string(),
+            let error = if place.elements.is_empty() {
+                PlaceError::MutatingImmutableMemory {
+                    name: place.identifier,
                     reference: item_location,
-                }),
-            ));
+                }
+            } else {
+                PlaceError::MutatingImmutableElement {
+                    name: place.identifier,
+                    reference: item_location,
+                }
+            };
+            return Err(Error::Element(location, ElementError::Place(error)));
         }
-        if place.r#type != r#type {
+        if place.r#type != r#type && !Self::is_shift(operator) {
             return Err(Error::Element(
                 location,
                 ElementError::Place(PlaceError::MutatingWithDifferentType {
@@ -628,6 +785,14 @@ impl Analyzer {
             ));
         }
 
+        if let (ExpressionOperator::Assignment, true) = (operator, place.elements.is_empty()) {
+            Scope::initialize_variable(self.scope_stack.top(), place.identifier.as_str());
+        } else {
+            Scope::check_variable_initialized(self.scope_stack.top(), place.identifier.as_str())
+                .map_err(|error| Error::Scope(place.location, error))?;
+        }
+        Scope::forget_variable_constant(self.scope_stack.top(), place.identifier.as_str());
+
         self.evaluation_stack
             .push(StackElement::Evaluated(Element::Value(Value::Unit)));
 
@@ -659,6 +824,271 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Checks if the result of the last operation is proven to fit into its type, so its range
+    /// check can be skipped at runtime.
+    ///
+    fn is_result_bounded(&self) -> bool {
+        match self.evaluation_stack.top() {
+            StackElement::Evaluated(Element::Value(Value::Integer(integer))) => {
+                integer.bound.is_some()
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Analyzes the equals or not equals operation, returns the operands type, which defines
+    /// whether the operands are compared as scalars or element by element.
+    ///
+    fn equality<F>(&mut self, callback: F, location: Location) -> Result<Type, Error>
+    where
+        F: FnOnce(Element, Element) -> Result<Element, ElementError>,
+    {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let r#type = match operand_1 {
+            Element::Value(ref value) => value.r#type(),
+            Element::Constant(ref constant) => constant.r#type(),
+            _ => Type::unit(),
+        };
+
+        let result =
+            callback(operand_1, operand_2).map_err(|error| Error::Element(location, error))?;
+        self.evaluation_stack.push(StackElement::Evaluated(result));
+
+        Ok(r#type)
+    }
+
+    ///
+    /// Returns the location where the division or remainder divisor expression starts, that is,
+    /// the location of its leftmost binary operation operand.
+    ///
+    fn divisor_location(tree: &ExpressionTree) -> Location {
+        let mut divisor = match tree.right {
+            Some(ref right) => right,
+            None => return tree.location,
+        };
+        while let (Some(left), Some(_)) = (&divisor.left, &divisor.right) {
+            divisor = left;
+        }
+        divisor.location
+    }
+
+    ///
+    /// Moves the division by zero errors to the divisor location, which is more helpful than
+    /// the operator one if the divisor is a folded constant expression.
+    ///
+    fn divisor_error(error: Error, divisor_location: Location) -> Error {
+        match error {
+            Error::Element(
+                _location,
+                error @ ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ZeroDivision,
+                )),
+            )
+            | Error::Element(
+                _location,
+                error @ ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ZeroRemainder,
+                )),
+            ) => Error::Element(divisor_location, error),
+            error => error,
+        }
+    }
+
+    ///
+    /// Infers the types of the untyped integer literal operands of a binary operation, so `5`
+    /// in `x + 5` gets the type of `x`. If both operands are untyped literals, the narrower one
+    /// gets the type of the wider one.
+    ///
+    /// Is not used for the shift operators, whose second operand type is independent.
+    ///
+    fn infer_literal_types(&mut self, location: Location) -> Result<(), Error> {
+        let operand_2 = self.evaluation_stack.pop();
+        let operand_1 = self.evaluation_stack.pop();
+
+        let type_1 = Self::operand_type(&operand_1);
+        let type_2 = Self::operand_type(&operand_2);
+
+        let (operand_1, operand_2) = match (&operand_1, &operand_2) {
+            (
+                StackElement::Literal(Element::Constant(Constant::Integer(literal_1)), _),
+                StackElement::Literal(Element::Constant(Constant::Integer(literal_2)), _),
+            ) => {
+                if literal_1.bitlength < literal_2.bitlength {
+                    (self.infer_literal(operand_1, type_2, location)?, operand_2)
+                } else {
+                    (operand_1, self.infer_literal(operand_2, type_1, location)?)
+                }
+            }
+            (StackElement::Literal(..), _) => {
+                (self.infer_literal(operand_1, type_2, location)?, operand_2)
+            }
+            (_, StackElement::Literal(..)) => {
+                (operand_1, self.infer_literal(operand_2, type_1, location)?)
+            }
+            _ => (operand_1, operand_2),
+        };
+
+        self.evaluation_stack.push(operand_1);
+        self.evaluation_stack.push(operand_2);
+
+        Ok(())
+    }
+
+    ///
+    /// Gives the untyped integer literal `element` the integer `r#type`, replacing its constant
+    /// in the IR. Other elements are returned as is.
+    ///
+    fn infer_literal(
+        &mut self,
+        element: StackElement,
+        r#type: Option<Type>,
+        location: Location,
+    ) -> Result<StackElement, Error> {
+        match (element, r#type) {
+            (
+                StackElement::Literal(Element::Constant(Constant::Integer(integer)), index),
+                Some(r#type),
+            ) => {
+                let constant = Self::infer_integer(integer, &r#type, location)?;
+
+                if let Some(intermediate) =
+                    GeneratorExpressionConstant::try_from_semantic(&constant)
+                {
+                    self.intermediate
+                        .replace_operand(index, GeneratorExpressionOperand::Constant(intermediate));
+                }
+
+                Ok(StackElement::Evaluated(Element::Constant(constant)))
+            }
+            (element, _) => Ok(element),
+        }
+    }
+
+    ///
+    /// Gives the untyped integer literal `element` the integer `r#type`, rebuilding its IR
+    /// `expression`, which consists of the literal constant only. Other elements are returned
+    /// as is.
+    ///
+    /// Is used for the literals analyzed separately, e.g. array elements and function arguments.
+    ///
+    pub fn infer_literal_expression(
+        element: Element,
+        expression: GeneratorExpression,
+        r#type: &Type,
+        location: Location,
+    ) -> Result<(Element, GeneratorExpression), Error> {
+        match element {
+            Element::Constant(Constant::Integer(integer)) => {
+                let constant = Self::infer_integer(integer, r#type, location)?;
+
+                let mut expression = GeneratorExpression::new();
+                if let Some(intermediate) =
+                    GeneratorExpressionConstant::try_from_semantic(&constant)
+                {
+                    expression.push_operand(GeneratorExpressionOperand::Constant(intermediate));
+                }
+
+                Ok((Element::Constant(constant), expression))
+            }
+            element => Ok((element, expression)),
+        }
+    }
+
+    fn infer_integer(
+        integer: IntegerConstant,
+        r#type: &Type,
+        location: Location,
+    ) -> Result<Constant, Error> {
+        integer
+            .infer(r#type)
+            .map(Constant::Integer)
+            .map_err(|error| {
+                Error::Element(
+                    location,
+                    ElementError::Constant(ConstantError::Integer(error)),
+                )
+            })
+    }
+
+    ///
+    /// Returns the type of the evaluated operand, if it is a value, constant or place.
+    ///
+    fn operand_type(element: &StackElement) -> Option<Type> {
+        match element {
+            StackElement::Evaluated(Element::Value(value)) => Some(value.r#type()),
+            StackElement::Evaluated(Element::Place(place)) => Some(place.r#type.to_owned()),
+            StackElement::Evaluated(Element::Constant(constant))
+            | StackElement::Literal(Element::Constant(constant), _) => Some(constant.r#type()),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Replaces the IR written since `start` with a single constant, if the operation has been
+    /// evaluated to a constant at compile time.
+    ///
+    /// The overflow and other errors are already checked by the constant operations, so
+    /// `200u8 + 100u8` is a compile error rather than a wrapped constant.
+    ///
+    fn fold(&mut self, start: usize) {
+        if let StackElement::Evaluated(Element::Constant(constant)) = self.evaluation_stack.top() {
+            if let Some(constant) = GeneratorExpressionConstant::try_from_semantic(constant) {
+                self.intermediate.fold(start, constant);
+            }
+        }
+    }
+
+    ///
+    /// Analyzes the exponentiation operation.
+    ///
+    /// The exponent is a constant, so it is not written to the IR as an operand, but is
+    /// passed to the generator within the operator.
+    ///
+    fn exponentiation(&mut self, location: Location) -> Result<GeneratorExpressionOperator, Error> {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let exponent = match operand_2 {
+            Element::Constant(Constant::Integer(ref integer)) => integer.value.to_owned(),
+            _ => BigInt::zero(),
+        };
+
+        let result = Element::exponentiate(operand_1, operand_2)
+            .map_err(|error| Error::Element(location, error))?;
+        let (is_signed, bitlength) = match result {
+            Element::Value(Value::Integer(ref integer)) => (integer.is_signed, integer.bitlength),
+            Element::Constant(Constant::Integer(ref integer)) => {
+                (integer.is_signed, integer.bitlength)
+            }
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        self.evaluation_stack.push(StackElement::Evaluated(result));
+
+        Ok(GeneratorExpressionOperator::exponentiation(
+            exponent, is_signed, bitlength,
+        ))
+    }
+
     ///
     /// Analyzes the range operation, returns the range start value as the IR expression operand.
     ///
@@ -715,8 +1145,15 @@ impl Analyzer {
             TranslationHint::Value,
         )?;
 
-        let operator = match operand_2 {
-            Element::Type(ref r#type) => GeneratorExpressionOperator::casting(r#type),
+        let from = match operand_1 {
+            Element::Value(ref value) => Some(value.r#type()),
+            Element::Constant(ref constant) => Some(constant.r#type()),
+            _ => None,
+        };
+        let operator = match (from, &operand_2) {
+            (Some(ref from), Element::Type(ref to)) => {
+                GeneratorExpressionOperator::casting(from, to)
+            }
             _ => None,
         };
 
@@ -753,6 +1190,7 @@ impl Analyzer {
         &mut self,
         location: Location,
         expression: GeneratorExpression,
+        index: Option<Box<ExpressionTree>>,
     ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
@@ -765,6 +1203,10 @@ impl Analyzer {
             TranslationHint::Place,
         )?;
 
+        if let Some(index) = index {
+            self.check_index_range(&operand_1, &operand_2, &index);
+        }
+
         let (result, access) = Element::index(operand_1, operand_2.clone())
             .map_err(|error| Error::Element(location, error))?;
 
@@ -807,6 +1249,83 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Warns if the runtime array index type can hold values greater than or equal to the array
+    /// size, since the index is checked at runtime, which may fail or dominate the constraints.
+    ///
+    fn check_index_range(&self, array: &Element, index: &Element, tree: &ExpressionTree) {
+        let size = match array {
+            Element::Place(_) | Element::Value(_) => {
+                match Type::from_element(array, self.scope_stack.top()) {
+                    Ok(Type::Array { size, .. }) => size,
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+        let (r#type, maximum) = match index {
+            Element::Value(value @ Value::Integer(_)) => {
+                let maximum = match value.r#type() {
+                    Type::IntegerUnsigned { bitlength } => {
+                        (BigInt::one() << bitlength) - BigInt::one()
+                    }
+                    Type::IntegerSigned { bitlength } => {
+                        (BigInt::one() << (bitlength - 1)) - BigInt::one()
+                    }
+                    _ => return,
+                };
+                (value.r#type().to_string(), maximum)
+            }
+            _ => return,
+        };
+        if maximum < BigInt::from(size)
+            || Self::is_index_bounded(self.scope_stack.top(), tree, size)
+        {
+            return;
+        }
+
+        let mut operand = tree;
+        while let Some(ref left) = operand.left {
+            operand = left;
+        }
+        Scope::warn(
+            self.scope_stack.top(),
+            Warning::IndexTypeExceedsArraySize {
+                location: operand.location,
+                r#type,
+                size,
+            },
+        );
+    }
+
+    ///
+    /// Checks if the index expression is known to be within the array `size`, that is, it is
+    /// a loop index, whose values are known at compile time, or it is masked with a literal,
+    /// e.g. `index % 4`.
+    ///
+    /// The bitwise masks are not checked, since the bitwise operators accept only constants.
+    ///
+    fn is_index_bounded(scope: Rc<RefCell<Scope>>, tree: &ExpressionTree, size: usize) -> bool {
+        let mask = match tree.right.as_ref().map(|right| right.value.as_ref()) {
+            Some(ExpressionTreeNode::Operand(ExpressionOperand::LiteralInteger(literal))) => {
+                IntegerConstant::try_from(literal)
+                    .ok()
+                    .map(|integer| integer.value)
+            }
+            _ => None,
+        };
+
+        match (tree.value.as_ref(), mask) {
+            (ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)), _) => {
+                Scope::is_loop_index(scope, identifier.name.as_str())
+            }
+            (ExpressionTreeNode::Operator(ExpressionOperator::Remainder), Some(mask)) => {
+                mask <= BigInt::from(size)
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Analyzes the tuple or structure field access operation.
     ///
@@ -842,6 +1361,62 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Analyzes the function call argument list.
+    ///
+    /// The untyped integer literal arguments of user-defined functions get the types of the
+    /// corresponding formal parameters.
+    ///
+    fn arguments(&mut self, right: Option<Box<ExpressionTree>>) -> Result<(), Error> {
+        let list = match right.map(|right| *right.value) {
+            Some(ExpressionTreeNode::Operand(ExpressionOperand::List(list))) => list,
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+
+        self.argument_locations = list
+            .elements
+            .iter()
+            .map(|expression| expression.location)
+            .collect();
+
+        let parameter_types = self.formal_parameter_types();
+        let (element, intermediate) =
+            ListAnalyzer::analyze(self.scope_stack.top(), list, parameter_types.as_slice())?;
+
+        self.evaluation_stack.push(StackElement::Evaluated(element));
+        self.intermediate.push_operand(intermediate);
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the formal parameter types of the user-defined function at the top of the
+    /// evaluation stack. The resolution errors are reported by the call analyzer later.
+    ///
+    fn formal_parameter_types(&self) -> Vec<Type> {
+        let function = match self.evaluation_stack.top() {
+            StackElement::Evaluated(Element::Type(Type::Function(function))) => {
+                Some(function.to_owned())
+            }
+            StackElement::Evaluated(Element::Path(path)) => {
+                match Scope::resolve_path(self.scope_stack.top(), path).map(|item| item.variant) {
+                    Ok(ScopeItemVariant::Type(Type::Function(function))) => Some(function),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match function {
+            Some(FunctionType::UserDefined(function)) => function
+                .formal_params()
+                .iter()
+                .map(|(_name, r#type)| r#type.to_owned())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     ///
     /// Analyzes the function call operation.
     ///
@@ -866,6 +1441,7 @@ impl Analyzer {
             operand_2,
             is_call_builtin,
             location,
+            std::mem::take(&mut self.argument_locations),
         )?;
 
         self.evaluation_stack.push(StackElement::Evaluated(element));
@@ -908,10 +1484,14 @@ impl Analyzer {
                 ExpressionOperand::Unit => Ok((Element::Constant(Constant::Unit), None)),
                 ExpressionOperand::LiteralBoolean(inner) => LiteralAnalyzer::boolean(inner),
                 ExpressionOperand::LiteralInteger(inner) => LiteralAnalyzer::integer(inner),
+                ExpressionOperand::LiteralChar(inner) => LiteralAnalyzer::char(inner),
                 ExpressionOperand::LiteralString(inner) => {
                     Ok((LiteralAnalyzer::string(inner)?, None))
                 }
                 ExpressionOperand::TupleIndex(inner) => Ok((MemberAnalyzer::integer(inner)?, None)),
+                ExpressionOperand::TupleIndexExpression(inner) => {
+                    Ok((MemberAnalyzer::expression(scope, inner)?, None))
+                }
                 ExpressionOperand::Identifier(inner) => {
                     IdentifierAnalyzer::analyze(scope, inner, hint)
                 }
@@ -922,7 +1502,7 @@ impl Analyzer {
                     .map(|(element, intermediate)| (element, Some(intermediate))),
                 ExpressionOperand::Structure(inner) => StructureAnalyzer::analyze(scope, inner)
                     .map(|(element, intermediate)| (element, Some(intermediate))),
-                ExpressionOperand::List(inner) => ListAnalyzer::analyze(scope, inner)
+                ExpressionOperand::List(inner) => ListAnalyzer::analyze(scope, inner, &[])
                     .map(|(element, intermediate)| (element, Some(intermediate))),
                 ExpressionOperand::Block(inner) => {
                     BlockAnalyzer::analyze(scope, inner).map(|(element, intermediate)| {
@@ -939,9 +1519,10 @@ impl Analyzer {
             },
             StackElement::Evaluated(inner) => match inner {
                 Element::Path(path) => PathTranslator::translate(scope, path, hint),
-                Element::Place(place) => PlaceTranslator::translate(place, hint),
+                Element::Place(place) => PlaceTranslator::translate(scope, place, hint),
                 element => Ok((element, None)),
             },
+            StackElement::Literal(element, _index) => Ok((element, None)),
         }
     }
 }
@@ -6,13 +6,18 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use crate::generator::expression::operand::array::builder::Builder as GeneratorArrayExpressionBuilder;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::element::constant::array::Array as ConstantArray;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::value::array::Array as ValueArray;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
@@ -46,12 +51,18 @@ impl Translator {
                     )),
                     None,
                 )),
+                ScopeItemVariant::Constant(Constant::Array(array)) => {
+                    Ok(Self::constant_array(array))
+                }
                 ScopeItemVariant::Constant(constant) => Ok((Element::Constant(constant), None)),
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
             },
-            TranslationHint::Value => match Scope::resolve_path(scope, &path)?.variant {
+            TranslationHint::Value => match Scope::resolve_path(scope.clone(), &path)?.variant {
                 ScopeItemVariant::Variable(variable) => {
+                    Scope::check_variable_initialized(scope, path_last_element_name.as_str())
+                        .map_err(|error| Error::Scope(location, error))?;
+
                     let value = Value::try_from(&variable.r#type)
                         .map_err(ElementError::Value)
                         .map_err(|error| Error::Element(location, error))?;
@@ -70,6 +81,9 @@ impl Translator {
                     let element = Element::Value(value);
                     Ok((element, intermediate))
                 }
+                ScopeItemVariant::Constant(Constant::Array(array)) => {
+                    Ok(Self::constant_array(array))
+                }
                 ScopeItemVariant::Constant(constant) => {
                     let intermediate = GeneratorConstant::try_from_semantic(&constant)
                         .map(GeneratorExpressionOperand::Constant);
@@ -80,6 +94,23 @@ impl Translator {
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
             },
 
+            TranslationHint::Constant => {
+                let constant = match path.elements.len() {
+                    1 => Scope::variable_constant(scope.clone(), path_last_element_name.as_str()),
+                    _ => None,
+                };
+
+                match constant {
+                    Some(constant) => {
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant);
+                        let element = Element::Constant(constant);
+                        Ok((element, intermediate))
+                    }
+                    None => Self::translate(scope, path, TranslationHint::Value),
+                }
+            }
+
             TranslationHint::Type => match Scope::resolve_path(scope, &path)?.variant {
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 _ => Ok((Element::Path(path), None)),
@@ -91,4 +122,26 @@ impl Translator {
             )),
         }
     }
+
+    ///
+    /// Translates the constant array to an array value with known elements.
+    ///
+    /// Constant arrays are written to the bytecode as array literals, so they can be indexed and
+    /// passed anywhere an ordinary array is expected.
+    ///
+    fn constant_array(array: ConstantArray) -> (Element, Option<GeneratorExpressionOperand>) {
+        let mut builder = GeneratorArrayExpressionBuilder::default();
+        for constant in array.values().iter() {
+            let mut expression = GeneratorExpression::new();
+            if let Some(constant) = GeneratorConstant::try_from_semantic(constant) {
+                expression.push_operand(GeneratorExpressionOperand::Constant(constant));
+            }
+            builder.push_expression(expression);
+        }
+
+        let intermediate = GeneratorExpressionOperand::Array(builder.finish());
+        let element = Element::Value(Value::Array(ValueArray::from(array)));
+
+        (element, Some(intermediate))
+    }
 }
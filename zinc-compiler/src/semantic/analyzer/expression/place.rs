@@ -2,7 +2,9 @@
 //! The place expression translator.
 //!
 
+use std::cell::RefCell;
 use std::convert::TryFrom;
+use std::rc::Rc;
 
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
@@ -11,6 +13,7 @@ use crate::semantic::element::place::Place;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
 
 pub struct Translator {}
 
@@ -19,11 +22,15 @@ impl Translator {
     /// Translates the place expression to a semantic expression type specified in `hint`.
     ///
     pub fn translate(
+        scope: Rc<RefCell<Scope>>,
         place: Place,
         hint: TranslationHint,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
         match hint {
-            TranslationHint::Value => {
+            TranslationHint::Value | TranslationHint::Constant => {
+                Scope::check_variable_initialized(scope, place.identifier.as_str())
+                    .map_err(|error| Error::Scope(place.location, error))?;
+
                 let element = Value::try_from(&place.r#type)
                     .map(Element::Value)
                     .map_err(ElementError::Value)
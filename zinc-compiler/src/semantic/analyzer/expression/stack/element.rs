@@ -9,4 +9,7 @@ use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionO
 pub enum Element {
     NotEvaluated(ExpressionOperand),
     Evaluated(SemanticElement),
+    /// The untyped integer literal and the index of its constant in the IR, which is replaced
+    /// if the literal type is inferred from the other operand.
+    Literal(SemanticElement, usize),
 }
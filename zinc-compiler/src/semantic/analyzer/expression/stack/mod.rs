@@ -30,6 +30,12 @@ impl Stack {
         self.elements.push(element);
     }
 
+    pub fn top(&self) -> &Element {
+        self.elements
+            .last()
+            .expect(PANIC_THERE_MUST_ALWAYS_BE_AN_OPERAND)
+    }
+
     pub fn pop(&mut self) -> Element {
         self.elements
             .pop()
@@ -53,14 +53,23 @@ impl Analyzer {
         };
         let mut result = Structure::new(structure_type);
 
+        let mut fields = Vec::with_capacity(structure.fields.len());
         for (identifier, expression) in structure.fields.into_iter() {
             let identifier_location = identifier.location;
 
             let (element, expression) = ExpressionAnalyzer::new(scope.clone())
                 .analyze(expression, TranslationHint::Value)?;
+            if let Element::Constant(ref constant) = element {
+                if constant.is_range() {
+                    return Err(Error::RangeUsedAsValue {
+                        location: identifier_location,
+                        found: constant.to_string(),
+                    });
+                }
+            }
             let element_type = Type::from_element(&element, scope.clone())?;
-            result
-                .push(identifier.name, element_type.clone())
+            let position = result
+                .set(identifier.name, element_type.clone())
                 .map_err(|error| {
                     Error::Element(
                         identifier_location,
@@ -68,6 +77,17 @@ impl Analyzer {
                     )
                 })?;
 
+            fields.push((position, element_type, expression));
+        }
+        result.validate().map_err(|error| {
+            Error::Element(
+                identifier_location,
+                ElementError::Value(ValueError::Structure(error)),
+            )
+        })?;
+
+        fields.sort_by_key(|(position, _type, _expression)| *position);
+        for (_position, element_type, expression) in fields.into_iter() {
             builder.push_expression(element_type, expression);
         }
 
@@ -6,12 +6,20 @@
 
 use std::convert::TryFrom;
 
+use num_bigint::BigInt;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::range::Range as RangeConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
+use crate::warning::Warning;
 
 #[test]
 fn error_const_expression_has_non_const_element() {
@@ -37,3 +45,393 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_function_recursion_direct() {
+    let input = r#"
+fn factorial(n: u8) -> u8 {
+    if false {
+        factorial(n - 1)
+    } else {
+        1
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionRecursion {
+        location: Location::new(4, 18),
+        cycle: "factorial -> factorial".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_recursion_mutual() {
+    let input = r#"
+fn outer(n: u8) -> u8 {
+    fn inner(n: u8) -> u8 {
+        outer(n)
+    }
+
+    inner(n)
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionRecursion {
+        location: Location::new(7, 10),
+        cycle: "outer -> outer::inner -> outer".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_range_used_as_value_argument() {
+    let input = r#"
+fn sum(value: u8) -> u8 {
+    value
+}
+
+fn main() {
+    let result = sum(0..10);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsValue {
+        location: Location::new(7, 21),
+        found: Constant::Range(RangeConstant::new(
+            BigInt::from(0),
+            BigInt::from(10),
+            false,
+            crate::BITLENGTH_BYTE,
+        ))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_range_used_as_value_structure_field() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+fn main() {
+    let data = Data { value: 0..10 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsValue {
+        location: Location::new(7, 23),
+        found: Constant::Range(RangeConstant::new(
+            BigInt::from(0),
+            BigInt::from(10),
+            false,
+            crate::BITLENGTH_BYTE,
+        ))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_range_used_as_value_tuple_element() {
+    let input = r#"
+fn main() {
+    let tuple = (0..10, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsValue {
+        location: Location::new(3, 19),
+        found: Constant::Range(RangeConstant::new(
+            BigInt::from(0),
+            BigInt::from(10),
+            false,
+            crate::BITLENGTH_BYTE,
+        ))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_literal_inference_binary() {
+    let input = r#"
+fn main() {
+    let x: u64 = 3;
+    let y = x + 5;
+    let z = 1000 > y;
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_literal_inference_argument() {
+    let input = r#"
+fn double(value: u64) -> u64 {
+    value * 2
+}
+
+fn main() {
+    let result = double(1000);
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_literal_inference_array() {
+    let input = r#"
+fn main() {
+    let x: u16 = 1000;
+    let array = [1, x, 300];
+    let sum = array[0] + x;
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_literal_inference_assignment() {
+    let input = r#"
+fn main() {
+    let mut sum: u64 = 0;
+    sum = 1;
+    sum += 1;
+    sum -= 1;
+    sum *= 1000;
+    sum /= 1000;
+    sum %= 1000;
+    sum |= 1;
+    sum ^= 1;
+    sum &= 1;
+    sum <<= 1;
+    sum >>= 1;
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_literal_inference_assignment_signed() {
+    let input = r#"
+fn main() {
+    let mut value: i16 = 0;
+    value = 1000;
+    value -= 2000;
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_inference_assignment() {
+    let input = r#"
+fn main() {
+    let mut value: u8 = 0;
+    value += 300;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 14),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowInference {
+                value: BigInt::from(300),
+                r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_inference_binary() {
+    let input = r#"
+fn main() {
+    let x: u8 = 1;
+    let y = x + 300;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 15),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowInference {
+                value: BigInt::from(300),
+                r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_inference_argument() {
+    let input = r#"
+fn id(value: i8) -> i8 {
+    value
+}
+
+fn main() {
+    let result = id(200);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 21),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowInference {
+                value: BigInt::from(200),
+                r#type: Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_folding() {
+    let input = r#"
+fn main() {
+    let value = 200u8 + 100u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 23),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowAddition {
+                value: BigInt::from(300),
+                r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_index_type_exceeds_array_size() {
+    let input = r#"
+fn main(array: [u8; 4], index: u16) -> u8 {
+    array[index]
+}
+"#;
+
+    let expected = Ok(vec![Warning::IndexTypeExceedsArraySize {
+        location: Location::new(3, 11),
+        r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        size: 4,
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_index_type_within_array_size() {
+    let input = r#"
+fn main(array: [u8; 256], index: u8) -> u8 {
+    array[index]
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_index_loop() {
+    let input = r#"
+fn main(array: [u8; 4]) -> u8 {
+    let mut sum = 0;
+    for i in 0..4 {
+        sum += array[i];
+    }
+    sum
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_index_masked() {
+    let input = r#"
+fn main(array: [u8; 4], index: u16) -> u8 {
+    array[index % 4] + array[(index + 1) % 4]
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
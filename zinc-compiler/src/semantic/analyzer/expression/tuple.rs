@@ -33,8 +33,18 @@ impl Analyzer {
         let mut builder = GeneratorGroupExpressionBuilder::default();
 
         for expression in tuple.elements.into_iter() {
+            let location = expression.location;
+
             let (element, expression) = ExpressionAnalyzer::new(scope.clone())
                 .analyze(expression, TranslationHint::Value)?;
+            if let Element::Constant(ref constant) = element {
+                if constant.is_range() {
+                    return Err(Error::RangeUsedAsValue {
+                        location,
+                        found: constant.to_string(),
+                    });
+                }
+            }
             let element_type = Type::from_element(&element, scope.clone())?;
             result.push(element_type.clone());
 
@@ -12,6 +12,7 @@ use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes a module, which are located in non-`main.zn` files.
@@ -35,7 +36,20 @@ impl Analyzer {
         }
     }
 
-    pub fn compile(self, program: SyntaxTree) -> Result<(Rc<RefCell<Scope>>, Tree), CompilerError> {
+    ///
+    /// Sets the maximal number of loop iterations, including the ones of the enclosing loops.
+    ///
+    pub fn set_loop_iterations_limit(&mut self, limit: usize) {
+        Scope::set_loop_iterations_limit(self.scope_stack.top(), limit);
+    }
+
+    ///
+    /// Analyzes the module `program`, returning its scope, the IR tree and the warnings.
+    ///
+    pub fn compile(
+        self,
+        program: SyntaxTree,
+    ) -> Result<(Rc<RefCell<Scope>>, Tree, Vec<Warning>), CompilerError> {
         let mut intermediate = Tree::new();
 
         let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), HashMap::new());
@@ -48,6 +62,9 @@ impl Analyzer {
             }
         }
 
-        Ok((self.scope_stack.top(), intermediate))
+        analyzer.check_unused_imports();
+        let warnings = Scope::take_warnings(self.scope_stack.top());
+
+        Ok((self.scope_stack.top(), intermediate, warnings))
     }
 }
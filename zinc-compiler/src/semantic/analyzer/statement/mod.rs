@@ -9,15 +9,23 @@ use std::cmp;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+use num_traits::One;
 use num_traits::ToPrimitive;
 
+use crate::generator::expression::operand::array::builder::Builder as GeneratorArrayExpressionBuilder;
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::statement::declaration::Statement as GeneratorDeclarationStatement;
+use crate::generator::statement::destructuring::Statement as GeneratorDestructuringStatement;
 use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
 use crate::generator::statement::loop_for::Statement as GeneratorForLoopStatement;
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::lexical::token::location::Location;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::casting::Caster;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::constant::Constant;
@@ -29,13 +37,18 @@ use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::r#type::INDEX as TYPE_INDEX;
+use crate::semantic::element::value::array::Array as ValueArray;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
+use crate::syntax::tree::r#type::Type as SyntaxType;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
@@ -49,6 +62,17 @@ use crate::syntax::tree::statement::r#let::Statement as LetStatement;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
 use crate::syntax::tree::statement::r#type::Statement as TypeStatement;
 use crate::syntax::tree::statement::r#use::Statement as UseStatement;
+use crate::warning::Warning;
+
+///
+/// The for-loop attribute, which forces the loop to be unrolled at compile time.
+///
+const ATTRIBUTE_UNROLL: &str = "unroll";
+
+///
+/// The for-loop attribute, which keeps the loop as the Zinc VM loop. This is the default.
+///
+const ATTRIBUTE_NO_UNROLL: &str = "no_unroll";
 
 ///
 /// Analyzes statements.
@@ -59,6 +83,7 @@ use crate::syntax::tree::statement::r#use::Statement as UseStatement;
 pub struct Analyzer {
     scope_stack: ScopeStack,
     dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    imports: Vec<(String, Location, String)>,
 }
 
 impl Analyzer {
@@ -69,6 +94,26 @@ impl Analyzer {
         Self {
             scope_stack: ScopeStack::new(scope),
             dependencies,
+            imports: Vec::new(),
+        }
+    }
+
+    ///
+    /// Warns about the items imported with `use`, which have never been used.
+    ///
+    /// Must be called after all the module statements have been analyzed.
+    ///
+    pub fn check_unused_imports(&self) {
+        for (name, location, path) in self.imports.iter() {
+            if !Scope::is_item_used(self.scope_stack.top(), name.as_str()) {
+                Scope::warn(
+                    self.scope_stack.top(),
+                    Warning::UnusedImport {
+                        location: *location,
+                        path: path.to_owned(),
+                    },
+                );
+            }
         }
     }
 
@@ -128,9 +173,7 @@ impl Analyzer {
         statement: FunctionLocalStatement,
     ) -> Result<Option<GeneratorStatement>, Error> {
         match statement {
-            FunctionLocalStatement::Let(statement) => {
-                Ok(self.r#let(statement)?.map(GeneratorStatement::Declaration))
-            }
+            FunctionLocalStatement::Let(statement) => self.r#let(statement),
             FunctionLocalStatement::Const(statement) => {
                 self.r#const(statement)?;
                 Ok(None)
@@ -138,9 +181,20 @@ impl Analyzer {
             FunctionLocalStatement::For(statement) => {
                 Ok(Some(GeneratorStatement::Loop(self.r#for(statement)?)))
             }
+            FunctionLocalStatement::Fn(statement) => {
+                let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
+                Ok(Some(intermediate))
+            }
             FunctionLocalStatement::Expression(expression) => {
-                let (_result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
+                let mut leftmost = &expression;
+                while let Some(ref left) = leftmost.left {
+                    leftmost = left;
+                }
+                let location = leftmost.location;
+
+                let (result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
                     .analyze(expression, TranslationHint::Value)?;
+                Self::check_result_discarded(&result, location)?;
                 let intermediate = GeneratorStatement::Expression(expression);
                 Ok(Some(intermediate))
             }
@@ -148,6 +202,28 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Checks that the expression statement `result` is a unit, since other values would be left
+    /// on the evaluation stack. The value must be explicitly discarded with `let _ = ...;`.
+    ///
+    fn check_result_discarded(result: &Element, location: Location) -> Result<(), Error> {
+        let r#type = match result {
+            Element::Value(value) => value.r#type(),
+            Element::Constant(constant) => constant.r#type(),
+            Element::Place(place) => place.r#type.to_owned(),
+            _ => return Ok(()),
+        };
+
+        if r#type != Type::Unit {
+            return Err(Error::StatementResultDiscarded {
+                location,
+                found: r#type.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     ///
     /// Analyzes a statement local to an implementation.
     ///
@@ -176,31 +252,55 @@ impl Analyzer {
     fn r#fn(&mut self, statement: FnStatement) -> Result<GeneratorFunctionStatement, Error> {
         let location = statement.location;
 
+        let enclosing_function = Scope::function_name(self.scope_stack.top());
+        let is_main = enclosing_function.is_none()
+            && statement.identifier.name.as_str()
+                == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+
         let mut arguments = Vec::with_capacity(statement.argument_bindings.len());
         for argument_binding in statement.argument_bindings.iter() {
             let identifier = match argument_binding.variant {
                 BindingPatternVariant::Binding(ref identifier) => identifier,
                 BindingPatternVariant::MutableBinding(ref identifier) => identifier,
                 BindingPatternVariant::Wildcard => continue,
+                BindingPatternVariant::Structure(_) => {
+                    panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS)
+                }
             };
-            arguments.push((
-                identifier.name.clone(),
-                Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?,
-            ));
+            let r#type =
+                Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?;
+            if is_main && !r#type.is_representable() {
+                return Err(Error::EntryPointArgumentInvalidType {
+                    location: argument_binding.r#type.location,
+                    name: identifier.name.clone(),
+                    found: r#type.to_string(),
+                });
+            }
+            arguments.push((identifier.name.clone(), r#type));
         }
         let expected_type = match statement.return_type {
-            Some(ref r#type) => Type::from_type_variant(&r#type.variant, self.scope_stack.top())?,
-            None => Type::unit(),
+            Some(ref r#type) => Some(Type::from_type_variant(
+                &r#type.variant,
+                self.scope_stack.top(),
+            )?),
+            None => None,
         };
 
         let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        let function_type = UserDefinedFunctionType::new(
-            statement.identifier.name.clone(),
-            unique_id,
-            arguments.clone(),
-            expected_type.clone(),
-        );
-        let r#type = Type::Function(FunctionType::UserDefined(function_type));
+        let mut function_type = match expected_type {
+            Some(ref expected_type) => UserDefinedFunctionType::new(
+                statement.identifier.name.clone(),
+                unique_id,
+                arguments.clone(),
+                expected_type.clone(),
+            ),
+            None => UserDefinedFunctionType::new_inferred(
+                statement.identifier.name.clone(),
+                unique_id,
+                arguments.clone(),
+            ),
+        };
+        let r#type = Type::Function(FunctionType::UserDefined(function_type.clone()));
 
         TYPE_INDEX
             .write()
@@ -209,12 +309,27 @@ impl Analyzer {
         Scope::declare_type(self.scope_stack.top(), statement.identifier.clone(), r#type)
             .map_err(|error| Error::Scope(location, error))?;
 
-        self.scope_stack.push();
+        let identifier = match enclosing_function {
+            Some(ref enclosing_function) => {
+                format!("{}::{}", enclosing_function, statement.identifier.name)
+            }
+            None => statement.identifier.name.clone(),
+        };
+
+        self.scope_stack.push_scope(Scope::new_function(
+            self.scope_stack.top(),
+            identifier.clone(),
+            unique_id,
+            statement.identifier.location,
+        ));
         for argument_binding in statement.argument_bindings.into_iter() {
             let (identifier, is_mutable) = match argument_binding.variant {
                 BindingPatternVariant::Binding(identifier) => (identifier, false),
                 BindingPatternVariant::MutableBinding(identifier) => (identifier, true),
                 BindingPatternVariant::Wildcard => continue,
+                BindingPatternVariant::Structure(_) => {
+                    panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS)
+                }
             };
             let identifier_location = identifier.location;
             let r#type =
@@ -242,34 +357,63 @@ impl Analyzer {
                 .map(|statement| statement.location())
                 .unwrap_or(statement.location),
         };
+        let end_location = statement.body.end_location;
         let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.body)?;
+        Scope::snapshot(self.scope_stack.top(), Some((location, end_location)));
         self.scope_stack.pop();
 
         let result_type = Type::from_element(&result, self.scope_stack.top())?;
-        if expected_type != result_type {
-            return Err(Error::Element(
-                return_expression_location,
-                ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
-                    statement.identifier.name.clone(),
-                    expected_type.to_string(),
-                    result_type.to_string(),
-                    statement
-                        .return_type
-                        .map(|r#type| r#type.location)
-                        .unwrap_or(statement.location),
-                ))),
-            ));
-        }
+        let return_type = match expected_type {
+            Some(expected_type) => {
+                if expected_type != result_type {
+                    return Err(Error::Element(
+                        return_expression_location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+                            statement.identifier.name.clone(),
+                            expected_type.to_string(),
+                            result_type.to_string(),
+                            statement
+                                .return_type
+                                .map(|r#type| r#type.location)
+                                .unwrap_or(statement.location),
+                        ))),
+                    ));
+                }
+                expected_type
+            }
+            None => {
+                function_type.set_return_type(result_type.clone());
+                let r#type = Type::Function(FunctionType::UserDefined(function_type));
 
-        let is_main = statement.identifier.name.as_str()
-            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+                TYPE_INDEX
+                    .write()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .insert(unique_id, r#type.to_string());
+                Scope::update_type(
+                    self.scope_stack.top(),
+                    statement.identifier.name.as_str(),
+                    r#type,
+                );
+                result_type
+            }
+        };
+
+        if is_main && !return_type.is_representable() {
+            return Err(Error::EntryPointReturnTypeInvalid {
+                location: statement
+                    .return_type
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.identifier.location),
+                found: return_type.to_string(),
+            });
+        }
 
         Ok(GeneratorFunctionStatement::new(
             location,
-            statement.identifier.name,
+            identifier,
             arguments,
             body,
-            expected_type,
+            return_type,
             unique_id,
             is_main,
         ))
@@ -312,39 +456,295 @@ impl Analyzer {
     ///
     /// Analyzes a variable declaration statement and returns its IR for the next compiler phase.
     ///
-    fn r#let(
-        &mut self,
-        statement: LetStatement,
-    ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
+    fn r#let(&mut self, statement: LetStatement) -> Result<Option<GeneratorStatement>, Error> {
         let location = statement.location;
 
-        let (element, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
-            .analyze(statement.expression, TranslationHint::Value)?;
+        let expression = match statement.expression {
+            Some(expression) => expression,
+            None => {
+                return self.let_uninitialized(location, statement.binding, statement.r#type);
+            }
+        };
+
+        let expression_location = expression.location;
+        let is_untyped_literal = expression.is_untyped_integer_literal();
+        let (element, expression) = match statement.r#type {
+            Some(ref r#type) if expression.is_empty_array_literal() => {
+                self.let_empty_array(r#type)?
+            }
+            _ => ExpressionAnalyzer::new(self.scope_stack.top())
+                .analyze(expression, TranslationHint::Value)?,
+        };
+
+        if let (Element::Constant(constant), None) = (&element, &statement.r#type) {
+            if constant.is_range() {
+                return self.let_range(location, statement.binding, constant.to_owned());
+            }
+        }
 
-        let r#type = if let Some(r#type) = statement.r#type {
+        let (r#type, element, expression) = if let Some(r#type) = statement.r#type {
             let type_location = r#type.location;
             let r#type = Type::from_type_variant(&r#type.variant, self.scope_stack.top())?;
-            element
-                .cast(Element::Type(r#type.clone()))
+            let (element, expression) = if is_untyped_literal {
+                Self::let_literal(element, expression, &r#type, expression_location)?
+            } else {
+                (element, expression)
+            };
+            let element = element
+                .cast_implicit(Element::Type(r#type.clone()))
                 .map_err(|error| Error::Element(type_location, error))?;
-            r#type
+            (r#type, element, expression)
         } else {
-            Type::from_element(&element, self.scope_stack.top())?
+            (
+                Type::from_element(&element, self.scope_stack.top())?,
+                element,
+                expression,
+            )
+        };
+
+        let (identifier, is_mutable) = match statement.binding {
+            BindingPatternVariant::Binding(identifier) => (identifier, false),
+            BindingPatternVariant::MutableBinding(identifier) => (identifier, true),
+            BindingPatternVariant::Structure(pattern) => {
+                let mut bindings = Vec::new();
+                self.structure_pattern(pattern, r#type.clone(), 0, &mut bindings)?;
+
+                return Ok(GeneratorDestructuringStatement::new(
+                    location, r#type, expression, bindings,
+                )
+                .map(GeneratorStatement::Destructuring));
+            }
+            BindingPatternVariant::Wildcard => {
+                return Ok(GeneratorDestructuringStatement::new(
+                    location,
+                    r#type,
+                    expression,
+                    Vec::new(),
+                )
+                .map(GeneratorStatement::Destructuring));
+            }
+        };
+
+        let variable = match element {
+            Element::Constant(constant @ Constant::Boolean(_))
+            | Element::Constant(constant @ Constant::Integer(_)) => {
+                ScopeVariableItem::new_constant(is_mutable, constant)
+            }
+            _ => ScopeVariableItem::new(is_mutable, r#type.clone()),
+        };
+        Scope::declare_variable(self.scope_stack.top(), identifier.clone(), variable)
+            .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(
+            GeneratorDeclarationStatement::new(location, identifier.name, r#type, Some(expression))
+                .map(GeneratorStatement::Declaration),
+        )
+    }
+
+    ///
+    /// Gives the empty array literal the element type of the annotated array type, since it
+    /// cannot be inferred from the elements, e.g. `let array: [u8; 0] = [];`.
+    ///
+    fn let_empty_array(
+        &self,
+        r#type: &SyntaxType,
+    ) -> Result<(Element, GeneratorExpression), Error> {
+        let array = match Type::from_type_variant(&r#type.variant, self.scope_stack.top())? {
+            Type::Array { r#type, .. } => ValueArray::new(*r#type, 0),
+            _ => ValueArray::default(),
+        };
+
+        let mut expression = GeneratorExpression::new();
+        expression.push_operand(GeneratorExpressionOperand::Array(
+            GeneratorArrayExpressionBuilder::default().finish(),
+        ));
+
+        Ok((Element::Value(Value::Array(array)), expression))
+    }
+
+    ///
+    /// Gives the untyped integer literal the annotated `r#type`, so the literal is checked to be
+    /// within the type range, e.g. `-128..=127` for `i8`.
+    ///
+    fn let_literal(
+        element: Element,
+        expression: GeneratorExpression,
+        r#type: &Type,
+        location: Location,
+    ) -> Result<(Element, GeneratorExpression), Error> {
+        ExpressionAnalyzer::infer_literal_expression(element, expression, r#type, location).map_err(
+            |error| match error {
+                Error::Element(
+                    location,
+                    ElementError::Constant(ConstantError::Integer(
+                        IntegerConstantError::OverflowInference { value, r#type },
+                    )),
+                ) => Error::IntegerLiteralOutOfRange {
+                    location,
+                    value,
+                    r#type,
+                },
+                error => error,
+            },
+        )
+    }
+
+    ///
+    /// Analyzes a range binding.
+    ///
+    /// Ranges exist only at compile time, so the binding is declared as a constant, which can be
+    /// used as a loop bound, and is not passed to the next compiler phase.
+    ///
+    fn let_range(
+        &mut self,
+        location: Location,
+        binding: BindingPatternVariant,
+        constant: Constant,
+    ) -> Result<Option<GeneratorStatement>, Error> {
+        let identifier = match binding {
+            BindingPatternVariant::Binding(identifier) => identifier,
+            _ => {
+                return Err(Error::RangeUsedAsValue {
+                    location,
+                    found: constant.to_string(),
+                })
+            }
+        };
+
+        Scope::declare_constant(self.scope_stack.top(), identifier, constant)
+            .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(None)
+    }
+
+    ///
+    /// Analyzes a mutable variable declaration without a value.
+    ///
+    /// The variable is declared uninitialized, so it must be assigned on every control path
+    /// before it is read.
+    ///
+    fn let_uninitialized(
+        &mut self,
+        location: Location,
+        binding: BindingPatternVariant,
+        r#type: Option<SyntaxType>,
+    ) -> Result<Option<GeneratorStatement>, Error> {
+        let identifier = match binding {
+            BindingPatternVariant::MutableBinding(identifier) => identifier,
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+        let r#type = match r#type {
+            Some(r#type) => Type::from_type_variant(&r#type.variant, self.scope_stack.top())?,
+            None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
 
         Scope::declare_variable(
             self.scope_stack.top(),
-            statement.identifier.clone(),
-            ScopeVariableItem::new(statement.is_mutable, r#type.clone()),
+            identifier.clone(),
+            ScopeVariableItem::new_uninitialized(r#type.clone()),
         )
         .map_err(|error| Error::Scope(location, error))?;
 
-        Ok(GeneratorDeclarationStatement::new(
-            location,
-            statement.identifier.name,
-            r#type,
-            expression,
-        ))
+        Ok(
+            GeneratorDeclarationStatement::new(location, identifier.name, r#type, None)
+                .map(GeneratorStatement::Declaration),
+        )
+    }
+
+    ///
+    /// Checks the structure pattern against the destructured value type and declares the bound
+    /// variables.
+    ///
+    /// The bound variable names, types, and offsets within the destructured value are written
+    /// to `bindings`. Nested patterns are checked recursively with the offset of their field.
+    ///
+    fn structure_pattern(
+        &mut self,
+        pattern: StructurePattern,
+        r#type: Type,
+        offset: usize,
+        bindings: &mut Vec<(String, Type, usize)>,
+    ) -> Result<(), Error> {
+        let structure = match Scope::resolve_item(self.scope_stack.top(), &pattern.identifier.name)
+            .map_err(|error| Error::Scope(pattern.identifier.location, error))?
+            .variant
+        {
+            ScopeItemVariant::Type(Type::Structure(ref structure))
+                if Type::Structure(structure.to_owned()) == r#type =>
+            {
+                structure.to_owned()
+            }
+            _ => {
+                return Err(Error::LetPatternInvalidType {
+                    location: pattern.location,
+                    expected: pattern.identifier.name,
+                    found: r#type.to_string(),
+                })
+            }
+        };
+
+        let mut is_field_bound = vec![false; structure.fields.len()];
+        for field in pattern.fields.into_iter() {
+            let index = match structure
+                .fields
+                .iter()
+                .position(|(name, _type)| name == &field.identifier.name)
+            {
+                Some(index) => index,
+                None => {
+                    let suggestion = zinc_utils::suggestion::suggest(
+                        field.identifier.name.as_str(),
+                        structure.fields.iter().map(|(name, _type)| name.as_str()),
+                    )
+                    .map(str::to_owned);
+
+                    return Err(Error::LetPatternFieldDoesNotExist {
+                        location: field.identifier.location,
+                        type_identifier: structure.identifier.to_owned(),
+                        field_name: field.identifier.name,
+                        suggestion,
+                    });
+                }
+            };
+            is_field_bound[index] = true;
+
+            let field_type = structure.fields[index].1.to_owned();
+            let field_offset = offset
+                + structure.fields[..index]
+                    .iter()
+                    .map(|(_name, r#type)| r#type.size())
+                    .sum::<usize>();
+
+            let (identifier, is_mutable) = match field.binding {
+                BindingPatternVariant::Binding(identifier) => (identifier, false),
+                BindingPatternVariant::MutableBinding(identifier) => (identifier, true),
+                BindingPatternVariant::Wildcard => continue,
+                BindingPatternVariant::Structure(pattern) => {
+                    self.structure_pattern(pattern, field_type, field_offset, bindings)?;
+                    continue;
+                }
+            };
+
+            Scope::declare_variable(
+                self.scope_stack.top(),
+                identifier.clone(),
+                ScopeVariableItem::new(is_mutable, field_type.clone()),
+            )
+            .map_err(|error| Error::Scope(identifier.location, error))?;
+            bindings.push((identifier.name, field_type, field_offset));
+        }
+
+        if !pattern.is_rest {
+            if let Some(index) = is_field_bound.iter().position(|is_bound| !is_bound) {
+                return Err(Error::LetPatternFieldMissing {
+                    location: pattern.location,
+                    type_identifier: structure.identifier,
+                    field_name: structure.fields[index].0.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
     }
 
     ///
@@ -354,9 +754,36 @@ impl Analyzer {
         let location = statement.location;
         let bounds_expression_location = statement.bounds_expression.location;
 
+        let mut unroll_attribute: Option<(bool, Location)> = None;
+        for attribute in statement.attributes.into_iter() {
+            let is_unrolled = match attribute.identifier.name.as_str() {
+                ATTRIBUTE_UNROLL => true,
+                ATTRIBUTE_NO_UNROLL => false,
+                _ => {
+                    return Err(Error::LoopAttributeUnknown {
+                        location: attribute.location,
+                        found: attribute.identifier.name,
+                    })
+                }
+            };
+            match unroll_attribute {
+                Some((previous, reference)) if previous != is_unrolled => {
+                    return Err(Error::LoopAttributeConflict {
+                        location: attribute.location,
+                        reference,
+                    })
+                }
+                Some(_) => {}
+                None => unroll_attribute = Some((is_unrolled, attribute.location)),
+            }
+        }
+        let is_unrolled = unroll_attribute
+            .map(|(is_unrolled, _location)| is_unrolled)
+            .unwrap_or_default();
+
         let (range_start, range_end, index_bitlength, is_index_signed, is_inclusive) =
             match ExpressionAnalyzer::new(self.scope_stack.top())
-                .analyze(statement.bounds_expression, TranslationHint::Value)?
+                .analyze(statement.bounds_expression, TranslationHint::Constant)?
             {
                 (Element::Constant(Constant::RangeInclusive(range)), _intermediate) => (
                     range.start,
@@ -380,7 +807,46 @@ impl Analyzer {
                 }
             };
 
+        let is_reversed = range_start > range_end;
+        let range_start = if is_reversed {
+            cmp::max(range_start, range_end.clone())
+        } else {
+            cmp::min(range_start, range_end.clone())
+        };
+        let range_end = if is_reversed {
+            cmp::min(range_start.clone(), range_end)
+        } else {
+            cmp::max(range_start.clone(), range_end)
+        };
+        let iterations_count = if is_reversed {
+            range_start.clone() - range_end
+        } else {
+            range_end - range_start.clone()
+        };
+        let iterations_count = if is_inclusive {
+            iterations_count + BigInt::one()
+        } else {
+            iterations_count
+        };
+        // the nested loops are unrolled into the product of their iterations counts
+        let limit = Scope::loop_iterations_limit(self.scope_stack.top());
+        let total_count = iterations_count.clone()
+            * BigInt::from(Scope::enclosing_loop_iterations(self.scope_stack.top()));
+        let iterations_count = match (iterations_count.to_usize(), total_count.to_usize()) {
+            (Some(count), Some(total_count)) if total_count <= limit => count,
+            _ => {
+                return Err(Error::LoopIterationsLimitExceeded {
+                    location: bounds_expression_location,
+                    found: total_count,
+                    limit,
+                })
+            }
+        };
+
+        let uninitialized = Scope::uninitialized_variables(self.scope_stack.top());
+
         self.scope_stack.push();
+        Scope::set_loop_iterations(self.scope_stack.top(), iterations_count);
 
         let index_identifier = statement.index_identifier.name.to_owned();
         Scope::declare_variable(
@@ -411,37 +877,15 @@ impl Analyzer {
             None
         };
 
+        let end_location = statement.block.end_location;
         let (_result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.block)?;
 
+        Scope::snapshot(self.scope_stack.top(), Some((location, end_location)));
         self.scope_stack.pop();
 
-        let is_reversed = range_start > range_end;
-        let range_start = if is_reversed {
-            cmp::max(range_start, range_end.clone())
-        } else {
-            cmp::min(range_start, range_end.clone())
-        };
-        let range_end = if is_reversed {
-            cmp::min(range_start.clone(), range_end)
-        } else {
-            cmp::max(range_start.clone(), range_end)
-        };
-        let iterations_count = if is_reversed {
-            range_start.clone() - range_end
-        } else {
-            range_end - range_start.clone()
-        };
-        let mut iterations_count = iterations_count.to_usize().ok_or(Error::Element(
-            bounds_expression_location,
-            ElementError::Constant(ConstantError::Integer(
-                IntegerConstantError::IntegerTooLarge {
-                    value: iterations_count,
-                    bitlength: index_bitlength,
-                },
-            )),
-        ))?;
-        if is_inclusive {
-            iterations_count += 1;
+        // the body initializes variables only if it is executed at least once unconditionally
+        if iterations_count == 0 || while_condition.is_some() {
+            Scope::set_uninitialized_variables(self.scope_stack.top(), &uninitialized);
         }
 
         Ok(GeneratorForLoopStatement::new(
@@ -454,6 +898,7 @@ impl Analyzer {
             index_bitlength,
             while_condition,
             body,
+            is_unrolled,
         ))
     }
 
@@ -468,11 +913,20 @@ impl Analyzer {
         let (element, _intermediate) = ExpressionAnalyzer::new(self.scope_stack.top())
             .analyze(statement.expression, TranslationHint::Value)?;
 
+        let element = match element {
+            Element::Value(Value::Array(array)) => array
+                .try_into_constant()
+                .map(|array| Element::Constant(Constant::Array(array)))
+                .unwrap_or_else(|array| Element::Value(Value::Array(array))),
+            element => element,
+        };
+
         let const_type =
             Type::from_type_variant(&statement.r#type.variant, self.scope_stack.top())?;
         let constant = match element {
-            Element::Constant(constant) => constant
-                .cast(const_type)
+            Element::Constant(constant) => Caster::cast_implicit(&constant.r#type(), &const_type)
+                .map_err(ConstantError::Casting)
+                .and_then(|()| constant.cast(const_type))
                 .map_err(ElementError::Constant)
                 .map_err(|error| Error::Element(type_location, error))?,
             element => {
@@ -510,16 +964,17 @@ impl Analyzer {
         let location = statement.location;
 
         let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
+        let mut field_locations = HashMap::with_capacity(statement.fields.len());
         for field in statement.fields.into_iter() {
-            if fields
-                .iter()
-                .any(|(name, _type)| name == &field.identifier.name)
+            if let Some(reference) =
+                field_locations.insert(field.identifier.name.clone(), field.location)
             {
                 return Err(Error::Element(
                     field.location,
                     ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
                         type_identifier: statement.identifier.name,
                         field_name: field.identifier.name,
+                        reference,
                     })),
                 ));
             }
@@ -597,6 +1052,7 @@ impl Analyzer {
     /// Analyzes a compile time only import statement.
     ///
     fn r#use(&mut self, statement: UseStatement) -> Result<(), Error> {
+        let location = statement.location;
         let path_location = statement.path.location;
 
         let path = match ExpressionAnalyzer::new(self.scope_stack.top())
@@ -615,8 +1071,17 @@ impl Analyzer {
             .elements
             .last()
             .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-        Scope::declare_item(self.scope_stack.top(), path_last_element.to_owned(), item)
-            .map_err(|error| Error::Scope(path_last_element.location, error))?;
+        Scope::declare_item(
+            self.scope_stack.top(),
+            path_last_element.to_owned(),
+            ScopeItem::new(item.variant, item.location),
+        )
+        .map_err(|error| Error::Scope(path_last_element.location, error))?;
+        self.imports.push((
+            path_last_element.name.to_owned(),
+            location,
+            path.to_string(),
+        ));
 
         Ok(())
     }
@@ -4,17 +4,22 @@
 
 #![cfg(test)]
 
+use std::convert::TryFrom;
+
 use num_bigint::BigInt;
 
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::range::Range as RangeConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
 #[test]
@@ -63,6 +68,183 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_for_attribute_unknown() {
+    let input = r#"
+fn main() {
+    #[unrolled]
+    for i in 0..4 {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LoopAttributeUnknown {
+        location: Location::new(3, 5),
+        found: "unrolled".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_for_attribute_conflict() {
+    let input = r#"
+fn main() {
+    #[unroll]
+    #[no_unroll]
+    for i in 0..4 {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LoopAttributeConflict {
+        location: Location::new(4, 5),
+        reference: Location::new(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_for_iterations_limit_exceeded() {
+    let input = r#"
+const START: u128 = 100000000000000000000000;
+const END: u128 = 100000000000000002000000;
+fn main() {
+    for i in START..END {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::LoopIterationsLimitExceeded {
+            location: Location::new(5, 19),
+            found: BigInt::from(2_000_000),
+            limit: crate::LIMIT_LOOP_ITERATIONS,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_for_iterations_limit_exceeded_nested() {
+    let input = r#"
+fn main() {
+    for i in 0..1000 {
+        for j in 0..1000 {}
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::LoopIterationsLimitExceeded {
+            location: Location::new(4, 19),
+            found: BigInt::from(1_000_000),
+            limit: crate::LIMIT_LOOP_ITERATIONS,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_for_iterations_limit_raised() {
+    let input = r#"
+fn main() {
+    for i in 0..1000 {
+        for j in 0..1000 {}
+    }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_loop_iterations_limit(input, 1_000_000);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_let_pattern_invalid_type() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let Point { x, y } = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LetPatternInvalidType {
+        location: Location::new(8, 9),
+        expected: "Point".to_owned(),
+        found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_field_does_not_exist() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2 };
+    let Point { x, z } = point;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::LetPatternFieldDoesNotExist {
+            location: Location::new(9, 20),
+            type_identifier: "Point".to_owned(),
+            field_name: "z".to_owned(),
+            suggestion: None,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_field_missing() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2 };
+    let Point { y } = point;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LetPatternFieldMissing {
+        location: Location::new(9, 9),
+        type_identifier: "Point".to_owned(),
+        field_name: "x".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_structure_duplicate_field() {
     let input = r#"
@@ -82,6 +264,7 @@ fn main() -> u8 {
         ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
             type_identifier: "Data".to_owned(),
             field_name: "b".to_owned(),
+            reference: Location::new(4, 5),
         })),
     )));
 
@@ -149,3 +332,243 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_range_used_as_value_mutable_binding() {
+    let input = r#"
+fn main() {
+    let mut range = 0..10;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsValue {
+        location: Location::new(3, 5),
+        found: Constant::Range(RangeConstant::new(
+            BigInt::from(0),
+            BigInt::from(10),
+            false,
+            crate::BITLENGTH_BYTE,
+        ))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_let_literal_within_range() {
+    let input = r#"
+fn main() {
+    let byte: u8 = 255;
+    let wide: u16 = 300;
+    let minimal: i8 = -128;
+    let maximal: u248 = 452312848583266388373324160190187140051835877600158453279131187530910662655;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_let_literal_out_of_range_unsigned() {
+    let input = r#"
+fn main() {
+    let value: u8 = 300;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::IntegerLiteralOutOfRange {
+        location: Location::new(3, 21),
+        value: BigInt::from(300),
+        r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_literal_out_of_range_signed_positive() {
+    let input = r#"
+fn main() {
+    let value: i8 = 128;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::IntegerLiteralOutOfRange {
+        location: Location::new(3, 21),
+        value: BigInt::from(128),
+        r#type: Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_literal_out_of_range_signed_negative() {
+    let input = r#"
+fn main() {
+    let value: i8 = -129;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::IntegerLiteralOutOfRange {
+        location: Location::new(3, 21),
+        value: BigInt::from(-129),
+        r#type: Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_literal_out_of_range_unsigned_negative() {
+    let input = r#"
+fn main() {
+    let value: u248 = -1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::IntegerLiteralOutOfRange {
+        location: Location::new(3, 23),
+        value: BigInt::from(-1),
+        r#type: Type::integer_unsigned(crate::BITLENGTH_MAX_INT).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_let_constant_in_constant_positions() {
+    let input = r#"
+fn main() {
+    let size = 4;
+    let offset: u8 = 2;
+    let array = [0; size];
+    let matrix: [[u8; size]; size * 2] = [[1; size]; size * 2];
+    let shifted = 1 << offset;
+
+    let mut sum = 0;
+    for i in 0..size {
+        sum = sum + array[i];
+    }
+
+    let mut count = 3;
+    let counted = [0; count];
+    count = 4;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_let_constant_reassigned() {
+    let input = r#"
+fn main() {
+    let mut size = 4;
+    size = 5;
+    let array = [0; size];
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConstantExpressionHasNonConstantElement {
+            location: Location::new(5, 21),
+            found: Element::Value(
+                Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                    .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_constant_reassigned_in_loop() {
+    let input = r#"
+fn main() {
+    let mut size = 2;
+    for i in 0..2 {
+        let array = [0; size];
+        size = 3;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConstantExpressionHasNonConstantElement {
+            location: Location::new(5, 25),
+            found: Element::Value(
+                Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                    .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_statement_result_discarded() {
+    let input = r#"
+fn hash(value: u8) -> u8 {
+    value * 2
+}
+
+fn main() {
+    hash(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::StatementResultDiscarded {
+        location: Location::new(7, 5),
+        found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_statement_result_discarded_explicitly() {
+    let input = r#"
+fn check(value: u8) {
+    assert!(value < 100);
+}
+
+fn hash(value: u8) -> u8 {
+    value * 2
+}
+
+fn main() {
+    check(42);
+    let _ = hash(42);
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
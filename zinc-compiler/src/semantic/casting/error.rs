@@ -8,6 +8,7 @@ use crate::semantic::element::r#type::Type;
 pub enum Error {
     CastingFromInvalidType { from: String, to: String },
     CastingToInvalidType { from: String, to: String },
+    CastingToNarrowerType { from: String, to: String },
 }
 
 impl Error {
@@ -24,4 +25,19 @@ impl Error {
             to: to.to_string(),
         }
     }
+
+    pub fn casting_to_narrower_type(from: &Type, to: &Type) -> Self {
+        Self::CastingToNarrowerType {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::CastingFromInvalidType { .. } => "E1201",
+            Self::CastingToInvalidType { .. } => "E1202",
+            Self::CastingToNarrowerType { .. } => "E1203",
+        }
+    }
 }
@@ -6,6 +6,10 @@ mod tests;
 
 pub mod error;
 
+use num_bigint::BigInt;
+
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::Type;
 
 use self::error::Error;
@@ -20,9 +24,14 @@ impl Caster {
     /// u<b1> -> u<b2>
     /// u<b1> -> i<b2>
     /// u<b1> -> field
+    /// u<b1> -> bool
     /// i<b1> -> i<b2>
     /// i<b1> -> u<b2>
     /// i<b1> -> field
+    /// i<b1> -> bool
+    /// bool -> u<b2>
+    /// bool -> i<b2>
+    /// bool -> field
     /// enum<b1> -> i<b2>
     /// enum<b1> -> u<b2>
     /// enum<b1> -> field
@@ -31,6 +40,10 @@ impl Caster {
     /// `b1` and `b2` are bitlengths
     /// `T` is any type
     ///
+    /// An integer cast to `bool` must be either 0 or 1, which is checked at runtime.
+    ///
+    /// An enumeration can only be cast to an integer type, which is able to hold all its variants.
+    ///
     /// For more information on type semantics, see the official Zinc book.
     ///
     pub fn cast(from: &Type, to: &Type) -> Result<(), Error> {
@@ -38,16 +51,31 @@ impl Caster {
             (Type::IntegerUnsigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerUnsigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerUnsigned { .. }, Type::Field) => Ok(()),
+            (Type::IntegerUnsigned { .. }, Type::Boolean) => Ok(()),
             (from @ Type::IntegerUnsigned { .. }, to) => {
                 Err(Error::casting_to_invalid_type(from, to))
             }
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::Field) => Ok(()),
+            (Type::IntegerSigned { .. }, Type::Boolean) => Ok(()),
             (from @ Type::IntegerSigned { .. }, to) => {
                 Err(Error::casting_to_invalid_type(from, to))
             }
+            (Type::Boolean, Type::IntegerUnsigned { .. }) => Ok(()),
+            (Type::Boolean, Type::IntegerSigned { .. }) => Ok(()),
+            (Type::Boolean, Type::Field) => Ok(()),
+            (Type::Enumeration(enumeration), Type::IntegerSigned { bitlength })
+                if !Self::is_enumeration_fitting(enumeration, true, *bitlength) =>
+            {
+                Err(Error::casting_to_narrower_type(from, to))
+            }
             (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
+            (Type::Enumeration(enumeration), Type::IntegerUnsigned { bitlength })
+                if !Self::is_enumeration_fitting(enumeration, false, *bitlength) =>
+            {
+                Err(Error::casting_to_narrower_type(from, to))
+            }
             (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::Field) => Ok(()),
             (from, to) => {
@@ -59,4 +87,39 @@ impl Caster {
             }
         }
     }
+
+    ///
+    /// Validates implicit casting from one type to another, e.g. to the type annotation
+    /// of a `let` statement.
+    ///
+    /// The same rules as for the explicit casting apply, except that `bool` can be neither
+    /// cast to nor from an integer type implicitly.
+    ///
+    pub fn cast_implicit(from: &Type, to: &Type) -> Result<(), Error> {
+        match (from, to) {
+            (from @ Type::IntegerUnsigned { .. }, to @ Type::Boolean)
+            | (from @ Type::IntegerSigned { .. }, to @ Type::Boolean) => {
+                Err(Error::casting_to_invalid_type(from, to))
+            }
+            (from @ Type::Boolean, to) if to != &Type::Boolean => {
+                Err(Error::casting_from_invalid_type(from, to))
+            }
+            (from, to) => Self::cast(from, to),
+        }
+    }
+
+    ///
+    /// Checks whether every variant of the enumeration fits into the integer type.
+    ///
+    fn is_enumeration_fitting(
+        enumeration: &Enumeration,
+        is_signed: bool,
+        bitlength: usize,
+    ) -> bool {
+        let values: Vec<&BigInt> = enumeration.values.iter().collect();
+        match IntegerConstant::minimal_bitlength_bigints(values.as_slice(), is_signed) {
+            Ok(minimal_bitlength) => minimal_bitlength <= bitlength,
+            Err(_error) => false,
+        }
+    }
 }
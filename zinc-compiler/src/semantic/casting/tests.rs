@@ -7,6 +7,7 @@
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
@@ -41,7 +42,7 @@ fn error_casting_to_invalid_type() {
     let input = r#"
 fn main() {
     let value: u8 = 0;
-    let result = value as bool;
+    let result = value as ();
 }
 "#;
 
@@ -49,7 +50,7 @@ fn main() {
         Location::new(4, 24),
         ElementError::Value(ValueError::Casting(CastingError::casting_to_invalid_type(
             &Type::integer_unsigned(crate::BITLENGTH_BYTE),
-            &Type::boolean(),
+            &Type::unit(),
         ))),
     )));
 
@@ -79,3 +80,110 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_casting_to_narrower_type_enumeration_value() {
+    let input = r#"
+enum Data {
+    FIRST = 1,
+    LARGE = 1000,
+}
+
+fn main() {
+    let value = Data::FIRST;
+    let result = value as u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 24),
+        ElementError::Value(ValueError::Casting(CastingError::CastingToNarrowerType {
+            from: "enum Data".to_owned(),
+            to: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_casting_to_narrower_type_enumeration_constant() {
+    let input = r#"
+enum Data {
+    FIRST = 1,
+    LARGE = 1000,
+}
+
+fn main() {
+    let result = Data::FIRST as u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 30),
+        ElementError::Constant(ConstantError::Casting(
+            CastingError::CastingToNarrowerType {
+                from: "enum Data".to_owned(),
+                to: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_casting_from_invalid_type_let_implicit_boolean() {
+    let input = r#"
+fn main() {
+    let flag = true;
+    let result: u8 = flag;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 17),
+        ElementError::Value(ValueError::Casting(
+            CastingError::casting_from_invalid_type(
+                &Type::boolean(),
+                &Type::integer_unsigned(crate::BITLENGTH_BYTE),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_casting_integer_to_boolean() {
+    let input = r#"
+fn main() {
+    let value: u8 = 1;
+    let result = value as bool;
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_casting_boolean_to_integer() {
+    let input = r#"
+fn main() {
+    let flag = true;
+    let result = flag as i16 + (false as i16);
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
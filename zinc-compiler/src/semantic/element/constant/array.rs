@@ -0,0 +1,94 @@
+//!
+//! The semantic analyzer constant array element.
+//!
+
+use std::fmt;
+
+use crate::semantic::casting::Caster;
+use crate::semantic::element::constant::error::Error;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+
+///
+/// Constant arrays are arrays of scalar constants, which are known at compile time.
+///
+/// They are declared with `const` statements and used mostly as lookup tables.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    r#type: Type,
+    values: Vec<Constant>,
+}
+
+impl Array {
+    pub fn new(r#type: Type, values: Vec<Constant>) -> Self {
+        Self { r#type, values }
+    }
+
+    pub fn r#type(&self) -> Type {
+        Type::array(self.r#type.to_owned(), self.values.len())
+    }
+
+    pub fn element_type(&self) -> &Type {
+        &self.r#type
+    }
+
+    pub fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type() == other.r#type()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[Constant] {
+        self.values.as_slice()
+    }
+
+    pub fn into_values(self) -> Vec<Constant> {
+        self.values
+    }
+
+    ///
+    /// Casts the array element-wise, if the array sizes are equal.
+    ///
+    pub fn cast(self, to: Type) -> Result<Self, Error> {
+        match to {
+            Type::Array { r#type, size } if size == self.values.len() => {
+                let mut values = Vec::with_capacity(self.values.len());
+                for value in self.values.into_iter() {
+                    values.push(value.cast(*r#type.to_owned())?);
+                }
+
+                Ok(Self::new(*r#type, values))
+            }
+            to => {
+                Caster::cast(&self.r#type(), &to).map_err(Error::Casting)?;
+                Ok(self)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Array {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "constant array '[{}]' of type '{}'",
+            self.values
+                .iter()
+                .map(|value| match value {
+                    Constant::Boolean(inner) => inner.inner.to_string(),
+                    Constant::Integer(inner) => inner.value.to_string(),
+                    value => value.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.r#type(),
+        )
+    }
+}
@@ -73,6 +73,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedInteger { found: String },
     OperatorRemainderSecondOperandExpectedInteger { found: String },
 
+    OperatorExponentiationFirstOperandExpectedInteger { found: String },
+    OperatorExponentiationSecondOperandExpectedInteger { found: String },
+
     OperatorNotExpectedBoolean { found: String },
 
     OperatorBitwiseNotExpectedInteger { found: String },
@@ -82,3 +85,63 @@ pub enum Error {
     Integer(IntegerConstantError),
     Casting(CastingError),
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OperatorRangeInclusiveFirstOperandExpectedInteger { .. } => "E1001",
+            Self::OperatorRangeInclusiveSecondOperandExpectedInteger { .. } => "E1002",
+            Self::OperatorRangeFirstOperandExpectedInteger { .. } => "E1003",
+            Self::OperatorRangeSecondOperandExpectedInteger { .. } => "E1004",
+            Self::OperatorOrFirstOperandExpectedBoolean { .. } => "E1005",
+            Self::OperatorOrSecondOperandExpectedBoolean { .. } => "E1006",
+            Self::OperatorXorFirstOperandExpectedBoolean { .. } => "E1007",
+            Self::OperatorXorSecondOperandExpectedBoolean { .. } => "E1008",
+            Self::OperatorAndFirstOperandExpectedBoolean { .. } => "E1009",
+            Self::OperatorAndSecondOperandExpectedBoolean { .. } => "E1010",
+            Self::OperatorEqualsSecondOperandExpectedUnit { .. } => "E1011",
+            Self::OperatorEqualsSecondOperandExpectedBoolean { .. } => "E1012",
+            Self::OperatorEqualsSecondOperandExpectedInteger { .. } => "E1013",
+            Self::OperatorEqualsFirstOperandExpectedPrimitiveType { .. } => "E1014",
+            Self::OperatorNotEqualsSecondOperandExpectedUnit { .. } => "E1015",
+            Self::OperatorNotEqualsSecondOperandExpectedBoolean { .. } => "E1016",
+            Self::OperatorNotEqualsSecondOperandExpectedInteger { .. } => "E1017",
+            Self::OperatorNotEqualsFirstOperandExpectedPrimitiveType { .. } => "E1018",
+            Self::OperatorGreaterEqualsFirstOperandExpectedInteger { .. } => "E1019",
+            Self::OperatorGreaterEqualsSecondOperandExpectedInteger { .. } => "E1020",
+            Self::OperatorLesserEqualsFirstOperandExpectedInteger { .. } => "E1021",
+            Self::OperatorLesserEqualsSecondOperandExpectedInteger { .. } => "E1022",
+            Self::OperatorGreaterFirstOperandExpectedInteger { .. } => "E1023",
+            Self::OperatorGreaterSecondOperandExpectedInteger { .. } => "E1024",
+            Self::OperatorLesserFirstOperandExpectedInteger { .. } => "E1025",
+            Self::OperatorLesserSecondOperandExpectedInteger { .. } => "E1026",
+            Self::OperatorBitwiseOrFirstOperandExpectedInteger { .. } => "E1027",
+            Self::OperatorBitwiseOrSecondOperandExpectedInteger { .. } => "E1028",
+            Self::OperatorBitwiseXorFirstOperandExpectedInteger { .. } => "E1029",
+            Self::OperatorBitwiseXorSecondOperandExpectedInteger { .. } => "E1030",
+            Self::OperatorBitwiseAndFirstOperandExpectedInteger { .. } => "E1031",
+            Self::OperatorBitwiseAndSecondOperandExpectedInteger { .. } => "E1032",
+            Self::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { .. } => "E1033",
+            Self::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { .. } => "E1034",
+            Self::OperatorBitwiseShiftRightFirstOperandExpectedInteger { .. } => "E1035",
+            Self::OperatorBitwiseShiftRightSecondOperandExpectedInteger { .. } => "E1036",
+            Self::OperatorAdditionFirstOperandExpectedInteger { .. } => "E1037",
+            Self::OperatorAdditionSecondOperandExpectedInteger { .. } => "E1038",
+            Self::OperatorSubtractionFirstOperandExpectedInteger { .. } => "E1039",
+            Self::OperatorSubtractionSecondOperandExpectedInteger { .. } => "E1040",
+            Self::OperatorMultiplicationFirstOperandExpectedInteger { .. } => "E1041",
+            Self::OperatorMultiplicationSecondOperandExpectedInteger { .. } => "E1042",
+            Self::OperatorDivisionFirstOperandExpectedInteger { .. } => "E1043",
+            Self::OperatorDivisionSecondOperandExpectedInteger { .. } => "E1044",
+            Self::OperatorRemainderFirstOperandExpectedInteger { .. } => "E1045",
+            Self::OperatorRemainderSecondOperandExpectedInteger { .. } => "E1046",
+            Self::OperatorExponentiationFirstOperandExpectedInteger { .. } => "E1047",
+            Self::OperatorExponentiationSecondOperandExpectedInteger { .. } => "E1048",
+            Self::OperatorNotExpectedBoolean { .. } => "E1049",
+            Self::OperatorBitwiseNotExpectedInteger { .. } => "E1050",
+            Self::OperatorNegationExpectedInteger { .. } => "E1051",
+            Self::Integer(inner) => inner.code(),
+            Self::Casting(inner) => inner.code(),
+        }
+    }
+}
@@ -23,18 +23,23 @@ pub enum Error {
 
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found: String },
     OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found: String },
+    OperatorExponentiationSecondOperatorExpectedUnsigned { found: String },
 
     OverflowAddition { value: BigInt, r#type: String },
     OverflowSubtraction { value: BigInt, r#type: String },
     OverflowMultiplication { value: BigInt, r#type: String },
     OverflowDivision { value: BigInt, r#type: String },
     OverflowRemainder { value: BigInt, r#type: String },
+    OverflowExponentiation { value: BigInt, r#type: String },
     OverflowCasting { value: BigInt, r#type: String },
     OverflowNegation { value: BigInt, r#type: String },
+    OverflowLiteral { value: BigInt, r#type: String },
+    OverflowInference { value: BigInt, r#type: String },
 
     ForbiddenFieldDivision,
     ForbiddenFieldRemainder,
     ForbiddenFieldBitwise,
+    ForbiddenSignedBitwiseNot,
     ForbiddenFieldNegation,
 
     ZeroDivision,
@@ -43,3 +48,46 @@ pub enum Error {
     IntegerTooLarge { value: BigInt, bitlength: usize },
     UnsignedNegative { value: BigInt, r#type: String },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TypesMismatchEquals { .. } => "E1101",
+            Self::TypesMismatchNotEquals { .. } => "E1102",
+            Self::TypesMismatchGreaterEquals { .. } => "E1103",
+            Self::TypesMismatchLesserEquals { .. } => "E1104",
+            Self::TypesMismatchGreater { .. } => "E1105",
+            Self::TypesMismatchLesser { .. } => "E1106",
+            Self::TypesMismatchBitwiseOr { .. } => "E1107",
+            Self::TypesMismatchBitwiseXor { .. } => "E1108",
+            Self::TypesMismatchBitwiseAnd { .. } => "E1109",
+            Self::TypesMismatchAddition { .. } => "E1110",
+            Self::TypesMismatchSubtraction { .. } => "E1111",
+            Self::TypesMismatchMultiplication { .. } => "E1112",
+            Self::TypesMismatchDivision { .. } => "E1113",
+            Self::TypesMismatchRemainder { .. } => "E1114",
+            Self::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { .. } => "E1115",
+            Self::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { .. } => "E1116",
+            Self::OperatorExponentiationSecondOperatorExpectedUnsigned { .. } => "E1117",
+            Self::OverflowAddition { .. } => "E1118",
+            Self::OverflowSubtraction { .. } => "E1119",
+            Self::OverflowMultiplication { .. } => "E1120",
+            Self::OverflowDivision { .. } => "E1121",
+            Self::OverflowRemainder { .. } => "E1122",
+            Self::OverflowExponentiation { .. } => "E1123",
+            Self::OverflowCasting { .. } => "E1124",
+            Self::OverflowNegation { .. } => "E1125",
+            Self::OverflowLiteral { .. } => "E1126",
+            Self::OverflowInference { .. } => "E1127",
+            Self::ForbiddenFieldDivision => "E1128",
+            Self::ForbiddenFieldRemainder => "E1129",
+            Self::ForbiddenFieldBitwise => "E1130",
+            Self::ForbiddenSignedBitwiseNot => "E1131",
+            Self::ForbiddenFieldNegation => "E1132",
+            Self::ZeroDivision => "E1133",
+            Self::ZeroRemainder => "E1134",
+            Self::IntegerTooLarge { .. } => "E1135",
+            Self::UnsignedNegative { .. } => "E1136",
+        }
+    }
+}
@@ -12,11 +12,14 @@ use std::fmt;
 
 use num_bigint::BigInt;
 use num_traits::Num;
+use num_traits::One;
 use num_traits::Signed;
 use num_traits::ToPrimitive;
+use num_traits::Zero;
 
 use zinc_utils::euclidean;
 
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::range::Range;
@@ -466,7 +469,74 @@ impl Integer {
         })
     }
 
+    ///
+    /// Raises the constant to the power of `other` using the square-and-multiply method.
+    ///
+    /// Every intermediate result is checked for overflow, so the behavior is the same as
+    /// if the multiplication were repeated `other` times.
+    ///
+    pub fn pow(self, other: Self) -> Result<Self, Error> {
+        if other.is_signed {
+            return Err(
+                Error::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                    found: other.to_string(),
+                },
+            );
+        }
+
+        let exponent = other.to_usize()?;
+        let exponent_bits = (0..std::mem::size_of::<usize>() * crate::BITLENGTH_BYTE)
+            .rev()
+            .map(|index| (exponent >> index) & 1 == 1)
+            .skip_while(|is_set| !is_set);
+
+        let mut result = BigInt::one();
+        for is_set in exponent_bits {
+            result = &result * &result;
+            if is_set {
+                result *= &self.value;
+            }
+
+            if result.is_negative() && !self.is_signed {
+                return Err(Error::OverflowExponentiation {
+                    value: result,
+                    r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                });
+            }
+
+            if Self::minimal_bitlength(&result, self.is_signed)? > self.bitlength {
+                return Err(Error::OverflowExponentiation {
+                    value: result,
+                    r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+                });
+            }
+        }
+
+        Ok(Self {
+            value: result,
+            is_signed: self.is_signed,
+            bitlength: self.bitlength,
+            enumeration: self.enumeration,
+        })
+    }
+
+    ///
+    /// Casts the integer to another integer type.
+    ///
+    /// If only the signedness is changed, the bits are reinterpreted using the two's complement
+    /// representation, e.g. `255 as i8 == -1` and `-1 as u8 == 255`. Otherwise, the value must
+    /// fit into the new type.
+    ///
     pub fn cast(mut self, is_signed: bool, bitlength: usize) -> Result<Self, Error> {
+        if self.is_signed != is_signed && self.bitlength == bitlength {
+            let modulus = BigInt::one() << bitlength;
+            if is_signed && self.value >= BigInt::one() << (bitlength - 1) {
+                self.value -= modulus;
+            } else if !is_signed && self.value.is_negative() {
+                self.value += modulus;
+            }
+        }
+
         if self.value.is_negative() && !is_signed {
             return Err(Error::OverflowCasting {
                 value: self.value,
@@ -488,18 +558,73 @@ impl Integer {
         Ok(self)
     }
 
+    ///
+    /// Casts the integer to `bool`, which is only possible for the values 0 and 1.
+    ///
+    pub fn to_boolean(self) -> Result<BooleanConstant, Error> {
+        if self.value == BigInt::zero() {
+            Ok(BooleanConstant::new(false))
+        } else if self.value == BigInt::one() {
+            Ok(BooleanConstant::new(true))
+        } else {
+            Err(Error::OverflowCasting {
+                value: self.value,
+                r#type: Type::boolean().to_string(),
+            })
+        }
+    }
+
+    ///
+    /// Gives the untyped literal the integer `r#type` it meets in an expression, e.g. the type of
+    /// the other operand or the function parameter. Other types are ignored, so the type mismatch
+    /// is reported by the operation itself.
+    ///
+    pub fn infer(mut self, r#type: &Type) -> Result<Self, Error> {
+        let (is_signed, bitlength) = match r#type {
+            Type::IntegerUnsigned { bitlength } => (false, *bitlength),
+            Type::IntegerSigned { bitlength } => (true, *bitlength),
+            Type::Field => (false, crate::BITLENGTH_FIELD),
+            _ => return Ok(self),
+        };
+
+        if self.value.is_negative() && !is_signed {
+            return Err(Error::OverflowInference {
+                value: self.value,
+                r#type: r#type.to_string(),
+            });
+        }
+        match Self::minimal_bitlength(&self.value, is_signed) {
+            Ok(minimal) if minimal <= bitlength => {}
+            _ => {
+                return Err(Error::OverflowInference {
+                    value: self.value,
+                    r#type: r#type.to_string(),
+                })
+            }
+        }
+
+        self.is_signed = is_signed;
+        self.bitlength = bitlength;
+
+        Ok(self)
+    }
+
     pub fn bitwise_not(self) -> Result<Self, Error> {
         if self.bitlength == crate::BITLENGTH_FIELD {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        let result = !self.value;
+        if self.is_signed {
+            return Err(Error::ForbiddenSignedBitwiseNot);
+        }
+
+        let result = (BigInt::one() << self.bitlength) - BigInt::one() - self.value;
 
         Ok(Self {
             value: result,
             is_signed: self.is_signed,
             bitlength: self.bitlength,
-            enumeration: self.enumeration,
+            enumeration: None,
         })
     }
 
@@ -615,23 +740,56 @@ impl TryFrom<&IntegerLiteral> for Integer {
 
     ///
     /// Converts `literal` to a `BigInt` and its bitlength.
-    /// For now, the minimal bitlength enough to contain the number is inferred.
+    /// If the literal has a type suffix like `42u64`, the suffix type is used, provided the value
+    /// fits into it. Otherwise, the minimal bitlength enough to contain the number is inferred.
+    ///
+    /// A negative literal is signed, so `-128` fits into `i8`, whereas `-129` requires `i16`.
     ///
     fn try_from(literal: &IntegerLiteral) -> Result<Self, Self::Error> {
         let (string, base) = match literal.inner {
-            LexicalIntegerLiteral::Binary { ref inner } => (inner, crate::BASE_BINARY as u32),
-            LexicalIntegerLiteral::Octal { ref inner } => (inner, crate::BASE_OCTAL as u32),
-            LexicalIntegerLiteral::Decimal { ref inner } => (inner, crate::BASE_DECIMAL as u32),
-            LexicalIntegerLiteral::Hexadecimal { ref inner } => {
+            LexicalIntegerLiteral::Binary { ref inner, .. } => (inner, crate::BASE_BINARY as u32),
+            LexicalIntegerLiteral::Octal { ref inner, .. } => (inner, crate::BASE_OCTAL as u32),
+            LexicalIntegerLiteral::Decimal { ref inner, .. } => (inner, crate::BASE_DECIMAL as u32),
+            LexicalIntegerLiteral::Hexadecimal { ref inner, .. } => {
                 (inner, crate::BASE_HEXADECIMAL as u32)
             }
         };
 
-        let value = BigInt::from_str_radix(&string, base)
+        let mut value = BigInt::from_str_radix(&string, base)
             .expect(crate::PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS);
-        let bitlength = Self::minimal_bitlength(&value, false)?;
+        if literal.is_negative {
+            value = -value;
+        }
+
+        let (is_signed, bitlength) = match literal.inner.suffix() {
+            Some(Keyword::IntegerUnsigned { bitlength }) => (false, *bitlength),
+            Some(Keyword::IntegerSigned { bitlength }) => (true, *bitlength),
+            Some(Keyword::Field) => (false, crate::BITLENGTH_FIELD),
+            _ => {
+                let is_signed = literal.is_negative;
+                let bitlength = Self::minimal_bitlength(&value, is_signed)?;
+                return Ok(Self::new(value, is_signed, bitlength));
+            }
+        };
+
+        let r#type = Type::scalar(is_signed, bitlength);
+        if value.is_negative() && !is_signed {
+            return Err(Error::UnsignedNegative {
+                value,
+                r#type: r#type.to_string(),
+            });
+        }
+        match Self::minimal_bitlength(&value, is_signed) {
+            Ok(minimal) if minimal <= bitlength => {}
+            _ => {
+                return Err(Error::OverflowLiteral {
+                    value,
+                    r#type: r#type.to_string(),
+                })
+            }
+        }
 
-        Ok(Self::new(value, false, bitlength))
+        Ok(Self::new(value, is_signed, bitlength))
     }
 }
 
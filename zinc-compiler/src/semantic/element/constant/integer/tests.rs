@@ -10,6 +10,7 @@ use num_bigint::BigInt;
 
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -117,6 +118,116 @@ fn ok_minimal_bitlength() {
     );
 }
 
+#[test]
+fn ok_comparison_signed() {
+    let pairs: [(i64, i64); 9] = [
+        (-1, 1),
+        (1, -1),
+        (-2, -1),
+        (-1, -2),
+        (1, 2),
+        (-128, 127),
+        (127, -128),
+        (0, 0),
+        (-5, -5),
+    ];
+
+    for (left, right) in pairs.iter() {
+        let constant =
+            |value: i64| IntegerConstant::new(BigInt::from(value), true, crate::BITLENGTH_BYTE);
+
+        assert_eq!(
+            constant(*left).greater(constant(*right)),
+            Ok(BooleanConstant::new(left > right)),
+            "{} > {}",
+            left,
+            right
+        );
+        assert_eq!(
+            constant(*left).greater_equals(constant(*right)),
+            Ok(BooleanConstant::new(left >= right)),
+            "{} >= {}",
+            left,
+            right
+        );
+        assert_eq!(
+            constant(*left).lesser(constant(*right)),
+            Ok(BooleanConstant::new(left < right)),
+            "{} < {}",
+            left,
+            right
+        );
+        assert_eq!(
+            constant(*left).lesser_equals(constant(*right)),
+            Ok(BooleanConstant::new(left <= right)),
+            "{} <= {}",
+            left,
+            right
+        );
+    }
+}
+
+#[test]
+fn ok_cast() {
+    // widening
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(200), false, crate::BITLENGTH_BYTE)
+            .cast(false, crate::BITLENGTH_BYTE * 2),
+        Ok(IntegerConstant::new(
+            BigInt::from(200),
+            false,
+            crate::BITLENGTH_BYTE * 2
+        )),
+    );
+    // narrowing
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(-100), true, crate::BITLENGTH_BYTE * 2)
+            .cast(true, crate::BITLENGTH_BYTE),
+        Ok(IntegerConstant::new(
+            BigInt::from(-100),
+            true,
+            crate::BITLENGTH_BYTE
+        )),
+    );
+    // sign change of the same width
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(200), false, crate::BITLENGTH_BYTE)
+            .cast(true, crate::BITLENGTH_BYTE),
+        Ok(IntegerConstant::new(
+            BigInt::from(-56),
+            true,
+            crate::BITLENGTH_BYTE
+        )),
+    );
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(-1), true, crate::BITLENGTH_BYTE)
+            .cast(false, crate::BITLENGTH_BYTE),
+        Ok(IntegerConstant::new(
+            BigInt::from(255),
+            false,
+            crate::BITLENGTH_BYTE
+        )),
+    );
+    // sign change with widening
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(200), false, crate::BITLENGTH_BYTE)
+            .cast(true, crate::BITLENGTH_BYTE * 2),
+        Ok(IntegerConstant::new(
+            BigInt::from(200),
+            true,
+            crate::BITLENGTH_BYTE * 2
+        )),
+    );
+    assert_eq!(
+        IntegerConstant::new(BigInt::from(-1), true, crate::BITLENGTH_BYTE)
+            .cast(false, crate::BITLENGTH_BYTE * 2),
+        Err(IntegerConstantError::OverflowCasting {
+            value: BigInt::from(-1),
+            r#type: Type::integer(false, crate::BITLENGTH_BYTE * 2).to_string(),
+        }),
+    );
+}
+
 #[test]
 fn error_integer_too_large_ordinar_constant() {
     let input = r#"
@@ -191,6 +302,68 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_literal_suffix() {
+    let input = r#"
+fn main() -> u64 {
+    let value = 0x10_00_u64 * 1000u64;
+    value
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_literal_unsigned() {
+    let input = r#"
+fn main() {
+    let value = 300u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 17),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowLiteral {
+                value: BigInt::from(300),
+                r#type: Type::integer(false, crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_literal_signed() {
+    let input = r#"
+fn main() {
+    let value = 0b1000_0000_i8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 17),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowLiteral {
+                value: BigInt::from(128),
+                r#type: Type::integer(true, crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_types_mismatch_greater_equals() {
     let input = r#"
@@ -1209,6 +1382,29 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_exponentiation_2nd_operand_expected_unsigned() {
+    let input = r#"
+fn main() {
+    let value = 2 ** -2;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                found: IntegerConstant::new(BigInt::from(-2), true, crate::BITLENGTH_BYTE)
+                    .to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_overflow_addition_signed_negative() {
     let input = r#"
@@ -1416,6 +1612,52 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_overflow_exponentiation_signed_positive() {
+    let input = r#"
+fn main() {
+    let value = 2 as i8 ** 7;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowExponentiation {
+                value: BigInt::from(128),
+                r#type: Type::integer(true, crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_exponentiation_unsigned_positive() {
+    let input = r#"
+fn main() {
+    let value = 2 ** 8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowExponentiation {
+                value: BigInt::from(256),
+                r#type: Type::integer(false, crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_overflow_division_signed_positive() {
     let input = r#"
@@ -1443,7 +1685,7 @@ fn main() {
 fn error_overflow_casting_signed_positive() {
     let input = r#"
 fn main() {
-    let value = 200 as i8;
+    let value = 300 as i8;
 }
 "#;
 
@@ -1451,7 +1693,7 @@ fn main() {
         Location::new(3, 21),
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::OverflowCasting {
-                value: BigInt::from(200),
+                value: BigInt::from(300),
                 r#type: Type::integer(true, crate::BITLENGTH_BYTE).to_string(),
             },
         )),
@@ -1466,15 +1708,15 @@ fn main() {
 fn error_overflow_casting_unsigned_negative() {
     let input = r#"
 fn main() {
-    let value = (-100 as u8);
+    let value = (-1000 as u8);
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(3, 23),
+        Location::new(3, 24),
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::OverflowCasting {
-                value: BigInt::from(-100),
+                value: BigInt::from(-1000),
                 r#type: Type::integer(false, crate::BITLENGTH_BYTE).to_string(),
             },
         )),
@@ -1485,6 +1727,29 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_overflow_casting_boolean() {
+    let input = r#"
+fn main() {
+    let value = 2 as bool;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowCasting {
+                value: BigInt::from(2),
+                r#type: Type::boolean().to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_overflow_negation_signed_positive() {
     let input = r#"
@@ -1512,7 +1777,7 @@ fn main() {
 fn error_overflow_negation_unsigned_negative() {
     let input = r#"
 fn main() {
-    let value = -200;
+    let value = -(200);
 }
 "#;
 
@@ -1691,6 +1956,26 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_forbidden_signed_bitwise_not() {
+    let input = r#"
+fn main() {
+    let value = ~(42 as i8);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 17),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::ForbiddenSignedBitwiseNot,
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_forbidden_field_negation() {
     let input = r#"
@@ -1720,7 +2005,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(3, 20),
+        Location::new(3, 22),
         ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)),
     )));
 
@@ -1738,7 +2023,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(3, 20),
+        Location::new(3, 22),
         ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)),
     )));
 
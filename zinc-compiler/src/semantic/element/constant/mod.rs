@@ -4,6 +4,7 @@
 
 mod tests;
 
+pub mod array;
 pub mod boolean;
 pub mod error;
 pub mod integer;
@@ -12,9 +13,12 @@ pub mod range_inclusive;
 
 use std::fmt;
 
+use num_bigint::BigInt;
+
 use crate::semantic::casting::Caster;
 use crate::semantic::element::r#type::Type;
 
+use self::array::Array;
 use self::boolean::Boolean;
 use self::error::Error;
 use self::integer::Integer;
@@ -32,6 +36,7 @@ pub enum Constant {
     Range(Range),
     RangeInclusive(RangeInclusive),
     String(String),
+    Array(Array),
 }
 
 impl Constant {
@@ -43,6 +48,18 @@ impl Constant {
             Self::Range(inner) => inner.r#type(),
             Self::RangeInclusive(inner) => inner.r#type(),
             Self::String(_) => Type::string(),
+            Self::Array(inner) => inner.r#type(),
+        }
+    }
+
+    ///
+    /// Ranges exist only at compile time, so they cannot become runtime values.
+    ///
+    pub fn is_range(&self) -> bool {
+        match self {
+            Self::Range(_) => true,
+            Self::RangeInclusive(_) => true,
+            _ => false,
         }
     }
 
@@ -59,6 +76,7 @@ impl Constant {
             (Self::RangeInclusive(inner_1), Self::RangeInclusive(inner_2)) => {
                 inner_1.has_the_same_type_as(inner_2)
             }
+            (Self::Array(inner_1), Self::Array(inner_2)) => inner_1.has_the_same_type_as(inner_2),
             _ => false,
         }
     }
@@ -437,6 +455,23 @@ impl Constant {
         }
     }
 
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        match self {
+            Self::Integer(integer_1) => match other {
+                Self::Integer(integer_2) => integer_1
+                    .pow(integer_2)
+                    .map(Self::Integer)
+                    .map_err(Error::Integer),
+                value => Err(Error::OperatorExponentiationSecondOperandExpectedInteger {
+                    found: value.to_string(),
+                }),
+            },
+            value => Err(Error::OperatorExponentiationFirstOperandExpectedInteger {
+                found: value.to_string(),
+            }),
+        }
+    }
+
     pub fn not(self) -> Result<Self, Error> {
         match self {
             Self::Boolean(value) => {
@@ -471,6 +506,10 @@ impl Constant {
     }
 
     pub fn cast(self, to: Type) -> Result<Self, Error> {
+        if let Self::Array(array) = self {
+            return array.cast(to).map(Self::Array);
+        }
+
         let from = self.r#type();
         Caster::cast(&from, &to).map_err(Error::Casting)?;
 
@@ -478,6 +517,15 @@ impl Constant {
             Type::IntegerUnsigned { bitlength } => (false, bitlength),
             Type::IntegerSigned { bitlength } => (true, bitlength),
             Type::Field => (false, crate::BITLENGTH_FIELD),
+            Type::Boolean => {
+                return match self {
+                    Self::Integer(integer) => integer
+                        .to_boolean()
+                        .map(Self::Boolean)
+                        .map_err(Error::Integer),
+                    operand => Ok(operand),
+                }
+            }
             _ => return Ok(self),
         };
 
@@ -486,6 +534,11 @@ impl Constant {
                 .cast(is_signed, bitlength)
                 .map(Self::Integer)
                 .map_err(Error::Integer)?,
+            Self::Boolean(boolean) => Self::Integer(Integer::new(
+                BigInt::from(boolean.inner as u8),
+                is_signed,
+                bitlength,
+            )),
             operand => operand,
         })
     }
@@ -500,6 +553,7 @@ impl fmt::Display for Constant {
             Self::Range(inner) => write!(f, "{}", inner),
             Self::RangeInclusive(inner) => write!(f, "{}", inner),
             Self::String(constant) => write!(f, "string constant '{}'", constant),
+            Self::Array(inner) => write!(f, "{}", inner),
         }
     }
 }
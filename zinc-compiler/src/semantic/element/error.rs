@@ -74,10 +74,10 @@ pub enum Error {
     OperatorBitwiseAndSecondOperandExpectedEvaluable { found: String },
 
     OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable { found: String },
-    OperatorBitwiseShiftLeftSecondOperandExpectedConstant { found: String },
+    OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable { found: String },
 
     OperatorBitwiseShiftRightFirstOperandExpectedEvaluable { found: String },
-    OperatorBitwiseShiftRightSecondOperandExpectedConstant { found: String },
+    OperatorBitwiseShiftRightSecondOperandExpectedEvaluable { found: String },
 
     OperatorAdditionFirstOperandExpectedEvaluable { found: String },
     OperatorAdditionSecondOperandExpectedEvaluable { found: String },
@@ -94,6 +94,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedEvaluable { found: String },
     OperatorRemainderSecondOperandExpectedEvaluable { found: String },
 
+    OperatorExponentiationFirstOperandExpectedEvaluable { found: String },
+    OperatorExponentiationSecondOperandExpectedConstant { found: String },
+
     OperatorCastingFirstOperandExpectedEvaluable { found: String },
     OperatorCastingSecondOperandExpectedType { found: String },
 
@@ -117,3 +120,95 @@ pub enum Error {
     Constant(ConstantError),
     Type(TypeError),
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OperatorAssignmentFirstOperandExpectedPlace { .. } => "E0301",
+            Self::OperatorAssignmentSecondOperandExpectedEvaluable { .. } => "E0302",
+            Self::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace { .. } => "E0303",
+            Self::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable { .. } => "E0304",
+            Self::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace { .. } => "E0305",
+            Self::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable { .. } => "E0306",
+            Self::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace { .. } => "E0307",
+            Self::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable { .. } => "E0308",
+            Self::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace { .. } => "E0309",
+            Self::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable { .. } => {
+                "E0310"
+            }
+            Self::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace { .. } => "E0311",
+            Self::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable { .. } => {
+                "E0312"
+            }
+            Self::OperatorAssignmentAdditionFirstOperandExpectedPlace { .. } => "E0313",
+            Self::OperatorAssignmentAdditionSecondOperandExpectedEvaluable { .. } => "E0314",
+            Self::OperatorAssignmentSubtractionFirstOperandExpectedPlace { .. } => "E0315",
+            Self::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable { .. } => "E0316",
+            Self::OperatorAssignmentMultiplicationFirstOperandExpectedPlace { .. } => "E0317",
+            Self::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable { .. } => "E0318",
+            Self::OperatorAssignmentDivisionFirstOperandExpectedPlace { .. } => "E0319",
+            Self::OperatorAssignmentDivisionSecondOperandExpectedEvaluable { .. } => "E0320",
+            Self::OperatorAssignmentRemainderFirstOperandExpectedPlace { .. } => "E0321",
+            Self::OperatorAssignmentRemainderSecondOperandExpectedEvaluable { .. } => "E0322",
+            Self::OperatorRangeInclusiveFirstOperandExpectedConstant { .. } => "E0323",
+            Self::OperatorRangeInclusiveSecondOperandExpectedConstant { .. } => "E0324",
+            Self::OperatorRangeFirstOperandExpectedConstant { .. } => "E0325",
+            Self::OperatorRangeSecondOperandExpectedConstant { .. } => "E0326",
+            Self::OperatorOrFirstOperandExpectedEvaluable { .. } => "E0327",
+            Self::OperatorOrSecondOperandExpectedEvaluable { .. } => "E0328",
+            Self::OperatorXorFirstOperandExpectedEvaluable { .. } => "E0329",
+            Self::OperatorXorSecondOperandExpectedEvaluable { .. } => "E0330",
+            Self::OperatorAndFirstOperandExpectedEvaluable { .. } => "E0331",
+            Self::OperatorAndSecondOperandExpectedEvaluable { .. } => "E0332",
+            Self::OperatorEqualsFirstOperandExpectedEvaluable { .. } => "E0333",
+            Self::OperatorEqualsSecondOperandExpectedEvaluable { .. } => "E0334",
+            Self::OperatorNotEqualsFirstOperandExpectedEvaluable { .. } => "E0335",
+            Self::OperatorNotEqualsSecondOperandExpectedEvaluable { .. } => "E0336",
+            Self::OperatorGreaterEqualsFirstOperandExpectedEvaluable { .. } => "E0337",
+            Self::OperatorGreaterEqualsSecondOperandExpectedEvaluable { .. } => "E0338",
+            Self::OperatorLesserEqualsFirstOperandExpectedEvaluable { .. } => "E0339",
+            Self::OperatorLesserEqualsSecondOperandExpectedEvaluable { .. } => "E0340",
+            Self::OperatorGreaterFirstOperandExpectedEvaluable { .. } => "E0341",
+            Self::OperatorGreaterSecondOperandExpectedEvaluable { .. } => "E0342",
+            Self::OperatorLesserFirstOperandExpectedEvaluable { .. } => "E0343",
+            Self::OperatorLesserSecondOperandExpectedEvaluable { .. } => "E0344",
+            Self::OperatorBitwiseOrFirstOperandExpectedEvaluable { .. } => "E0345",
+            Self::OperatorBitwiseOrSecondOperandExpectedEvaluable { .. } => "E0346",
+            Self::OperatorBitwiseXorFirstOperandExpectedEvaluable { .. } => "E0347",
+            Self::OperatorBitwiseXorSecondOperandExpectedEvaluable { .. } => "E0348",
+            Self::OperatorBitwiseAndFirstOperandExpectedEvaluable { .. } => "E0349",
+            Self::OperatorBitwiseAndSecondOperandExpectedEvaluable { .. } => "E0350",
+            Self::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable { .. } => "E0351",
+            Self::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable { .. } => "E0352",
+            Self::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable { .. } => "E0353",
+            Self::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable { .. } => "E0354",
+            Self::OperatorAdditionFirstOperandExpectedEvaluable { .. } => "E0355",
+            Self::OperatorAdditionSecondOperandExpectedEvaluable { .. } => "E0356",
+            Self::OperatorSubtractionFirstOperandExpectedEvaluable { .. } => "E0357",
+            Self::OperatorSubtractionSecondOperandExpectedEvaluable { .. } => "E0358",
+            Self::OperatorMultiplicationFirstOperandExpectedEvaluable { .. } => "E0359",
+            Self::OperatorMultiplicationSecondOperandExpectedEvaluable { .. } => "E0360",
+            Self::OperatorDivisionFirstOperandExpectedEvaluable { .. } => "E0361",
+            Self::OperatorDivisionSecondOperandExpectedEvaluable { .. } => "E0362",
+            Self::OperatorRemainderFirstOperandExpectedEvaluable { .. } => "E0363",
+            Self::OperatorRemainderSecondOperandExpectedEvaluable { .. } => "E0364",
+            Self::OperatorExponentiationFirstOperandExpectedEvaluable { .. } => "E0365",
+            Self::OperatorExponentiationSecondOperandExpectedConstant { .. } => "E0366",
+            Self::OperatorCastingFirstOperandExpectedEvaluable { .. } => "E0367",
+            Self::OperatorCastingSecondOperandExpectedType { .. } => "E0368",
+            Self::OperatorNotExpectedEvaluable { .. } => "E0369",
+            Self::OperatorBitwiseNotExpectedEvaluable { .. } => "E0370",
+            Self::OperatorNegationExpectedEvaluable { .. } => "E0371",
+            Self::OperatorIndexFirstOperandExpectedPlaceOrEvaluable { .. } => "E0372",
+            Self::OperatorIndexSecondOperandExpectedEvaluable { .. } => "E0373",
+            Self::OperatorFieldFirstOperandExpectedPlaceOrEvaluable { .. } => "E0374",
+            Self::OperatorFieldSecondOperandExpectedIdentifier { .. } => "E0375",
+            Self::OperatorPathFirstOperandExpectedPath { .. } => "E0376",
+            Self::OperatorPathSecondOperandExpectedIdentifier { .. } => "E0377",
+            Self::Place(inner) => inner.code(),
+            Self::Value(inner) => inner.code(),
+            Self::Constant(inner) => inner.code(),
+            Self::Type(inner) => inner.code(),
+        }
+    }
+}
@@ -15,15 +15,22 @@ pub mod value;
 use std::convert::TryFrom;
 use std::fmt;
 
+use num_traits::Zero;
+
+use crate::semantic::casting::Caster;
 use crate::syntax::tree::identifier::Identifier;
 
 use self::access::Field as FieldAccess;
 use self::access::Index as IndexAccess;
+use self::constant::error::Error as ConstantError;
+use self::constant::integer::error::Error as IntegerConstantError;
 use self::constant::Constant;
 use self::error::Error;
 use self::path::Path;
 use self::place::Place;
 use self::r#type::Type;
+use self::value::error::Error as ValueError;
+use self::value::integer::Integer as IntegerValue;
 use self::value::Value;
 
 ///
@@ -182,13 +189,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_shift_left(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_shift_left(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
@@ -216,13 +223,13 @@ impl Element {
             Self::Place(place) => {
                 let value_1 = Value::try_from(&place.r#type).map_err(Error::Value)?;
                 match other {
-                    // Self::Value(value_2) => {
-                    //     value_1
-                    //         .bitwise_shift_right(value_2)
-                    //         .map(Self::Value)
-                    //         .map_err(Error::Value)?;
-                    //     Ok(place)
-                    // }
+                    Self::Value(value_2) => {
+                        value_1
+                            .bitwise_shift_right(value_2)
+                            .map(Self::Value)
+                            .map_err(Error::Value)?;
+                        Ok(place)
+                    }
                     Self::Constant(value_2) => {
                         value_1
                             .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
@@ -358,6 +365,7 @@ impl Element {
                         Ok(place)
                     }
                     Self::Constant(value_2) => {
+                        Self::check_divisor(&value_2, IntegerConstantError::ZeroDivision)?;
                         value_1
                             .divide(Value::try_from(value_2).map_err(Error::Value)?)
                             .map(Self::Value)
@@ -390,6 +398,7 @@ impl Element {
                         Ok(place)
                     }
                     Self::Constant(value_2) => {
+                        Self::check_divisor(&value_2, IntegerConstantError::ZeroRemainder)?;
                         value_1
                             .remainder(Value::try_from(value_2).map_err(Error::Value)?)
                             .map(Self::Value)
@@ -864,21 +873,30 @@ impl Element {
 
     pub fn bitwise_shift_left(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => Err(
-            //     Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-            //         found: element_2.to_string(),
-            //     },
-            // ),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_shift_left(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_shift_left(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => Err(
+                Error::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                },
+            ),
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_shift_left(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_shift_left(value_2)
                 .map(Self::Constant)
                 .map_err(Error::Constant),
             (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
+                Error::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
                 },
             ),
@@ -892,21 +910,30 @@ impl Element {
 
     pub fn bitwise_shift_right(self, other: Self) -> Result<Self, Error> {
         match (self, other) {
-            // (Element::Value(value_1), Element::Constant(value_2)) => value_1
-            //     .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
-            //     .map(Self::Value)
-            //     .map_err(Error::Value),
-            // (Element::Value(_), element_2) => Err(
-            //     Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-            //         found: element_2.to_string(),
-            //     },
-            // ),
+            (Element::Value(value_1), Element::Value(value_2)) => value_1
+                .bitwise_shift_right(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .bitwise_shift_right(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => Err(
+                Error::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+                    found: element_2.to_string(),
+                },
+            ),
+            (Element::Constant(value_1), Element::Value(value_2)) => Value::try_from(value_1)
+                .map_err(Error::Value)?
+                .bitwise_shift_right(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
             (Element::Constant(value_1), Element::Constant(value_2)) => value_1
                 .bitwise_shift_right(value_2)
                 .map(Self::Constant)
                 .map_err(Error::Constant),
             (Element::Constant(_), element_2) => Err(
-                Error::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
+                Error::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
                 },
             ),
@@ -1028,10 +1055,13 @@ impl Element {
                 .divide(value_2)
                 .map(Self::Value)
                 .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .divide(Value::try_from(value_2).map_err(Error::Value)?)
-                .map(Self::Value)
-                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => {
+                Self::check_divisor(&value_2, IntegerConstantError::ZeroDivision)?;
+                value_1
+                    .divide(Value::try_from(value_2).map_err(Error::Value)?)
+                    .map(Self::Value)
+                    .map_err(Error::Value)
+            }
             (Element::Value(_), element_2) => {
                 Err(Error::OperatorDivisionSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
@@ -1063,10 +1093,13 @@ impl Element {
                 .remainder(value_2)
                 .map(Self::Value)
                 .map_err(Error::Value),
-            (Element::Value(value_1), Element::Constant(value_2)) => value_1
-                .remainder(Value::try_from(value_2).map_err(Error::Value)?)
-                .map(Self::Value)
-                .map_err(Error::Value),
+            (Element::Value(value_1), Element::Constant(value_2)) => {
+                Self::check_divisor(&value_2, IntegerConstantError::ZeroRemainder)?;
+                value_1
+                    .remainder(Value::try_from(value_2).map_err(Error::Value)?)
+                    .map(Self::Value)
+                    .map_err(Error::Value)
+            }
             (Element::Value(_), element_2) => {
                 Err(Error::OperatorRemainderSecondOperandExpectedEvaluable {
                     found: element_2.to_string(),
@@ -1092,6 +1125,45 @@ impl Element {
         }
     }
 
+    ///
+    /// Checks if the constant divisor of a runtime value is not zero, since otherwise the
+    /// division fails only at the proving time.
+    ///
+    fn check_divisor(divisor: &Constant, error: IntegerConstantError) -> Result<(), Error> {
+        match divisor {
+            Constant::Integer(integer) if integer.value.is_zero() => {
+                Err(Error::Constant(ConstantError::Integer(error)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        match (self, other) {
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .exponentiate(Value::try_from(value_2).map_err(Error::Value)?)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => {
+                Err(Error::OperatorExponentiationSecondOperandExpectedConstant {
+                    found: element_2.to_string(),
+                })
+            }
+            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
+                .exponentiate(value_2)
+                .map(Self::Constant)
+                .map_err(Error::Constant),
+            (Element::Constant(_), element_2) => {
+                Err(Error::OperatorExponentiationSecondOperandExpectedConstant {
+                    found: element_2.to_string(),
+                })
+            }
+            (element_1, _) => Err(Error::OperatorExponentiationFirstOperandExpectedEvaluable {
+                found: element_1.to_string(),
+            }),
+        }
+    }
+
     pub fn cast(self, other: Self) -> Result<Self, Error> {
         let r#type = match other {
             Self::Type(r#type) => r#type,
@@ -1114,6 +1186,30 @@ impl Element {
         }
     }
 
+    ///
+    /// Casts the element implicitly, e.g. to the type annotation of a `let` statement.
+    ///
+    /// Unlike the `as` operator, the implicit casting does not convert between `bool` and
+    /// the integer types.
+    ///
+    pub fn cast_implicit(self, other: Self) -> Result<Self, Error> {
+        if let Self::Type(ref r#type) = other {
+            match self {
+                Element::Value(ref value) => Caster::cast_implicit(&value.r#type(), r#type)
+                    .map_err(ValueError::Casting)
+                    .map_err(Error::Value)?,
+                Element::Constant(ref constant) => {
+                    Caster::cast_implicit(&constant.r#type(), r#type)
+                        .map_err(ConstantError::Casting)
+                        .map_err(Error::Constant)?
+                }
+                _ => {}
+            }
+        }
+
+        self.cast(other)
+    }
+
     pub fn not(self) -> Result<Self, Error> {
         match self {
             Element::Value(value) => value.not().map(Self::Value).map_err(Error::Value),
@@ -1128,7 +1224,7 @@ impl Element {
 
     pub fn bitwise_not(self) -> Result<Self, Error> {
         match self {
-            // Element::Value(value) => value.bitwise_not().map(Self::Value).map_err(Error::Value),
+            Element::Value(value) => value.bitwise_not().map(Self::Value).map_err(Error::Value),
             Element::Constant(constant) => constant
                 .bitwise_not()
                 .map(Self::Constant)
@@ -1220,6 +1316,29 @@ impl Element {
         }
     }
 
+    ///
+    /// Joins the result of another branch of a conditional or match expression, one of which is
+    /// taken at runtime.
+    ///
+    /// The constant result is kept only if every branch yields the same constant. Otherwise, the
+    /// integer result is bounded by the greatest bound of the branches, and is unbounded if any of
+    /// the branches is.
+    ///
+    pub fn join_branch(&mut self, other: &Self) {
+        if let (Element::Constant(left), Element::Constant(right)) = (&*self, other) {
+            if left == right {
+                return;
+            }
+        }
+
+        if let (Some(mut left), Some(right)) =
+            (Self::runtime_integer(self), Self::runtime_integer(other))
+        {
+            left.join_bound(&right);
+            *self = Element::Value(Value::Integer(left));
+        }
+    }
+
     pub fn path(self, other: Self) -> Result<Self, Error> {
         let mut path = match self {
             Self::Path(path) => path,
@@ -1243,6 +1362,22 @@ impl Element {
 
         Ok(Self::Path(path))
     }
+
+    ///
+    /// Returns the integer value, which the element yields at runtime, including its bound.
+    ///
+    fn runtime_integer(element: &Self) -> Option<IntegerValue> {
+        match element {
+            Element::Value(Value::Integer(integer)) => Some(integer.to_owned()),
+            Element::Constant(constant @ Constant::Integer(_)) => {
+                match Value::try_from(constant.to_owned()) {
+                    Ok(Value::Integer(integer)) => Some(integer),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Element {
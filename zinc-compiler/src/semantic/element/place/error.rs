@@ -14,6 +14,10 @@ pub enum Error {
         name: String,
         reference: Option<Location>,
     },
+    MutatingImmutableElement {
+        name: String,
+        reference: Option<Location>,
+    },
 
     OperatorIndexFirstOperandExpectedArray {
         found: String,
@@ -28,6 +32,10 @@ pub enum Error {
         found: String,
     },
 
+    ArrayIndexOutOfRange {
+        index: String,
+        size: usize,
+    },
     ArraySliceStartOutOfRange {
         start: String,
     },
@@ -42,9 +50,30 @@ pub enum Error {
     TupleFieldDoesNotExist {
         type_identifier: String,
         field_index: usize,
+        field_count: usize,
     },
     StructureFieldDoesNotExist {
         type_identifier: String,
         field_name: String,
     },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::MutatingWithDifferentType { .. } => "E0401",
+            Self::MutatingImmutableMemory { .. } => "E0402",
+            Self::MutatingImmutableElement { .. } => "E0403",
+            Self::OperatorIndexFirstOperandExpectedArray { .. } => "E0404",
+            Self::OperatorIndexSecondOperandExpectedIntegerOrRange { .. } => "E0405",
+            Self::OperatorFieldFirstOperandExpectedTuple { .. } => "E0406",
+            Self::OperatorFieldFirstOperandExpectedStructure { .. } => "E0407",
+            Self::ArrayIndexOutOfRange { .. } => "E0408",
+            Self::ArraySliceStartOutOfRange { .. } => "E0409",
+            Self::ArraySliceEndOutOfRange { .. } => "E0410",
+            Self::ArraySliceEndLesserThanStart { .. } => "E0411",
+            Self::TupleFieldDoesNotExist { .. } => "E0412",
+            Self::StructureFieldDoesNotExist { .. } => "E0413",
+        }
+    }
+}
@@ -53,8 +53,10 @@ impl Place {
     }
 
     pub fn index(mut self, index_value: Element) -> Result<(Self, IndexAccess), Error> {
-        let (inner_type, array_size) = match self.r#type {
-            Type::Array { ref r#type, size } => (r#type.deref().to_owned(), r#type.size() * size),
+        let (inner_type, array_length, array_size) = match self.r#type {
+            Type::Array { ref r#type, size } => {
+                (r#type.deref().to_owned(), size, r#type.size() * size)
+            }
             ref r#type => {
                 return Err(Error::OperatorIndexFirstOperandExpectedArray {
                     found: r#type.to_string(),
@@ -71,7 +73,14 @@ impl Place {
 
                 Ok((self, access))
             }
-            Element::Constant(Constant::Integer(_integer)) => {
+            Element::Constant(Constant::Integer(integer)) => {
+                if integer.value.is_negative() || integer.value >= BigInt::from(array_length) {
+                    return Err(Error::ArrayIndexOutOfRange {
+                        index: integer.value.to_string(),
+                        size: array_length,
+                    });
+                }
+
                 self.r#type = inner_type;
 
                 let access = IndexAccess::new(inner_type_size, array_size);
@@ -173,6 +182,7 @@ impl Place {
                     return Err(Error::TupleFieldDoesNotExist {
                         type_identifier: self.r#type.to_string(),
                         field_index,
+                        field_count: types.len(),
                     });
                 }
 
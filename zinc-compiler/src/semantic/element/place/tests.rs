@@ -160,6 +160,120 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_mutating_immutable_element_index() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3];
+    array[0] = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 14),
+        ElementError::Place(PlaceError::MutatingImmutableElement {
+            name: "array".to_string(),
+            reference: Some(Location::new(3, 9)),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_immutable_element_field() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+fn main() {
+    let data = Data { value: 1 };
+    data.value = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 16),
+        ElementError::Place(PlaceError::MutatingImmutableElement {
+            name: "data".to_string(),
+            reference: Some(Location::new(7, 9)),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_index_out_of_range_read() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4, 5];
+    let value = array[5];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 22),
+        ElementError::Place(PlaceError::ArrayIndexOutOfRange {
+            index: BigInt::from(5).to_string(),
+            size: 5,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_index_out_of_range_write() {
+    let input = r#"
+fn main() {
+    let mut array = [1, 2, 3, 4, 5];
+    array[10] = 1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 10),
+        ElementError::Place(PlaceError::ArrayIndexOutOfRange {
+            index: BigInt::from(10).to_string(),
+            size: 5,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_index_out_of_range_nested() {
+    let input = r#"
+fn main() {
+    let array = [[1, 2], [3, 4]];
+    let value = array[1][2];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 25),
+        ElementError::Place(PlaceError::ArrayIndexOutOfRange {
+            index: BigInt::from(2).to_string(),
+            size: 2,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_slice_start_out_of_range() {
     let input = r#"
@@ -240,6 +354,7 @@ fn main() {
             type_identifier: Type::tuple(vec![Type::integer_unsigned(crate::BITLENGTH_BYTE); 3])
                 .to_string(),
             field_index: 5,
+            field_count: 3,
         }),
     )));
 
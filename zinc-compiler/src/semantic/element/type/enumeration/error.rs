@@ -0,0 +1,22 @@
+//!
+//! The semantic analyzer enumeration type element error.
+//!
+
+use crate::lexical::token::location::Location;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    DuplicateVariant {
+        type_identifier: String,
+        variant_name: String,
+        reference: Location,
+    },
+}
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateVariant { .. } => "E1801",
+        }
+    }
+}
@@ -2,23 +2,32 @@
 //! The semantic analyzer enumeration type element.
 //!
 
+mod tests;
+
+pub mod error;
+
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
 use std::rc::Rc;
 
 use num_bigint::BigInt;
+use num_traits::Signed;
 
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::variant::Variant;
 
+use self::error::Error as EnumerationTypeError;
+
 ///
 /// Describes an enumeration type.
 ///
@@ -26,10 +35,13 @@ use crate::syntax::tree::variant::Variant;
 /// and the implementation `scope`, which contains the enumeration variants and
 /// reference to its parent scope.
 ///
+/// The enumeration is signed if at least one of its variants is negative.
+///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     pub identifier: String,
     pub unique_id: usize,
+    pub is_signed: bool,
     pub bitlength: usize,
     pub values: Vec<BigInt>,
     pub scope: Rc<RefCell<Scope>>,
@@ -45,7 +57,23 @@ impl Enumeration {
         let scope = Rc::new(RefCell::new(Scope::new(scope_parent)));
 
         let mut variants_bigint = Vec::with_capacity(variants.len());
+        let mut variant_locations = HashMap::with_capacity(variants.len());
         for variant in variants.into_iter() {
+            if let Some(reference) = variant_locations
+                .insert(variant.identifier.name.clone(), variant.identifier.location)
+            {
+                return Err(Error::Element(
+                    variant.identifier.location,
+                    ElementError::Type(TypeError::Enumeration(
+                        EnumerationTypeError::DuplicateVariant {
+                            type_identifier: identifier.name,
+                            variant_name: variant.identifier.name,
+                            reference,
+                        },
+                    )),
+                ));
+            }
+
             let value = IntegerConstant::try_from(&variant.literal).map_err(|error| {
                 Error::Element(
                     variant.identifier.location,
@@ -58,10 +86,11 @@ impl Enumeration {
             .iter()
             .map(|variant| variant.1.to_owned())
             .collect();
+        let is_signed = bigints.iter().any(|value| value.is_negative());
 
         let minimal_bitlength = IntegerConstant::minimal_bitlength_bigints(
             bigints.iter().collect::<Vec<&BigInt>>().as_slice(),
-            false,
+            is_signed,
         )
         .map_err(|error| {
             Error::Element(
@@ -73,20 +102,18 @@ impl Enumeration {
         let mut enumeration = Self {
             identifier: identifier.name,
             unique_id,
+            is_signed,
             bitlength: minimal_bitlength,
             values: bigints,
             scope: scope.clone(),
         };
 
         for (identifier, value) in variants_bigint.into_iter() {
-            let location = identifier.location;
-
-            let mut constant = IntegerConstant::new(value, false, minimal_bitlength);
+            let mut constant = IntegerConstant::new(value, is_signed, minimal_bitlength);
 
             constant.set_enumeration(enumeration.clone());
 
-            Scope::declare_constant(scope.clone(), identifier, Constant::Integer(constant))
-                .map_err(|error| Error::Scope(location, error))?;
+            Scope::declare_variant(scope.clone(), identifier, Constant::Integer(constant));
         }
 
         scope
@@ -0,0 +1,42 @@
+//!
+//! The semantic analyzer enumeration type element tests.
+//!
+
+#![cfg(test)]
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn error_duplicate_variant() {
+    let input = r#"
+enum List {
+    First = 1,
+    Second = 2,
+    First = 3,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 5),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::DuplicateVariant {
+                type_identifier: "List".to_owned(),
+                variant_name: "First".to_owned(),
+                reference: Location::new(3, 5),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
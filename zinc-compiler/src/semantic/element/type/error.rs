@@ -2,6 +2,7 @@
 //! The semantic analyzer type error.
 //!
 
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 
@@ -12,4 +13,17 @@ pub enum Error {
 
     Function(FunctionTypeError),
     Structure(StructureTypeError),
+    Enumeration(EnumerationTypeError),
+}
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::AliasDoesNotPointToType { .. } => "E1301",
+            Self::AliasDoesNotPointToStructure { .. } => "E1302",
+            Self::Function(inner) => inner.code(),
+            Self::Structure(inner) => inner.code(),
+            Self::Enumeration(inner) => inner.code(),
+        }
+    }
 }
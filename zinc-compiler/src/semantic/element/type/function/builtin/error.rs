@@ -21,4 +21,12 @@ impl Error {
     pub fn debug_argument_count(expected: usize, found: usize) -> Self {
         Self::DebugArgumentCount { expected, found }
     }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Unknown { .. } => "E1601",
+            Self::SpecifierMissing { .. } => "E1602",
+            Self::DebugArgumentCount { .. } => "E1603",
+        }
+    }
 }
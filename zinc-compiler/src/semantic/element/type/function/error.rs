@@ -40,6 +40,9 @@ pub enum Error {
     NonCallable {
         name: String,
     },
+    ReturnTypeNotInferred {
+        function: String,
+    },
 
     BuiltIn(BuiltInFunctionTypeError),
     StandardLibrary(StandardLibraryFunctionTypeError),
@@ -109,4 +112,22 @@ impl Error {
     pub fn non_callable(name: String) -> Self {
         Self::NonCallable { name }
     }
+
+    pub fn return_type_not_inferred(function: String) -> Self {
+        Self::ReturnTypeNotInferred { function }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ArgumentCount { .. } => "E1501",
+            Self::ArgumentType { .. } => "E1502",
+            Self::ArgumentConstantness { .. } => "E1503",
+            Self::ArgumentNotEvaluable { .. } => "E1504",
+            Self::ReturnType { .. } => "E1505",
+            Self::NonCallable { .. } => "E1506",
+            Self::ReturnTypeNotInferred { .. } => "E1507",
+            Self::BuiltIn(inner) => inner.code(),
+            Self::StandardLibrary(inner) => inner.code(),
+        }
+    }
 }
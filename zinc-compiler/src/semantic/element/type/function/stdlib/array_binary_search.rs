@@ -0,0 +1,152 @@
+//!
+//! The semantic analyzer standard library `std::array::binary_search` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::stdlib::error::Error as StdlibError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_ARRAY: usize = 0;
+    pub const ARGUMENT_INDEX_KEY: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "binary_search",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, constants) = match element {
+                Element::Value(Value::Array(array)) => (
+                    array.r#type(),
+                    array.constants().map(|constants| constants.to_vec()),
+                ),
+                Element::Value(value) => (value.r#type(), None),
+                Element::Constant(constant) => (constant.r#type(), None),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, constants));
+        }
+
+        let (input_array_type, constants) = match actual_params.get(Self::ARGUMENT_INDEX_ARRAY) {
+            Some((Type::Array { r#type, .. }, Some(constants)))
+                if r#type.is_scalar_unsigned() || r#type.is_scalar_signed() =>
+            {
+                (r#type.deref().to_owned(), constants)
+            }
+            Some((r#type @ Type::Array { .. }, None)) => {
+                return Err(Error::argument_constantness(
+                    self.identifier.to_owned(),
+                    "array".to_owned(),
+                    Self::ARGUMENT_INDEX_ARRAY + 1,
+                    r#type.to_string(),
+                ))
+            }
+            Some((r#type, _constants)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "array".to_owned(),
+                    Self::ARGUMENT_INDEX_ARRAY + 1,
+                    "[{integer}; N]".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_KEY) {
+            Some((r#type, _constants)) if r#type == &input_array_type => {}
+            Some((r#type, _constants)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "key".to_owned(),
+                    Self::ARGUMENT_INDEX_KEY + 1,
+                    input_array_type.to_string(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        for (index, pair) in constants.windows(2).enumerate() {
+            if let (Constant::Integer(previous), Constant::Integer(next)) = (&pair[0], &pair[1]) {
+                if previous.value > next.value {
+                    return Err(Error::StandardLibrary(StdlibError::array_not_sorted(
+                        index,
+                        previous.value.to_string(),
+                        next.value.to_string(),
+                    )));
+                }
+            }
+        }
+
+        Ok(Type::tuple(vec![
+            Type::boolean(),
+            Type::integer_unsigned(crate::BITLENGTH_INDEX),
+            input_array_type,
+        ]))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::array::{}(array: [T; N], key: T) -> (bool, u64, T)",
+            self.identifier,
+        )
+    }
+}
@@ -4,9 +4,22 @@
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
-    ArrayTruncatingToBiggerSize { from: usize, to: usize },
-    ArrayPaddingToLesserSize { from: usize, to: usize },
-    ArrayNewLengthInvalid { value: String },
+    ArrayTruncatingToBiggerSize {
+        from: usize,
+        to: usize,
+    },
+    ArrayPaddingToLesserSize {
+        from: usize,
+        to: usize,
+    },
+    ArrayNewLengthInvalid {
+        value: String,
+    },
+    ArrayNotSorted {
+        index: usize,
+        previous: String,
+        next: String,
+    },
 }
 
 impl Error {
@@ -21,4 +34,21 @@ impl Error {
     pub fn array_new_length_invalid(value: String) -> Self {
         Self::ArrayNewLengthInvalid { value }
     }
+
+    pub fn array_not_sorted(index: usize, previous: String, next: String) -> Self {
+        Self::ArrayNotSorted {
+            index,
+            previous,
+            next,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ArrayTruncatingToBiggerSize { .. } => "E1701",
+            Self::ArrayPaddingToLesserSize { .. } => "E1702",
+            Self::ArrayNewLengthInvalid { .. } => "E1703",
+            Self::ArrayNotSorted { .. } => "E1704",
+        }
+    }
 }
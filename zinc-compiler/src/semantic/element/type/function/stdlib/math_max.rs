@@ -0,0 +1,117 @@
+//!
+//! The semantic analyzer standard library `std::math::max` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_FIRST: usize = 0;
+    pub const ARGUMENT_INDEX_SECOND: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "max",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        let result_type = match actual_params.get(Self::ARGUMENT_INDEX_FIRST) {
+            Some(r#type @ Type::IntegerUnsigned { .. }) => r#type.to_owned(),
+            Some(r#type @ Type::IntegerSigned { .. }) => r#type.to_owned(),
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "first".to_owned(),
+                    Self::ARGUMENT_INDEX_FIRST + 1,
+                    "{integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_SECOND) {
+            Some(r#type) if r#type == &result_type => {}
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "second".to_owned(),
+                    Self::ARGUMENT_INDEX_SECOND + 1,
+                    result_type.to_string(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(result_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::math::{}(first: T, second: T) -> T",
+            self.identifier,
+        )
+    }
+}
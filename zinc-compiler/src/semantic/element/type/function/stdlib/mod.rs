@@ -4,6 +4,7 @@
 
 mod tests;
 
+pub mod array_binary_search;
 pub mod array_pad;
 pub mod array_reverse;
 pub mod array_truncate;
@@ -16,6 +17,13 @@ pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod error;
 pub mod ff_invert;
+pub mod math_abs;
+pub mod math_max;
+pub mod math_min;
+pub mod ops_checked;
+pub mod ops_saturating;
+pub mod ops_wrapping;
+pub mod pow;
 
 use std::fmt;
 
@@ -25,6 +33,7 @@ use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 
+use self::array_binary_search::Function as ArrayBinarySearchFunction;
 use self::array_pad::Function as ArrayPadFunction;
 use self::array_reverse::Function as ArrayReverseFunction;
 use self::array_truncate::Function as ArrayTruncateFunction;
@@ -36,6 +45,13 @@ use self::crypto_pedersen::Function as PedersenFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
 use self::ff_invert::Function as FfInvertFunction;
+use self::math_abs::Function as MathAbsFunction;
+use self::math_max::Function as MathMaxFunction;
+use self::math_min::Function as MathMinFunction;
+use self::ops_checked::Function as OpsCheckedFunction;
+use self::ops_saturating::Function as OpsSaturatingFunction;
+use self::ops_wrapping::Function as OpsWrappingFunction;
+use self::pow::Function as PowFunction;
 
 #[derive(Debug, Clone)]
 pub enum Function {
@@ -51,8 +67,24 @@ pub enum Function {
     ArrayReverse(ArrayReverseFunction),
     ArrayTruncate(ArrayTruncateFunction),
     ArrayPad(ArrayPadFunction),
+    ArrayBinarySearch(ArrayBinarySearchFunction),
 
     FfInvert(FfInvertFunction),
+
+    MathMin(MathMinFunction),
+    MathMax(MathMaxFunction),
+    MathAbs(MathAbsFunction),
+
+    OpsWrappingAdd(OpsWrappingFunction),
+    OpsWrappingSub(OpsWrappingFunction),
+    OpsWrappingMul(OpsWrappingFunction),
+    OpsSaturatingAdd(OpsSaturatingFunction),
+    OpsSaturatingSub(OpsSaturatingFunction),
+    OpsSaturatingMul(OpsSaturatingFunction),
+    OpsCheckedAdd(OpsCheckedFunction),
+    OpsCheckedMul(OpsCheckedFunction),
+
+    Pow(PowFunction),
 }
 
 impl Function {
@@ -84,8 +116,42 @@ impl Function {
                 Self::ArrayTruncate(ArrayTruncateFunction::new(identifier))
             }
             BuiltinIdentifier::ArrayPad => Self::ArrayPad(ArrayPadFunction::new(identifier)),
+            BuiltinIdentifier::ArrayBinarySearch => {
+                Self::ArrayBinarySearch(ArrayBinarySearchFunction::new(identifier))
+            }
 
             BuiltinIdentifier::FieldInverse => Self::FfInvert(FfInvertFunction::new(identifier)),
+
+            BuiltinIdentifier::MathMin => Self::MathMin(MathMinFunction::new(identifier)),
+            BuiltinIdentifier::MathMax => Self::MathMax(MathMaxFunction::new(identifier)),
+            BuiltinIdentifier::MathAbs => Self::MathAbs(MathAbsFunction::new(identifier)),
+
+            BuiltinIdentifier::OpsWrappingAdd => {
+                Self::OpsWrappingAdd(OpsWrappingFunction::new(identifier, "wrapping_add"))
+            }
+            BuiltinIdentifier::OpsWrappingSub => {
+                Self::OpsWrappingSub(OpsWrappingFunction::new(identifier, "wrapping_sub"))
+            }
+            BuiltinIdentifier::OpsWrappingMul => {
+                Self::OpsWrappingMul(OpsWrappingFunction::new(identifier, "wrapping_mul"))
+            }
+            BuiltinIdentifier::OpsSaturatingAdd => {
+                Self::OpsSaturatingAdd(OpsSaturatingFunction::new(identifier, "saturating_add"))
+            }
+            BuiltinIdentifier::OpsSaturatingSub => {
+                Self::OpsSaturatingSub(OpsSaturatingFunction::new(identifier, "saturating_sub"))
+            }
+            BuiltinIdentifier::OpsSaturatingMul => {
+                Self::OpsSaturatingMul(OpsSaturatingFunction::new(identifier, "saturating_mul"))
+            }
+            BuiltinIdentifier::OpsCheckedAdd => {
+                Self::OpsCheckedAdd(OpsCheckedFunction::new(identifier, "checked_add"))
+            }
+            BuiltinIdentifier::OpsCheckedMul => {
+                Self::OpsCheckedMul(OpsCheckedFunction::new(identifier, "checked_mul"))
+            }
+
+            BuiltinIdentifier::Pow => Self::Pow(PowFunction::new(identifier)),
         }
     }
 
@@ -103,8 +169,26 @@ impl Function {
             Self::ArrayReverse(inner) => inner.call(elements),
             Self::ArrayTruncate(inner) => inner.call(elements),
             Self::ArrayPad(inner) => inner.call(elements),
+            Self::ArrayBinarySearch(inner) => inner.call(elements),
 
             Self::FfInvert(inner) => inner.call(elements),
+
+            Self::MathMin(inner) => inner.call(elements),
+            Self::MathMax(inner) => inner.call(elements),
+            Self::MathAbs(inner) => inner.call(elements),
+
+            Self::OpsWrappingAdd(inner) => inner.call(elements),
+            Self::OpsWrappingSub(inner) => inner.call(elements),
+            Self::OpsWrappingMul(inner) => inner.call(elements),
+            Self::OpsSaturatingAdd(inner) => inner.call(elements),
+            Self::OpsSaturatingSub(inner) => inner.call(elements),
+            Self::OpsSaturatingMul(inner) => inner.call(elements),
+            Self::OpsCheckedAdd(inner) => inner.call(elements),
+            Self::OpsCheckedMul(inner) => inner.call(elements),
+
+            Self::Pow(inner) => inner
+                .call(elements)
+                .map(|(return_type, _exponent_bitlength)| return_type),
         }
     }
 
@@ -122,8 +206,24 @@ impl Function {
             Self::ArrayReverse(inner) => inner.identifier(),
             Self::ArrayTruncate(inner) => inner.identifier(),
             Self::ArrayPad(inner) => inner.identifier(),
+            Self::ArrayBinarySearch(inner) => inner.identifier(),
 
             Self::FfInvert(inner) => inner.identifier(),
+
+            Self::MathMin(inner) => inner.identifier(),
+            Self::MathMax(inner) => inner.identifier(),
+            Self::MathAbs(inner) => inner.identifier(),
+
+            Self::OpsWrappingAdd(inner) => inner.identifier(),
+            Self::OpsWrappingSub(inner) => inner.identifier(),
+            Self::OpsWrappingMul(inner) => inner.identifier(),
+            Self::OpsSaturatingAdd(inner) => inner.identifier(),
+            Self::OpsSaturatingSub(inner) => inner.identifier(),
+            Self::OpsSaturatingMul(inner) => inner.identifier(),
+            Self::OpsCheckedAdd(inner) => inner.identifier(),
+            Self::OpsCheckedMul(inner) => inner.identifier(),
+
+            Self::Pow(inner) => inner.identifier(),
         }
     }
 
@@ -141,8 +241,24 @@ impl Function {
             Self::ArrayReverse(inner) => inner.builtin_identifier(),
             Self::ArrayTruncate(inner) => inner.builtin_identifier(),
             Self::ArrayPad(inner) => inner.builtin_identifier(),
+            Self::ArrayBinarySearch(inner) => inner.builtin_identifier(),
 
             Self::FfInvert(inner) => inner.builtin_identifier(),
+
+            Self::MathMin(inner) => inner.builtin_identifier(),
+            Self::MathMax(inner) => inner.builtin_identifier(),
+            Self::MathAbs(inner) => inner.builtin_identifier(),
+
+            Self::OpsWrappingAdd(inner) => inner.builtin_identifier(),
+            Self::OpsWrappingSub(inner) => inner.builtin_identifier(),
+            Self::OpsWrappingMul(inner) => inner.builtin_identifier(),
+            Self::OpsSaturatingAdd(inner) => inner.builtin_identifier(),
+            Self::OpsSaturatingSub(inner) => inner.builtin_identifier(),
+            Self::OpsSaturatingMul(inner) => inner.builtin_identifier(),
+            Self::OpsCheckedAdd(inner) => inner.builtin_identifier(),
+            Self::OpsCheckedMul(inner) => inner.builtin_identifier(),
+
+            Self::Pow(inner) => inner.builtin_identifier(),
         }
     }
 }
@@ -162,8 +278,24 @@ impl fmt::Display for Function {
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
             Self::ArrayPad(inner) => write!(f, "{}", inner),
+            Self::ArrayBinarySearch(inner) => write!(f, "{}", inner),
 
             Self::FfInvert(inner) => write!(f, "{}", inner),
+
+            Self::MathMin(inner) => write!(f, "{}", inner),
+            Self::MathMax(inner) => write!(f, "{}", inner),
+            Self::MathAbs(inner) => write!(f, "{}", inner),
+
+            Self::OpsWrappingAdd(inner) => write!(f, "{}", inner),
+            Self::OpsWrappingSub(inner) => write!(f, "{}", inner),
+            Self::OpsWrappingMul(inner) => write!(f, "{}", inner),
+            Self::OpsSaturatingAdd(inner) => write!(f, "{}", inner),
+            Self::OpsSaturatingSub(inner) => write!(f, "{}", inner),
+            Self::OpsSaturatingMul(inner) => write!(f, "{}", inner),
+            Self::OpsCheckedAdd(inner) => write!(f, "{}", inner),
+            Self::OpsCheckedMul(inner) => write!(f, "{}", inner),
+
+            Self::Pow(inner) => write!(f, "{}", inner),
         }
     }
 }
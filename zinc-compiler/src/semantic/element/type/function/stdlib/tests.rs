@@ -13,6 +13,7 @@ use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::stdlib::array_binary_search::Function as ArrayBinarySearchFunction;
 use crate::semantic::element::r#type::function::stdlib::array_pad::Function as ArrayPadFunction;
 use crate::semantic::element::r#type::function::stdlib::array_reverse::Function as ArrayReverseFunction;
 use crate::semantic::element::r#type::function::stdlib::array_truncate::Function as ArrayTruncateFunction;
@@ -25,6 +26,13 @@ use crate::semantic::element::r#type::function::stdlib::crypto_schnorr_signature
 use crate::semantic::element::r#type::function::stdlib::crypto_sha256::Function as CryptoSha256Function;
 use crate::semantic::element::r#type::function::stdlib::error::Error as StandardLibraryFunctionTypeError;
 use crate::semantic::element::r#type::function::stdlib::ff_invert::Function as FfInvertFunction;
+use crate::semantic::element::r#type::function::stdlib::math_abs::Function as MathAbsFunction;
+use crate::semantic::element::r#type::function::stdlib::math_max::Function as MathMaxFunction;
+use crate::semantic::element::r#type::function::stdlib::math_min::Function as MathMinFunction;
+use crate::semantic::element::r#type::function::stdlib::ops_checked::Function as OpsCheckedFunction;
+use crate::semantic::element::r#type::function::stdlib::ops_saturating::Function as OpsSaturatingFunction;
+use crate::semantic::element::r#type::function::stdlib::ops_wrapping::Function as OpsWrappingFunction;
+use crate::semantic::element::r#type::function::stdlib::pow::Function as PowFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
 use crate::semantic::error::Error as SemanticError;
@@ -1424,6 +1432,80 @@ fn main() -> [u8; 4] {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_array_binary_search_argument_1_array_expected_constant() {
+    let input = r#"
+fn main(key: u8) -> bool {
+    let table = [1, 2, 3, 4];
+    std::array::binary_search(table, key).0
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 30),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::argument_constantness(
+                "binary_search".to_owned(),
+                "array".to_owned(),
+                ArrayBinarySearchFunction::ARGUMENT_INDEX_ARRAY + 1,
+                Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 4).to_string(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_binary_search_argument_2_key_expected_array_element() {
+    let input = r#"
+const TABLE: [u8; 4] = [1, 2, 3, 4];
+
+fn main(key: u16) -> bool {
+    std::array::binary_search(TABLE, key).0
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 30),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "binary_search".to_owned(),
+            "key".to_owned(),
+            ArrayBinarySearchFunction::ARGUMENT_INDEX_KEY + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_binary_search_array_not_sorted() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 2, 5, 4, 8];
+
+fn main(key: u8) -> bool {
+    std::array::binary_search(TABLE, key).0
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 30),
+        ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+            StandardLibraryFunctionTypeError::array_not_sorted(2, "5".to_owned(), "4".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_ff_invert_argument_count_lesser() {
     let input = r#"
@@ -1491,3 +1573,215 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_math_min_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::math::min(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "min".to_owned(),
+            MathMinFunction::ARGUMENT_COUNT,
+            MathMinFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_min_argument_1_first_expected_integer() {
+    let input = r#"
+fn main() {
+    std::math::min(42 as field, 64 as field);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "min".to_owned(),
+            "first".to_owned(),
+            MathMinFunction::ARGUMENT_INDEX_FIRST + 1,
+            "{integer}".to_owned(),
+            Type::field().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_max_argument_2_second_expected_first_type() {
+    let input = r#"
+fn main() {
+    std::math::max(42 as u8, 64 as i8);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "max".to_owned(),
+            "second".to_owned(),
+            MathMaxFunction::ARGUMENT_INDEX_SECOND + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_abs_argument_1_value_expected_integer() {
+    let input = r#"
+fn main() {
+    std::math::abs(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "abs".to_owned(),
+            "value".to_owned(),
+            MathAbsFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{integer}".to_owned(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ops_wrapping_add_argument_1_left_expected_integer() {
+    let input = r#"
+fn main() {
+    std::ops::wrapping_add(true, false);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 27),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "wrapping_add".to_owned(),
+            "left".to_owned(),
+            OpsWrappingFunction::ARGUMENT_INDEX_LEFT + 1,
+            "{integer}".to_owned(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ops_saturating_mul_argument_2_right_expected_left_type() {
+    let input = r#"
+fn main() {
+    std::ops::saturating_mul(42 as u8, 64 as u16);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 29),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "saturating_mul".to_owned(),
+            "right".to_owned(),
+            OpsSaturatingFunction::ARGUMENT_INDEX_RIGHT + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ops_checked_add_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::ops::checked_add(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 26),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "checked_add".to_owned(),
+            OpsCheckedFunction::ARGUMENT_COUNT,
+            OpsCheckedFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_pow_argument_1_base_expected_integer() {
+    let input = r#"
+fn main() {
+    std::pow(true, 2);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 13),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "pow".to_owned(),
+            "base".to_owned(),
+            PowFunction::ARGUMENT_INDEX_BASE + 1,
+            "{integer}".to_owned(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_pow_argument_2_exponent_expected_unsigned_integer() {
+    let input = r#"
+fn main() {
+    std::pow(42 as u64, 2 as i8);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 13),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "pow".to_owned(),
+            "exponent".to_owned(),
+            PowFunction::ARGUMENT_INDEX_EXPONENT + 1,
+            "{unsigned integer}".to_owned(),
+            Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -38,6 +38,58 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_argument_count_too_few() {
+    let input = r#"
+fn another(a: u8, b: bool, c: u64) -> u8 {
+    42
+}
+
+fn main() {
+    let value = another(1, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "another".to_owned(),
+            3,
+            2,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_too_many() {
+    let input = r#"
+fn another(a: u8, b: bool, c: u64) -> u8 {
+    42
+}
+
+fn main() {
+    let value = another(1, true, 3, 4);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "another".to_owned(),
+            3,
+            4,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_argument_type() {
     let input = r#"
@@ -51,7 +103,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(7, 24),
+        Location::new(7, 25),
         ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
             "another".to_owned(),
             "x".to_owned(),
@@ -66,6 +118,34 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_argument_type_in_the_middle() {
+    let input = r#"
+fn another(a: u8, b: bool, c: u64) -> u8 {
+    42
+}
+
+fn main() {
+    let value = another(1, 2, 3);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 28),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "another".to_owned(),
+            "b".to_owned(),
+            2,
+            Type::boolean().to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_argument_constantness() {
     let input = r#"
@@ -150,6 +230,83 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_return_type_inferred() {
+    let input = r#"
+fn omitted() {
+    42
+}
+
+fn underscore() -> _ {
+    true
+}
+
+fn main() {
+    let value: u8 = omitted();
+    let flag: bool = underscore();
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_return_type_inferred_branch_types_mismatch() {
+    let input = r#"
+fn another(flag: bool) -> _ {
+    if flag { 42 } else { false }
+}
+
+fn main() {
+    let value = another(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::new(3, 27),
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::boolean().to_string(),
+            reference: Location::new(3, 15),
+            difference: None,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_type_not_inferred() {
+    let input = r#"
+fn another() -> _ {
+    fn nested() -> u8 {
+        another()
+    }
+
+    nested()
+}
+
+fn main() {
+    let value = another();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 16),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::return_type_not_inferred("another".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_non_callable_object() {
     let input = r#"
@@ -3,7 +3,6 @@
 //!
 
 use std::fmt;
-use std::ops::Deref;
 
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
@@ -16,7 +15,7 @@ pub struct Function {
     identifier: String,
     unique_id: usize,
     formal_params: Vec<(String, Type)>,
-    return_type: Box<Type>,
+    return_type: Option<Box<Type>>,
 }
 
 impl Function {
@@ -29,7 +28,26 @@ impl Function {
         Self {
             identifier,
             formal_params: arguments,
-            return_type: Box::new(return_type),
+            return_type: Some(Box::new(return_type)),
+            unique_id,
+        }
+    }
+
+    ///
+    /// Creates a function, whose return type is inferred from its body.
+    ///
+    /// The return type is set after the body is analyzed, so the function cannot be called
+    /// until then.
+    ///
+    pub fn new_inferred(
+        identifier: String,
+        unique_id: usize,
+        arguments: Vec<(String, Type)>,
+    ) -> Self {
+        Self {
+            identifier,
+            formal_params: arguments,
+            return_type: None,
             unique_id,
         }
     }
@@ -46,8 +64,12 @@ impl Function {
         self.formal_params.as_slice()
     }
 
-    pub fn return_type(&self) -> &Type {
-        self.return_type.deref()
+    pub fn return_type(&self) -> Option<&Type> {
+        self.return_type.as_deref()
+    }
+
+    pub fn set_return_type(&mut self, r#type: Type) {
+        self.return_type = Some(Box::new(r#type));
     }
 
     pub fn input_size(&self) -> usize {
@@ -58,10 +80,18 @@ impl Function {
     }
 
     pub fn output_size(&self) -> usize {
-        self.return_type.size()
+        self.return_type
+            .as_ref()
+            .map(|r#type| r#type.size())
+            .unwrap_or_default()
     }
 
     pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let return_type = match self.return_type {
+            Some(r#type) => *r#type,
+            None => return Err(Error::return_type_not_inferred(self.identifier)),
+        };
+
         let mut actual_params = Vec::with_capacity(actual_elements.len());
         for (index, element) in actual_elements.into_iter().enumerate() {
             let (r#type, is_constant) = match element {
@@ -78,30 +108,32 @@ impl Function {
             actual_params.push((r#type, is_constant));
         }
 
-        let formal_params_length = self.formal_params.len();
-        for (index, (name, r#type)) in self.formal_params.into_iter().enumerate() {
-            match actual_params.get(index) {
-                Some((actual_type, _is_constant)) if actual_type == &r#type => {}
-                Some((actual_type, _is_constant)) => {
-                    return Err(Error::argument_type(
-                        self.identifier.to_owned(),
-                        name,
-                        index + 1,
-                        r#type.to_string(),
-                        actual_type.to_string(),
-                    ))
-                }
-                None => {
-                    return Err(Error::argument_count(
-                        self.identifier.to_owned(),
-                        formal_params_length,
-                        actual_params.len(),
-                    ))
-                }
+        if actual_params.len() != self.formal_params.len() {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                self.formal_params.len(),
+                actual_params.len(),
+            ));
+        }
+
+        for (index, ((name, r#type), (actual_type, _is_constant))) in self
+            .formal_params
+            .into_iter()
+            .zip(actual_params.into_iter())
+            .enumerate()
+        {
+            if actual_type != r#type {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    name,
+                    index + 1,
+                    r#type.to_string(),
+                    actual_type.to_string(),
+                ));
             }
         }
 
-        Ok(*self.return_type)
+        Ok(return_type)
     }
 }
 
@@ -116,7 +148,10 @@ impl fmt::Display for Function {
                 .map(|(name, r#type)| format!("{}: {}", name, r#type))
                 .collect::<Vec<String>>()
                 .join(", "),
-            self.return_type,
+            match self.return_type {
+                Some(ref r#type) => r#type.to_string(),
+                None => "_".to_owned(),
+            },
         )
     }
 }
@@ -28,6 +28,7 @@ use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::builtin::BuiltInItems;
+use crate::semantic::scope::error::Error as ScopeError;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
@@ -53,6 +54,11 @@ lazy_static! {
     };
 }
 
+///
+/// The type name which is reported as a specific error, since the ranges are not first-class values.
+///
+const RANGE_IDENTIFIER: &str = "range";
+
 ///
 /// Describes a type.
 ///
@@ -233,7 +239,7 @@ impl Type {
         match self {
             Self::IntegerUnsigned { .. } => true,
             Self::Field => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(enumeration) => !enumeration.is_signed,
             _ => false,
         }
     }
@@ -241,6 +247,7 @@ impl Type {
     pub fn is_scalar_signed(&self) -> bool {
         match self {
             Self::IntegerSigned { .. } => true,
+            Self::Enumeration(enumeration) => enumeration.is_signed,
             _ => false,
         }
     }
@@ -266,6 +273,122 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the type values can be passed as the circuit input or output, that is,
+    /// the type does not contain the compile-time only strings, ranges, and functions.
+    ///
+    pub fn is_representable(&self) -> bool {
+        match self {
+            Self::String => false,
+            Self::Range { .. } => false,
+            Self::RangeInclusive { .. } => false,
+            Self::Function(_) => false,
+            Self::Array { r#type, .. } => r#type.is_representable(),
+            Self::Tuple { types } => types.iter().all(Self::is_representable),
+            Self::Structure(structure) => structure
+                .fields
+                .iter()
+                .all(|(_name, r#type)| r#type.is_representable()),
+            _ => true,
+        }
+    }
+
+    ///
+    /// Describes where the composite types diverge, e.g. 'field point.x: expected `u64`, found
+    /// `u8`', or how the array and tuple lengths differ.
+    ///
+    /// Returns `None` if the types are equal or differ at the top level only, which is already
+    /// clear from their names.
+    ///
+    pub fn difference(&self, other: &Self) -> Option<String> {
+        self.difference_at(other, "", "")
+    }
+
+    fn difference_at(&self, other: &Self, path: &str, kind: &str) -> Option<String> {
+        match (self, other) {
+            _ if self == other => None,
+            (
+                Self::Array {
+                    r#type: type_1,
+                    size: size_1,
+                },
+                Self::Array {
+                    r#type: type_2,
+                    size: size_2,
+                },
+            ) => {
+                if size_1 != size_2 {
+                    Some(Self::length_difference("array", path, *size_1, *size_2))
+                } else {
+                    type_1.difference_at(type_2, format!("{}[_]", path).as_str(), "array element")
+                }
+            }
+            (Self::Tuple { types: types_1 }, Self::Tuple { types: types_2 }) => {
+                if types_1.len() != types_2.len() {
+                    Some(Self::length_difference(
+                        "tuple",
+                        path,
+                        types_1.len(),
+                        types_2.len(),
+                    ))
+                } else {
+                    types_1.iter().zip(types_2.iter()).enumerate().find_map(
+                        |(index, (type_1, type_2))| {
+                            type_1.difference_at(
+                                type_2,
+                                Self::path_join(path, index.to_string().as_str()).as_str(),
+                                "tuple element",
+                            )
+                        },
+                    )
+                }
+            }
+            (Self::Structure(structure_1), Self::Structure(structure_2))
+                if structure_1
+                    .fields
+                    .iter()
+                    .map(|(name, _type)| name)
+                    .eq(structure_2.fields.iter().map(|(name, _type)| name)) =>
+            {
+                structure_1
+                    .fields
+                    .iter()
+                    .zip(structure_2.fields.iter())
+                    .find_map(|((name, type_1), (_name, type_2))| {
+                        type_1.difference_at(
+                            type_2,
+                            Self::path_join(path, name.as_str()).as_str(),
+                            "field",
+                        )
+                    })
+            }
+            _ if path.is_empty() => None,
+            _ => Some(format!(
+                "{} {}: expected `{}`, found `{}`",
+                kind, path, self, other
+            )),
+        }
+    }
+
+    fn length_difference(kind: &str, path: &str, expected: usize, found: usize) -> String {
+        if path.is_empty() {
+            format!("{} length: expected {}, found {}", kind, expected, found)
+        } else {
+            format!(
+                "{} {} length: expected {}, found {}",
+                kind, path, expected, found
+            )
+        }
+    }
+
+    fn path_join(path: &str, element: &str) -> String {
+        if path.is_empty() {
+            element.to_owned()
+        } else {
+            format!("{}.{}", path, element)
+        }
+    }
+
     pub fn from_type_variant(
         type_variant: &TypeVariant,
         scope: Rc<RefCell<Scope>>,
@@ -281,7 +404,7 @@ impl Type {
 
                 let size_location = size.location;
                 let size = match ExpressionAnalyzer::new(scope)
-                    .analyze(size.to_owned(), TranslationHint::Value)?
+                    .analyze(size.to_owned(), TranslationHint::Constant)?
                 {
                     (Element::Constant(Constant::Integer(integer)), _intermediate) => {
                         integer.to_usize().map_err(|error| {
@@ -310,11 +433,10 @@ impl Type {
             }
             TypeVariant::Alias { path } => {
                 let location = path.location;
-                match ExpressionAnalyzer::new(scope)
-                    .analyze(path.to_owned(), TranslationHint::Type)?
+                match ExpressionAnalyzer::new(scope).analyze(path.to_owned(), TranslationHint::Type)
                 {
-                    (Element::Type(r#type), _intermediate) => r#type,
-                    (element, _intermediate) => {
+                    Ok((Element::Type(r#type), _intermediate)) => r#type,
+                    Ok((element, _intermediate)) => {
                         return Err(Error::Element(
                             location,
                             ElementError::Type(TypeError::AliasDoesNotPointToType {
@@ -322,6 +444,12 @@ impl Type {
                             }),
                         ));
                     }
+                    Err(Error::Scope(_location, ScopeError::ItemUndeclared { ref name, .. }))
+                        if name == RANGE_IDENTIFIER =>
+                    {
+                        return Err(Error::RangeUsedAsType { location });
+                    }
+                    Err(error) => return Err(error),
                 }
             }
         })
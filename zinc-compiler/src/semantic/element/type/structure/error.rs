@@ -2,10 +2,21 @@
 //! The semantic analyzer structure type element error.
 //!
 
+use crate::lexical::token::location::Location;
+
 #[derive(Debug, PartialEq)]
 pub enum Error {
     DuplicateField {
         type_identifier: String,
         field_name: String,
+        reference: Location,
     },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateField { .. } => "E1401",
+        }
+    }
+}
@@ -30,6 +30,7 @@ fn main() -> u8 {
         ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
             type_identifier: "Data".to_owned(),
             field_name: "b".to_owned(),
+            reference: Location::new(4, 5),
         })),
     )));
 
@@ -37,3 +38,25 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_field_named_as_item() {
+    let input = r#"
+const x: u8 = 1;
+
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() -> u8 {
+    let y = 2;
+    let point = Point { x: x, y: y };
+    point.x
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
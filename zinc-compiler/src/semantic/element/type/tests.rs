@@ -65,3 +65,54 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_range_used_as_type_argument() {
+    let input = r#"
+fn sum(range: range) -> u64 {
+    0
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsType {
+        location: Location::new(2, 15),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_range_used_as_type_let() {
+    let input = r#"
+fn main() {
+    let bounds: range = 0..10;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::RangeUsedAsType {
+        location: Location::new(3, 17),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_range_alias_declared() {
+    let input = r#"
+type range = u8;
+
+fn main(value: range) -> range {
+    value
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
@@ -5,7 +5,20 @@
 #[derive(Debug, PartialEq)]
 pub enum Error {
     PushingInvalidType { expected: String, found: String },
+    IndexOutOfRange { index: String, size: usize },
     SliceStartOutOfRange { start: String },
     SliceEndOutOfRange { end: String, size: usize },
     SliceEndLesserThanStart { start: String, end: String },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::PushingInvalidType { .. } => "E0701",
+            Self::IndexOutOfRange { .. } => "E0702",
+            Self::SliceStartOutOfRange { .. } => "E0703",
+            Self::SliceEndOutOfRange { .. } => "E0704",
+            Self::SliceEndLesserThanStart { .. } => "E0705",
+        }
+    }
+}
@@ -15,6 +15,8 @@ use num_traits::Signed;
 use num_traits::ToPrimitive;
 
 use crate::semantic::element::access::Index as IndexAccess;
+use crate::semantic::element::constant::array::Array as ConstantArray;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 
@@ -23,10 +25,14 @@ use self::error::Error;
 ///
 /// Arrays are collections of elements of the same type.
 ///
+/// If all the elements are known at compile time, they are kept in `constants`, so the array
+/// can be declared as a constant or passed to a function which requires a constant array.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Array {
     r#type: Type,
     size: usize,
+    constants: Option<Vec<Constant>>,
 }
 
 impl Default for Array {
@@ -34,13 +40,36 @@ impl Default for Array {
         Self {
             r#type: Type::Unit,
             size: 0,
+            constants: None,
         }
     }
 }
 
 impl Array {
     pub fn new(r#type: Type, size: usize) -> Self {
-        Self { r#type, size }
+        Self {
+            r#type,
+            size,
+            constants: None,
+        }
+    }
+
+    pub fn set_constants(&mut self, constants: Vec<Constant>) {
+        self.constants = Some(constants);
+    }
+
+    pub fn constants(&self) -> Option<&[Constant]> {
+        self.constants.as_deref()
+    }
+
+    ///
+    /// Converts the array to a constant one, if all its elements are known at compile time.
+    ///
+    pub fn try_into_constant(self) -> Result<ConstantArray, Self> {
+        match self.constants {
+            Some(constants) => Ok(ConstantArray::new(self.r#type, constants)),
+            None => Err(self),
+        }
     }
 
     pub fn r#type(&self) -> Type {
@@ -96,6 +125,17 @@ impl Array {
         )
     }
 
+    pub fn slice_constant(self, index: BigInt) -> Result<(Value, IndexAccess), Error> {
+        if index.is_negative() || index >= BigInt::from(self.size) {
+            return Err(Error::IndexOutOfRange {
+                index: index.to_string(),
+                size: self.size,
+            });
+        }
+
+        Ok(self.slice_single())
+    }
+
     pub fn slice_range(self, start: BigInt, end: BigInt) -> Result<(Value, IndexAccess), Error> {
         if start.is_negative() {
             return Err(Error::SliceStartOutOfRange {
@@ -180,6 +220,19 @@ impl Array {
     }
 }
 
+impl From<ConstantArray> for Array {
+    fn from(array: ConstantArray) -> Self {
+        let r#type = array.element_type().to_owned();
+        let size = array.len();
+
+        Self {
+            r#type,
+            size,
+            constants: Some(array.into_values()),
+        }
+    }
+}
+
 impl fmt::Display for Array {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "<array> of '{}'s", self.r#type)
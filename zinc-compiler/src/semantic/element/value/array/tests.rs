@@ -35,6 +35,78 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_pushing_invalid_type_after_literals() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, true];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 24),
+        ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType {
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::boolean().to_string(),
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_empty_without_type() {
+    let input = r#"
+fn main() {
+    let array = [];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ArrayEmptyWithoutType {
+        location: Location::new(3, 17),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_empty_with_type() {
+    let input = r#"
+fn main() {
+    let array: [u8; 0] = [];
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_index_out_of_range() {
+    let input = r#"
+fn main() {
+    [1, 2, 3, 4, 5][5];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 20),
+        ElementError::Value(ValueError::Array(ArrayValueError::IndexOutOfRange {
+            index: BigInt::from(5).to_string(),
+            size: 5,
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_slice_start_out_of_range() {
     let input = r#"
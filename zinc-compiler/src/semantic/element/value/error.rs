@@ -22,12 +22,12 @@ pub enum Error {
     OperatorEqualsSecondOperandExpectedUnit { found: String },
     OperatorEqualsSecondOperandExpectedBoolean { found: String },
     OperatorEqualsSecondOperandExpectedInteger { found: String },
-    OperatorEqualsFirstOperandExpectedPrimitiveType { found: String },
+    OperatorEqualsTypesMismatch { first: String, second: String },
 
     OperatorNotEqualsSecondOperandExpectedUnit { found: String },
     OperatorNotEqualsSecondOperandExpectedBoolean { found: String },
     OperatorNotEqualsSecondOperandExpectedInteger { found: String },
-    OperatorNotEqualsFirstOperandExpectedPrimitiveType { found: String },
+    OperatorNotEqualsTypesMismatch { first: String, second: String },
 
     OperatorGreaterEqualsFirstOperandExpectedInteger { found: String },
     OperatorGreaterEqualsSecondOperandExpectedInteger { found: String },
@@ -71,6 +71,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedInteger { found: String },
     OperatorRemainderSecondOperandExpectedInteger { found: String },
 
+    OperatorExponentiationFirstOperandExpectedInteger { found: String },
+    OperatorExponentiationSecondOperandExpectedInteger { found: String },
+
     OperatorNotExpectedBoolean { found: String },
 
     OperatorBitwiseNotExpectedInteger { found: String },
@@ -89,3 +92,66 @@ pub enum Error {
     Structure(StructureValueError),
     Casting(CastingError),
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::OperatorOrFirstOperandExpectedBoolean { .. } => "E0501",
+            Self::OperatorOrSecondOperandExpectedBoolean { .. } => "E0502",
+            Self::OperatorXorFirstOperandExpectedBoolean { .. } => "E0503",
+            Self::OperatorXorSecondOperandExpectedBoolean { .. } => "E0504",
+            Self::OperatorAndFirstOperandExpectedBoolean { .. } => "E0505",
+            Self::OperatorAndSecondOperandExpectedBoolean { .. } => "E0506",
+            Self::OperatorEqualsSecondOperandExpectedUnit { .. } => "E0507",
+            Self::OperatorEqualsSecondOperandExpectedBoolean { .. } => "E0508",
+            Self::OperatorEqualsSecondOperandExpectedInteger { .. } => "E0509",
+            Self::OperatorEqualsTypesMismatch { .. } => "E0510",
+            Self::OperatorNotEqualsSecondOperandExpectedUnit { .. } => "E0511",
+            Self::OperatorNotEqualsSecondOperandExpectedBoolean { .. } => "E0512",
+            Self::OperatorNotEqualsSecondOperandExpectedInteger { .. } => "E0513",
+            Self::OperatorNotEqualsTypesMismatch { .. } => "E0514",
+            Self::OperatorGreaterEqualsFirstOperandExpectedInteger { .. } => "E0515",
+            Self::OperatorGreaterEqualsSecondOperandExpectedInteger { .. } => "E0516",
+            Self::OperatorLesserEqualsFirstOperandExpectedInteger { .. } => "E0517",
+            Self::OperatorLesserEqualsSecondOperandExpectedInteger { .. } => "E0518",
+            Self::OperatorGreaterFirstOperandExpectedInteger { .. } => "E0519",
+            Self::OperatorGreaterSecondOperandExpectedInteger { .. } => "E0520",
+            Self::OperatorLesserFirstOperandExpectedInteger { .. } => "E0521",
+            Self::OperatorLesserSecondOperandExpectedInteger { .. } => "E0522",
+            Self::OperatorBitwiseOrFirstOperandExpectedInteger { .. } => "E0523",
+            Self::OperatorBitwiseOrSecondOperandExpectedInteger { .. } => "E0524",
+            Self::OperatorBitwiseXorFirstOperandExpectedInteger { .. } => "E0525",
+            Self::OperatorBitwiseXorSecondOperandExpectedInteger { .. } => "E0526",
+            Self::OperatorBitwiseAndFirstOperandExpectedInteger { .. } => "E0527",
+            Self::OperatorBitwiseAndSecondOperandExpectedInteger { .. } => "E0528",
+            Self::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { .. } => "E0529",
+            Self::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { .. } => "E0530",
+            Self::OperatorBitwiseShiftRightFirstOperandExpectedInteger { .. } => "E0531",
+            Self::OperatorBitwiseShiftRightSecondOperandExpectedInteger { .. } => "E0532",
+            Self::OperatorAdditionFirstOperandExpectedInteger { .. } => "E0533",
+            Self::OperatorAdditionSecondOperandExpectedInteger { .. } => "E0534",
+            Self::OperatorSubtractionFirstOperandExpectedInteger { .. } => "E0535",
+            Self::OperatorSubtractionSecondOperandExpectedInteger { .. } => "E0536",
+            Self::OperatorMultiplicationFirstOperandExpectedInteger { .. } => "E0537",
+            Self::OperatorMultiplicationSecondOperandExpectedInteger { .. } => "E0538",
+            Self::OperatorDivisionFirstOperandExpectedInteger { .. } => "E0539",
+            Self::OperatorDivisionSecondOperandExpectedInteger { .. } => "E0540",
+            Self::OperatorRemainderFirstOperandExpectedInteger { .. } => "E0541",
+            Self::OperatorRemainderSecondOperandExpectedInteger { .. } => "E0542",
+            Self::OperatorExponentiationFirstOperandExpectedInteger { .. } => "E0543",
+            Self::OperatorExponentiationSecondOperandExpectedInteger { .. } => "E0544",
+            Self::OperatorNotExpectedBoolean { .. } => "E0545",
+            Self::OperatorBitwiseNotExpectedInteger { .. } => "E0546",
+            Self::OperatorNegationExpectedInteger { .. } => "E0547",
+            Self::OperatorIndexFirstOperandExpectedArray { .. } => "E0548",
+            Self::OperatorIndexSecondOperandExpectedIntegerOrRange { .. } => "E0549",
+            Self::OperatorFieldFirstOperandExpectedTuple { .. } => "E0550",
+            Self::OperatorFieldFirstOperandExpectedStructure { .. } => "E0551",
+            Self::Integer(inner) => inner.code(),
+            Self::Array(inner) => inner.code(),
+            Self::Tuple(inner) => inner.code(),
+            Self::Structure(inner) => inner.code(),
+            Self::Casting(inner) => inner.code(),
+        }
+    }
+}
@@ -21,9 +21,40 @@ pub enum Error {
 
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found: String },
     OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found: String },
+    OperatorExponentiationSecondOperatorExpectedUnsigned { found: String },
 
     ForbiddenFieldDivision,
     ForbiddenFieldRemainder,
     ForbiddenFieldBitwise,
+    ForbiddenSignedBitwiseNot,
     ForbiddenFieldNegation,
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TypesMismatchEquals { .. } => "E0601",
+            Self::TypesMismatchNotEquals { .. } => "E0602",
+            Self::TypesMismatchGreaterEquals { .. } => "E0603",
+            Self::TypesMismatchLesserEquals { .. } => "E0604",
+            Self::TypesMismatchGreater { .. } => "E0605",
+            Self::TypesMismatchLesser { .. } => "E0606",
+            Self::TypesMismatchBitwiseOr { .. } => "E0607",
+            Self::TypesMismatchBitwiseXor { .. } => "E0608",
+            Self::TypesMismatchBitwiseAnd { .. } => "E0609",
+            Self::TypesMismatchAddition { .. } => "E0610",
+            Self::TypesMismatchSubtraction { .. } => "E0611",
+            Self::TypesMismatchMultiplication { .. } => "E0612",
+            Self::TypesMismatchDivision { .. } => "E0613",
+            Self::TypesMismatchRemainder { .. } => "E0614",
+            Self::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { .. } => "E0615",
+            Self::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { .. } => "E0616",
+            Self::OperatorExponentiationSecondOperatorExpectedUnsigned { .. } => "E0617",
+            Self::ForbiddenFieldDivision => "E0618",
+            Self::ForbiddenFieldRemainder => "E0619",
+            Self::ForbiddenFieldBitwise => "E0620",
+            Self::ForbiddenSignedBitwiseNot => "E0621",
+            Self::ForbiddenFieldNegation => "E0622",
+        }
+    }
+}
@@ -8,6 +8,9 @@ pub mod error;
 
 use std::fmt;
 
+use num_bigint::BigInt;
+use num_traits::One;
+
 use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::Type;
 
@@ -18,11 +21,16 @@ use self::error::Error;
 /// If a value belongs to an enumeration, the enumeration type is stored in `enumeration`.
 /// Enumeration uniquely defines the value type, even if the sign and bitlength are the same.
 ///
+/// If an unsigned value is known to be less than its type maximum, e.g. `x as u16` where `x`
+/// is `u8`, the upper bound is stored in `bound`. The sums and products of such values, which
+/// are proven to fit into their type, are not range checked at runtime.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Integer {
     pub is_signed: bool,
     pub bitlength: usize,
     pub enumeration: Option<Enumeration>,
+    pub bound: Option<BigInt>,
 }
 
 impl Integer {
@@ -31,6 +39,7 @@ impl Integer {
             is_signed,
             bitlength,
             enumeration: None,
+            bound: None,
         }
     }
 
@@ -38,6 +47,24 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    ///
+    /// Sets the upper bound, if the value is unsigned and the bound is less than the type maximum.
+    ///
+    pub fn set_bound(&mut self, bound: BigInt) {
+        self.bound = self.fitting(Some(bound));
+    }
+
+    ///
+    /// Joins the bound of a value, which is taken instead of this one at runtime, e.g. by another
+    /// conditional branch. The result is unbounded if any of the values is.
+    ///
+    pub fn join_bound(&mut self, other: &Self) {
+        self.bound = match (self.bound.take(), other.bound.as_ref()) {
+            (Some(left), Some(right)) => Some(std::cmp::max(left, right.to_owned())),
+            _ => None,
+        };
+    }
+
     pub fn r#type(&self) -> Type {
         match self.enumeration {
             Some(ref enumeration) => Type::Enumeration(enumeration.to_owned()),
@@ -133,7 +160,7 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
     pub fn bitwise_xor(self, other: Self) -> Result<Self, Error> {
@@ -148,7 +175,7 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
     pub fn bitwise_and(self, other: Self) -> Result<Self, Error> {
@@ -163,7 +190,7 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
     pub fn bitwise_shift_left(self, other: Self) -> Result<Self, Error> {
@@ -173,13 +200,13 @@ impl Integer {
 
         if other.is_signed {
             return Err(
-                Error::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                Error::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
                     found: other.to_string(),
                 },
             );
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
     pub fn bitwise_shift_right(self, other: Self) -> Result<Self, Error> {
@@ -195,10 +222,10 @@ impl Integer {
             );
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
-    pub fn add(self, other: Self) -> Result<Self, Error> {
+    pub fn add(mut self, other: Self) -> Result<Self, Error> {
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchAddition {
                 first: self.r#type().to_string(),
@@ -206,6 +233,9 @@ impl Integer {
             });
         }
 
+        let bound = self.bound_of(&other, |left, right| left + right);
+        self.bound = self.fitting(bound);
+
         Ok(self)
     }
 
@@ -217,10 +247,10 @@ impl Integer {
             });
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
-    pub fn multiply(self, other: Self) -> Result<Self, Error> {
+    pub fn multiply(mut self, other: Self) -> Result<Self, Error> {
         if !self.has_the_same_type_as(&other) {
             return Err(Error::TypesMismatchMultiplication {
                 first: self.r#type().to_string(),
@@ -228,6 +258,9 @@ impl Integer {
             });
         }
 
+        let bound = self.bound_of(&other, |left, right| left * right);
+        self.bound = self.fitting(bound);
+
         Ok(self)
     }
 
@@ -243,7 +276,7 @@ impl Integer {
             return Err(Error::ForbiddenFieldDivision);
         }
 
-        Ok(self)
+        Ok(self.unbounded())
     }
 
     pub fn remainder(self, other: Self) -> Result<Self, Error> {
@@ -258,13 +291,27 @@ impl Integer {
             return Err(Error::ForbiddenFieldRemainder);
         }
 
-        Ok(self)
+        Ok(self.unbounded())
+    }
+
+    pub fn pow(self, other: Self) -> Result<Self, Error> {
+        if other.is_signed {
+            return Err(
+                Error::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                    found: other.to_string(),
+                },
+            );
+        }
+
+        Ok(self.unbounded())
     }
 
     pub fn cast(mut self, is_signed: bool, bitlength: usize) -> Result<Self, Error> {
+        let bound = self.upper_bound();
         self.is_signed = is_signed;
         self.bitlength = bitlength;
         self.enumeration = None;
+        self.bound = self.fitting(bound);
 
         Ok(self)
     }
@@ -274,7 +321,11 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
-        Ok(self)
+        if self.is_signed {
+            return Err(Error::ForbiddenSignedBitwiseNot);
+        }
+
+        Ok(self.unbounded())
     }
 
     pub fn negate(mut self) -> Result<Self, Error> {
@@ -284,7 +335,48 @@ impl Integer {
 
         self.is_signed = true;
 
-        Ok(self)
+        Ok(self.unbounded())
+    }
+
+    ///
+    /// Returns the `bound`, if it fits into the unsigned type.
+    ///
+    fn fitting(&self, bound: Option<BigInt>) -> Option<BigInt> {
+        let maximum = self.maximum()?;
+        bound.filter(|bound| bound <= &maximum)
+    }
+
+    ///
+    /// The upper bound of an unsigned value, which is the type maximum if nothing is known.
+    ///
+    fn upper_bound(&self) -> Option<BigInt> {
+        self.bound.clone().or_else(|| self.maximum())
+    }
+
+    ///
+    /// The maximum of the unsigned type. The signed and field values are never bounded.
+    ///
+    fn maximum(&self) -> Option<BigInt> {
+        if self.is_signed || self.bitlength == crate::BITLENGTH_FIELD {
+            return None;
+        }
+
+        Some((BigInt::one() << self.bitlength) - BigInt::one())
+    }
+
+    fn bound_of<F>(&self, other: &Self, operation: F) -> Option<BigInt>
+    where
+        F: FnOnce(BigInt, BigInt) -> BigInt,
+    {
+        match (self.upper_bound(), other.upper_bound()) {
+            (Some(left), Some(right)) => Some(operation(left, right)),
+            _ => None,
+        }
+    }
+
+    fn unbounded(mut self) -> Self {
+        self.bound = None;
+        self
     }
 }
 
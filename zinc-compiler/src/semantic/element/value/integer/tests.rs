@@ -6,6 +6,8 @@
 
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
@@ -63,6 +65,148 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_types_mismatch_equals_enumeration_integer() {
+    let input = r#"
+enum List {
+    First = 1,
+    Second = 2,
+}
+
+fn main(integer: u8) {
+    let variant = List::First;
+    let value = variant == integer;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 25),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::TypesMismatchEquals {
+                first: "enum List".to_owned(),
+                second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_types_mismatch_equals_enumerations() {
+    let input = r#"
+enum List {
+    First = 1,
+    Second = 2,
+}
+
+enum Other {
+    First = 1,
+    Second = 2,
+}
+
+fn main() {
+    let variant = List::First;
+    let value = variant == Other::First;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(14, 25),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::TypesMismatchEquals {
+                first: "enum List".to_owned(),
+                second: "enum Other".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_equals_enumeration() {
+    let input = r#"
+enum List {
+    First = 1,
+    Second = 2,
+}
+
+fn main() -> (bool, bool) {
+    let variant = List::First;
+    (variant == List::First, variant != List::Second)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_types_mismatch_greater_equals_enumerations() {
+    let input = r#"
+enum List {
+    First = 1,
+    Second = 2,
+}
+
+enum Other {
+    First = 1,
+    Second = 2,
+}
+
+fn main() {
+    let variant = List::First;
+    let value = variant >= Other::First;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(14, 25),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::TypesMismatchGreaterEquals {
+                first: "enum List".to_owned(),
+                second: "enum Other".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_ordering_enumeration() {
+    let input = r#"
+enum State {
+    Idle = 0,
+    Running = 1,
+    Done = 2,
+}
+
+fn main() -> (bool, bool, bool, bool, u8) {
+    let state = State::Running;
+    (
+        state >= State::Running,
+        state > State::Idle,
+        state <= State::Done,
+        state < State::Done,
+        state as u8,
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn error_types_mismatch_greater_equals() {
     let input = r#"
@@ -366,7 +510,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_operator_bitwise_shift_left_2nd_operand_expected_unsigned() {
     let input = r#"
@@ -390,7 +533,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_operator_bitwise_shift_right_2nd_operand_expected_unsigned() {
     let input = r#"
@@ -638,7 +780,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_forbidden_field_bitwise_shift_left() {
     let input = r#"
@@ -660,7 +801,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_forbidden_field_bitwise_shift_right() {
     let input = r#"
@@ -682,7 +822,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_forbidden_field_bitwise_not() {
     let input = r#"
@@ -704,6 +843,27 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_forbidden_signed_bitwise_not() {
+    let input = r#"
+fn main() {
+    let value: i8 = 42;
+    let value = ~value;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 17),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::ForbiddenSignedBitwiseNot,
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_forbidden_field_negation() {
     let input = r#"
@@ -724,3 +884,85 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_zero_division_constant_divisor() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    value / 0
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 13),
+        ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_remainder_folded_divisor() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    value % (2 - 2)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 14),
+        ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_division_assignment() {
+    let input = r#"
+fn main(mut value: u8) -> u8 {
+    value /= 0;
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 14),
+        ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_division_runtime_divisor() {
+    let input = r#"
+fn main(value: u8, divisor: u8) -> u8 {
+    value / divisor
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_bitwise_shift_runtime() {
+    let input = r#"
+fn main(mut value: u8, amount: u8) -> u8 {
+    value <<= amount;
+    (value >> 1) << amount
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
@@ -125,9 +125,16 @@ impl Value {
             (Self::Integer(_), value_2) => Err(Error::OperatorEqualsSecondOperandExpectedInteger {
                 found: value_2.r#type().to_string(),
             }),
-            (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                found: value_1.r#type().to_string(),
-            }),
+            (value_1, value_2) => {
+                if !value_1.has_the_same_type_as(&value_2) {
+                    return Err(Error::OperatorEqualsTypesMismatch {
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    });
+                }
+
+                Ok(Self::Boolean)
+            }
         }
     }
 
@@ -150,9 +157,16 @@ impl Value {
                     found: value_2.r#type().to_string(),
                 })
             }
-            (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                found: value_1.r#type().to_string(),
-            }),
+            (value_1, value_2) => {
+                if !value_1.has_the_same_type_as(&value_2) {
+                    return Err(Error::OperatorNotEqualsTypesMismatch {
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    });
+                }
+
+                Ok(Self::Boolean)
+            }
         }
     }
 
@@ -400,6 +414,23 @@ impl Value {
         }
     }
 
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        match self {
+            Self::Integer(integer_1) => match other {
+                Self::Integer(integer_2) => integer_1
+                    .pow(integer_2)
+                    .map(Self::Integer)
+                    .map_err(Error::Integer),
+                value => Err(Error::OperatorExponentiationSecondOperandExpectedInteger {
+                    found: value.r#type().to_string(),
+                }),
+            },
+            value => Err(Error::OperatorExponentiationFirstOperandExpectedInteger {
+                found: value.r#type().to_string(),
+            }),
+        }
+    }
+
     pub fn cast(self, to: Type) -> Result<Self, Error> {
         let from = self.r#type();
         Caster::cast(&from, &to).map_err(Error::Casting)?;
@@ -408,6 +439,7 @@ impl Value {
             Type::IntegerUnsigned { bitlength } => (false, bitlength),
             Type::IntegerSigned { bitlength } => (true, bitlength),
             Type::Field => (false, crate::BITLENGTH_FIELD),
+            Type::Boolean => return Ok(Self::Boolean),
             _ => return Ok(self),
         };
 
@@ -416,6 +448,7 @@ impl Value {
                 .cast(is_signed, bitlength)
                 .map(Self::Integer)
                 .map_err(Error::Integer)?,
+            Self::Boolean => Self::Integer(Integer::new(is_signed, bitlength)),
             operand => operand,
         })
     }
@@ -467,7 +500,9 @@ impl Value {
     pub fn index_constant(self, other: Constant) -> Result<(Self, IndexAccess), Error> {
         match self {
             Value::Array(array) => match other {
-                Constant::Integer(_) => Ok(array.slice_single()),
+                Constant::Integer(integer) => {
+                    array.slice_constant(integer.value).map_err(Error::Array)
+                }
                 Constant::Range(range) => array
                     .slice_range(range.start, range.end)
                     .map(|(value, access)| (value, access))
@@ -519,7 +554,7 @@ impl TryFrom<&Type> for Value {
             Type::Tuple { types } => Self::Tuple(Tuple::new(types.to_owned())),
             Type::Structure(structure) => Self::Structure(Structure::new(structure.to_owned())),
             Type::Enumeration(enumeration) => {
-                let mut integer = Integer::new(false, enumeration.bitlength);
+                let mut integer = Integer::new(enumeration.is_signed, enumeration.bitlength);
                 integer.set_enumeration(enumeration.to_owned());
                 Self::Integer(integer)
             }
@@ -532,7 +567,11 @@ impl TryFrom<Constant> for Value {
     type Error = Error;
 
     fn try_from(constant: Constant) -> Result<Self, Self::Error> {
-        Self::try_from(&constant.r#type())
+        let mut value = Self::try_from(&constant.r#type())?;
+        if let (Self::Integer(integer), Constant::Integer(constant)) = (&mut value, &constant) {
+            integer.set_bound(constant.value.to_owned());
+        }
+        Ok(value)
     }
 }
 
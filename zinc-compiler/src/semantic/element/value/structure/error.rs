@@ -8,11 +8,9 @@ pub enum Error {
         type_identifier: String,
         field_name: String,
     },
-    FieldExpected {
+    FieldInitializedTwice {
         type_identifier: String,
-        position: usize,
-        expected: String,
-        found: String,
+        field_name: String,
     },
     FieldInvalidType {
         type_identifier: String,
@@ -20,9 +18,19 @@ pub enum Error {
         expected: String,
         found: String,
     },
-    FieldOutOfRange {
+    FieldMissing {
         type_identifier: String,
-        expected: usize,
-        found: usize,
+        field_name: String,
     },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FieldDoesNotExist { .. } => "E0901",
+            Self::FieldInitializedTwice { .. } => "E0902",
+            Self::FieldInvalidType { .. } => "E0903",
+            Self::FieldMissing { .. } => "E0904",
+        }
+    }
+}
@@ -22,14 +22,16 @@ use self::error::Error;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Structure {
     r#type: StructureType,
-    field_index: usize,
+    is_initialized: Vec<bool>,
 }
 
 impl Structure {
     pub fn new(r#type: StructureType) -> Self {
+        let is_initialized = vec![false; r#type.fields.len()];
+
         Self {
             r#type,
-            field_index: 0,
+            is_initialized,
         }
     }
 
@@ -41,39 +43,65 @@ impl Structure {
         self.r#type.unique_id == other.r#type.unique_id
     }
 
-    pub fn push(&mut self, name: String, r#type: Type) -> Result<(), Error> {
-        match self.r#type.fields.get(self.field_index) {
-            Some((expected_name, expected_type)) => {
-                if &name != expected_name {
-                    return Err(Error::FieldExpected {
-                        type_identifier: self.r#type.identifier.to_owned(),
-                        position: self.field_index + 1,
-                        expected: expected_name.to_owned(),
-                        found: name,
-                    });
-                }
-
-                if &r#type != expected_type {
-                    return Err(Error::FieldInvalidType {
-                        type_identifier: self.r#type.identifier.to_owned(),
-                        field_name: expected_name.to_owned(),
-                        expected: expected_type.to_string(),
-                        found: r#type.to_string(),
-                    });
-                }
-            }
+    ///
+    /// Initializes the field `name` with a value of type `r#type`.
+    ///
+    /// The fields may be initialized in any order. Returns the field position in the
+    /// structure type declaration.
+    ///
+    pub fn set(&mut self, name: String, r#type: Type) -> Result<usize, Error> {
+        let position = match self
+            .r#type
+            .fields
+            .iter()
+            .position(|(field_name, _field_type)| field_name == &name)
+        {
+            Some(position) => position,
             None => {
-                return Err(Error::FieldOutOfRange {
+                return Err(Error::FieldDoesNotExist {
                     type_identifier: self.r#type.identifier.to_owned(),
-                    expected: self.r#type.fields.len(),
-                    found: self.field_index + 1,
-                });
+                    field_name: name,
+                })
             }
+        };
+
+        if self.is_initialized[position] {
+            return Err(Error::FieldInitializedTwice {
+                type_identifier: self.r#type.identifier.to_owned(),
+                field_name: name,
+            });
+        }
+
+        let expected_type = &self.r#type.fields[position].1;
+        if &r#type != expected_type {
+            return Err(Error::FieldInvalidType {
+                type_identifier: self.r#type.identifier.to_owned(),
+                field_name: name,
+                expected: expected_type.to_string(),
+                found: r#type.to_string(),
+            });
         }
 
-        self.field_index += 1;
+        self.is_initialized[position] = true;
+
+        Ok(position)
+    }
 
-        Ok(())
+    ///
+    /// Checks that every field has been initialized.
+    ///
+    pub fn validate(&self) -> Result<(), Error> {
+        match self
+            .is_initialized
+            .iter()
+            .position(|is_initialized| !is_initialized)
+        {
+            Some(position) => Err(Error::FieldMissing {
+                type_identifier: self.r#type.identifier.to_owned(),
+                field_name: self.r#type.fields[position].0.to_owned(),
+            }),
+            None => Ok(()),
+        }
     }
 
     pub fn slice(self, field_name: String) -> Result<(Value, FieldAccess), Error> {
@@ -42,7 +42,7 @@ fn main() {
 }
 
 #[test]
-fn error_field_expected() {
+fn error_field_does_not_exist_in_literal() {
     let input = r#"
 struct Data {
     a: u8,
@@ -59,12 +59,44 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(10, 9),
-        ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected {
-            type_identifier: "Data".to_owned(),
-            position: 2,
-            expected: "b".to_owned(),
-            found: "c".to_owned(),
-        })),
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FieldDoesNotExist {
+                type_identifier: "Data".to_owned(),
+                field_name: "c".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_field_initialized_twice() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: u8,
+}
+
+fn main() {
+    let result = Data {
+        b: 42,
+        a: 25,
+        b: 69,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(11, 9),
+        ElementError::Value(ValueError::Structure(
+            StructureValueError::FieldInitializedTwice {
+                type_identifier: "Data".to_owned(),
+                field_name: "b".to_owned(),
+            },
+        )),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -104,7 +136,7 @@ fn main() {
 }
 
 #[test]
-fn error_field_out_of_range() {
+fn error_field_missing() {
     let input = r#"
 struct Data {
     a: u8,
@@ -113,25 +145,51 @@ struct Data {
 
 fn main() {
     let result = Data {
-        a: 42,
         b: 25,
-        c: 69,
     };
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(11, 9),
-        ElementError::Value(ValueError::Structure(
-            StructureValueError::FieldOutOfRange {
-                type_identifier: "Data".to_owned(),
-                expected: 2,
-                found: 3,
-            },
-        )),
+        Location::new(8, 18),
+        ElementError::Value(ValueError::Structure(StructureValueError::FieldMissing {
+            type_identifier: "Data".to_owned(),
+            field_name: "a".to_owned(),
+        })),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_fields_in_any_order() {
+    let input = r#"
+struct Inner {
+    x: u8,
+    y: bool,
+}
+
+struct Data {
+    a: u8,
+    inner: Inner,
+    b: field,
+}
+
+fn main() -> Data {
+    Data {
+        b: 1 as field,
+        inner: Inner {
+            y: true,
+            x: 42,
+        },
+        a: 25,
+    }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
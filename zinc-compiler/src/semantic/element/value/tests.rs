@@ -149,7 +149,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -160,11 +160,10 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(5, 23),
-        ElementError::Value(
-            ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                found: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -239,7 +238,56 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_types_mismatch_array_size() {
+    let input = r#"
+fn main() {
+    let first = [1, 2, 3];
+    let second = [1, 2];
+    let value = first == second;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 23),
+        ElementError::Value(ValueError::OperatorEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 2).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_operator_equals_composite() {
+    let input = r#"
+struct Data {
+    values: [u8; 2],
+    pair: (bool, u8),
+}
+
+fn main() -> (bool, bool) {
+    let first = Data {
+        values: [1, 2],
+        pair: (true, 3),
+    };
+    let second = Data {
+        values: [1, 2],
+        pair: (false, 3),
+    };
+    (first == second, [first, second] != [second, first])
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_operator_not_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -250,11 +298,10 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(5, 23),
-        ElementError::Value(
-            ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                found: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorNotEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -997,7 +1044,6 @@ fn main() {
     assert_eq!(result, expected);
 }
 
-#[ignore]
 #[test]
 fn error_operator_bitwise_not_expected_integer() {
     let input = r#"
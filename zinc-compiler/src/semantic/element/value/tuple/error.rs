@@ -7,5 +7,14 @@ pub enum Error {
     FieldDoesNotExist {
         type_identifier: String,
         field_index: usize,
+        field_count: usize,
     },
 }
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FieldDoesNotExist { .. } => "E0801",
+        }
+    }
+}
@@ -62,6 +62,7 @@ impl Tuple {
             return Err(Error::FieldDoesNotExist {
                 type_identifier: self.r#type().to_string(),
                 field_index: index,
+                field_count: self.element_types.len(),
             });
         }
 
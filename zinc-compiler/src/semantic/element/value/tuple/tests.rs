@@ -4,12 +4,16 @@
 
 #![cfg(test)]
 
+use std::convert::TryFrom;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::element::value::tuple::error::Error as TupleValueError;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
 #[test]
@@ -25,6 +29,31 @@ fn main() {
         ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist {
             type_identifier: Type::tuple(vec![Type::boolean(); 3]).to_string(),
             field_index: 5,
+            field_count: 3,
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_field_does_not_exist_index_expression() {
+    let input = r#"
+const INDEX: u8 = 1;
+
+fn main() {
+    let result = (true, true, false).(INDEX + 2);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 37),
+        ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist {
+            type_identifier: Type::tuple(vec![Type::boolean(); 3]).to_string(),
+            field_index: 3,
+            field_count: 3,
         })),
     )));
 
@@ -32,3 +61,27 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_field_index_expression_not_constant() {
+    let input = r#"
+fn main(index: u8) {
+    let result = (true, true, false).(index);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConstantExpressionHasNonConstantElement {
+            location: Location::new(3, 39),
+            found: Element::Value(
+                Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                    .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
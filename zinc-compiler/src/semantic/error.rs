@@ -2,6 +2,8 @@
 //! The semantic error.
 //!
 
+use num_bigint::BigInt;
+
 use crate::lexical::token::location::Location;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::scope::error::Error as ScopeError;
@@ -44,6 +46,10 @@ pub enum Error {
         location: Location,
         reference: Location,
     },
+    MatchBranchGuardExpectedBoolean {
+        location: Location,
+        found: String,
+    },
 
     LoopWhileExpectedBooleanCondition {
         location: Location,
@@ -53,6 +59,36 @@ pub enum Error {
         location: Location,
         found: String,
     },
+    LoopAttributeUnknown {
+        location: Location,
+        found: String,
+    },
+    LoopAttributeConflict {
+        location: Location,
+        reference: Location,
+    },
+    LoopIterationsLimitExceeded {
+        location: Location,
+        found: BigInt,
+        limit: usize,
+    },
+
+    LetPatternInvalidType {
+        location: Location,
+        expected: String,
+        found: String,
+    },
+    LetPatternFieldDoesNotExist {
+        location: Location,
+        type_identifier: String,
+        field_name: String,
+        suggestion: Option<String>,
+    },
+    LetPatternFieldMissing {
+        location: Location,
+        type_identifier: String,
+        field_name: String,
+    },
 
     ConditionalExpectedBooleanCondition {
         location: Location,
@@ -63,9 +99,39 @@ pub enum Error {
         expected: String,
         found: String,
         reference: Location,
+        difference: Option<String>,
+    },
+
+    FunctionRecursion {
+        location: Location,
+        cycle: String,
+    },
+    RangeUsedAsValue {
+        location: Location,
+        found: String,
+    },
+    RangeUsedAsType {
+        location: Location,
     },
 
     EntryPointMissing,
+    EntryPointMisnamed {
+        location: Location,
+        found: String,
+    },
+    EntryPointNotFunction {
+        location: Location,
+        found: String,
+    },
+    EntryPointArgumentInvalidType {
+        location: Location,
+        name: String,
+        found: String,
+    },
+    EntryPointReturnTypeInvalid {
+        location: Location,
+        found: String,
+    },
 
     ModuleNotFound {
         location: Location,
@@ -86,4 +152,65 @@ pub enum Error {
         location: Location,
         found: String,
     },
+
+    IntegerLiteralOutOfRange {
+        location: Location,
+        value: BigInt,
+        r#type: String,
+    },
+
+    ArrayEmptyWithoutType {
+        location: Location,
+    },
+
+    StatementResultDiscarded {
+        location: Location,
+        found: String,
+    },
+}
+
+impl Error {
+    ///
+    /// Returns the stable error code, whose explanation can be found with `crate::explain`.
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Element(_, inner) => inner.code(),
+            Self::Scope(_, inner) => inner.code(),
+            Self::MatchScrutineeInvalidType { .. } => "E0101",
+            Self::MatchNotExhausted { .. } => "E0102",
+            Self::MatchLessThanTwoBranches { .. } => "E0103",
+            Self::MatchBranchUnreachable { .. } => "E0104",
+            Self::MatchBranchPatternPathExpectedConstant { .. } => "E0105",
+            Self::MatchBranchPatternInvalidType { .. } => "E0106",
+            Self::MatchBranchExpressionInvalidType { .. } => "E0107",
+            Self::MatchBranchDuplicate { .. } => "E0108",
+            Self::MatchBranchGuardExpectedBoolean { .. } => "E0109",
+            Self::LoopWhileExpectedBooleanCondition { .. } => "E0110",
+            Self::LoopBoundsExpectedConstantRangeExpression { .. } => "E0111",
+            Self::LoopAttributeUnknown { .. } => "E0112",
+            Self::LoopAttributeConflict { .. } => "E0113",
+            Self::LoopIterationsLimitExceeded { .. } => "E0114",
+            Self::LetPatternInvalidType { .. } => "E0115",
+            Self::LetPatternFieldDoesNotExist { .. } => "E0116",
+            Self::LetPatternFieldMissing { .. } => "E0117",
+            Self::ConditionalExpectedBooleanCondition { .. } => "E0118",
+            Self::ConditionalBranchTypesMismatch { .. } => "E0119",
+            Self::FunctionRecursion { .. } => "E0120",
+            Self::RangeUsedAsValue { .. } => "E0121",
+            Self::EntryPointMissing => "E0122",
+            Self::ModuleNotFound { .. } => "E0123",
+            Self::UseExpectedPath { .. } => "E0124",
+            Self::ImplStatementExpectedStructureOrEnumeration { .. } => "E0125",
+            Self::ConstantExpressionHasNonConstantElement { .. } => "E0126",
+            Self::IntegerLiteralOutOfRange { .. } => "E0127",
+            Self::EntryPointMisnamed { .. } => "E0128",
+            Self::EntryPointNotFunction { .. } => "E0129",
+            Self::EntryPointArgumentInvalidType { .. } => "E0130",
+            Self::EntryPointReturnTypeInvalid { .. } => "E0131",
+            Self::ArrayEmptyWithoutType { .. } => "E0132",
+            Self::StatementResultDiscarded { .. } => "E0133",
+            Self::RangeUsedAsType { .. } => "E0134",
+        }
+    }
 }
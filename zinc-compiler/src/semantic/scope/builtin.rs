@@ -154,6 +154,7 @@ impl BuiltInItems {
         let std_array_reverse = FunctionType::new_std(BuiltinIdentifier::ArrayReverse);
         let std_array_truncate = FunctionType::new_std(BuiltinIdentifier::ArrayTruncate);
         let std_array_pad = FunctionType::new_std(BuiltinIdentifier::ArrayPad);
+        let std_array_binary_search = FunctionType::new_std(BuiltinIdentifier::ArrayBinarySearch);
         std_array_scope.items.insert(
             std_array_reverse.identifier(),
             ScopeItem::new(
@@ -172,6 +173,13 @@ impl BuiltInItems {
             std_array_pad.identifier(),
             ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_array_pad)), None),
         );
+        std_array_scope.items.insert(
+            std_array_binary_search.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_array_binary_search)),
+                None,
+            ),
+        );
 
         let mut std_ff_scope = Scope::default();
         let std_ff_invert = FunctionType::new_std(BuiltinIdentifier::FieldInverse);
@@ -180,6 +188,91 @@ impl BuiltInItems {
             ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_ff_invert)), None),
         );
 
+        let mut std_math_scope = Scope::default();
+        let std_math_min = FunctionType::new_std(BuiltinIdentifier::MathMin);
+        let std_math_max = FunctionType::new_std(BuiltinIdentifier::MathMax);
+        let std_math_abs = FunctionType::new_std(BuiltinIdentifier::MathAbs);
+        std_math_scope.items.insert(
+            std_math_min.identifier(),
+            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_math_min)), None),
+        );
+        std_math_scope.items.insert(
+            std_math_max.identifier(),
+            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_math_max)), None),
+        );
+        std_math_scope.items.insert(
+            std_math_abs.identifier(),
+            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_math_abs)), None),
+        );
+
+        let mut std_ops_scope = Scope::default();
+        let std_ops_wrapping_add = FunctionType::new_std(BuiltinIdentifier::OpsWrappingAdd);
+        let std_ops_wrapping_sub = FunctionType::new_std(BuiltinIdentifier::OpsWrappingSub);
+        let std_ops_wrapping_mul = FunctionType::new_std(BuiltinIdentifier::OpsWrappingMul);
+        let std_ops_saturating_add = FunctionType::new_std(BuiltinIdentifier::OpsSaturatingAdd);
+        let std_ops_saturating_sub = FunctionType::new_std(BuiltinIdentifier::OpsSaturatingSub);
+        let std_ops_saturating_mul = FunctionType::new_std(BuiltinIdentifier::OpsSaturatingMul);
+        let std_ops_checked_add = FunctionType::new_std(BuiltinIdentifier::OpsCheckedAdd);
+        let std_ops_checked_mul = FunctionType::new_std(BuiltinIdentifier::OpsCheckedMul);
+        std_ops_scope.items.insert(
+            std_ops_wrapping_add.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_wrapping_add)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_wrapping_sub.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_wrapping_sub)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_wrapping_mul.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_wrapping_mul)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_saturating_add.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_saturating_add)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_saturating_sub.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_saturating_sub)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_saturating_mul.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_saturating_mul)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_checked_add.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_checked_add)),
+                None,
+            ),
+        );
+        std_ops_scope.items.insert(
+            std_ops_checked_mul.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_ops_checked_mul)),
+                None,
+            ),
+        );
+
+        let std_pow = FunctionType::new_std(BuiltinIdentifier::Pow);
+
         let mut std_scope = Scope::default();
         std_scope.items.insert(
             "crypto".to_owned(),
@@ -209,6 +302,24 @@ impl BuiltInItems {
                 None,
             ),
         );
+        std_scope.items.insert(
+            "math".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_math_scope))),
+                None,
+            ),
+        );
+        std_scope.items.insert(
+            "ops".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_ops_scope))),
+                None,
+            ),
+        );
+        std_scope.items.insert(
+            std_pow.identifier(),
+            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_pow)), None),
+        );
 
         let mut items = HashMap::with_capacity(3);
         let builtin_function_dbg = FunctionType::new_dbg();
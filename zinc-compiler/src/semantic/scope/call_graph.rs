@@ -0,0 +1,125 @@
+//!
+//! The semantic analyzer function call graph.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::lexical::token::location::Location;
+
+///
+/// The call graph of the user-defined functions, whose vertices are the function unique IDs.
+///
+/// The graph is shared by all the scopes of a program and is checked for cycles every time a new
+/// call is added, since the recursive calls cannot be compiled into a finite circuit.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CallGraph {
+    names: HashMap<usize, String>,
+    locations: HashMap<usize, Location>,
+    calls: HashMap<usize, Vec<usize>>,
+}
+
+impl CallGraph {
+    ///
+    /// Declares a function vertex with its name, which is used to describe the cycles, and its
+    /// location, which is used to report the unused functions.
+    ///
+    pub fn declare(&mut self, unique_id: usize, name: String, location: Location) {
+        self.names.insert(unique_id, name);
+        self.locations.insert(unique_id, location);
+    }
+
+    ///
+    /// Adds the `caller -> callee` edge.
+    ///
+    /// If the edge closes a cycle, returns the names of the functions forming it, starting and
+    /// ending with the caller, e.g. `f -> g -> f`.
+    ///
+    pub fn add_call(&mut self, caller: usize, callee: usize) -> Result<(), String> {
+        let cycle = self.path(callee, caller);
+
+        let callees = self.calls.entry(caller).or_default();
+        if !callees.contains(&callee) {
+            callees.push(callee);
+        }
+
+        match cycle {
+            Some(path) => Err(std::iter::once(caller)
+                .chain(path.into_iter())
+                .map(|unique_id| self.name(unique_id))
+                .collect::<Vec<String>>()
+                .join(" -> ")),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Returns the names and locations of the declared functions, which cannot be reached from
+    /// the `root` one.
+    ///
+    pub fn unreachable(&self, root: usize) -> Vec<(String, Location)> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![root];
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {
+                continue;
+            }
+            stack.extend(self.calls.get(&current).into_iter().flatten());
+        }
+
+        let mut unreachable: Vec<(String, Location)> = self
+            .locations
+            .iter()
+            .filter(|(unique_id, _location)| !visited.contains(unique_id))
+            .map(|(unique_id, location)| (self.name(*unique_id), *location))
+            .collect();
+        unreachable.sort_by_key(|(_name, location)| (location.line, location.column));
+        unreachable
+    }
+
+    ///
+    /// Finds a path between the two functions using the depth-first search.
+    ///
+    fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let mut visited = HashSet::new();
+        let mut path = vec![from];
+        if self.search(from, to, &mut visited, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    fn search(
+        &self,
+        current: usize,
+        to: usize,
+        visited: &mut HashSet<usize>,
+        path: &mut Vec<usize>,
+    ) -> bool {
+        if current == to {
+            return true;
+        }
+        if !visited.insert(current) {
+            return false;
+        }
+
+        for callee in self.calls.get(&current).into_iter().flatten() {
+            path.push(*callee);
+            if self.search(*callee, to, visited, path) {
+                return true;
+            }
+            path.pop();
+        }
+
+        false
+    }
+
+    fn name(&self, unique_id: usize) -> String {
+        self.names
+            .get(&unique_id)
+            .cloned()
+            .unwrap_or_else(|| unique_id.to_string())
+    }
+}
@@ -8,12 +8,44 @@ use crate::lexical::token::location::Location;
 pub enum Error {
     ItemUndeclared {
         name: String,
+        suggestion: Option<String>,
     },
     ItemRedeclared {
         name: String,
         reference: Option<Location>,
     },
+    ItemRedefined {
+        name: String,
+        first: Location,
+        second: Location,
+    },
     ItemIsNotNamespace {
         name: String,
     },
+    VariableUninitialized {
+        name: String,
+        reference: Option<Location>,
+    },
+    VariableCaptured {
+        name: String,
+        reference: Option<Location>,
+    },
+    EnumerationVariantUnqualified {
+        name: String,
+        enumeration: String,
+    },
+}
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ItemUndeclared { .. } => "E0201",
+            Self::ItemRedeclared { .. } => "E0202",
+            Self::ItemIsNotNamespace { .. } => "E0203",
+            Self::VariableUninitialized { .. } => "E0204",
+            Self::VariableCaptured { .. } => "E0205",
+            Self::EnumerationVariantUnqualified { .. } => "E0206",
+            Self::ItemRedefined { .. } => "E0207",
+        }
+    }
 }
@@ -4,6 +4,7 @@
 
 pub mod variant;
 
+use std::cell::Cell;
 use std::fmt;
 
 use crate::lexical::token::location::Location;
@@ -15,15 +16,34 @@ use self::variant::Variant;
 ///
 /// Items are variables, constants, types, modules, etc.
 ///
+/// An item is marked as used when it is resolved, which is checked to warn about the unused
+/// variables and imports.
+///
+/// A namespaced item, e.g. an enumeration variant, can only be resolved by its qualified path.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Item {
     pub variant: Variant,
     pub location: Option<Location>,
+    pub is_used: Cell<bool>,
+    pub is_namespaced: bool,
 }
 
 impl Item {
     pub fn new(variant: Variant, location: Option<Location>) -> Self {
-        Self { variant, location }
+        Self {
+            variant,
+            location,
+            is_used: Cell::new(false),
+            is_namespaced: false,
+        }
+    }
+
+    pub fn new_namespaced(variant: Variant, location: Option<Location>) -> Self {
+        Self {
+            is_namespaced: true,
+            ..Self::new(variant, location)
+        }
     }
 }
 
@@ -4,20 +4,52 @@
 
 use std::fmt;
 
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 
 ///
 /// The variable item, declared using a `let` statement.
 ///
+/// A mutable variable declared without a value stays uninitialized until it is assigned
+/// on every control path.
+///
+/// A variable initialized with a constant expression keeps the constant value, so it can be used
+/// where a constant is expected, e.g. as an array size, until it is reassigned.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variable {
     pub is_mutable: bool,
+    pub is_initialized: bool,
     pub r#type: Type,
+    pub constant: Option<Constant>,
 }
 
 impl Variable {
     pub fn new(is_mutable: bool, r#type: Type) -> Self {
-        Self { is_mutable, r#type }
+        Self {
+            is_mutable,
+            is_initialized: true,
+            r#type,
+            constant: None,
+        }
+    }
+
+    pub fn new_constant(is_mutable: bool, constant: Constant) -> Self {
+        Self {
+            is_mutable,
+            is_initialized: true,
+            r#type: constant.r#type(),
+            constant: Some(constant),
+        }
+    }
+
+    pub fn new_uninitialized(r#type: Type) -> Self {
+        Self {
+            is_mutable: true,
+            is_initialized: false,
+            r#type,
+            constant: None,
+        }
     }
 }
 
@@ -5,37 +5,65 @@
 mod tests;
 
 pub mod builtin;
+pub mod call_graph;
 pub mod error;
 pub mod item;
+pub mod snapshot;
 pub mod stack;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::str;
 
+use crate::diagnostic::span::Span;
 use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::syntax::tree::identifier::Identifier;
+use crate::warning::Warning;
 
 use self::builtin::BuiltInItems;
+use self::call_graph::CallGraph;
 use self::error::Error;
 use self::item::variant::variable::Variable as VariableItem;
 use self::item::variant::Variant as ItemVariant;
 use self::item::Item;
+use self::snapshot::symbol::Symbol;
+use self::snapshot::Snapshot;
 
 ///
 /// A scope consists of a hashmap of the declared items and a reference to its parent.
 /// The global scope has no parent.
 /// Modules are connected to the program scope hierarchy horizontally, being stored as module items.
+/// Function scopes are marked with the function name, so variables of the enclosing functions are
+/// not captured by the nested ones.
+/// The function call graph and the warnings are shared by all the scopes of the hierarchy.
+/// Loop body scopes are marked with the loop iterations count, so the nested loops are checked
+/// against the loop iterations limit, which is inherited from the parent.
+/// If the snapshots are enabled, the symbols of the analyzed scopes are collected into a list
+/// shared by the hierarchy, which is used by the IDE integrations.
 ///
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     items: HashMap<String, Item>,
+    function: Option<(String, usize)>,
+    loop_iterations: Option<usize>,
+    loop_iterations_limit: usize,
+    call_graph: Rc<RefCell<CallGraph>>,
+    warnings: Rc<RefCell<Vec<Warning>>>,
+    snapshots: Option<Rc<RefCell<Vec<Snapshot>>>>,
+}
+
+impl Default for Scope {
+    fn default() -> Self {
+        Self::new(None)
+    }
 }
 
 impl Scope {
@@ -43,9 +71,33 @@ impl Scope {
     /// Initializes a nested scope with an explicit optional parent.
     ///
     pub fn new(parent: Option<Rc<RefCell<Self>>>) -> Self {
+        let (call_graph, warnings, loop_iterations_limit, snapshots) = match parent {
+            Some(ref parent) => {
+                let parent = parent.borrow();
+                (
+                    parent.call_graph.clone(),
+                    parent.warnings.clone(),
+                    parent.loop_iterations_limit,
+                    parent.snapshots.clone(),
+                )
+            }
+            None => (
+                Rc::new(RefCell::new(CallGraph::default())),
+                Rc::new(RefCell::new(Vec::new())),
+                crate::LIMIT_LOOP_ITERATIONS,
+                None,
+            ),
+        };
+
         Self {
             parent,
             items: HashMap::new(),
+            function: None,
+            loop_iterations: None,
+            loop_iterations_limit,
+            call_graph,
+            warnings,
+            snapshots,
         }
     }
 
@@ -56,6 +108,12 @@ impl Scope {
         Self {
             parent: None,
             items: BuiltInItems::new_map(),
+            function: None,
+            loop_iterations: None,
+            loop_iterations_limit: crate::LIMIT_LOOP_ITERATIONS,
+            call_graph: Rc::new(RefCell::new(CallGraph::default())),
+            warnings: Rc::new(RefCell::new(Vec::new())),
+            snapshots: None,
         }
     }
 
@@ -67,12 +125,7 @@ impl Scope {
         identifier: Identifier,
         item: Item,
     ) -> Result<(), Error> {
-        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
-        }
+        Self::check_item_declarable(scope.clone(), &identifier)?;
         scope.borrow_mut().items.insert(identifier.name, item);
         Ok(())
     }
@@ -80,16 +133,25 @@ impl Scope {
     ///
     /// Declares a variable, which is normally a `let` binding or a function actual parameter.
     ///
+    /// A variable may shadow an item declared in an outer scope, e.g. a function or a constant,
+    /// but not another variable.
+    ///
     pub fn declare_variable(
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         variable: VariableItem,
     ) -> Result<(), Error> {
-        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
+        if let Ok(item) = Self::resolve_qualified_item(scope.clone(), &identifier.name) {
+            let is_shadowing_item = match item.variant {
+                ItemVariant::Variable(_) => false,
+                _ => !scope.borrow().items.contains_key(identifier.name.as_str()),
+            };
+            if !is_shadowing_item {
+                return Err(Error::ItemRedeclared {
+                    name: identifier.name,
+                    reference: item.location,
+                });
+            }
         }
         scope.borrow_mut().items.insert(
             identifier.name,
@@ -106,12 +168,7 @@ impl Scope {
         identifier: Identifier,
         constant: Constant,
     ) -> Result<(), Error> {
-        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
-        }
+        Self::check_item_declarable(scope.clone(), &identifier)?;
         scope.borrow_mut().items.insert(
             identifier.name,
             Item::new(ItemVariant::Constant(constant), Some(identifier.location)),
@@ -119,6 +176,20 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Declares an enumeration variant, which can only be resolved by its qualified path,
+    /// e.g. `Color::Red`.
+    ///
+    /// The variant names are checked for duplicates by the enumeration, and they do not clash
+    /// with the items outside the enumeration namespace.
+    ///
+    pub fn declare_variant(scope: Rc<RefCell<Scope>>, identifier: Identifier, constant: Constant) {
+        scope.borrow_mut().items.insert(
+            identifier.name,
+            Item::new_namespaced(ItemVariant::Constant(constant), Some(identifier.location)),
+        );
+    }
+
     ///
     /// Declares a type, which is normally a `type`, `struct`, or `enum` binding.
     ///
@@ -127,12 +198,7 @@ impl Scope {
         identifier: Identifier,
         r#type: Type,
     ) -> Result<(), Error> {
-        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
-        }
+        Self::check_item_declarable(scope.clone(), &identifier)?;
         scope.borrow_mut().items.insert(
             identifier.name,
             Item::new(ItemVariant::Type(r#type), Some(identifier.location)),
@@ -140,6 +206,16 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Replaces the type declared in the current scope, e.g. the function type, whose return type
+    /// has been inferred from its body.
+    ///
+    pub fn update_type(scope: Rc<RefCell<Scope>>, identifier: &str, r#type: Type) {
+        if let Some(item) = scope.borrow_mut().items.get_mut(identifier) {
+            item.variant = ItemVariant::Type(r#type);
+        }
+    }
+
     ///
     /// Declares a module, which is normally a `mod` binding.
     ///
@@ -148,12 +224,7 @@ impl Scope {
         identifier: Identifier,
         module: Rc<RefCell<Scope>>,
     ) -> Result<(), Error> {
-        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
-        }
+        Self::check_item_declarable(scope.clone(), &identifier)?;
         scope.borrow_mut().items.insert(
             identifier.name,
             Item::new(ItemVariant::Module(module), Some(identifier.location)),
@@ -161,6 +232,40 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Checks whether an item named `identifier` can be declared in the scope.
+    ///
+    /// An item with the same name as another item of the same scope is redefined. Otherwise, the
+    /// name must not be visible from the scope at all.
+    ///
+    fn check_item_declarable(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &Identifier,
+    ) -> Result<(), Error> {
+        if let Some(item) = scope.borrow().items.get(identifier.name.as_str()) {
+            match (&item.variant, item.location) {
+                (ItemVariant::Variable(_), _) => {}
+                (_, Some(first)) if !item.is_namespaced => {
+                    return Err(Error::ItemRedefined {
+                        name: identifier.name.to_owned(),
+                        first,
+                        second: identifier.location,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(item) = Self::resolve_qualified_item(scope, &identifier.name) {
+            return Err(Error::ItemRedeclared {
+                name: identifier.name.to_owned(),
+                reference: item.location,
+            });
+        }
+
+        Ok(())
+    }
+
     ///
     /// Declares the `Self` alias within a type implementation.
     ///
@@ -181,8 +286,20 @@ impl Scope {
         let mut current_scope = scope;
 
         for (index, identifier) in path.elements.iter().enumerate() {
-            let item = Self::resolve_item(current_scope.clone(), &identifier.name)
-                .map_err(|error| SemanticError::Scope(identifier.location, error))?;
+            let item = if index == 0 {
+                Self::resolve_item(current_scope.clone(), &identifier.name).map_err(|error| {
+                    match Self::variant_enumeration(current_scope.clone(), &identifier.name) {
+                        Some(enumeration) => Error::EnumerationVariantUnqualified {
+                            name: identifier.name.to_owned(),
+                            enumeration,
+                        },
+                        None => error,
+                    }
+                })
+            } else {
+                Self::resolve_qualified_item(current_scope.clone(), &identifier.name)
+            }
+            .map_err(|error| SemanticError::Scope(identifier.location, error))?;
 
             if index == path.elements.len() - 1 {
                 return Ok(item);
@@ -209,6 +326,7 @@ impl Scope {
             path.location,
             Error::ItemUndeclared {
                 name: path.to_string(),
+                suggestion: None,
             },
         ))
     }
@@ -216,18 +334,487 @@ impl Scope {
     ///
     /// Resolves the item within the current scope hierarchy.
     ///
+    /// The variables declared outside the nearest function scope cannot be captured, so only
+    /// the constants, types, and other compile-time items are resolved from there.
+    ///
+    /// If the item is not found, the most similar visible name is suggested.
+    ///
     pub fn resolve_item(scope: Rc<RefCell<Scope>>, identifier: &str) -> Result<Item, Error> {
-        match scope.borrow().items.get(identifier) {
-            Some(item) => Ok(item.to_owned()),
-            None => match scope.borrow().parent {
-                Some(ref parent) => Self::resolve_item(parent.to_owned(), identifier),
-                None => Err(Error::ItemUndeclared {
-                    name: identifier.to_owned(),
-                }),
+        Self::resolve_item_captured(scope.clone(), identifier, false).map_err(|error| match error {
+            Error::ItemUndeclared { name, .. } => {
+                Self::item_undeclared(name, Self::visible_names(scope, false))
+            }
+            error => error,
+        })
+    }
+
+    ///
+    /// Resolves the item, which follows a namespace in a path or is being declared, so the
+    /// namespaced items declared directly in the `scope` are resolved and suggested as well.
+    ///
+    fn resolve_qualified_item(scope: Rc<RefCell<Scope>>, identifier: &str) -> Result<Item, Error> {
+        if let Some(item) = scope.borrow().items.get(identifier) {
+            if item.is_namespaced {
+                item.is_used.set(true);
+                return Ok(item.to_owned());
+            }
+        }
+
+        Self::resolve_item_captured(scope.clone(), identifier, false).map_err(|error| match error {
+            Error::ItemUndeclared { name, .. } => {
+                let mut names: Vec<String> = scope
+                    .borrow()
+                    .items
+                    .iter()
+                    .filter(|(_name, item)| item.is_namespaced)
+                    .map(|(name, _item)| name.to_owned())
+                    .collect();
+                names.extend(Self::visible_names(scope, false));
+
+                Self::item_undeclared(name, names)
+            }
+            error => error,
+        })
+    }
+
+    ///
+    /// Finds the item declared in the `scope` with the `identifier` written in another letter
+    /// case, e.g. `Main` instead of `main`.
+    ///
+    pub fn resolve_item_ignoring_case(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &str,
+    ) -> Option<(String, Item)> {
+        let scope = scope.borrow();
+
+        let mut items: Vec<(&String, &Item)> = scope
+            .items
+            .iter()
+            .filter(|(name, item)| !item.is_namespaced && name.eq_ignore_ascii_case(identifier))
+            .collect();
+        items.sort_by(|(first, _), (second, _)| first.cmp(second));
+        items
+            .into_iter()
+            .next()
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+    }
+
+    ///
+    /// Finds the visible enumeration, which has the variant `identifier`, to suggest the
+    /// qualified variant path.
+    ///
+    pub fn variant_enumeration(scope: Rc<RefCell<Scope>>, identifier: &str) -> Option<String> {
+        let scope = scope.borrow();
+
+        let enumeration = scope
+            .items
+            .values()
+            .filter_map(|item| match item.variant {
+                ItemVariant::Type(Type::Enumeration(ref enumeration))
+                    if Self::is_variant_declared(enumeration.scope.clone(), identifier) =>
+                {
+                    Some(enumeration.identifier.to_owned())
+                }
+                _ => None,
+            })
+            .min();
+        if enumeration.is_some() {
+            return enumeration;
+        }
+
+        match scope.parent {
+            Some(ref parent) => Self::variant_enumeration(parent.to_owned(), identifier),
+            None => None,
+        }
+    }
+
+    ///
+    /// Checks if the enumeration `scope` declares the variant `identifier`.
+    ///
+    pub fn is_variant_declared(scope: Rc<RefCell<Scope>>, identifier: &str) -> bool {
+        scope
+            .borrow()
+            .items
+            .get(identifier)
+            .map(|item| item.is_namespaced)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Gets the name of the nearest function, which the scope belongs to.
+    ///
+    pub fn function_name(scope: Rc<RefCell<Scope>>) -> Option<String> {
+        let scope = scope.borrow();
+        match scope.function {
+            Some((ref name, _unique_id)) => Some(name.to_owned()),
+            None => match scope.parent {
+                Some(ref parent) => Self::function_name(parent.to_owned()),
+                None => None,
             },
         }
     }
 
+    ///
+    /// Sets the maximal number of loop iterations, including the ones of the enclosing loops.
+    ///
+    /// The limit is inherited by the scopes created afterwards, so it must be set on the global
+    /// scope before the analysis.
+    ///
+    pub fn set_loop_iterations_limit(scope: Rc<RefCell<Scope>>, limit: usize) {
+        scope.borrow_mut().loop_iterations_limit = limit;
+    }
+
+    ///
+    /// Returns the maximal number of loop iterations, including the ones of the enclosing loops.
+    ///
+    pub fn loop_iterations_limit(scope: Rc<RefCell<Scope>>) -> usize {
+        scope.borrow().loop_iterations_limit
+    }
+
+    ///
+    /// Marks the scope as the loop body, which is executed `count` times.
+    ///
+    pub fn set_loop_iterations(scope: Rc<RefCell<Scope>>, count: usize) {
+        scope.borrow_mut().loop_iterations = Some(count);
+    }
+
+    ///
+    /// Returns the product of the iterations counts of the loops enclosing the scope.
+    ///
+    /// The loops outside the nearest function do not count, since the function body is
+    /// generated only once.
+    ///
+    pub fn enclosing_loop_iterations(scope: Rc<RefCell<Scope>>) -> usize {
+        let scope = scope.borrow();
+        let count = scope.loop_iterations.unwrap_or(1);
+        if scope.function.is_some() {
+            return count;
+        }
+
+        match scope.parent {
+            Some(ref parent) => {
+                count.saturating_mul(Self::enclosing_loop_iterations(parent.to_owned()))
+            }
+            None => count,
+        }
+    }
+
+    ///
+    /// Checks if the variable is the index of an enclosing loop, whose values are known at compile
+    /// time, since the loops are unrolled.
+    ///
+    pub fn is_loop_index(scope: Rc<RefCell<Scope>>, name: &str) -> bool {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            let scope = scope.borrow();
+            if let Some(item) = scope.items.get(name) {
+                return match item.variant {
+                    ItemVariant::Variable(_) => scope.loop_iterations.is_some(),
+                    _ => false,
+                };
+            }
+            current = scope.parent.clone();
+        }
+
+        false
+    }
+
+    ///
+    /// Returns the unique ID of the innermost enclosing function.
+    ///
+    pub fn function_id(scope: Rc<RefCell<Scope>>) -> Option<usize> {
+        let scope = scope.borrow();
+        match scope.function {
+            Some((_, unique_id)) => Some(unique_id),
+            None => match scope.parent {
+                Some(ref parent) => Self::function_id(parent.to_owned()),
+                None => None,
+            },
+        }
+    }
+
+    ///
+    /// Records the call of the function `callee` from the innermost enclosing function.
+    ///
+    /// If the call makes the functions recursive, returns the description of the call cycle.
+    ///
+    pub fn add_call(scope: Rc<RefCell<Scope>>, callee: usize) -> Result<(), String> {
+        let caller = match Self::function_id(scope.clone()) {
+            Some(caller) => caller,
+            None => return Ok(()),
+        };
+
+        let call_graph = scope.borrow().call_graph.clone();
+        let mut call_graph = call_graph.borrow_mut();
+        call_graph.add_call(caller, callee)
+    }
+
+    ///
+    /// Checks whether the variable is initialized on every control path leading to the
+    /// current point of the analysis.
+    ///
+    pub fn check_variable_initialized(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &str,
+    ) -> Result<(), Error> {
+        let item = Self::resolve_item(scope, identifier)?;
+        match item.variant {
+            ItemVariant::Variable(ref variable) if !variable.is_initialized => {
+                Err(Error::VariableUninitialized {
+                    name: identifier.to_owned(),
+                    reference: item.location,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Marks the variable as initialized in the scope where it is declared.
+    ///
+    pub fn initialize_variable(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        let parent = {
+            let mut scope = scope.borrow_mut();
+            match scope.items.get_mut(identifier) {
+                Some(item) => {
+                    if let ItemVariant::Variable(ref mut variable) = item.variant {
+                        variable.is_initialized = true;
+                    }
+                    return;
+                }
+                None => scope.parent.clone(),
+            }
+        };
+
+        if let Some(parent) = parent {
+            Self::initialize_variable(parent, identifier);
+        }
+    }
+
+    ///
+    /// Returns the constant value of the variable, if it was initialized with a constant
+    /// expression and has not been reassigned since.
+    ///
+    /// A mutable variable declared outside a loop is not treated as a constant within the loop,
+    /// since the loop body is analyzed only once, but it may be reassigned by a previous iteration.
+    ///
+    pub fn variable_constant(scope: Rc<RefCell<Scope>>, identifier: &str) -> Option<Constant> {
+        let mut is_loop_crossed = false;
+
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            let scope = scope.borrow();
+            if let Some(item) = scope.items.get(identifier) {
+                return match item.variant {
+                    ItemVariant::Variable(ref variable)
+                        if !variable.is_mutable || !is_loop_crossed =>
+                    {
+                        if variable.constant.is_some() {
+                            item.is_used.set(true);
+                        }
+                        variable.constant.clone()
+                    }
+                    _ => None,
+                };
+            }
+            is_loop_crossed |= scope.loop_iterations.is_some();
+            current = scope.parent.clone();
+        }
+
+        None
+    }
+
+    ///
+    /// Drops the constant value of the variable in the scope where it is declared.
+    ///
+    /// Is called when the variable is reassigned.
+    ///
+    pub fn forget_variable_constant(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        let parent = {
+            let mut scope = scope.borrow_mut();
+            match scope.items.get_mut(identifier) {
+                Some(item) => {
+                    if let ItemVariant::Variable(ref mut variable) = item.variant {
+                        variable.constant = None;
+                    }
+                    return;
+                }
+                None => scope.parent.clone(),
+            }
+        };
+
+        if let Some(parent) = parent {
+            Self::forget_variable_constant(parent, identifier);
+        }
+    }
+
+    ///
+    /// Collects the names of the uninitialized variables within the current scope hierarchy.
+    ///
+    /// Is used to save the initialization state before analyzing a control flow branch.
+    ///
+    pub fn uninitialized_variables(scope: Rc<RefCell<Scope>>) -> HashSet<String> {
+        let mut names = HashSet::new();
+
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            for (name, item) in scope.borrow().items.iter() {
+                if let ItemVariant::Variable(ref variable) = item.variant {
+                    if !variable.is_initialized {
+                        names.insert(name.to_owned());
+                    }
+                }
+            }
+            current = scope.borrow().parent.clone();
+        }
+
+        names
+    }
+
+    ///
+    /// Sets the initialization state of the variables within the current scope hierarchy,
+    /// so that only the variables from `names` are uninitialized.
+    ///
+    /// Is used to restore the initialization state after analyzing a control flow branch, or
+    /// to merge the states of several branches.
+    ///
+    pub fn set_uninitialized_variables(scope: Rc<RefCell<Scope>>, names: &HashSet<String>) {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            for (name, item) in scope.borrow_mut().items.iter_mut() {
+                if let ItemVariant::Variable(ref mut variable) = item.variant {
+                    variable.is_initialized = !names.contains(name);
+                }
+            }
+            current = scope.borrow().parent.clone();
+        }
+    }
+
+    ///
+    /// Adds a warning to the list shared by the scope hierarchy.
+    ///
+    pub fn warn(scope: Rc<RefCell<Scope>>, warning: Warning) {
+        let warnings = scope.borrow().warnings.clone();
+        warnings.borrow_mut().push(warning);
+    }
+
+    ///
+    /// Takes the warnings of the scope hierarchy, sorted by their location.
+    ///
+    pub fn take_warnings(scope: Rc<RefCell<Scope>>) -> Vec<Warning> {
+        let warnings = scope.borrow().warnings.clone();
+        let mut warnings: Vec<Warning> = warnings.borrow_mut().drain(..).collect();
+        warnings.sort_by_key(|warning| {
+            let location = warning.location();
+            (location.line, location.column)
+        });
+        warnings
+    }
+
+    ///
+    /// Enables collecting the scope snapshots, which is inherited by the scopes created afterwards.
+    ///
+    pub fn enable_snapshots(scope: Rc<RefCell<Scope>>) {
+        scope.borrow_mut().snapshots = Some(Rc::new(RefCell::new(Vec::new())));
+    }
+
+    ///
+    /// Takes a snapshot of the symbols declared in the scope itself, if the snapshots are enabled.
+    ///
+    /// The `span` is the source code range the scope covers, where `None` means the whole file.
+    /// The scopes without symbols are not recorded, unless they cover the whole file.
+    ///
+    pub fn snapshot(scope: Rc<RefCell<Scope>>, span: Option<(Location, Location)>) {
+        let snapshots = match scope.borrow().snapshots {
+            Some(ref snapshots) => snapshots.clone(),
+            None => return,
+        };
+
+        let mut symbols: Vec<Symbol> = scope
+            .borrow()
+            .items
+            .iter()
+            .filter_map(|(name, item)| Symbol::from_item(name, item))
+            .collect();
+        if symbols.is_empty() && span.is_some() {
+            return;
+        }
+        symbols.sort_by_key(|symbol| (symbol.span.start_line, symbol.span.start_column));
+
+        let span = span.map(|(start, end)| Span::new(start, end));
+        snapshots.borrow_mut().push(Snapshot::new(span, symbols));
+    }
+
+    ///
+    /// Takes the snapshots of the scope hierarchy, sorted by their start location, so the module
+    /// scope goes first.
+    ///
+    pub fn take_snapshots(scope: Rc<RefCell<Scope>>) -> Vec<Snapshot> {
+        let snapshots = match scope.borrow().snapshots {
+            Some(ref snapshots) => snapshots.clone(),
+            None => return vec![],
+        };
+        let mut snapshots: Vec<Snapshot> = snapshots.borrow_mut().drain(..).collect();
+        snapshots.sort_by_key(|snapshot| {
+            snapshot
+                .span
+                .as_ref()
+                .map(|span| (span.start_line, span.start_column))
+        });
+        snapshots
+    }
+
+    ///
+    /// Warns about the variables declared in the scope, which have never been used.
+    ///
+    /// The function parameters and the names starting with an underscore are not checked.
+    ///
+    pub fn check_unused_variables(scope: Rc<RefCell<Scope>>) {
+        if scope.borrow().function.is_some() {
+            return;
+        }
+
+        let mut unused = Vec::new();
+        for (name, item) in scope.borrow().items.iter() {
+            if let (ItemVariant::Variable(_), Some(location)) = (&item.variant, item.location) {
+                if !item.is_used.get() && !name.starts_with('_') {
+                    unused.push(Warning::UnusedVariable {
+                        location,
+                        name: name.to_owned(),
+                    });
+                }
+            }
+        }
+
+        for warning in unused.into_iter() {
+            Self::warn(scope.clone(), warning);
+        }
+    }
+
+    ///
+    /// Checks whether the item declared in the scope itself has been used.
+    ///
+    pub fn is_item_used(scope: Rc<RefCell<Scope>>, identifier: &str) -> bool {
+        scope
+            .borrow()
+            .items
+            .get(identifier)
+            .map(|item| item.is_used.get())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Warns about the functions which cannot be reached from the `root` one, e.g. `main`.
+    ///
+    pub fn check_unused_functions(scope: Rc<RefCell<Scope>>, root: usize) {
+        let call_graph = scope.borrow().call_graph.clone();
+        let unreachable = call_graph.borrow().unreachable(root);
+
+        for (name, location) in unreachable.into_iter() {
+            Self::warn(scope.clone(), Warning::UnusedFunction { location, name });
+        }
+    }
+
     ///
     /// Checks whether the item is declared within the current scope hierarchy.
     ///
@@ -248,4 +835,105 @@ impl Scope {
     pub fn new_child(parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
         Rc::new(RefCell::new(Scope::new(Some(parent))))
     }
+
+    ///
+    /// Creates a function scope with the current one as its parent.
+    ///
+    pub fn new_function(
+        parent: Rc<RefCell<Scope>>,
+        name: String,
+        unique_id: usize,
+        location: Location,
+    ) -> Rc<RefCell<Scope>> {
+        let mut scope = Scope::new(Some(parent));
+        scope
+            .call_graph
+            .borrow_mut()
+            .declare(unique_id, name.clone(), location);
+        scope.function = Some((name, unique_id));
+        Rc::new(RefCell::new(scope))
+    }
+
+    ///
+    /// Resolves the item, remembering whether a function scope boundary has been crossed.
+    ///
+    fn resolve_item_captured(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &str,
+        is_captured: bool,
+    ) -> Result<Item, Error> {
+        let scope = scope.borrow();
+        match scope
+            .items
+            .get(identifier)
+            .filter(|item| !item.is_namespaced)
+        {
+            Some(Item {
+                variant: ItemVariant::Variable(_),
+                location,
+                ..
+            }) if is_captured => Err(Error::VariableCaptured {
+                name: identifier.to_owned(),
+                reference: *location,
+            }),
+            Some(item) => {
+                item.is_used.set(true);
+                Ok(item.to_owned())
+            }
+            None => match scope.parent {
+                Some(ref parent) => Self::resolve_item_captured(
+                    parent.to_owned(),
+                    identifier,
+                    is_captured || scope.function.is_some(),
+                ),
+                None => Err(Error::ItemUndeclared {
+                    name: identifier.to_owned(),
+                    suggestion: None,
+                }),
+            },
+        }
+    }
+
+    ///
+    /// Creates the undeclared item error with the most similar of the `names` suggested.
+    ///
+    fn item_undeclared(name: String, mut names: Vec<String>) -> Error {
+        names.sort();
+        names.dedup();
+
+        let suggestion =
+            zinc_utils::suggestion::suggest(name.as_str(), names.iter().map(String::as_str))
+                .map(str::to_owned);
+
+        Error::ItemUndeclared { name, suggestion }
+    }
+
+    ///
+    /// Collects the names of the items, which can be resolved from the scope.
+    ///
+    /// Like in `resolve_item_captured`, the variables outside the nearest function are skipped.
+    ///
+    fn visible_names(scope: Rc<RefCell<Scope>>, is_captured: bool) -> Vec<String> {
+        let scope = scope.borrow();
+
+        let mut names: Vec<String> = scope
+            .items
+            .iter()
+            .filter(|(_name, item)| match item.variant {
+                _ if item.is_namespaced => false,
+                ItemVariant::Variable(_) => !is_captured,
+                _ => true,
+            })
+            .map(|(name, _item)| name.to_owned())
+            .collect();
+
+        if let Some(ref parent) = scope.parent {
+            names.extend(Self::visible_names(
+                parent.to_owned(),
+                is_captured || scope.function.is_some(),
+            ));
+        }
+
+        names
+    }
 }
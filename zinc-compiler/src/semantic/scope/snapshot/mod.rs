@@ -0,0 +1,66 @@
+//!
+//! The semantic analyzer scope snapshot.
+//!
+
+pub mod symbol;
+
+use serde_derive::Serialize;
+
+use crate::diagnostic::span::Span;
+use crate::lexical::token::location::Location;
+
+use self::symbol::Symbol;
+
+///
+/// The symbols declared in a scope, taken when the scope has been analyzed, which is useful for
+/// the IDE integrations, e.g. autocompletion.
+///
+/// The `span` is `None` for the module scope, which covers the whole file.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snapshot {
+    pub span: Option<Span>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Snapshot {
+    pub fn new(span: Option<Span>, symbols: Vec<Symbol>) -> Self {
+        Self { span, symbols }
+    }
+
+    ///
+    /// Checks if the scope covers the `location`, that is, its symbols are visible there.
+    ///
+    pub fn contains(&self, location: Location) -> bool {
+        match self.span {
+            Some(ref span) => {
+                (span.start_line, span.start_column) <= (location.line, location.column)
+                    && (location.line, location.column) <= (span.end_line, span.end_column)
+            }
+            None => true,
+        }
+    }
+
+    ///
+    /// Collects the symbols visible at the `location` from the `snapshots`, which are the symbols
+    /// of all the scopes covering it. The symbols of the inner scopes go first.
+    ///
+    pub fn visible_at(snapshots: &[Self], location: Location) -> Vec<Symbol> {
+        let mut scopes: Vec<&Self> = snapshots
+            .iter()
+            .filter(|snapshot| snapshot.contains(location))
+            .collect();
+        scopes.sort_by_key(|snapshot| {
+            snapshot
+                .span
+                .as_ref()
+                .map(|span| (span.start_line, span.start_column))
+        });
+
+        scopes
+            .into_iter()
+            .rev()
+            .flat_map(|snapshot| snapshot.symbols.iter().cloned())
+            .collect()
+    }
+}
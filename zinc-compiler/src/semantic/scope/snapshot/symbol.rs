@@ -0,0 +1,74 @@
+//!
+//! The semantic analyzer scope snapshot symbol.
+//!
+
+use serde_derive::Serialize;
+
+use crate::diagnostic::span::Span;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::item::variant::Variant as ItemVariant;
+use crate::semantic::scope::item::Item;
+
+///
+/// The symbol kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Variable,
+    Constant,
+    Function,
+    Type,
+    Module,
+}
+
+///
+/// An item declared in a scope, represented as data.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: Kind,
+    pub r#type: String,
+    pub is_mutable: bool,
+    pub span: Span,
+}
+
+impl Symbol {
+    ///
+    /// Converts the scope item into a symbol.
+    ///
+    /// The built-in items and the enumeration variants are not converted, since they have no
+    /// declaration location or cannot be resolved without their enumeration name.
+    ///
+    pub fn from_item(name: &str, item: &Item) -> Option<Self> {
+        if item.is_namespaced {
+            return None;
+        }
+
+        let span = Span::from(item.location?);
+        let (kind, r#type, is_mutable) = match item.variant {
+            ItemVariant::Variable(ref variable) => (
+                Kind::Variable,
+                variable.r#type.to_string(),
+                variable.is_mutable,
+            ),
+            ItemVariant::Constant(ref constant) => {
+                (Kind::Constant, constant.r#type().to_string(), false)
+            }
+            ItemVariant::Type(ref r#type) => match r#type {
+                Type::Function(_) => (Kind::Function, r#type.to_string(), false),
+                _ => (Kind::Type, r#type.to_string(), false),
+            },
+            ItemVariant::Module(_) => (Kind::Module, item.variant.to_string(), false),
+        };
+
+        Some(Self {
+            name: name.to_owned(),
+            kind,
+            r#type,
+            is_mutable,
+            span,
+        })
+    }
+}
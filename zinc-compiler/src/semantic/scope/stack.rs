@@ -66,11 +66,13 @@ impl Stack {
     }
 
     ///
-    /// Removes the deepest scope from the current hierarchy.
+    /// Removes the deepest scope from the current hierarchy, warning about its unused variables.
     ///
     pub fn pop(&mut self) {
-        self.elements
+        let scope = self
+            .elements
             .pop()
             .expect(PANIC_THERE_MUST_ALWAYS_BE_A_SCOPE);
+        Scope::check_unused_variables(scope);
     }
 }
@@ -4,10 +4,15 @@
 
 #![cfg(test)]
 
+use crate::diagnostic::span::Span as DiagnosticSpan;
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::snapshot::symbol::Kind as ScopeSymbolKind;
+use crate::semantic::scope::snapshot::symbol::Symbol as ScopeSymbol;
+use crate::semantic::scope::snapshot::Snapshot as ScopeSnapshot;
+use crate::warning::Warning;
 
 #[test]
 fn error_item_is_not_namespace() {
@@ -55,6 +60,104 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_item_redeclared_in_conditional_branch() {
+    let input = r#"
+fn main(flag: bool) -> u8 {
+    let value = 42;
+    if flag {
+        let value = true;
+    };
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(5, 9),
+        ScopeError::ItemRedeclared {
+            name: "value".to_owned(),
+            reference: Some(Location::new(3, 9)),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redefined_function() {
+    let input = r#"
+fn hash() -> u8 { 1 }
+
+fn hash() -> u8 { 2 }
+
+fn main() -> u8 {
+    hash()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 1),
+        ScopeError::ItemRedefined {
+            name: "hash".to_owned(),
+            first: Location::new(2, 4),
+            second: Location::new(4, 4),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redefined_structure_enumeration() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+enum Data {
+    Value = 1,
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(6, 1),
+        ScopeError::ItemRedefined {
+            name: "Data".to_owned(),
+            first: Location::new(2, 8),
+            second: Location::new(6, 6),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_variable_shadows_item() {
+    let input = r#"
+const VALUE: u8 = 42;
+
+fn hash() -> u8 { VALUE }
+
+fn main() -> u8 {
+    let hash = 25;
+    let VALUE = hash;
+    VALUE
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn error_item_undeclared() {
     let input = r#"
@@ -67,6 +170,7 @@ fn main() {
         Location::new(3, 5),
         ScopeError::ItemUndeclared {
             name: "result".to_owned(),
+            suggestion: None,
         },
     )));
 
@@ -91,6 +195,158 @@ fn main() {
         Location::new(7, 31),
         ScopeError::ItemUndeclared {
             name: "Exists".to_owned(),
+            suggestion: None,
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_enumeration_variant_unqualified() {
+    let input = r#"
+enum Jabberwocky {
+    Gone = 42,
+}
+
+fn main() -> Jabberwocky {
+    Gone
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(7, 5),
+        ScopeError::EnumerationVariantUnqualified {
+            name: "Gone".to_owned(),
+            enumeration: "Jabberwocky".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_enumeration_variant_unqualified_in_implementation() {
+    let input = r#"
+enum Jabberwocky {
+    Gone = 42,
+}
+
+impl Jabberwocky {
+    fn gone() -> Jabberwocky {
+        Gone
+    }
+}
+
+fn main() -> Jabberwocky {
+    Jabberwocky::gone()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(8, 9),
+        ScopeError::EnumerationVariantUnqualified {
+            name: "Gone".to_owned(),
+            enumeration: "Jabberwocky".to_owned(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_enumeration_variants_namespaced() {
+    let input = r#"
+const First: u8 = 10;
+
+enum Left {
+    First = 1,
+    Second = 2,
+}
+
+enum Right {
+    First = 3,
+    Second = 4,
+}
+
+fn main() -> u8 {
+    Left::First as u8 + Right::First as u8 + First
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_variable_initialized_in_both_branches() {
+    let input = r#"
+fn main() -> u8 {
+    let mut best: u8;
+    let condition = true;
+    if condition {
+        best = 42;
+    } else {
+        best = 69;
+    };
+    best
+}
+"#;
+
+    let expected = Ok(());
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variable_uninitialized() {
+    let input = r#"
+fn main() -> u8 {
+    let mut best: u8;
+    best
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 5),
+        ScopeError::VariableUninitialized {
+            name: "best".to_owned(),
+            reference: Some(Location::new(3, 13)),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variable_uninitialized_in_one_branch() {
+    let input = r#"
+fn main() -> u8 {
+    let mut best: u8;
+    let condition = true;
+    if condition {
+        best = 42;
+    };
+    best
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(8, 5),
+        ScopeError::VariableUninitialized {
+            name: "best".to_owned(),
+            reference: Some(Location::new(3, 13)),
         },
     )));
 
@@ -98,3 +354,271 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_variable_uninitialized_compound_assignment() {
+    let input = r#"
+fn main() {
+    let mut best: u8;
+    best += 1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 5),
+        ScopeError::VariableUninitialized {
+            name: "best".to_owned(),
+            reference: Some(Location::new(3, 13)),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variable_captured() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 42;
+    fn helper() -> u8 {
+        value
+    }
+    helper()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(5, 9),
+        ScopeError::VariableCaptured {
+            name: "value".to_owned(),
+            reference: Some(Location::new(3, 9)),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_undeclared_suggestion() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 42;
+    valu
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 5),
+        ScopeError::ItemUndeclared {
+            name: "valu".to_owned(),
+            suggestion: Some("value".to_owned()),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main() -> u8 {
+    let unused = 42;
+    let _ignored = 42;
+    let used = 42;
+    used
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::new(3, 9),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_used_as_array_size() {
+    let input = r#"
+fn main() -> u8 {
+    let size = 4;
+    let array = [0; size];
+    array[0]
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_used_as_loop_bound() {
+    let input = r#"
+fn main() -> u8 {
+    let count = 4;
+    let mut sum = 0;
+    for i in 0..count {
+        sum += i;
+    }
+    sum
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_used_as_shift_amount() {
+    let input = r#"
+fn main() -> u8 {
+    let shift = 2;
+    let value: u8 = 1;
+    value << shift
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function() {
+    let input = r#"
+fn called() -> u8 {
+    42
+}
+
+fn unused() -> u8 {
+    called()
+}
+
+fn main() -> u8 {
+    called()
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedFunction {
+        location: Location::new(6, 4),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_import() {
+    let input = r#"
+enum Default {
+    First = 1,
+    Second = 2,
+}
+
+use Default::First;
+use Default::Second;
+
+fn main() -> u8 {
+    First as u8
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedImport {
+        location: Location::new(8, 1),
+        path: "Default::Second".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_snapshot_nested_block() {
+    let input = r#"
+const LIMIT: u8 = 10;
+
+fn main(input: u8) -> u8 {
+    let base = input;
+    let result = {
+        let mut inner = base;
+        inner = inner + LIMIT;
+        inner
+    };
+    result
+}
+"#;
+
+    let (snapshots, error) = crate::semantic::tests::compile_entry_symbols(input);
+    assert_eq!(error, None);
+
+    let names = |location: Location| -> Vec<String> {
+        ScopeSnapshot::visible_at(snapshots.as_slice(), location)
+            .into_iter()
+            .map(|symbol| symbol.name)
+            .collect()
+    };
+
+    assert_eq!(
+        names(Location::new(8, 9)),
+        vec!["inner", "base", "result", "input", "LIMIT", "main"]
+    );
+    assert_eq!(
+        names(Location::new(11, 5)),
+        vec!["base", "result", "input", "LIMIT", "main"]
+    );
+
+    let symbols = ScopeSnapshot::visible_at(snapshots.as_slice(), Location::new(8, 9));
+    assert_eq!(
+        symbols.first(),
+        Some(&ScopeSymbol {
+            name: "inner".to_owned(),
+            kind: ScopeSymbolKind::Variable,
+            r#type: "u8".to_owned(),
+            is_mutable: true,
+            span: DiagnosticSpan::from(Location::new(7, 17)),
+        })
+    );
+}
+
+#[test]
+fn ok_snapshot_partial_analysis() {
+    let input = r#"
+fn helper(value: u8) -> u8 {
+    value
+}
+
+fn main() -> u8 {
+    let result = helper(42);
+    undeclared
+}
+"#;
+
+    let (snapshots, error) = crate::semantic::tests::compile_entry_symbols(input);
+    assert!(error.is_some());
+
+    let names: Vec<String> = ScopeSnapshot::visible_at(snapshots.as_slice(), Location::new(3, 5))
+        .into_iter()
+        .map(|symbol| symbol.name)
+        .collect();
+
+    assert_eq!(names, vec!["value", "helper", "main"]);
+}
@@ -13,7 +13,9 @@ use std::rc::Rc;
 use crate::error::Error;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
+use crate::semantic::scope::snapshot::Snapshot as ScopeSnapshot;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 use crate::Parser;
 
 static PANIC_SYNTAX_ERROR: &str = "Syntax errors must be eliminated at this point";
@@ -36,8 +38,44 @@ pub(crate) fn compile_entry_with_dependencies(
     Ok(())
 }
 
+pub(crate) fn compile_entry_with_loop_iterations_limit(
+    input: &str,
+    limit: usize,
+) -> Result<(), Error> {
+    let mut analyzer = EntryAnalyzer::default();
+    analyzer.set_loop_iterations_limit(limit);
+    let _intermediate = analyzer.compile(
+        Parser::default()
+            .parse(input, None)
+            .expect(PANIC_SYNTAX_ERROR),
+        HashMap::new(),
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn compile_entry_warnings(input: &str) -> Result<Vec<Warning>, Error> {
+    let (_intermediate, warnings) = EntryAnalyzer::default().compile(
+        Parser::default()
+            .parse(input, None)
+            .expect(PANIC_SYNTAX_ERROR),
+        HashMap::new(),
+    )?;
+
+    Ok(warnings)
+}
+
+pub(crate) fn compile_entry_symbols(input: &str) -> (Vec<ScopeSnapshot>, Option<Error>) {
+    EntryAnalyzer::default().symbols(
+        Parser::default()
+            .parse(input, None)
+            .expect(PANIC_SYNTAX_ERROR),
+        HashMap::new(),
+    )
+}
+
 pub(crate) fn compile_module(input: &str) -> Result<Rc<RefCell<Scope>>, Error> {
-    let (scope, _intermediate) = ModuleAnalyzer::new().compile(
+    let (scope, _intermediate, _warnings) = ModuleAnalyzer::new().compile(
         Parser::default()
             .parse(input, None)
             .expect(PANIC_SYNTAX_ERROR),
@@ -2,6 +2,9 @@
 //! The syntax parser error.
 //!
 
+use std::convert::TryFrom;
+
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::location::Location;
 
@@ -12,12 +15,14 @@ pub enum Error {
         expected: String,
         found: Lexeme,
         help: Option<&'static str>,
+        suggestion: Option<String>,
     },
     ExpectedOneOfOrOperator {
         location: Location,
         expected: String,
         found: Lexeme,
         help: Option<&'static str>,
+        suggestion: Option<String>,
     },
     ExpectedIdentifier {
         location: Location,
@@ -34,6 +39,11 @@ pub enum Error {
         found: Lexeme,
         help: Option<&'static str>,
     },
+    ReservedKeyword {
+        location: Location,
+        keyword: Keyword,
+        usage: &'static str,
+    },
     ExpectedType {
         location: Location,
         found: Lexeme,
@@ -74,11 +84,14 @@ impl Error {
         found: Lexeme,
         help: Option<&'static str>,
     ) -> Self {
+        let suggestion = Self::suggest_keyword(expected.as_slice(), &found);
+
         Self::ExpectedOneOf {
             location,
             expected: Self::format_one_of(expected.as_slice()),
             found,
             help,
+            suggestion,
         }
     }
 
@@ -88,11 +101,14 @@ impl Error {
         found: Lexeme,
         help: Option<&'static str>,
     ) -> Self {
-        Self::ExpectedOneOf {
+        let suggestion = Self::suggest_keyword(expected.as_slice(), &found);
+
+        Self::ExpectedOneOfOrOperator {
             location,
             expected: Self::format_one_of(expected.as_slice()),
             found,
             help,
+            suggestion,
         }
     }
 
@@ -132,6 +148,14 @@ impl Error {
         }
     }
 
+    pub fn reserved_keyword(location: Location, keyword: Keyword, usage: &'static str) -> Self {
+        Self::ReservedKeyword {
+            location,
+            keyword,
+            usage,
+        }
+    }
+
     pub fn expected_type(location: Location, found: Lexeme, help: Option<&'static str>) -> Self {
         Self::ExpectedType {
             location,
@@ -176,6 +200,26 @@ impl Error {
         Self::ExpectedMatchPattern { location, found }
     }
 
+    ///
+    /// Suggests one of the expected keywords, if the found identifier looks like its misspelling,
+    /// e.g. `lett` instead of `let`.
+    ///
+    pub fn suggest_keyword(expected: &[&'static str], found: &Lexeme) -> Option<String> {
+        let identifier = match found {
+            Lexeme::Identifier(identifier) => identifier,
+            _ => return None,
+        };
+
+        zinc_utils::suggestion::suggest(
+            identifier.inner.as_str(),
+            expected
+                .iter()
+                .copied()
+                .filter(|expected| Keyword::try_from(*expected).is_ok()),
+        )
+        .map(str::to_owned)
+    }
+
     pub fn format_one_of(lexemes: &[&'static str]) -> String {
         lexemes
             .iter()
@@ -183,4 +227,65 @@ impl Error {
             .collect::<Vec<String>>()
             .join(", ")
     }
+
+    ///
+    /// Returns the stable error code, whose explanation can be found with `crate::explain`.
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ExpectedOneOf { .. } => "E0001",
+            Self::ExpectedOneOfOrOperator { .. } => "E0002",
+            Self::ExpectedIdentifier { .. } => "E0003",
+            Self::ExpectedMutOrIdentifier { .. } => "E0004",
+            Self::ExpectedFieldIdentifier { .. } => "E0005",
+            Self::ReservedKeyword { .. } => "E0006",
+            Self::ExpectedType { .. } => "E0007",
+            Self::ExpectedExpressionOrOperand { .. } => "E0008",
+            Self::ExpectedTypeOrValue { .. } => "E0009",
+            Self::ExpectedValue { .. } => "E0010",
+            Self::ExpectedIntegerLiteral { .. } => "E0011",
+            Self::ExpectedBindingPattern { .. } => "E0012",
+            Self::ExpectedMatchPattern { .. } => "E0013",
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        match self {
+            Self::ExpectedOneOf { location, .. } => *location,
+            Self::ExpectedOneOfOrOperator { location, .. } => *location,
+            Self::ExpectedIdentifier { location, .. } => *location,
+            Self::ExpectedMutOrIdentifier { location, .. } => *location,
+            Self::ExpectedFieldIdentifier { location, .. } => *location,
+            Self::ReservedKeyword { location, .. } => *location,
+            Self::ExpectedType { location, .. } => *location,
+            Self::ExpectedExpressionOrOperand { location, .. } => *location,
+            Self::ExpectedTypeOrValue { location, .. } => *location,
+            Self::ExpectedValue { location, .. } => *location,
+            Self::ExpectedIntegerLiteral { location, .. } => *location,
+            Self::ExpectedBindingPattern { location, .. } => *location,
+            Self::ExpectedMatchPattern { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the erroneous lexeme. The reserved keyword error has none, since the keyword
+    /// is reported as an identifier.
+    ///
+    pub fn found(&self) -> Option<&Lexeme> {
+        match self {
+            Self::ExpectedOneOf { found, .. } => Some(found),
+            Self::ExpectedOneOfOrOperator { found, .. } => Some(found),
+            Self::ExpectedIdentifier { found, .. } => Some(found),
+            Self::ExpectedMutOrIdentifier { found, .. } => Some(found),
+            Self::ExpectedFieldIdentifier { found, .. } => Some(found),
+            Self::ReservedKeyword { .. } => None,
+            Self::ExpectedType { found, .. } => Some(found),
+            Self::ExpectedExpressionOrOperand { found, .. } => Some(found),
+            Self::ExpectedTypeOrValue { found, .. } => Some(found),
+            Self::ExpectedValue { found, .. } => Some(found),
+            Self::ExpectedIntegerLiteral { found, .. } => Some(found),
+            Self::ExpectedBindingPattern { found, .. } => Some(found),
+            Self::ExpectedMatchPattern { found, .. } => Some(found),
+        }
+    }
 }
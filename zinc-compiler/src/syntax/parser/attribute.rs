@@ -0,0 +1,194 @@
+//!
+//! The attribute parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::attribute::builder::Builder as AttributeBuilder;
+use crate::syntax::tree::attribute::Attribute;
+use crate::syntax::tree::identifier::Identifier;
+
+static HINT_EXPECTED_IDENTIFIER: &str = "attribute must have an identifier, e.g. `#[unroll]`";
+
+#[derive(Default)]
+pub struct Parser {
+    builder: AttributeBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses an attribute.
+    ///
+    /// '#[unroll]'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Attribute, Option<Token>), Error> {
+        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Number),
+                location,
+            } => self.builder.set_location(location),
+            Token { lexeme, location } => {
+                return Err(Error::Syntax(SyntaxError::expected_one_of(
+                    location,
+                    vec!["#"],
+                    lexeme,
+                    None,
+                )));
+            }
+        }
+
+        match crate::syntax::parser::take_or_next(None, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
+                ..
+            } => {}
+            Token { lexeme, location } => {
+                return Err(Error::Syntax(SyntaxError::expected_one_of(
+                    location,
+                    vec!["["],
+                    lexeme,
+                    None,
+                )));
+            }
+        }
+
+        match crate::syntax::parser::take_or_next(None, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Identifier(identifier),
+                location,
+            } => {
+                let identifier = Identifier::new(location, identifier.inner);
+                self.builder.set_identifier(identifier);
+            }
+            Token { lexeme, location } => {
+                return Err(Error::Syntax(SyntaxError::expected_identifier(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_IDENTIFIER),
+                )));
+            }
+        }
+
+        match crate::syntax::parser::take_or_next(None, stream)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketSquareRight),
+                ..
+            } => Ok((self.builder.finish(), None)),
+            Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec!["]"],
+                lexeme,
+                None,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::attribute::Attribute;
+    use crate::syntax::tree::identifier::Identifier;
+
+    #[test]
+    fn ok() {
+        let input = "#[unroll]";
+
+        let expected = Ok((
+            Attribute::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 3), "unroll".to_owned()),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_square_left() {
+        let input = "#unroll]";
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 2),
+            vec!["["],
+            Lexeme::Identifier(LexicalIdentifier::new("unroll".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = "#[for]";
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 3),
+            Lexeme::Keyword(Keyword::For),
+            Some(super::HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_square_right() {
+        let input = "#[unroll for";
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 10),
+            vec!["]"],
+            Lexeme::Keyword(Keyword::For),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_number() {
+        let input = "[unroll]";
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 1),
+            vec!["#"],
+            Lexeme::Symbol(Symbol::BracketSquareLeft),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -161,6 +161,19 @@ impl Parser {
                             );
                             self.state = State::AccessOrCallOrEnd;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            let (expression, next) =
+                                ExpressionParser::default().parse(stream.clone(), None)?;
+                            self.next = next;
+                            self.builder.eat_operand(
+                                ExpressionOperand::TupleIndexExpression(expression),
+                                location,
+                            );
+                            self.state = State::ParenthesisRight;
+                        }
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
@@ -212,6 +225,7 @@ mod tests {
     use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
@@ -222,6 +236,8 @@ mod tests {
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::tuple_index::TupleIndex;
 
     #[test]
     fn ok() {
@@ -295,6 +311,105 @@ mod tests {
 
         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
 
+        assert_eq!(result, expected);
+    }
+    #[test]
+    fn ok_tuple_index_nested() {
+        let input = r#"tuple.1.2"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 8),
+                ExpressionTreeNode::operator(ExpressionOperator::Field),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::new(1, 6),
+                    ExpressionTreeNode::operator(ExpressionOperator::Field),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 1), "tuple".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::TupleIndex(
+                            TupleIndex::new(
+                                Location::new(1, 7),
+                                IntegerLiteral::new(
+                                    Location::new(1, 7),
+                                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                ),
+                            ),
+                        )),
+                    )),
+                )),
+                Some(ExpressionTree::new(
+                    Location::new(1, 9),
+                    ExpressionTreeNode::operand(ExpressionOperand::TupleIndex(TupleIndex::new(
+                        Location::new(1, 9),
+                        IntegerLiteral::new(
+                            Location::new(1, 9),
+                            LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                        ),
+                    ))),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 10))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_index_expression() {
+        let input = r#"tuple.(INDEX)"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 6),
+                ExpressionTreeNode::operator(ExpressionOperator::Field),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 1),
+                        "tuple".to_owned(),
+                    ))),
+                )),
+                Some(ExpressionTree::new(
+                    Location::new(1, 7),
+                    ExpressionTreeNode::operand(ExpressionOperand::TupleIndexExpression(
+                        ExpressionTree::new(
+                            Location::new(1, 8),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 8), "INDEX".to_owned()),
+                            )),
+                        ),
+                    )),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 14))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_parenthesis_right_tuple_index() {
+        let input = r#"tuple.(INDEX]"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 13),
+            vec![")"],
+            Lexeme::Symbol(Symbol::BracketSquareRight),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
         assert_eq!(result, expected);
     }
 }
@@ -12,8 +12,11 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::parser::expression::access::Parser as AccessOperandParser;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
 #[derive(Default)]
 pub struct Parser {
@@ -26,6 +29,8 @@ impl Parser {
     /// a lower precedence unary logical NOT, bitwise NOT or negation, or binary access
     /// operator expression.
     ///
+    /// The negation applied directly to an integer literal is folded into a negative literal.
+    ///
     /// '-42'
     /// '~0b101010'
     /// '!true'
@@ -60,11 +65,35 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::Minus),
                 location,
             } => {
-                let (expression, next) = Self::default().parse(stream, None)?;
-                self.builder.eat(expression);
-                self.builder
-                    .eat_operator(ExpressionOperator::Negation, location);
-                Ok((self.builder.finish(), next))
+                let token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+                let operand_location = token.location;
+                let (expression, next) = Self::default().parse(stream, Some(token))?;
+                let literal = match *expression.value {
+                    ExpressionTreeNode::Operand(ExpressionOperand::LiteralInteger(ref literal))
+                        if literal.location == operand_location && !literal.is_negative =>
+                    {
+                        Some(literal.inner.to_owned())
+                    }
+                    _ => None,
+                };
+
+                match literal {
+                    Some(literal) => Ok((
+                        ExpressionTree::new(
+                            location,
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new_negative(location, literal),
+                            )),
+                        ),
+                        next,
+                    )),
+                    None => {
+                        self.builder.eat(expression);
+                        self.builder
+                            .eat_operator(ExpressionOperator::Negation, location);
+                        Ok((self.builder.finish(), next))
+                    }
+                }
             }
             token => {
                 let (expression, next) =
@@ -157,25 +186,45 @@ mod tests {
     }
 
     #[test]
-    fn ok_negation() {
+    fn ok_negation_literal() {
         let input = r#"-42"#;
 
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::new(1, 1),
+                ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                    IntegerLiteral::new_negative(
+                        Location::new(1, 1),
+                        LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                    ),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 4))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_negation() {
+        let input = r#"-value"#;
+
         let expected = Ok((
             ExpressionTree::new_with_leaves(
                 Location::new(1, 1),
                 ExpressionTreeNode::operator(ExpressionOperator::Negation),
                 Some(ExpressionTree::new(
                     Location::new(1, 2),
-                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
-                        IntegerLiteral::new(
-                            Location::new(1, 2),
-                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
-                        ),
-                    )),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 2),
+                        "value".to_owned(),
+                    ))),
                 )),
                 None,
             ),
-            Some(Token::new(Lexeme::Eof, Location::new(1, 4))),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 7))),
         ));
 
         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
@@ -0,0 +1,138 @@
+//!
+//! The exponentiation operand parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::parser::expression::casting::Parser as CastingOperandParser;
+use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    CastingFirstOperand,
+    CastingOperator,
+    CastingSecondOperand,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::CastingFirstOperand
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    next: Option<Token>,
+    builder: ExpressionTreeBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a binary exponentiation expression operand, which is
+    /// a lower precedence casting operator expression.
+    ///
+    /// '42 as field'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(ExpressionTree, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::CastingFirstOperand => {
+                    let (expression, next) =
+                        CastingOperandParser::default().parse(stream.clone(), initial.take())?;
+                    self.next = next;
+                    self.builder.eat(expression);
+                    self.state = State::CastingOperator;
+                }
+                State::CastingOperator => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::As),
+                            location,
+                        } => {
+                            self.builder
+                                .eat_operator(ExpressionOperator::Casting, location);
+                            self.state = State::CastingSecondOperand;
+                        }
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::CastingSecondOperand => {
+                    let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
+                    let location = r#type.location;
+                    self.next = next;
+                    self.builder
+                        .eat_operand(ExpressionOperand::Type(r#type), location);
+                    self.state = State::CastingOperator;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok() {
+        let input = r#"42 as field"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 4),
+                ExpressionTreeNode::operator(ExpressionOperator::Casting),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 1),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+                Some(ExpressionTree::new(
+                    Location::new(1, 7),
+                    ExpressionTreeNode::operand(ExpressionOperand::Type(Type::new(
+                        Location::new(1, 7),
+                        TypeVariant::field(),
+                    ))),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 12))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -12,6 +12,7 @@ pub mod bitwise_shift;
 pub mod bitwise_xor;
 pub mod casting;
 pub mod comparison;
+pub mod exponentiation;
 pub mod mul_div_rem;
 pub mod or;
 pub mod path;
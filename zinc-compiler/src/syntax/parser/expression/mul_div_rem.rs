@@ -7,26 +7,24 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
-use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
-use crate::syntax::parser::expression::casting::Parser as CastingOperandParser;
-use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::parser::expression::exponentiation::Parser as ExponentiationOperandParser;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
-use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
-    CastingFirstOperand,
-    CastingOperator,
-    CastingSecondOperand,
+    ExponentiationFirstOperand,
+    ExponentiationOperator,
+    ExponentiationSecondOperand,
 }
 
 impl Default for State {
     fn default() -> Self {
-        State::CastingFirstOperand
+        State::ExponentiationFirstOperand
     }
 }
 
@@ -40,9 +38,12 @@ pub struct Parser {
 impl Parser {
     ///
     /// Parses a binary multiplication, division or remainder expression operand, which is
-    /// a lower precedence casting operator expression.
+    /// a lower precedence exponentiation operator expression.
     ///
-    /// '42 as field'
+    /// The exponentiation operator is right-associative, so the second operand is parsed
+    /// recursively with this parser.
+    ///
+    /// '2 ** 8'
     ///
     pub fn parse(
         mut self,
@@ -51,33 +52,30 @@ impl Parser {
     ) -> Result<(ExpressionTree, Option<Token>), Error> {
         loop {
             match self.state {
-                State::CastingFirstOperand => {
-                    let (expression, next) =
-                        CastingOperandParser::default().parse(stream.clone(), initial.take())?;
+                State::ExponentiationFirstOperand => {
+                    let (expression, next) = ExponentiationOperandParser::default()
+                        .parse(stream.clone(), initial.take())?;
                     self.next = next;
                     self.builder.eat(expression);
-                    self.state = State::CastingOperator;
+                    self.state = State::ExponentiationOperator;
                 }
-                State::CastingOperator => {
+                State::ExponentiationOperator => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
-                            lexeme: Lexeme::Keyword(Keyword::As),
+                            lexeme: Lexeme::Symbol(Symbol::DoubleAsterisk),
                             location,
                         } => {
                             self.builder
-                                .eat_operator(ExpressionOperator::Casting, location);
-                            self.state = State::CastingSecondOperand;
+                                .eat_operator(ExpressionOperator::Exponentiation, location);
+                            self.state = State::ExponentiationSecondOperand;
                         }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
-                State::CastingSecondOperand => {
-                    let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
-                    let location = r#type.location;
-                    self.next = next;
-                    self.builder
-                        .eat_operand(ExpressionOperand::Type(r#type), location);
-                    self.state = State::CastingOperator;
+                State::ExponentiationSecondOperand => {
+                    let (expression, next) = Self::default().parse(stream.clone(), None)?;
+                    self.builder.eat(expression);
+                    return Ok((self.builder.finish(), next));
                 }
             }
         }
@@ -100,32 +98,80 @@ mod tests {
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
-    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
-    use crate::syntax::tree::r#type::Type;
 
     #[test]
     fn ok() {
-        let input = r#"42 as field"#;
+        let input = r#"2 ** 8"#;
 
         let expected = Ok((
             ExpressionTree::new_with_leaves(
-                Location::new(1, 4),
-                ExpressionTreeNode::operator(ExpressionOperator::Casting),
+                Location::new(1, 3),
+                ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
                 Some(ExpressionTree::new(
                     Location::new(1, 1),
                     ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
                         IntegerLiteral::new(
                             Location::new(1, 1),
-                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                            LexicalIntegerLiteral::new_decimal("2".to_owned()),
                         ),
                     )),
                 )),
                 Some(ExpressionTree::new(
-                    Location::new(1, 7),
-                    ExpressionTreeNode::operand(ExpressionOperand::Type(Type::new(
-                        Location::new(1, 7),
-                        TypeVariant::field(),
-                    ))),
+                    Location::new(1, 6),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 6),
+                            LexicalIntegerLiteral::new_decimal("8".to_owned()),
+                        ),
+                    )),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 7))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_right_associative() {
+        let input = r#"2 ** 3 ** 2"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 3),
+                ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 1),
+                            LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                        ),
+                    )),
+                )),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::new(1, 8),
+                    ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 6),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 6),
+                                LexicalIntegerLiteral::new_decimal("3".to_owned()),
+                            ),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 11),
+                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                            ),
+                        )),
+                    )),
                 )),
             ),
             Some(Token::new(Lexeme::Eof, Location::new(1, 12))),
@@ -9,6 +9,7 @@ use crate::error::Error;
 use crate::lexical::stream::TokenStream;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::location::Location;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::statement::local_fn::Parser as FunctionLocalStatementParser;
@@ -78,25 +79,44 @@ impl Parser {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
-                            ..
-                        } => return Ok((self.builder.finish(), self.next.take())),
+                            location,
+                        } => {
+                            self.builder.set_end_location(location);
+                            return Ok((self.builder.finish(), self.next.take()));
+                        }
                         token => {
-                            let (statement, next, is_unterminated) =
-                                FunctionLocalStatementParser::default()
-                                    .parse(stream.clone(), Some(token))?;
-                            self.next = next;
-                            match statement {
-                                FunctionLocalStatement::Expression(expression) => {
-                                    if is_unterminated {
-                                        self.builder.set_expression(expression);
-                                        self.state = State::BracketCurlyRight;
-                                    } else {
-                                        self.builder.push_statement(
-                                            FunctionLocalStatement::Expression(expression),
-                                        );
+                            match FunctionLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))
+                            {
+                                Ok((statement, next, is_unterminated)) => {
+                                    self.next = next;
+                                    match statement {
+                                        FunctionLocalStatement::Expression(expression) => {
+                                            if is_unterminated {
+                                                self.builder.set_expression(expression);
+                                                self.state = State::BracketCurlyRight;
+                                            } else {
+                                                self.builder.push_statement(
+                                                    FunctionLocalStatement::Expression(expression),
+                                                );
+                                            }
+                                        }
+                                        statement => self.builder.push_statement(statement),
+                                    }
+                                }
+                                Err(Error::Syntax(error)) => {
+                                    if let Some(Lexeme::Eof) = error.found() {
+                                        return Err(Error::Syntax(error));
+                                    }
+
+                                    let end_location = self.synchronize(stream.clone(), &error)?;
+                                    stream.borrow_mut().push_recovered_error(error);
+                                    if let Some(location) = end_location {
+                                        self.builder.set_end_location(location);
+                                        return Ok((self.builder.finish(), None));
                                     }
                                 }
-                                statement => self.builder.push_statement(statement),
+                                Err(error) => return Err(error),
                             }
                         }
                     }
@@ -105,8 +125,11 @@ impl Parser {
                     return match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
-                            ..
-                        } => Ok((self.builder.finish(), self.next.take())),
+                            location,
+                        } => {
+                            self.builder.set_end_location(location);
+                            Ok((self.builder.finish(), self.next.take()))
+                        }
                         Token { lexeme, location } => {
                             Err(Error::Syntax(SyntaxError::expected_one_of_or_operator(
                                 location,
@@ -120,6 +143,44 @@ impl Parser {
             }
         }
     }
+
+    ///
+    /// Skips the tokens of an erroneous statement up to its `;` at the block nesting level.
+    ///
+    /// Returns the location of the closing `}` of the block, if it has been reached instead,
+    /// so the block is finished with the statements parsed so far. The erroneous token has been
+    /// already consumed and is taken from the error.
+    ///
+    fn synchronize(
+        &mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        error: &SyntaxError,
+    ) -> Result<Option<Location>, Error> {
+        let mut depth = 0;
+        match error.found() {
+            Some(Lexeme::Symbol(Symbol::Semicolon)) => return Ok(None),
+            Some(Lexeme::Symbol(Symbol::BracketCurlyRight)) => return Ok(Some(error.location())),
+            Some(Lexeme::Symbol(Symbol::BracketCurlyLeft)) => depth += 1,
+            _ => {}
+        }
+
+        loop {
+            let token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+            match token.lexeme {
+                Lexeme::Eof => {
+                    self.next = Some(token);
+                    return Ok(None);
+                }
+                Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+                Lexeme::Symbol(Symbol::BracketCurlyRight) if depth == 0 => {
+                    return Ok(Some(token.location))
+                }
+                Lexeme::Symbol(Symbol::BracketCurlyRight) => depth -= 1,
+                Lexeme::Symbol(Symbol::Semicolon) if depth == 0 => return Ok(None),
+                _ => {}
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -147,7 +208,7 @@ mod tests {
         let input = r#"{}"#;
 
         let expected = Ok((
-            BlockExpression::new(Location::new(1, 1), vec![], None),
+            BlockExpression::new(Location::new(1, 1), vec![], None, Location::new(1, 2)),
             None,
         ));
 
@@ -186,6 +247,7 @@ mod tests {
                         )),
                     )),
                 )),
+                Location::new(1, 9),
             ),
             None,
         ));
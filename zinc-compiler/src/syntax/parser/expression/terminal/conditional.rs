@@ -14,12 +14,8 @@ use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
-use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::conditional::builder::Builder as ConditionalExpressionBuilder;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
-use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
-use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
-use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
@@ -50,8 +46,10 @@ impl Parser {
     /// '
     /// if a > b {
     ///     a
-    /// } else {
+    /// } else if a < b {
     ///     b
+    /// } else {
+    ///     0
     /// }
     /// '
     ///
@@ -92,7 +90,7 @@ impl Parser {
                     let (block, next) =
                         BlockExpressionParser::default().parse(stream.clone(), self.next.take())?;
                     self.next = next;
-                    self.builder.set_main_block(block);
+                    self.builder.push_block(block);
                     self.state = State::ElseKeywordOrEnd;
                 }
                 State::ElseKeywordOrEnd => {
@@ -105,30 +103,11 @@ impl Parser {
                     }
                 }
                 State::KeywordIfOrElseBlock => {
-                    return match crate::syntax::parser::take_or_next(
-                        self.next.take(),
-                        stream.clone(),
-                    )? {
-                        token
-                        @
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::If),
                             ..
-                        } => {
-                            let (expression, next) = Self::default().parse(stream, Some(token))?;
-                            let block = BlockExpression::new(
-                                expression.location,
-                                vec![],
-                                Some(ExpressionTree::new(
-                                    expression.location,
-                                    ExpressionTreeNode::operand(ExpressionOperand::Conditional(
-                                        expression,
-                                    )),
-                                )),
-                            );
-                            self.builder.set_else_block(block);
-                            Ok((self.builder.finish(), next))
-                        }
+                        } => self.state = State::Condition,
                         token
                         @
                         Token {
@@ -137,14 +116,18 @@ impl Parser {
                         } => {
                             let (block, next) =
                                 BlockExpressionParser::default().parse(stream, Some(token))?;
-                            self.next = next;
                             self.builder.set_else_block(block);
-                            Ok((self.builder.finish(), None))
+                            return Ok((self.builder.finish(), next));
                         }
-                        Token { lexeme, location } => Err(Error::Syntax(
-                            SyntaxError::expected_one_of(location, vec!["if", "{"], lexeme, None),
-                        )),
-                    };
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["if", "{"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
                 }
             }
         }
@@ -174,77 +157,77 @@ mod tests {
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
     #[test]
-    fn ok_nested() {
+    fn ok_else_if_chain() {
         let input = r#"if true { 1 } else if false { 2 } else { 3 }"#;
 
         let expected = Ok((
             ConditionalExpression::new(
                 Location::new(1, 1),
-                ExpressionTree::new(
-                    Location::new(1, 4),
-                    ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
-                        BooleanLiteral::new(Location::new(1, 4), LexicalBooleanLiteral::r#true()),
-                    )),
-                ),
-                BlockExpression::new(
-                    Location::new(1, 9),
-                    vec![],
-                    Some(ExpressionTree::new(
-                        Location::new(1, 11),
-                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
-                            IntegerLiteral::new(
+                vec![
+                    (
+                        ExpressionTree::new(
+                            Location::new(1, 4),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                                BooleanLiteral::new(
+                                    Location::new(1, 4),
+                                    LexicalBooleanLiteral::r#true(),
+                                ),
+                            )),
+                        ),
+                        BlockExpression::new(
+                            Location::new(1, 9),
+                            vec![],
+                            Some(ExpressionTree::new(
                                 Location::new(1, 11),
-                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                            ),
-                        )),
-                    )),
-                ),
-                Some(BlockExpression::new(
-                    Location::new(1, 20),
-                    vec![],
-                    Some(ExpressionTree::new(
-                        Location::new(1, 20),
-                        ExpressionTreeNode::operand(ExpressionOperand::Conditional(
-                            ConditionalExpression::new(
-                                Location::new(1, 20),
-                                ExpressionTree::new(
+                                ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                    IntegerLiteral::new(
+                                        Location::new(1, 11),
+                                        LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                    ),
+                                )),
+                            )),
+                            Location::new(1, 13),
+                        ),
+                    ),
+                    (
+                        ExpressionTree::new(
+                            Location::new(1, 23),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                                BooleanLiteral::new(
                                     Location::new(1, 23),
-                                    ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
-                                        BooleanLiteral::new(
-                                            Location::new(1, 23),
-                                            LexicalBooleanLiteral::r#false(),
-                                        ),
-                                    )),
+                                    LexicalBooleanLiteral::r#false(),
                                 ),
-                                BlockExpression::new(
-                                    Location::new(1, 29),
-                                    vec![],
-                                    Some(ExpressionTree::new(
+                            )),
+                        ),
+                        BlockExpression::new(
+                            Location::new(1, 29),
+                            vec![],
+                            Some(ExpressionTree::new(
+                                Location::new(1, 31),
+                                ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                    IntegerLiteral::new(
                                         Location::new(1, 31),
-                                        ExpressionTreeNode::operand(
-                                            ExpressionOperand::LiteralInteger(IntegerLiteral::new(
-                                                Location::new(1, 31),
-                                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
-                                            )),
-                                        ),
-                                    )),
-                                ),
-                                Some(BlockExpression::new(
-                                    Location::new(1, 40),
-                                    vec![],
-                                    Some(ExpressionTree::new(
-                                        Location::new(1, 42),
-                                        ExpressionTreeNode::operand(
-                                            ExpressionOperand::LiteralInteger(IntegerLiteral::new(
-                                                Location::new(1, 42),
-                                                LexicalIntegerLiteral::new_decimal("3".to_owned()),
-                                            )),
-                                        ),
-                                    )),
+                                        LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                                    ),
                                 )),
+                            )),
+                            Location::new(1, 33),
+                        ),
+                    ),
+                ],
+                Some(BlockExpression::new(
+                    Location::new(1, 40),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::new(1, 42),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 42),
+                                LexicalIntegerLiteral::new_decimal("3".to_owned()),
                             ),
                         )),
                     )),
+                    Location::new(1, 44),
                 )),
             ),
             None,
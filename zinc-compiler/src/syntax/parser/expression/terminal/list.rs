@@ -78,13 +78,16 @@ mod tests {
     use std::cell::RefCell;
     use std::rc::Rc;
 
+    use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::literal::boolean::Boolean as LexicalBooleanLiteral;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::list::Expression as ListExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
@@ -260,4 +263,23 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_expression_lone_comma() {
+        let input = r#","#;
+
+        let expected: Result<_, Error> =
+            Err(Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(1, 1),
+                Lexeme::Symbol(Symbol::Comma),
+            )));
+
+        let result = Parser::default().parse(
+            Rc::new(RefCell::new(TokenStream::new(input))),
+            None,
+            Location::new(1, 1),
+        );
+
+        assert_eq!(result, expected);
+    }
 }
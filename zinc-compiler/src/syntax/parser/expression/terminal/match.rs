@@ -23,6 +23,8 @@ pub enum State {
     ScrutineeExpression,
     BracketCurlyLeft,
     BracketCurlyRightOrBranchPattern,
+    GuardOrSelect,
+    GuardExpression,
     Select,
     BranchExpression,
     CommaOrBracketCurlyRight,
@@ -49,6 +51,7 @@ impl Parser {
     /// match value {
     ///     1 => value * 5,
     ///     2 => value * 10,
+    ///     another if another > 100 => 100,
     ///     another => another - 1,
     /// }
     /// '
@@ -113,10 +116,37 @@ impl Parser {
                                 MatchPatternParser::default().parse(stream.clone(), Some(token))?;
                             self.next = next;
                             self.builder.push_branch_pattern(pattern);
-                            self.state = State::Select;
+                            self.state = State::GuardOrSelect;
                         }
                     }
                 }
+                State::GuardOrSelect => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::If),
+                            ..
+                        } => self.state = State::GuardExpression,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::EqualsGreater),
+                            ..
+                        } => self.state = State::BranchExpression,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["if", "=>"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::GuardExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), None)?;
+                    self.next = next;
+                    self.builder.set_branch_guard(expression);
+                    self.state = State::Select;
+                }
                 State::Select => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -181,6 +211,7 @@ mod tests {
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
@@ -241,6 +272,7 @@ mod tests {
                             LexicalBooleanLiteral::r#false(),
                         )),
                     ),
+                    None,
                     ExpressionTree::new(
                         Location::new(3, 18),
                         ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
@@ -288,6 +320,7 @@ mod tests {
                                 LexicalIntegerLiteral::new_decimal("1".to_owned()),
                             )),
                         ),
+                        None,
                         ExpressionTree::new(
                             Location::new(3, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -306,6 +339,7 @@ mod tests {
                                 LexicalIntegerLiteral::new_decimal("2".to_owned()),
                             )),
                         ),
+                        None,
                         ExpressionTree::new(
                             Location::new(4, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -318,6 +352,7 @@ mod tests {
                     ),
                     (
                         MatchPattern::new(Location::new(5, 9), MatchPatternVariant::new_wildcard()),
+                        None,
                         ExpressionTree::new(
                             Location::new(5, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -338,6 +373,85 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_guard() {
+        let input = r#"
+    match test {
+        value if value > 100 => 100,
+        _ => 0,
+    }
+"#;
+        let expected = Ok((
+            MatchExpression::new(
+                Location::new(2, 5),
+                ExpressionTree::new(
+                    Location::new(2, 11),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(2, 11),
+                        "test".to_owned(),
+                    ))),
+                ),
+                vec![
+                    (
+                        MatchPattern::new(
+                            Location::new(3, 9),
+                            MatchPatternVariant::new_binding(Identifier::new(
+                                Location::new(3, 9),
+                                "value".to_owned(),
+                            )),
+                        ),
+                        Some(ExpressionTree::new_with_leaves(
+                            Location::new(3, 24),
+                            ExpressionTreeNode::operator(ExpressionOperator::Greater),
+                            Some(ExpressionTree::new(
+                                Location::new(3, 18),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::new(3, 18), "value".to_owned()),
+                                )),
+                            )),
+                            Some(ExpressionTree::new(
+                                Location::new(3, 26),
+                                ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                    IntegerLiteral::new(
+                                        Location::new(3, 26),
+                                        LexicalIntegerLiteral::new_decimal("100".to_owned()),
+                                    ),
+                                )),
+                            )),
+                        )),
+                        ExpressionTree::new(
+                            Location::new(3, 33),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::new(3, 33),
+                                    LexicalIntegerLiteral::new_decimal("100".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                    (
+                        MatchPattern::new(Location::new(4, 9), MatchPatternVariant::new_wildcard()),
+                        None,
+                        ExpressionTree::new(
+                            Location::new(4, 14),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::new(4, 14),
+                                    LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_curly_left() {
         let input = r#"match 42 * 2 )"#;
@@ -355,11 +469,27 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_select() {
+    fn error_expected_guard_or_select() {
         let input = r#"match 42 * 2 { value ->"#;
 
         let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
             Location::new(1, 22),
+            vec!["if", "=>"],
+            Lexeme::Symbol(Symbol::MinusGreater),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_select() {
+        let input = r#"match 42 * 2 { value if value ->"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 31),
             vec!["=>"],
             Lexeme::Symbol(Symbol::MinusGreater),
             None,
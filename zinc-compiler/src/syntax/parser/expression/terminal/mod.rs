@@ -26,6 +26,7 @@ use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::builder::Builder as IdentifierBuilder;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
+use crate::syntax::tree::literal::char::Literal as CharLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::literal::string::Literal as StringLiteral;
 
@@ -150,6 +151,14 @@ impl Parser {
                     location,
                     None,
                 )),
+                Token {
+                    lexeme: Lexeme::Literal(LexicalLiteral::Char(character)),
+                    location,
+                } => Ok((
+                    ExpressionOperand::LiteralChar(CharLiteral::new(location, character)),
+                    location,
+                    None,
+                )),
                 Token {
                     lexeme: Lexeme::Literal(LexicalLiteral::String(string)),
                     location,
@@ -192,6 +201,7 @@ mod tests {
     use super::Parser;
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::literal::boolean::Boolean as LexicalBooleanLiteral;
+    use crate::lexical::token::lexeme::literal::char::Char as LexicalCharLiteral;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::literal::string::String as LexicalStringLiteral;
     use crate::lexical::token::lexeme::symbol::Symbol;
@@ -205,6 +215,7 @@ mod tests {
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
+    use crate::syntax::tree::literal::char::Literal as CharLiteral;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
     use crate::syntax::tree::literal::string::Literal as StringLiteral;
 
@@ -249,6 +260,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_literal_char() {
+        let input = r#"'A'"#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::new(1, 1),
+                ExpressionTreeNode::Operand(ExpressionOperand::LiteralChar(CharLiteral::new(
+                    Location::new(1, 1),
+                    LexicalCharLiteral::new(b'A'),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_literal_string() {
         let input = r#""description""#;
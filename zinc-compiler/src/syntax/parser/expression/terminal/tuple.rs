@@ -269,4 +269,58 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_multiple_with_comma() {
+        let input = r#"(1, 2,)"#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::new(1, 1),
+                ExpressionTreeNode::operand(ExpressionOperand::Tuple(TupleExpression::new(
+                    Location::new(1, 1),
+                    vec![
+                        ExpressionTree::new(
+                            Location::new(1, 2),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::new(1, 2),
+                                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                ),
+                            )),
+                        ),
+                        ExpressionTree::new(
+                            Location::new(1, 5),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::new(1, 5),
+                                    LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ],
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_expression_lone_comma() {
+        let input = r#"(,)"#;
+
+        let expected: Result<_, Error> =
+            Err(Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(1, 2),
+                Lexeme::Symbol(Symbol::Comma),
+            )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -45,6 +45,16 @@ impl Parser {
                 self.builder.set_location(location);
                 self.builder.set_identifier(identifier);
             }
+            Token {
+                lexeme: Lexeme::Keyword(keyword),
+                location,
+            } => {
+                return Err(Error::Syntax(SyntaxError::reserved_keyword(
+                    location,
+                    keyword,
+                    "field name",
+                )));
+            }
             Token { lexeme, location } => {
                 return Err(Error::Syntax(SyntaxError::expected_identifier(
                     location,
@@ -43,6 +43,17 @@ impl Parser {
                     self.next = next;
                     self.fields.push(field);
                 }
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Keyword(_),
+                    ..
+                } => {
+                    let (field, next) =
+                        FieldParser::default().parse(stream.clone(), Some(token))?;
+                    self.next = next;
+                    self.fields.push(field);
+                }
                 token => return Ok((self.fields, Some(token))),
             }
 
@@ -2,12 +2,14 @@
 //! The syntax parser.
 //!
 
+pub mod attribute;
 pub mod expression;
 pub mod field;
 pub mod field_list;
 pub mod pattern_binding;
 pub mod pattern_binding_list;
 pub mod pattern_match;
+pub mod pattern_structure;
 pub mod statement;
 pub mod r#type;
 pub mod variant;
@@ -18,6 +20,8 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::parser::statement::local_mod::Parser as ModuleLocalStatementParser;
@@ -32,7 +36,29 @@ impl Parser {
     ///
     /// The top-level parser. Parses a list of module level statements.
     ///
-    pub fn parse(mut self, input: &str, file: Option<usize>) -> Result<Tree, Error> {
+    /// Returns the first syntax error, if any. Use `parse_recovering` to get all of them.
+    ///
+    pub fn parse(self, input: &str, file: Option<usize>) -> Result<Tree, Error> {
+        let (tree, errors) = self.parse_recovering(input, file);
+
+        match errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(tree),
+        }
+    }
+
+    ///
+    /// The top-level parser, which does not stop at the first syntax error.
+    ///
+    /// If a statement cannot be parsed, the error is collected and the tokens are skipped up to
+    /// the next `;` or `}` followed by a module level statement, where the parsing is resumed.
+    /// The erroneous statements of blocks are skipped by the block parser in the same way, so
+    /// the enclosing function is still parsed.
+    /// Returns the tree of the successfully parsed statements along with all the errors.
+    ///
+    /// The lexical errors are not recovered, since the token stream cannot advance past them.
+    ///
+    pub fn parse_recovering(mut self, input: &str, file: Option<usize>) -> (Tree, Vec<Error>) {
         let stream = match file {
             Some(file) => TokenStream::new_with_file(input, file),
             None => TokenStream::new(input),
@@ -40,22 +66,94 @@ impl Parser {
         let stream = Rc::new(RefCell::new(stream));
 
         let mut statements = Vec::new();
+        let mut errors = Vec::new();
         loop {
-            match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                Token {
+            let token = match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())
+            {
+                Ok(Token {
                     lexeme: Lexeme::Eof,
                     ..
-                } => break,
-                token => {
-                    let (statement, next) =
-                        ModuleLocalStatementParser::default().parse(stream.clone(), Some(token))?;
+                }) => break,
+                Ok(token) => token,
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
+            };
+
+            let result = ModuleLocalStatementParser::default().parse(stream.clone(), Some(token));
+            errors.extend(
+                stream
+                    .borrow_mut()
+                    .take_recovered_errors()
+                    .into_iter()
+                    .map(Error::Syntax),
+            );
+            match result {
+                Ok((statement, next)) => {
                     self.next = next;
                     statements.push(statement);
                 }
+                Err(error @ Error::Syntax(_)) => {
+                    errors.push(error);
+                    if let Err(error) = self.synchronize(stream.clone()) {
+                        errors.push(error);
+                        break;
+                    }
+                }
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
             }
         }
 
-        Ok(Tree { statements })
+        (Tree { statements }, errors)
+    }
+
+    ///
+    /// Skips the tokens until a module level statement start, which follows a `;` or `}`.
+    ///
+    /// The erroneous token has been already consumed and might have been the statement
+    /// terminator itself, so the very next token is also checked.
+    ///
+    fn synchronize(&mut self, stream: Rc<RefCell<TokenStream>>) -> Result<(), Error> {
+        let mut is_after_terminator = true;
+        loop {
+            let token = crate::syntax::parser::take_or_next(None, stream.clone())?;
+            let is_statement_start = Self::is_statement_start(&token.lexeme);
+            match token.lexeme {
+                Lexeme::Eof => {
+                    self.next = Some(token);
+                    return Ok(());
+                }
+                _ if is_after_terminator && is_statement_start => {
+                    self.next = Some(token);
+                    return Ok(());
+                }
+                Lexeme::Symbol(Symbol::Semicolon) | Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                    is_after_terminator = true
+                }
+                _ => is_after_terminator = false,
+            }
+        }
+    }
+
+    ///
+    /// Checks if the lexeme starts a module level statement.
+    ///
+    fn is_statement_start(lexeme: &Lexeme) -> bool {
+        match lexeme {
+            Lexeme::Keyword(Keyword::Const) => true,
+            Lexeme::Keyword(Keyword::Type) => true,
+            Lexeme::Keyword(Keyword::Struct) => true,
+            Lexeme::Keyword(Keyword::Enum) => true,
+            Lexeme::Keyword(Keyword::Fn) => true,
+            Lexeme::Keyword(Keyword::Mod) => true,
+            Lexeme::Keyword(Keyword::Use) => true,
+            Lexeme::Keyword(Keyword::Impl) => true,
+            _ => false,
+        }
     }
 }
 
@@ -68,3 +166,145 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+
+    static RESERVED_WORDS: &[&str] = &[
+        "let", "const", "type", "struct", "enum", "fn", "mod", "use", "impl", "for", "in", "while",
+        "if", "else", "match", "bool", "u8", "i64", "field", "as", "Self", "self", "pub", "ref",
+        "extern", "return", "loop", "break", "continue", "static", "contract", "trait", "where",
+        "crate", "super",
+    ];
+
+    static POSITIONS: &[(&str, &str)] = &[
+        ("fn main() { let $ = 42; }", "variable name"),
+        ("fn main() { let mut $ = 42; }", "variable name"),
+        ("fn $() {}", "function name"),
+        ("struct Data { $: u8 }", "field name"),
+        ("fn main($: u8) {}", "parameter name"),
+        ("fn main(mut $: u8) {}", "parameter name"),
+    ];
+
+    #[test]
+    fn error_reserved_keyword() {
+        for (template, usage) in POSITIONS.iter() {
+            let column = template.find('$').expect("placeholder") + 1;
+
+            for word in RESERVED_WORDS.iter() {
+                let input = template.replace('$', word);
+
+                let expected = Err(Error::Syntax(SyntaxError::reserved_keyword(
+                    Location::new(1, column),
+                    Keyword::try_from(*word).expect("keyword"),
+                    *usage,
+                )));
+
+                let result = Parser::default().parse(input.as_str(), None);
+
+                assert_eq!(result, expected, "`{}` in `{}`", word, input);
+            }
+        }
+    }
+
+    #[test]
+    fn error_multiple_recovered() {
+        let input = r#"
+fn first() {
+    let value = 5 + ;
+}
+
+fn second() -> {}
+
+struct Data {
+    a: {,
+}
+
+fn main() {}
+"#;
+
+        let expected = vec![
+            Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(3, 21),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            Error::Syntax(SyntaxError::expected_type(
+                Location::new(6, 16),
+                Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                None,
+            )),
+            Error::Syntax(SyntaxError::expected_type(
+                Location::new(9, 8),
+                Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                None,
+            )),
+        ];
+
+        let (tree, errors) = Parser::default().parse_recovering(input, None);
+
+        assert_eq!(errors, expected);
+        assert_eq!(tree.statements.len(), 2);
+    }
+
+    #[test]
+    fn error_multiple_recovered_in_function_body() {
+        let input = r#"
+fn main() {
+    let a = 5 + ;
+    let b: = 10;
+    if true {
+        let c = * a;
+    }
+}
+"#;
+
+        let expected = vec![
+            Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(3, 17),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            Error::Syntax(SyntaxError::expected_type(
+                Location::new(4, 12),
+                Lexeme::Symbol(Symbol::Equals),
+                None,
+            )),
+            Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(6, 17),
+                Lexeme::Symbol(Symbol::Asterisk),
+            )),
+        ];
+
+        let (tree, errors) = Parser::default().parse_recovering(input, None);
+
+        assert_eq!(errors, expected);
+        assert_eq!(tree.statements.len(), 1);
+    }
+
+    #[test]
+    fn error_misspelled_keyword() {
+        let input = "fnn main() {}";
+
+        let result = Parser::default().parse(input, None);
+
+        match result {
+            Err(Error::Syntax(SyntaxError::ExpectedOneOf {
+                location,
+                suggestion,
+                ..
+            })) => {
+                assert_eq!(location, Location::new(1, 1));
+                assert_eq!(suggestion, Some("fn".to_owned()));
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
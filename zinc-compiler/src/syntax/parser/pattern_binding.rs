@@ -88,6 +88,16 @@ impl Parser {
                             self.builder.set_is_wildcard();
                             self.state = State::Colon;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(keyword),
+                            location,
+                        } => {
+                            return Err(Error::Syntax(SyntaxError::reserved_keyword(
+                                location,
+                                keyword,
+                                "parameter name",
+                            )));
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_binding_pattern(
                                 location, lexeme,
@@ -129,6 +139,7 @@ mod tests {
     use crate::error::Error;
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
@@ -200,11 +211,26 @@ mod tests {
 
     #[test]
     fn error_expected_binding_pattern() {
-        let input = "mut bool: bool";
+        let input = "mut (: bool";
 
         let expected = Err(Error::Syntax(SyntaxError::expected_binding_pattern(
             Location::new(1, 5),
-            Lexeme::Keyword(Keyword::Bool),
+            Lexeme::Symbol(Symbol::ParenthesisLeft),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_reserved_keyword() {
+        let input = "mut bool: bool";
+
+        let expected = Err(Error::Syntax(SyntaxError::reserved_keyword(
+            Location::new(1, 5),
+            Keyword::Bool,
+            "parameter name",
         )));
 
         let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
@@ -7,10 +7,10 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
-use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::pattern_binding::Parser as BindingPatternParser;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 
@@ -51,7 +51,7 @@ impl Parser {
                         token
                         @
                         Token {
-                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            lexeme: Lexeme::Keyword(_),
                             ..
                         } => {
                             let (pattern, next) = BindingPatternParser::default()
@@ -81,7 +81,16 @@ impl Parser {
                             self.next = next;
                             self.patterns.push(pattern);
                         }
-                        token => self.next = Some(token),
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            location,
+                        } => {
+                            return Err(Error::Syntax(SyntaxError::expected_binding_pattern(
+                                location,
+                                Lexeme::Symbol(Symbol::Comma),
+                            )));
+                        }
+                        token => return Ok((self.patterns, Some(token))),
                     }
                     self.state = State::CommaOrEnd;
                 }
@@ -104,11 +113,14 @@ mod tests {
     use std::cell::RefCell;
     use std::rc::Rc;
 
+    use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -209,4 +221,49 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_multiple_with_comma() {
+        let input = r#"a: u232, b: u8,"#;
+
+        let expected = Ok((
+            vec![
+                BindingPattern::new(
+                    Location::new(1, 1),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 1),
+                        "a".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 4), TypeVariant::integer_unsigned(232)),
+                ),
+                BindingPattern::new(
+                    Location::new(1, 10),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 10),
+                        "b".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 13), TypeVariant::integer_unsigned(8)),
+                ),
+            ],
+            Some(Token::new(Lexeme::Eof, Location::new(1, 16))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_binding_pattern_lone_comma() {
+        let input = r#","#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_binding_pattern(
+            Location::new(1, 1),
+            Lexeme::Symbol(Symbol::Comma),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -46,6 +46,7 @@ impl Parser {
     ///
     /// 'true'
     /// '42'
+    /// '-42'
     /// 'variable'
     /// 'Path::To::Item'
     /// '_'
@@ -77,6 +78,27 @@ impl Parser {
                                 .set_integer_literal(IntegerLiteral::new(location, integer));
                             return Ok((self.builder.finish(), None));
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Minus),
+                            location,
+                        } => match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                            Token {
+                                lexeme: Lexeme::Literal(LexicalLiteral::Integer(integer)),
+                                ..
+                            } => {
+                                self.builder.set_location(location);
+                                self.builder
+                                    .set_integer_literal(IntegerLiteral::new_negative(
+                                        location, integer,
+                                    ));
+                                return Ok((self.builder.finish(), None));
+                            }
+                            Token { lexeme, location } => {
+                                return Err(Error::Syntax(SyntaxError::expected_integer_literal(
+                                    location, lexeme,
+                                )));
+                            }
+                        },
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
@@ -188,6 +210,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_literal_integer_negative() {
+        let input = "-42";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::IntegerLiteral(IntegerLiteral::new_negative(
+                    Location::new(1, 1),
+                    LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_binding() {
         let input = "value";
@@ -0,0 +1,449 @@
+//!
+//! The structure binding pattern parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::pattern_structure::builder::Builder as StructurePatternBuilder;
+use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
+
+static HINT_EXPECTED_FIELD: &str =
+    "structure pattern must consist of fields, e.g. `let Point { x, y: new_y, .. } = point;`";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    Identifier,
+    BracketCurlyLeft,
+    FieldOrDoubleDotOrBracketCurlyRight,
+    MutableFieldIdentifier,
+    ColonOrCommaOrBracketCurlyRight,
+    Binding,
+    MutableBindingIdentifier,
+    CommaOrBracketCurlyRight,
+    BracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::Identifier
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: StructurePatternBuilder,
+    field: Option<Identifier>,
+}
+
+impl Parser {
+    ///
+    /// Parses a structure binding pattern.
+    ///
+    /// 'Point { x, y: mut new_y, inner: Inner { z, .. }, .. }'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(StructurePattern, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder
+                                .set_identifier(Identifier::new(location, identifier.inner));
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => self.state = State::FieldOrDoubleDotOrBracketCurlyRight,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::FieldOrDoubleDotOrBracketCurlyRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => {
+                            self.builder.set_is_rest();
+                            self.state = State::BracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            ..
+                        } => self.state = State::MutableFieldIdentifier,
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrCommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_field_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_FIELD),
+                            )));
+                        }
+                    }
+                }
+                State::MutableFieldIdentifier => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.push_field(
+                                identifier.clone(),
+                                BindingPatternVariant::new_mutable_binding(identifier),
+                            );
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_field_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_FIELD),
+                            )));
+                        }
+                    }
+                }
+                State::ColonOrCommaOrBracketCurlyRight => {
+                    let field = self
+                        .field
+                        .take()
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            self.field = Some(field);
+                            self.state = State::Binding;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder.push_field(
+                                field.clone(),
+                                BindingPatternVariant::new_binding(field),
+                            );
+                            self.state = State::FieldOrDoubleDotOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.builder.push_field(
+                                field.clone(),
+                                BindingPatternVariant::new_binding(field),
+                            );
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![":", ",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Binding => {
+                    let field = self
+                        .field
+                        .take()
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            ..
+                        } => {
+                            self.field = Some(field);
+                            self.state = State::MutableBindingIdentifier;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Underscore),
+                            ..
+                        } => {
+                            self.builder
+                                .push_field(field, BindingPatternVariant::new_wildcard());
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let is_structure = match stream.borrow_mut().look_ahead(1)? {
+                                Token {
+                                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                                    ..
+                                } => true,
+                                _ => false,
+                            };
+
+                            let binding = if is_structure {
+                                let token = Token::new(Lexeme::Identifier(identifier), location);
+                                let (pattern, _next) =
+                                    Self::default().parse(stream.clone(), Some(token))?;
+                                BindingPatternVariant::new_structure(pattern)
+                            } else {
+                                BindingPatternVariant::new_binding(Identifier::new(
+                                    location,
+                                    identifier.inner,
+                                ))
+                            };
+
+                            self.builder.push_field(field, binding);
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_binding_pattern(
+                                location, lexeme,
+                            )));
+                        }
+                    }
+                }
+                State::MutableBindingIdentifier => {
+                    let field = self
+                        .field
+                        .take()
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder.push_field(
+                                field,
+                                BindingPatternVariant::new_mutable_binding(Identifier::new(
+                                    location,
+                                    identifier.inner,
+                                )),
+                            );
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrBracketCurlyRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::FieldOrDoubleDotOrBracketCurlyRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::syntax::tree::pattern_structure::field::Field as StructurePatternField;
+    use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
+
+    #[test]
+    fn ok_shorthand() {
+        let input = r#"Point { x, mut y }"#;
+
+        let expected = Ok((
+            StructurePattern::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "Point".to_owned()),
+                vec![
+                    StructurePatternField::new(
+                        Identifier::new(Location::new(1, 9), "x".to_owned()),
+                        BindingPatternVariant::new_binding(Identifier::new(
+                            Location::new(1, 9),
+                            "x".to_owned(),
+                        )),
+                    ),
+                    StructurePatternField::new(
+                        Identifier::new(Location::new(1, 16), "y".to_owned()),
+                        BindingPatternVariant::new_mutable_binding(Identifier::new(
+                            Location::new(1, 16),
+                            "y".to_owned(),
+                        )),
+                    ),
+                ],
+                false,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_renaming_and_rest() {
+        let input = r#"Point { x: new_x, y: _, .. }"#;
+
+        let expected = Ok((
+            StructurePattern::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "Point".to_owned()),
+                vec![
+                    StructurePatternField::new(
+                        Identifier::new(Location::new(1, 9), "x".to_owned()),
+                        BindingPatternVariant::new_binding(Identifier::new(
+                            Location::new(1, 12),
+                            "new_x".to_owned(),
+                        )),
+                    ),
+                    StructurePatternField::new(
+                        Identifier::new(Location::new(1, 19), "y".to_owned()),
+                        BindingPatternVariant::new_wildcard(),
+                    ),
+                ],
+                true,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_nested() {
+        let input = r#"Line { start: Point { x, .. } }"#;
+
+        let expected = Ok((
+            StructurePattern::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "Line".to_owned()),
+                vec![StructurePatternField::new(
+                    Identifier::new(Location::new(1, 8), "start".to_owned()),
+                    BindingPatternVariant::new_structure(StructurePattern::new(
+                        Location::new(1, 15),
+                        Identifier::new(Location::new(1, 15), "Point".to_owned()),
+                        vec![StructurePatternField::new(
+                            Identifier::new(Location::new(1, 23), "x".to_owned()),
+                            BindingPatternVariant::new_binding(Identifier::new(
+                                Location::new(1, 23),
+                                "x".to_owned(),
+                            )),
+                        )],
+                        true,
+                    )),
+                )],
+                false,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_right_after_rest() {
+        let input = r#"Point { .., x }"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 11),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::Comma),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
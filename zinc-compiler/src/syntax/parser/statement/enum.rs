@@ -301,4 +301,20 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_bracket_curly_right_lone_comma() {
+        let input = "enum List { , }";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 13),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::Comma),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
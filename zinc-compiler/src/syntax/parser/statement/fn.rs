@@ -95,6 +95,16 @@ impl Parser {
                             self.builder.set_identifier(identifier);
                             self.state = State::ParenthesisLeft;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(keyword),
+                            location,
+                        } => {
+                            return Err(Error::Syntax(SyntaxError::reserved_keyword(
+                                location,
+                                keyword,
+                                "function name",
+                            )));
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
                                 location,
@@ -156,9 +166,18 @@ impl Parser {
                     }
                 }
                 State::ReturnType => {
-                    let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
-                    self.next = next;
-                    self.builder.set_return_type(r#type);
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Underscore),
+                            ..
+                        } => {}
+                        token => {
+                            let (r#type, next) =
+                                TypeParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.set_return_type(r#type);
+                        }
+                    }
                     self.state = State::Body;
                 }
                 State::Body => {
@@ -210,7 +229,7 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 None,
-                BlockExpression::new(Location::new(1, 16), vec![], None),
+                BlockExpression::new(Location::new(1, 16), vec![], None, Location::new(1, 17)),
             ),
             None,
         ));
@@ -237,7 +256,34 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 Some(Type::new(Location::new(1, 19), TypeVariant::field())),
-                BlockExpression::new(Location::new(1, 25), vec![], None),
+                BlockExpression::new(Location::new(1, 25), vec![], None, Location::new(1, 26)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_returns_inferred() {
+        let input = r#"fn f(a: field) -> _ {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![BindingPattern::new(
+                    Location::new(1, 6),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 6),
+                        "a".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 9), TypeVariant::field()),
+                )],
+                None,
+                BlockExpression::new(Location::new(1, 21), vec![], None, Location::new(1, 22)),
             ),
             None,
         ));
@@ -293,4 +339,18 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_binding_pattern_lone_comma() {
+        let input = r#"fn sort(,) {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_binding_pattern(
+            Location::new(1, 9),
+            Lexeme::Symbol(Symbol::Comma),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -12,6 +12,7 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::attribute::Parser as AttributeParser;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
 use crate::syntax::tree::identifier::Identifier;
@@ -23,7 +24,7 @@ static HINT_EXPECTED_INDEX_IDENTIFIER: &str =
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
-    KeywordFor,
+    AttributeOrKeywordFor,
     IndexIdentifier,
     KeywordIn,
     BoundsExpression,
@@ -34,7 +35,7 @@ pub enum State {
 
 impl Default for State {
     fn default() -> Self {
-        State::KeywordFor
+        State::AttributeOrKeywordFor
     }
 }
 
@@ -50,6 +51,7 @@ impl Parser {
     /// Parses a for-loop statement.
     ///
     /// '
+    /// #[unroll]
     /// for i in 0..100 while i < x {
     ///     x += i;
     /// }
@@ -62,8 +64,18 @@ impl Parser {
     ) -> Result<(ForStatement, Option<Token>), Error> {
         loop {
             match self.state {
-                State::KeywordFor => {
+                State::AttributeOrKeywordFor => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Number),
+                            ..
+                        } => {
+                            let (attribute, _next) =
+                                AttributeParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.push_attribute(attribute);
+                        }
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::For),
                             location,
@@ -74,7 +86,7 @@ impl Parser {
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_one_of(
                                 location,
-                                vec!["for"],
+                                vec!["#", "for"],
                                 lexeme,
                                 None,
                             )));
@@ -178,11 +190,13 @@ mod tests {
     use super::Parser;
     use crate::error::Error;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::attribute::Attribute;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
@@ -199,6 +213,7 @@ mod tests {
         let expected = Ok((
             ForStatement::new(
                 Location::new(1, 1),
+                vec![],
                 Identifier::new(Location::new(1, 5), "i".to_owned()),
                 ExpressionTree::new_with_leaves(
                     Location::new(1, 11),
@@ -223,7 +238,7 @@ mod tests {
                     )),
                 ),
                 None,
-                BlockExpression::new(Location::new(1, 15), vec![], None),
+                BlockExpression::new(Location::new(1, 15), vec![], None, Location::new(1, 16)),
             ),
             None,
         ));
@@ -240,6 +255,7 @@ mod tests {
         let expected = Ok((
             ForStatement::new(
                 Location::new(1, 1),
+                vec![],
                 Identifier::new(Location::new(1, 5), "i".to_owned()),
                 ExpressionTree::new_with_leaves(
                     Location::new(1, 11),
@@ -289,6 +305,7 @@ mod tests {
                             )),
                         )),
                     )),
+                    Location::new(1, 24),
                 ),
             ),
             None,
@@ -299,6 +316,67 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_attribute() {
+        let input = r#"#[unroll] for i in 0..4 {}"#;
+
+        let expected = Ok((
+            ForStatement::new(
+                Location::new(1, 11),
+                vec![Attribute::new(
+                    Location::new(1, 1),
+                    Identifier::new(Location::new(1, 3), "unroll".to_owned()),
+                )],
+                Identifier::new(Location::new(1, 15), "i".to_owned()),
+                ExpressionTree::new_with_leaves(
+                    Location::new(1, 21),
+                    ExpressionTreeNode::operator(ExpressionOperator::Range),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 20),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 20),
+                                LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                            ),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 23),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 23),
+                                LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                            ),
+                        )),
+                    )),
+                ),
+                None,
+                BlockExpression::new(Location::new(1, 25), vec![], None, Location::new(1, 26)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_keyword_for() {
+        let input = r#"#[unroll] let i = 0;"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 11),
+            vec!["#", "for"],
+            Lexeme::Keyword(Keyword::Let),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"for { 2 + 2 }"#;
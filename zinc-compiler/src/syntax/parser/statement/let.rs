@@ -13,6 +13,7 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::parser::pattern_structure::Parser as StructurePatternParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#let::builder::Builder as LetStatementBuilder;
@@ -44,6 +45,7 @@ impl Default for State {
 pub struct Parser {
     state: State,
     builder: LetStatementBuilder,
+    is_mutable: bool,
     next: Option<Token>,
 }
 
@@ -51,7 +53,12 @@ impl Parser {
     ///
     /// Parses a 'let' statement.
     ///
+    /// The value may be omitted only if the variable is mutable and has a type.
+    ///
     /// 'let mut value: field = 42;'
+    /// 'let mut value: field;'
+    /// 'let Point { x, y: new_y, .. } = point;'
+    /// 'let _ = hash(value);'
     ///
     pub fn parse(
         mut self,
@@ -86,16 +93,50 @@ impl Parser {
                             ..
                         } => {
                             self.builder.set_mutable();
+                            self.is_mutable = true;
                             self.state = State::Identifier;
                         }
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
                         } => {
-                            let identifier = Identifier::new(location, identifier.inner);
-                            self.builder.set_identifier(identifier);
+                            let is_structure_pattern = match stream.borrow_mut().look_ahead(1)? {
+                                Token {
+                                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                                    ..
+                                } => true,
+                                _ => false,
+                            };
+
+                            if is_structure_pattern {
+                                let token = Token::new(Lexeme::Identifier(identifier), location);
+                                let (pattern, _next) = StructurePatternParser::default()
+                                    .parse(stream.clone(), Some(token))?;
+                                self.builder.set_structure_pattern(pattern);
+                                self.state = State::Equals;
+                            } else {
+                                let identifier = Identifier::new(location, identifier.inner);
+                                self.builder.set_identifier(identifier);
+                                self.state = State::ColonOrEquals;
+                            }
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Underscore),
+                            ..
+                        } => {
+                            self.builder.set_wildcard();
                             self.state = State::ColonOrEquals;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(keyword),
+                            location,
+                        } => {
+                            return Err(Error::Syntax(SyntaxError::reserved_keyword(
+                                location,
+                                keyword,
+                                "variable name",
+                            )));
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_mut_or_identifier(
                                 location,
@@ -115,6 +156,16 @@ impl Parser {
                             self.builder.set_identifier(identifier);
                             self.state = State::ColonOrEquals;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(keyword),
+                            location,
+                        } => {
+                            return Err(Error::Syntax(SyntaxError::reserved_keyword(
+                                location,
+                                keyword,
+                                "variable name",
+                            )));
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
                                 location,
@@ -155,6 +206,10 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::Equals),
                             ..
                         } => self.state = State::Expression,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } if self.is_mutable => return Ok((self.builder.finish(), None)),
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_value(
                                 location,
@@ -210,6 +265,7 @@ mod tests {
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
     use crate::syntax::tree::statement::r#let::Statement as LetStatement;
@@ -221,10 +277,12 @@ mod tests {
         let expected = Ok((
             LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 5), "a".to_owned()),
-                false,
+                BindingPatternVariant::new_binding(Identifier::new(
+                    Location::new(1, 5),
+                    "a".to_owned(),
+                )),
                 None,
-                ExpressionTree::new(
+                Some(ExpressionTree::new(
                     Location::new(1, 9),
                     ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
                         IntegerLiteral::new(
@@ -232,7 +290,7 @@ mod tests {
                             LexicalIntegerLiteral::new_decimal("42".to_owned()),
                         ),
                     )),
-                ),
+                )),
             ),
             None,
         ));
@@ -249,13 +307,15 @@ mod tests {
         let expected = Ok((
             LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 9), "a".to_owned()),
-                true,
+                BindingPatternVariant::new_mutable_binding(Identifier::new(
+                    Location::new(1, 9),
+                    "a".to_owned(),
+                )),
                 Some(Type::new(
                     Location::new(1, 12),
                     TypeVariant::integer_unsigned(232),
                 )),
-                ExpressionTree::new(
+                Some(ExpressionTree::new(
                     Location::new(1, 19),
                     ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
                         IntegerLiteral::new(
@@ -263,7 +323,59 @@ mod tests {
                             LexicalIntegerLiteral::new_decimal("42".to_owned()),
                         ),
                     )),
-                ),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_mut_with_type_without_value() {
+        let input = r#"let mut a: u232;"#;
+
+        let expected = Ok((
+            LetStatement::new(
+                Location::new(1, 1),
+                BindingPatternVariant::new_mutable_binding(Identifier::new(
+                    Location::new(1, 9),
+                    "a".to_owned(),
+                )),
+                Some(Type::new(
+                    Location::new(1, 12),
+                    TypeVariant::integer_unsigned(232),
+                )),
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_wildcard() {
+        let input = r#"let _ = 42;"#;
+
+        let expected = Ok((
+            LetStatement::new(
+                Location::new(1, 1),
+                BindingPatternVariant::new_wildcard(),
+                None,
+                Some(ExpressionTree::new(
+                    Location::new(1, 9),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 9),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
             ),
             None,
         ));
@@ -11,12 +11,16 @@ use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
+use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::syntax::parser::statement::r#for::Parser as ForStatementParser;
 use crate::syntax::parser::statement::r#let::Parser as LetStatementParser;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
+static STATEMENT_KEYWORDS: &[&str] = &["let", "const", "for", "fn"];
+
 #[derive(Default)]
 pub struct Parser {
     next: Option<Token>,
@@ -65,11 +69,35 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Number),
+                ..
+            } => {
+                let (statement, next) =
+                    ForStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::For(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Fn),
+                ..
+            } => {
+                let (statement, next) =
+                    FnStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Fn(statement)
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
             } => return Ok((FunctionLocalStatement::Empty(location), None, false)),
             token => {
+                Self::check_misspelled_keyword(stream.clone(), &token)?;
+
                 let (expression, next) =
                     ExpressionParser::default().parse(stream.clone(), Some(token))?;
                 self.next = next;
@@ -89,6 +117,46 @@ impl Parser {
             statement => Ok((statement, None, false)),
         }
     }
+
+    ///
+    /// Checks if the statement starts with two identifiers, which is never a valid expression,
+    /// and the first one looks like a misspelled keyword, e.g. `lett value = 42;`.
+    ///
+    fn check_misspelled_keyword(
+        stream: Rc<RefCell<TokenStream>>,
+        token: &Token,
+    ) -> Result<(), Error> {
+        let identifier = match token.lexeme {
+            Lexeme::Identifier(ref identifier) => identifier,
+            _ => return Ok(()),
+        };
+
+        let is_followed_by_identifier = match stream.borrow_mut().look_ahead(1)? {
+            Token {
+                lexeme: Lexeme::Identifier(_),
+                ..
+            } => true,
+            _ => false,
+        };
+        if !is_followed_by_identifier {
+            return Ok(());
+        }
+
+        let suggestion = zinc_utils::suggestion::suggest(
+            identifier.inner.as_str(),
+            STATEMENT_KEYWORDS.iter().copied(),
+        );
+        if suggestion.is_none() {
+            return Ok(());
+        }
+
+        Err(Error::Syntax(SyntaxError::expected_one_of(
+            token.location,
+            STATEMENT_KEYWORDS.to_vec(),
+            token.lexeme.to_owned(),
+            None,
+        )))
+    }
 }
 
 #[cfg(test)]
@@ -97,20 +165,25 @@ mod tests {
     use std::rc::Rc;
 
     use super::Parser;
+    use crate::error::Error;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
     use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
+    use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
     use crate::syntax::tree::statement::r#let::Statement as LetStatement;
 
     #[test]
@@ -120,13 +193,15 @@ mod tests {
         let expected = Ok((
             FunctionLocalStatement::Let(LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 9), "a".to_owned()),
-                true,
+                BindingPatternVariant::new_mutable_binding(Identifier::new(
+                    Location::new(1, 9),
+                    "a".to_owned(),
+                )),
                 Some(Type::new(
                     Location::new(1, 12),
                     TypeVariant::integer_unsigned(232),
                 )),
-                ExpressionTree::new(
+                Some(ExpressionTree::new(
                     Location::new(1, 19),
                     ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
                         IntegerLiteral::new(
@@ -134,7 +209,7 @@ mod tests {
                             LexicalIntegerLiteral::new_decimal("42".to_owned()),
                         ),
                     )),
-                ),
+                )),
             )),
             None,
             false,
@@ -164,6 +239,7 @@ mod tests {
                             ),
                         )),
                     )),
+                    Location::new(1, 6),
                 ))),
             )),
             Some(Token::new(Lexeme::Eof, Location::new(1, 7))),
@@ -174,4 +250,58 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_fn() {
+        let input = r#"fn helper() -> u8 { 42 }"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Fn(FnStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 4), "helper".to_owned()),
+                vec![],
+                Some(Type::new(
+                    Location::new(1, 16),
+                    TypeVariant::integer_unsigned(8),
+                )),
+                BlockExpression::new(
+                    Location::new(1, 19),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::new(1, 21),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 21),
+                                LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                            ),
+                        )),
+                    )),
+                    Location::new(1, 24),
+                ),
+            )),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_misspelled_keyword() {
+        let input = r#"lett value = 42;"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::ExpectedOneOf {
+            location: Location::new(1, 1),
+            expected: SyntaxError::format_one_of(&["let", "const", "for", "fn"]),
+            found: Lexeme::Identifier(LexicalIdentifier::new("lett".to_owned())),
+            help: None,
+            suggestion: Some("let".to_owned()),
+        }));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -32,6 +32,7 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'B = -1'
     ///
     pub fn parse(
         mut self,
@@ -70,7 +71,7 @@ impl Parser {
             }
         }
 
-        match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
+        match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
             Token {
                 lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
                 location,
@@ -79,6 +80,22 @@ impl Parser {
                     .set_literal(IntegerLiteral::new(location, literal));
                 Ok((self.builder.finish(), self.next.take()))
             }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Minus),
+                location,
+            } => match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
+                Token {
+                    lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                    ..
+                } => {
+                    self.builder
+                        .set_literal(IntegerLiteral::new_negative(location, literal));
+                    Ok((self.builder.finish(), self.next.take()))
+                }
+                Token { lexeme, location } => Err(Error::Syntax(
+                    SyntaxError::expected_integer_literal(location, lexeme),
+                )),
+            },
             Token { lexeme, location } => Err(Error::Syntax(
                 SyntaxError::expected_integer_literal(location, lexeme),
             )),
@@ -124,6 +141,27 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_negative() {
+        let input = "A = -1";
+
+        let expected = Ok((
+            Variant::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                IntegerLiteral::new_negative(
+                    Location::new(1, 5),
+                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_value() {
         let input = "A";
@@ -152,4 +190,18 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_integer_literal_after_minus() {
+        let input = "A = -id";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_integer_literal(
+            Location::new(1, 6),
+            Lexeme::Identifier(LexicalIdentifier::new("id".to_owned())),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
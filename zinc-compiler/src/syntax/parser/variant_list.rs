@@ -166,4 +166,35 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_multiple_with_comma() {
+        let input = r#"A = 1, B = 2,"#;
+
+        let expected = Ok((
+            vec![
+                Variant::new(
+                    Location::new(1, 1),
+                    Identifier::new(Location::new(1, 1), "A".to_owned()),
+                    IntegerLiteral::new(
+                        Location::new(1, 5),
+                        LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                    ),
+                ),
+                Variant::new(
+                    Location::new(1, 8),
+                    Identifier::new(Location::new(1, 8), "B".to_owned()),
+                    IntegerLiteral::new(
+                        Location::new(1, 12),
+                        LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                    ),
+                ),
+            ],
+            Some(Token::new(Lexeme::Eof, Location::new(1, 14))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -0,0 +1,26 @@
+//!
+//! The attribute.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+
+///
+/// The attribute like `#[unroll]`, which changes the way the following item is compiled.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub location: Location,
+    pub identifier: Identifier,
+}
+
+impl Attribute {
+    pub fn new(location: Location, identifier: Identifier) -> Self {
+        Self {
+            location,
+            identifier,
+        }
+    }
+}
@@ -12,6 +12,7 @@ pub struct Builder {
     location: Option<Location>,
     statements: Vec<FunctionLocalStatement>,
     expression: Option<ExpressionTree>,
+    end_location: Option<Location>,
 }
 
 impl Builder {
@@ -27,6 +28,10 @@ impl Builder {
         self.expression = Some(value);
     }
 
+    pub fn set_end_location(&mut self, value: Location) {
+        self.end_location = Some(value);
+    }
+
     pub fn finish(mut self) -> BlockExpression {
         BlockExpression::new(
             self.location
@@ -34,6 +39,9 @@ impl Builder {
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
             self.statements,
             self.expression.take(),
+            self.end_location.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "end location")
+            }),
         )
     }
 }
@@ -8,11 +8,16 @@ use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
+///
+/// The block expression, whose location is the opening curly bracket and whose end location is
+/// the closing one, which is used to determine the block scope span.
+///
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Expression {
     pub location: Location,
     pub statements: Vec<FunctionLocalStatement>,
     pub expression: Option<Box<ExpressionTree>>,
+    pub end_location: Location,
 }
 
 impl Expression {
@@ -20,11 +25,13 @@ impl Expression {
         location: Location,
         statements: Vec<FunctionLocalStatement>,
         expression: Option<ExpressionTree>,
+        end_location: Location,
     ) -> Self {
         Self {
             location,
             statements,
             expression: expression.map(Box::new),
+            end_location,
         }
     }
 }
@@ -11,7 +11,7 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 pub struct Builder {
     location: Option<Location>,
     condition: Option<ExpressionTree>,
-    main_block: Option<BlockExpression>,
+    branches: Vec<(ExpressionTree, BlockExpression)>,
     else_block: Option<BlockExpression>,
 }
 
@@ -24,8 +24,16 @@ impl Builder {
         self.condition = Some(value);
     }
 
-    pub fn set_main_block(&mut self, value: BlockExpression) {
-        self.main_block = Some(value);
+    ///
+    /// Pushes the `if` or `else if` branch with the condition set before.
+    ///
+    pub fn push_block(&mut self, value: BlockExpression) {
+        let condition = self
+            .condition
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "condition"));
+
+        self.branches.push((condition, value));
     }
 
     pub fn set_else_block(&mut self, value: BlockExpression) {
@@ -33,16 +41,15 @@ impl Builder {
     }
 
     pub fn finish(mut self) -> ConditionalExpression {
+        if self.branches.is_empty() {
+            panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "main block");
+        }
+
         ConditionalExpression::new(
             self.location
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.condition.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "condition")
-            }),
-            self.main_block.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "main block")
-            }),
+            self.branches,
             self.else_block.take(),
         )
     }
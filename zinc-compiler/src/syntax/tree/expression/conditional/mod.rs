@@ -8,25 +8,26 @@ use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 
+///
+/// The `if` branch and the `else if` branches are stored as a flat chain of condition-block
+/// pairs, so the first pair always belongs to the `if` branch.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
     pub location: Location,
-    pub condition: Box<ExpressionTree>,
-    pub main_block: BlockExpression,
+    pub branches: Vec<(ExpressionTree, BlockExpression)>,
     pub else_block: Option<BlockExpression>,
 }
 
 impl Expression {
     pub fn new(
         location: Location,
-        condition: ExpressionTree,
-        main_block: BlockExpression,
+        branches: Vec<(ExpressionTree, BlockExpression)>,
         else_block: Option<BlockExpression>,
     ) -> Self {
         Self {
             location,
-            condition: Box::new(condition),
-            main_block,
+            branches,
             else_block,
         }
     }
@@ -11,7 +11,7 @@ use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 pub struct Builder {
     location: Option<Location>,
     scrutinee: Option<ExpressionTree>,
-    branches: Vec<(MatchPattern, Option<ExpressionTree>)>,
+    branches: Vec<(MatchPattern, Option<ExpressionTree>, Option<ExpressionTree>)>,
 }
 
 impl Builder {
@@ -24,7 +24,20 @@ impl Builder {
     }
 
     pub fn push_branch_pattern(&mut self, value: MatchPattern) {
-        self.branches.push((value, None));
+        self.branches.push((value, None, None));
+    }
+
+    pub fn set_branch_guard(&mut self, value: ExpressionTree) {
+        self.branches
+            .last_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    crate::PANIC_BUILDER_REQUIRES_VALUE,
+                    "branch pattern"
+                )
+            })
+            .1 = Some(value);
     }
 
     pub fn set_branch_expression(&mut self, value: ExpressionTree) {
@@ -37,7 +50,7 @@ impl Builder {
                     "branch expression"
                 )
             })
-            .1 = Some(value);
+            .2 = Some(value);
     }
 
     pub fn finish(self) -> MatchExpression {
@@ -49,9 +62,10 @@ impl Builder {
             }),
             self.branches
                 .into_iter()
-                .map(|(pattern, expression)| {
+                .map(|(pattern, guard, expression)| {
                     (
                         pattern,
+                        guard,
                         expression.unwrap_or_else(|| {
                             panic!(
                                 "{}{}",
@@ -61,7 +75,7 @@ impl Builder {
                         }),
                     )
                 })
-                .collect::<Vec<(MatchPattern, ExpressionTree)>>(),
+                .collect::<Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>>(),
         )
     }
 }
@@ -8,18 +8,21 @@ use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
+///
+/// The match expression branches consist of a pattern, an optional guard, and an expression.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
     pub location: Location,
     pub scrutinee: ExpressionTree,
-    pub branches: Vec<(MatchPattern, ExpressionTree)>,
+    pub branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
 }
 
 impl Expression {
     pub fn new(
         location: Location,
         scrutinee: ExpressionTree,
-        branches: Vec<(MatchPattern, ExpressionTree)>,
+        branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
     ) -> Self {
         Self {
             location,
@@ -6,7 +6,9 @@ pub mod builder;
 pub mod node;
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::expression::array::variant::Variant as ArrayExpressionVariant;
 
+use self::node::operand::Operand;
 use self::node::Node;
 
 ///
@@ -52,4 +54,29 @@ impl Tree {
             right: right.map(Box::new),
         }
     }
+
+    ///
+    /// Checks if the tree is a single integer literal without a type suffix, whose type may be
+    /// inferred from the context.
+    ///
+    pub fn is_untyped_integer_literal(&self) -> bool {
+        match *self.value {
+            Node::Operand(Operand::LiteralInteger(ref literal)) => literal.inner.suffix().is_none(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the tree is a single empty array literal `[]`, whose element type can only be
+    /// taken from a type annotation.
+    ///
+    pub fn is_empty_array_literal(&self) -> bool {
+        match *self.value {
+            Node::Operand(Operand::Array(ref array)) => match array.variant {
+                ArrayExpressionVariant::List { ref elements } => elements.is_empty(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
 }
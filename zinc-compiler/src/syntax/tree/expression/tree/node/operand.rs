@@ -8,9 +8,11 @@ use crate::syntax::tree::expression::conditional::Expression as ConditionalExpre
 use crate::syntax::tree::expression::list::Expression as ListExpression;
 use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
 use crate::syntax::tree::expression::structure::Expression as StructureExpression;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::expression::tuple::Expression as TupleExpression;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
+use crate::syntax::tree::literal::char::Literal as CharLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::literal::string::Literal as StringLiteral;
 use crate::syntax::tree::r#type::Type;
@@ -24,10 +26,14 @@ pub enum Operand {
     LiteralBoolean(BooleanLiteral),
     /// `42`, `0x101010`, etc.
     LiteralInteger(IntegerLiteral),
+    /// `'A'`, `'\xFF'`, etc.
+    LiteralChar(CharLiteral),
     /// "Zinc is the best language for ZKP"
     LiteralString(StringLiteral),
     /// a tuple field identifier
     TupleIndex(TupleIndex),
+    /// a tuple field constant expression index `tuple.(N)`
+    TupleIndexExpression(ExpressionTree),
     /// an item identifier
     Identifier(Identifier),
     /// a syntax type, e.g. a keyword, array, tuple, etc.
@@ -0,0 +1,18 @@
+//!
+//! The character literal.
+//!
+
+use crate::lexical::token::lexeme::literal::char::Char as LexicalCharLiteral;
+use crate::lexical::token::location::Location;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Literal {
+    pub location: Location,
+    pub inner: LexicalCharLiteral,
+}
+
+impl Literal {
+    pub fn new(location: Location, inner: LexicalCharLiteral) -> Self {
+        Self { location, inner }
+    }
+}
@@ -5,14 +5,33 @@
 use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
 use crate::lexical::token::location::Location;
 
+///
+/// The integer literal.
+///
+/// The unary minus applied directly to a literal is folded into the literal, so `is_negative`
+/// is set and the location points to the minus sign.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Literal {
     pub location: Location,
     pub inner: LexicalIntegerLiteral,
+    pub is_negative: bool,
 }
 
 impl Literal {
     pub fn new(location: Location, inner: LexicalIntegerLiteral) -> Self {
-        Self { location, inner }
+        Self {
+            location,
+            inner,
+            is_negative: false,
+        }
+    }
+
+    pub fn new_negative(location: Location, inner: LexicalIntegerLiteral) -> Self {
+        Self {
+            location,
+            inner,
+            is_negative: true,
+        }
     }
 }
@@ -3,5 +3,6 @@
 //!
 
 pub mod boolean;
+pub mod char;
 pub mod integer;
 pub mod string;
@@ -2,12 +2,14 @@
 //! The syntax tree.
 //!
 
+pub mod attribute;
 pub mod expression;
 pub mod field;
 pub mod identifier;
 pub mod literal;
 pub mod pattern_binding;
 pub mod pattern_match;
+pub mod pattern_structure;
 pub mod statement;
 pub mod tuple_index;
 pub mod r#type;
@@ -3,12 +3,14 @@
 //!
 
 use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
     Binding(Identifier),
     MutableBinding(Identifier),
     Wildcard,
+    Structure(StructurePattern),
 }
 
 impl Variant {
@@ -23,4 +25,8 @@ impl Variant {
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
+
+    pub fn new_structure(pattern: StructurePattern) -> Self {
+        Self::Structure(pattern)
+    }
 }
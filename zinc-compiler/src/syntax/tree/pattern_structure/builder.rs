@@ -0,0 +1,49 @@
+//!
+//! The structure binding pattern builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::pattern_structure::field::Field as StructurePatternField;
+use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    identifier: Option<Identifier>,
+    fields: Vec<StructurePatternField>,
+    is_rest: bool,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn push_field(&mut self, identifier: Identifier, binding: BindingPatternVariant) {
+        self.fields
+            .push(StructurePatternField::new(identifier, binding));
+    }
+
+    pub fn set_is_rest(&mut self) {
+        self.is_rest = true;
+    }
+
+    pub fn finish(mut self) -> StructurePattern {
+        StructurePattern::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
+            }),
+            self.fields,
+            self.is_rest,
+        )
+    }
+}
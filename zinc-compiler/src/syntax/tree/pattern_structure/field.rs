@@ -0,0 +1,21 @@
+//!
+//! The structure binding pattern field.
+//!
+
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub identifier: Identifier,
+    pub binding: BindingPatternVariant,
+}
+
+impl Field {
+    pub fn new(identifier: Identifier, binding: BindingPatternVariant) -> Self {
+        Self {
+            identifier,
+            binding,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The structure binding pattern.
+//!
+
+pub mod builder;
+pub mod field;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+
+use self::field::Field;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub location: Location,
+    pub identifier: Identifier,
+    pub fields: Vec<Field>,
+    pub is_rest: bool,
+}
+
+impl Pattern {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        fields: Vec<Field>,
+        is_rest: bool,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            fields,
+            is_rest,
+        }
+    }
+}
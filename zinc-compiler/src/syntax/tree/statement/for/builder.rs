@@ -3,6 +3,7 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
@@ -11,6 +12,7 @@ use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
+    attributes: Vec<Attribute>,
     index_identifier: Option<Identifier>,
     bounds_expression: Option<ExpressionTree>,
     while_condition: Option<ExpressionTree>,
@@ -22,6 +24,10 @@ impl Builder {
         self.location = Some(value);
     }
 
+    pub fn push_attribute(&mut self, value: Attribute) {
+        self.attributes.push(value);
+    }
+
     pub fn set_index_identifier(&mut self, value: Identifier) {
         self.index_identifier = Some(value);
     }
@@ -43,6 +49,7 @@ impl Builder {
             self.location
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.attributes,
             self.index_identifier.take().unwrap_or_else(|| {
                 panic!(
                     "{}{}",
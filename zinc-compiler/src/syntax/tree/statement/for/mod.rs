@@ -5,6 +5,7 @@
 pub mod builder;
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
@@ -12,6 +13,7 @@ use crate::syntax::tree::identifier::Identifier;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub attributes: Vec<Attribute>,
     pub index_identifier: Identifier,
     pub bounds_expression: ExpressionTree,
     pub while_condition: Option<ExpressionTree>,
@@ -21,6 +23,7 @@ pub struct Statement {
 impl Statement {
     pub fn new(
         location: Location,
+        attributes: Vec<Attribute>,
         index_identifier: Identifier,
         bounds_expression: ExpressionTree,
         while_condition: Option<ExpressionTree>,
@@ -28,6 +31,7 @@ impl Statement {
     ) -> Self {
         Self {
             location,
+            attributes,
             index_identifier,
             bounds_expression,
             while_condition,
@@ -5,6 +5,8 @@
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::pattern_structure::Pattern as StructurePattern;
 use crate::syntax::tree::r#type::Type;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
 
@@ -13,6 +15,8 @@ pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
     is_mutable: bool,
+    is_wildcard: bool,
+    structure_pattern: Option<StructurePattern>,
     r#type: Option<Type>,
     expression: Option<ExpressionTree>,
 }
@@ -30,6 +34,14 @@ impl Builder {
         self.is_mutable = true;
     }
 
+    pub fn set_wildcard(&mut self) {
+        self.is_wildcard = true;
+    }
+
+    pub fn set_structure_pattern(&mut self, value: StructurePattern) {
+        self.structure_pattern = Some(value);
+    }
+
     pub fn set_type(&mut self, value: Type) {
         self.r#type = Some(value);
     }
@@ -39,18 +51,31 @@ impl Builder {
     }
 
     pub fn finish(mut self) -> LetStatement {
+        let binding = if let Some(pattern) = self.structure_pattern.take() {
+            BindingPatternVariant::new_structure(pattern)
+        } else if let Some(identifier) = self.identifier.take() {
+            if self.is_mutable {
+                BindingPatternVariant::new_mutable_binding(identifier)
+            } else {
+                BindingPatternVariant::new_binding(identifier)
+            }
+        } else if self.is_wildcard {
+            BindingPatternVariant::new_wildcard()
+        } else {
+            panic!(
+                "{}{}",
+                crate::PANIC_BUILDER_REQUIRES_VALUE,
+                "identifier | wildcard | structure pattern"
+            );
+        };
+
         LetStatement::new(
             self.location
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
-            }),
-            self.is_mutable,
+            binding,
             self.r#type.take(),
-            self.expression.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "expression")
-            }),
+            self.expression.take(),
         )
     }
 }
@@ -6,30 +6,27 @@ pub mod builder;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
-use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::r#type::Type;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
-    pub identifier: Identifier,
-    pub is_mutable: bool,
+    pub binding: BindingPatternVariant,
     pub r#type: Option<Type>,
-    pub expression: ExpressionTree,
+    pub expression: Option<ExpressionTree>,
 }
 
 impl Statement {
     pub fn new(
         location: Location,
-        identifier: Identifier,
-        is_mutable: bool,
+        binding: BindingPatternVariant,
         r#type: Option<Type>,
-        expression: ExpressionTree,
+        expression: Option<ExpressionTree>,
     ) -> Self {
         Self {
             location,
-            identifier,
-            is_mutable,
+            binding,
             r#type,
             expression,
         }
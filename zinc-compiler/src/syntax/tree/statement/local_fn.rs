@@ -5,6 +5,7 @@
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
 
@@ -13,6 +14,7 @@ pub enum Statement {
     Let(LetStatement),
     Const(ConstStatement),
     For(ForStatement),
+    Fn(FnStatement),
     Empty(Location),
     Expression(ExpressionTree),
 }
@@ -23,6 +25,7 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::Fn(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
@@ -0,0 +1,107 @@
+//!
+//! The Zinc compiler warning.
+//!
+
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::lexical::token::location::Location;
+
+///
+/// The compiler warning, which does not prevent the circuit from being compiled, unless the
+/// warnings are denied.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    UnusedVariable {
+        location: Location,
+        name: String,
+    },
+    UnusedFunction {
+        location: Location,
+        name: String,
+    },
+    UnusedImport {
+        location: Location,
+        path: String,
+    },
+    IndexTypeExceedsArraySize {
+        location: Location,
+        r#type: String,
+        size: usize,
+    },
+}
+
+impl Warning {
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. } => *location,
+            Self::UnusedFunction { location, .. } => *location,
+            Self::UnusedImport { location, .. } => *location,
+            Self::IndexTypeExceedsArraySize { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the stable warning code, whose explanation can be found with `crate::explain`.
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnusedVariable { .. } => "W0001",
+            Self::UnusedFunction { .. } => "W0002",
+            Self::UnusedImport { .. } => "W0003",
+            Self::IndexTypeExceedsArraySize { .. } => "W0004",
+        }
+    }
+
+    pub fn format(self, context: &[&str]) -> String {
+        self.diagnostic().format(context)
+    }
+
+    ///
+    /// Converts the warning into a diagnostic, which can be formatted or serialized.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let code = self.code();
+
+        let mut diagnostic = match self {
+            Self::UnusedVariable { location, name } => Diagnostic::line(
+                format!("unused variable `{}`", name).as_str(),
+                location,
+                Some(
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        name
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::UnusedFunction { location, name } => Diagnostic::line(
+                format!("function `{}` is never called from `main`", name).as_str(),
+                location,
+                None,
+            ),
+            Self::UnusedImport { location, path } => {
+                Diagnostic::line(format!("unused import `{}`", path).as_str(), location, None)
+            }
+            Self::IndexTypeExceedsArraySize {
+                location,
+                r#type,
+                size,
+            } => Diagnostic::line(
+                format!("index of type `{}` may exceed the array size {}", r#type, size).as_str(),
+                location,
+                Some(
+                    format!(
+                        "use an index type whose maximal value is less than {0}, or mask the index, e.g. `index % {0}`",
+                        size
+                    )
+                    .as_str(),
+                ),
+            ),
+        };
+
+        diagnostic.set_severity(Severity::Warning);
+        diagnostic.set_code(Some(code));
+        diagnostic
+    }
+}
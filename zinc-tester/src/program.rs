@@ -48,7 +48,7 @@ impl ProgramData {
             .map_err(|error| error.format(lines.as_slice()))
             .map_err(Error::Compiler)?;
 
-        let intermediate = EntryAnalyzer::new()
+        let (intermediate, _warnings) = EntryAnalyzer::new()
             .compile(syntax_tree, HashMap::new())
             .map_err(|error| error.format(lines.as_slice()))
             .map_err(Error::Compiler)?;
@@ -65,3 +65,55 @@ impl ProgramData {
         Ok(program)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pairing::bn256::Bn256;
+    use serde_json::json;
+
+    use zinc_bytecode::data::values::Value;
+    use zinc_vm::{Artifact, ArtifactError, Proof, ProvingKey, VerifyingKey};
+
+    use super::ProgramData;
+
+    const CODE: &str = r#"
+fn main(a: u8, b: u8) -> u8 {
+    a * b
+}
+"#;
+
+    #[test]
+    fn ok_prove_and_verify_with_artifacts() {
+        let program_data =
+            ProgramData::new(&json!({ "a": "3", "b": "5" }), CODE).expect("compilation");
+        let program = program_data.program;
+
+        let params = zinc_vm::setup::<Bn256>(&program).expect("setup");
+        let params = ProvingKey::<Bn256>::from_artifact_bytes(&params.to_artifact_bytes())
+            .expect("proving key decoding");
+        let vk = VerifyingKey::<Bn256>::from_artifact_bytes(&params.vk.to_artifact_bytes())
+            .expect("verifying key decoding");
+
+        let (public_input, proof) =
+            zinc_vm::prove::<Bn256>(&program, &params, &program_data.input).expect("proving");
+        let proof_bytes = proof.to_artifact_bytes();
+        let proof = Proof::<Bn256>::from_artifact_bytes(&proof_bytes).expect("proof decoding");
+        assert_eq!(public_input.to_json(), json!("15"));
+
+        let verified = zinc_vm::verify(&vk, &proof, &public_input).expect("verification");
+        assert!(verified, "the valid proof must be accepted");
+
+        let tampered = Value::from_typed_json(&json!("16"), &program.public_input_type())
+            .expect("public input decoding");
+        let verified = zinc_vm::verify(&vk, &proof, &tampered).expect("verification");
+        assert!(!verified, "the tampered public input must be rejected");
+
+        match VerifyingKey::<Bn256>::from_artifact_bytes(&proof_bytes) {
+            Err(ArtifactError::KindMismatch {
+                expected: 2,
+                found: 3,
+            }) => {}
+            result => panic!("expected an artifact kind mismatch, got {:?}", result.err()),
+        }
+    }
+}
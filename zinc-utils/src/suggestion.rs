@@ -0,0 +1,86 @@
+//!
+//! The misspelled name suggestion.
+//!
+
+///
+/// The Levenshtein distance between two strings.
+///
+/// distance("kitten", "sitting") -> 3
+pub fn distance(first: &str, second: &str) -> usize {
+    let second: Vec<char> = second.chars().collect();
+
+    let mut previous: Vec<usize> = (0..=second.len()).collect();
+    let mut current = vec![0; second.len() + 1];
+
+    for (i, first_char) in first.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, second_char) in second.iter().enumerate() {
+            let substitution = previous[j] + if first_char == *second_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[second.len()]
+}
+
+/// The greatest edit distance of a candidate, which is considered a misspelling of the name.
+const DISTANCE_LIMIT: usize = 2;
+
+/// The shortest name, which is checked for misspellings, since almost any name is within the
+/// distance limit of a shorter one.
+const NAME_LENGTH_MINIMUM: usize = 3;
+
+///
+/// Picks the candidate closest to `name`, if it is similar enough to be a misspelling.
+///
+/// A candidate is considered similar if at most two characters must be changed. The names
+/// shorter than three characters get no suggestions.
+///
+/// suggest("lenght", &["length", "width"]) -> Some("length")
+pub fn suggest<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    if name.chars().count() < NAME_LENGTH_MINIMUM {
+        return None;
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| (distance(name, candidate), candidate))
+        .filter(|(distance, _candidate)| *distance <= DISTANCE_LIMIT)
+        .min_by_key(|(distance, _candidate)| *distance)
+        .map(|(_distance, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+    use super::distance;
+    use super::suggest;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_suggest() {
+        assert_eq!(suggest("lenght", vec!["length", "width"]), Some("length"));
+        assert_eq!(suggest("height", vec!["x", "y"]), None);
+    }
+
+    #[test]
+    fn test_suggest_two_edits() {
+        assert_eq!(suggest("valeu", vec!["width", "value"]), Some("value"));
+        assert_eq!(suggest("vlaeu", vec!["value"]), None);
+    }
+
+    #[test]
+    fn test_suggest_short_name() {
+        assert_eq!(suggest("z", vec!["x", "y"]), None);
+        assert_eq!(suggest("ab", vec!["ac", "xy"]), None);
+    }
+}
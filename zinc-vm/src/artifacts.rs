@@ -0,0 +1,151 @@
+//!
+//! The binary encoding of the proving artifacts.
+//!
+//! Each artifact starts with a header of the magic bytes, the format version, the curve and the
+//! artifact kind, so an artifact written for another curve or in another format is rejected
+//! instead of being misread. The header is followed by the bellman encoding of the artifact.
+//!
+
+use std::io;
+
+use failure::Fail;
+use franklin_crypto::bellman::groth16::Parameters;
+pub use franklin_crypto::bellman::groth16::{Proof, VerifyingKey};
+
+use crate::Engine;
+
+/// The proving key, which contains the verifying key as `vk`.
+pub type ProvingKey<E> = Parameters<E>;
+
+pub const ARTIFACT_MAGIC: [u8; 4] = *b"ZNCA";
+pub const ARTIFACT_VERSION: u8 = 1;
+
+#[derive(Debug, Fail)]
+pub enum ArtifactError {
+    #[fail(display = "{}", _0)]
+    Io(io::Error),
+
+    #[fail(display = "not a Zinc artifact")]
+    InvalidMagic,
+
+    #[fail(
+        display = "artifact version mismatch: expected {}, found {}",
+        expected, found
+    )]
+    VersionMismatch { expected: u8, found: u8 },
+
+    #[fail(
+        display = "artifact curve mismatch: expected {}, found {}",
+        expected, found
+    )]
+    CurveMismatch { expected: u8, found: u8 },
+
+    #[fail(
+        display = "artifact kind mismatch: expected {}, found {}",
+        expected, found
+    )]
+    KindMismatch { expected: u8, found: u8 },
+}
+
+impl From<io::Error> for ArtifactError {
+    fn from(error: io::Error) -> Self {
+        ArtifactError::Io(error)
+    }
+}
+
+///
+/// A proving artifact, which is written and read with the header.
+///
+pub trait Artifact<E: Engine>: Sized {
+    /// The artifact kind written into the header.
+    const KIND: u8;
+
+    fn write_body<W: io::Write>(&self, writer: W) -> io::Result<()>;
+
+    fn read_body<R: io::Read>(reader: R) -> io::Result<Self>;
+
+    fn write_artifact<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&ARTIFACT_MAGIC)?;
+        writer.write_all(&[ARTIFACT_VERSION, E::curve_id(), Self::KIND])?;
+        self.write_body(writer)
+    }
+
+    fn read_artifact<R: io::Read>(mut reader: R) -> Result<Self, ArtifactError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != ARTIFACT_MAGIC {
+            return Err(ArtifactError::InvalidMagic);
+        }
+
+        let mut header = [0; 3];
+        reader.read_exact(&mut header)?;
+        let (version, curve, kind) = (header[0], header[1], header[2]);
+        if version != ARTIFACT_VERSION {
+            return Err(ArtifactError::VersionMismatch {
+                expected: ARTIFACT_VERSION,
+                found: version,
+            });
+        }
+        if curve != E::curve_id() {
+            return Err(ArtifactError::CurveMismatch {
+                expected: E::curve_id(),
+                found: curve,
+            });
+        }
+        if kind != Self::KIND {
+            return Err(ArtifactError::KindMismatch {
+                expected: Self::KIND,
+                found: kind,
+            });
+        }
+
+        Ok(Self::read_body(reader)?)
+    }
+
+    fn to_artifact_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_artifact(&mut bytes)
+            .expect("writing to a vector never fails");
+        bytes
+    }
+
+    fn from_artifact_bytes(bytes: &[u8]) -> Result<Self, ArtifactError> {
+        Self::read_artifact(bytes)
+    }
+}
+
+impl<E: Engine> Artifact<E> for ProvingKey<E> {
+    const KIND: u8 = 1;
+
+    fn write_body<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        self.write(writer)
+    }
+
+    fn read_body<R: io::Read>(reader: R) -> io::Result<Self> {
+        Self::read(reader, true)
+    }
+}
+
+impl<E: Engine> Artifact<E> for VerifyingKey<E> {
+    const KIND: u8 = 2;
+
+    fn write_body<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        self.write(writer)
+    }
+
+    fn read_body<R: io::Read>(reader: R) -> io::Result<Self> {
+        Self::read(reader)
+    }
+}
+
+impl<E: Engine> Artifact<E> for Proof<E> {
+    const KIND: u8 = 3;
+
+    fn write_body<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        self.write(writer)
+    }
+
+    fn read_body<R: io::Read>(reader: R) -> io::Result<Self> {
+        Self::read(reader)
+    }
+}
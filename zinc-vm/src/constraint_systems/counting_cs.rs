@@ -0,0 +1,108 @@
+use bellman::ConstraintSystem;
+use franklin_crypto::bellman::{LinearCombination, SynthesisError, Variable};
+use pairing::Engine;
+use std::marker::PhantomData;
+
+/// Counts the constraints and allocations passed to the wrapped constraint system.
+pub struct CountingConstraintSystem<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    cs: CS,
+    num_constraints: usize,
+    num_allocations: usize,
+    _pd: PhantomData<E>,
+}
+
+impl<E, CS> CountingConstraintSystem<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    pub fn new(cs: CS) -> Self {
+        Self {
+            cs,
+            num_constraints: 0,
+            num_allocations: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// The number of both witness and public input allocations.
+    pub fn num_allocations(&self) -> usize {
+        self.num_allocations
+    }
+
+    pub fn inner(&self) -> &CS {
+        &self.cs
+    }
+
+    pub fn inner_mut(&mut self) -> &mut CS {
+        &mut self.cs
+    }
+
+    pub fn into_inner(self) -> CS {
+        self.cs
+    }
+}
+
+impl<E, CS> ConstraintSystem<E> for CountingConstraintSystem<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.num_allocations += 1;
+        self.cs.alloc(annotation, f)
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.num_allocations += 1;
+        self.cs.alloc_input(annotation, f)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.num_constraints += 1;
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn);
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
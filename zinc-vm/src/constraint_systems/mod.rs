@@ -1,8 +1,10 @@
+mod counting_cs;
 mod debug_cs;
 mod duplicate_removing_cs;
 mod logging_cs;
 mod noop_cs;
 
+pub use counting_cs::*;
 pub use debug_cs::*;
 pub use duplicate_removing_cs::*;
 pub use logging_cs::*;
@@ -0,0 +1,147 @@
+use crate::core::{Cell, State};
+use crate::Engine;
+use num_bigint::{BigInt, ToBigInt};
+use zinc_bytecode::Instruction;
+
+/// A read-only view of the virtual machine state.
+///
+/// The values are `None` if they are unknown, like the witnesses during the setup, and
+/// the uninitialized memory cells are `None` as well.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateView {
+    /// The evaluation stack from the bottom, including the values of the forked branches.
+    pub evaluation_stack: Vec<Option<BigInt>>,
+    /// The memory cells of the current function frame.
+    pub memory_frame: Vec<Option<BigInt>>,
+    /// The condition stack from the bottom.
+    pub conditions: Vec<Option<BigInt>>,
+}
+
+impl StateView {
+    pub fn new<E: Engine>(state: &State<E>) -> Self {
+        let cell_value = |cell: &Cell<E>| match cell {
+            Cell::Value(value) => value.to_bigint(),
+        };
+
+        let memory_frame = match state.frames_stack.last() {
+            Some(frame) => (frame.stack_frame_begin..frame.stack_frame_end)
+                .map(|address| state.data_stack.peek(address).and_then(cell_value))
+                .collect(),
+            None => vec![],
+        };
+
+        Self {
+            evaluation_stack: state.evaluation_stack.cells().map(cell_value).collect(),
+            memory_frame,
+            conditions: state
+                .conditions_stack
+                .iter()
+                .map(|condition| condition.to_bigint())
+                .collect(),
+        }
+    }
+}
+
+/// The hooks called by `VirtualMachine::run_with_debugger` around each executed instruction.
+///
+/// `pc` is the index of the instruction in the bytecode.
+pub trait Debugger {
+    fn before_instruction(&mut self, _pc: usize, _instruction: &Instruction, _state: &StateView) {}
+
+    fn after_instruction(&mut self, _pc: usize, _instruction: &Instruction, _state: &StateView) {}
+}
+
+/// A step of the trace recorded by `TraceRecorder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub before: StateView,
+    pub after: Option<StateView>,
+}
+
+/// Records the state before and after each executed instruction.
+///
+/// The last step has no state after the instruction if the instruction has failed.
+#[derive(Debug, Default)]
+pub struct TraceRecorder {
+    pub steps: Vec<TraceStep>,
+}
+
+impl TraceRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Debugger for TraceRecorder {
+    fn before_instruction(&mut self, pc: usize, instruction: &Instruction, state: &StateView) {
+        self.steps.push(TraceStep {
+            pc,
+            instruction: instruction.clone(),
+            before: state.clone(),
+            after: None,
+        });
+    }
+
+    fn after_instruction(&mut self, _pc: usize, _instruction: &Instruction, state: &StateView) {
+        if let Some(step) = self.steps.last_mut() {
+            step.after = Some(state.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::VirtualMachine;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::Bn256;
+    use zinc_bytecode::data::types::DataType;
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::{InstructionInfo, Program};
+
+    fn values(values: &[usize]) -> Vec<Option<BigInt>> {
+        values
+            .iter()
+            .map(|value| Some(BigInt::from(*value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_trace_recorder() {
+        let program = Program::new(
+            DataType::Unit,
+            DataType::Unit,
+            vec![
+                Call::new(1, 0).wrap(),
+                PushConst::new(2.into(), IntegerType::U8.into()).wrap(),
+                PushConst::new(3.into(), IntegerType::U8.into()).wrap(),
+                Add::default().wrap(),
+                Store::new(0).wrap(),
+                Load::new(0).wrap(),
+            ],
+        );
+
+        let mut vm = VirtualMachine::new(TestConstraintSystem::<Bn256>::new(), true);
+        let mut recorder = TraceRecorder::new();
+        vm.run_with_debugger(&program, Some(&[]), &mut recorder)
+            .expect("the program is valid");
+
+        assert_eq!(recorder.steps.len(), program.bytecode.len());
+
+        let add = &recorder.steps[3];
+        assert_eq!(add.pc, 3);
+        assert_eq!(add.instruction, Add::default().wrap());
+        assert_eq!(add.before.evaluation_stack, values(&[2, 3]));
+        let after = add.after.as_ref().expect("the instruction has succeeded");
+        assert_eq!(after.evaluation_stack, values(&[5]));
+        assert_eq!(after.conditions, values(&[1]));
+
+        let store = recorder.steps[4].after.as_ref().unwrap();
+        assert!(store.evaluation_stack.is_empty());
+        assert_eq!(store.memory_frame, values(&[5]));
+    }
+}
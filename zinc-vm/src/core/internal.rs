@@ -6,6 +6,7 @@ use crate::Result;
 use crate::RuntimeError;
 use crate::{gadgets, Engine};
 use franklin_crypto::bellman::ConstraintSystem;
+use num_bigint::ToBigInt;
 
 /// This is an internal interface to virtual machine used by instructions.
 pub trait InternalVM<E: Engine> {
@@ -28,6 +29,7 @@ pub trait InternalVM<E: Engine> {
     fn branch_end(&mut self) -> Result;
 
     fn exit(&mut self, values_count: usize) -> Result;
+    fn mark_public(&mut self, values_count: usize) -> Result;
     fn call_native<F: NativeFunction<E>>(&mut self, function: F) -> Result;
 }
 
@@ -45,7 +47,16 @@ where
     }
 
     fn load(&mut self, address: usize) -> Result<Cell<E>> {
-        let offset = self.top_frame()?.stack_frame_begin;
+        let frame = self.top_frame()?;
+        let frame_size = frame.stack_frame_end - frame.stack_frame_begin;
+        if address >= frame_size {
+            return Err(RuntimeError::AddressOutOfRange {
+                address,
+                frame_size,
+            });
+        }
+
+        let offset = frame.stack_frame_begin;
         self.state.data_stack.get(offset + address)
     }
 
@@ -54,12 +65,18 @@ where
     }
 
     fn store(&mut self, address: usize, cell: Cell<E>) -> Result {
-        {
-            let frame = self.top_frame()?;
-            frame.stack_frame_end =
-                std::cmp::max(frame.stack_frame_end, frame.stack_frame_begin + address + 1);
-        }
-        let offset = self.top_frame()?.stack_frame_begin;
+        let frame = self.top_frame()?;
+        let end = frame
+            .stack_frame_begin
+            .checked_add(address)
+            .and_then(|end| end.checked_add(1))
+            .ok_or_else(|| RuntimeError::AddressOutOfRange {
+                address,
+                frame_size: frame.stack_frame_end - frame.stack_frame_begin,
+            })?;
+        frame.stack_frame_end = std::cmp::max(frame.stack_frame_end, end);
+
+        let offset = frame.stack_frame_begin;
         self.state.data_stack.set(offset + address, cell)
     }
 
@@ -224,6 +241,15 @@ where
     }
 
     fn exit(&mut self, outputs_count: usize) -> Result {
+        let depth = self.state.evaluation_stack.depth();
+        if depth != outputs_count {
+            return Err(MalformedBytecode::ExitStackDepth {
+                expected: outputs_count,
+                found: depth,
+            }
+            .into());
+        }
+
         for _ in 0..outputs_count {
             let value = self.pop()?.value()?;
             self.outputs.push(value);
@@ -234,6 +260,22 @@ where
         Ok(())
     }
 
+    fn mark_public(&mut self, values_count: usize) -> Result {
+        let mut values = Vec::with_capacity(values_count);
+        for _ in 0..values_count {
+            values.push(self.pop()?.value()?);
+        }
+        values.reverse();
+
+        for value in values {
+            let public = self.operations().output(value.clone())?;
+            self.public_inputs.push(public.to_bigint());
+            self.push(Cell::Value(value))?;
+        }
+
+        Ok(())
+    }
+
     fn call_native<F: NativeFunction<E>>(&mut self, function: F) -> Result {
         let stack = &mut self.state.evaluation_stack;
         let cs = &mut self.cs.cs;
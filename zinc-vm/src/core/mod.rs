@@ -1,11 +1,14 @@
+mod debugger;
 mod internal;
 pub mod location;
 mod state;
 
 pub use crate::errors::RuntimeError;
+pub use debugger::*;
 pub use internal::*;
 pub use state::*;
 
+use crate::constraint_systems::ConstantCS;
 use crate::core::location::CodeLocation;
 use crate::errors::MalformedBytecode;
 use crate::gadgets::{Gadgets, Scalar, ScalarType};
@@ -13,11 +16,15 @@ use crate::Engine;
 use colored::Colorize;
 use franklin_crypto::bellman::ConstraintSystem;
 use num_bigint::{BigInt, ToBigInt};
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use zinc_bytecode::data::types as object_types;
 use zinc_bytecode::program::Program;
 use zinc_bytecode::{dispatch_instruction, Instruction, InstructionInfo};
 
+/// The number of the last executed instructions attached to the stack and type errors.
+const BACKTRACE_LENGTH: usize = 8;
+
 pub trait VMInstruction<E, CS>: InstructionInfo
 where
     E: Engine,
@@ -53,7 +60,9 @@ pub struct VirtualMachine<E: Engine, CS: ConstraintSystem<E>> {
     state: State<E>,
     cs: CounterNamespace<E, CS>,
     outputs: Vec<Scalar<E>>,
+    public_inputs: Vec<Option<BigInt>>,
     pub(crate) location: CodeLocation,
+    backtrace: VecDeque<String>,
 }
 
 impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
@@ -69,7 +78,9 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             },
             cs: CounterNamespace::new(cs),
             outputs: vec![],
+            public_inputs: vec![],
             location: CodeLocation::new(),
+            backtrace: VecDeque::with_capacity(BACKTRACE_LENGTH),
         }
     }
 
@@ -78,14 +89,39 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
     }
 
     pub fn run<CB, F>(
+        &mut self,
+        program: &Program,
+        inputs: Option<&[BigInt]>,
+        instruction_callback: CB,
+        check_cs: F,
+    ) -> Result<Vec<Option<BigInt>>, RuntimeError>
+    where
+        CB: FnMut(&CS, &Instruction, &CodeLocation) -> (),
+        F: FnMut(&CS) -> Result<(), RuntimeError>,
+    {
+        self.execute(program, inputs, instruction_callback, check_cs, None)
+    }
+
+    /// Runs the program calling the `debugger` hooks around each instruction.
+    pub fn run_with_debugger<D: Debugger>(
+        &mut self,
+        program: &Program,
+        inputs: Option<&[BigInt]>,
+        debugger: &mut D,
+    ) -> Result<Vec<Option<BigInt>>, RuntimeError> {
+        self.execute(program, inputs, |_, _, _| {}, |_| Ok(()), Some(debugger))
+    }
+
+    fn execute<CB, F>(
         &mut self,
         program: &Program,
         inputs: Option<&[BigInt]>,
         mut instruction_callback: CB,
         mut check_cs: F,
+        mut debugger: Option<&mut dyn Debugger>,
     ) -> Result<Vec<Option<BigInt>>, RuntimeError>
     where
-        CB: FnMut(&CS) -> (),
+        CB: FnMut(&CS, &Instruction, &CodeLocation) -> (),
         F: FnMut(&CS) -> Result<(), RuntimeError>,
     {
         self.cs.cs.enforce(
@@ -105,22 +141,35 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
         while self.state.instruction_counter < program.bytecode.len() {
             let namespace = format!("step={}, addr={}", step, self.state.instruction_counter);
             self.cs.cs.push_namespace(|| namespace);
-            let instruction = &program.bytecode[self.state.instruction_counter];
+            let index = self.state.instruction_counter;
+            let instruction = &program.bytecode[index];
             log::info!(
                 "{}:{} > {}",
                 step,
-                self.state.instruction_counter,
+                index,
                 dispatch_instruction!(instruction => instruction.to_assembly())
             );
+            if self.backtrace.len() == BACKTRACE_LENGTH {
+                self.backtrace.pop_front();
+            }
+            self.backtrace
+                .push_back(format!("{}: {:?}", index, instruction));
+            if let Some(debugger) = debugger.as_mut() {
+                debugger.before_instruction(index, instruction, &StateView::new(&self.state));
+            }
             self.state.instruction_counter += 1;
-            let result = dispatch_instruction!(instruction => instruction.execute(self));
+            let result = dispatch_instruction!(instruction => instruction.execute(self))
+                .map_err(|error| self.with_instruction_context(error, index, instruction));
             if let Err(err) = result.and(check_cs(&self.cs.cs)) {
                 log::error!("{}\nat {}", err, self.location.to_string().blue());
                 return Err(err);
             }
 
+            if let Some(debugger) = debugger.as_mut() {
+                debugger.after_instruction(index, instruction, &StateView::new(&self.state));
+            }
             log::trace!("{}", self.state);
-            instruction_callback(&self.cs.cs);
+            instruction_callback(&self.cs.cs, instruction, &self.location);
             self.cs.cs.pop_namespace();
             step += 1;
         }
@@ -128,6 +177,30 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
         self.get_outputs()
     }
 
+    /// Attaches the failed instruction and the backtrace to the stack underflow and type errors,
+    /// which are otherwise hard to trace back to the bytecode, and the instruction index to the
+    /// division by zero.
+    fn with_instruction_context(
+        &self,
+        error: RuntimeError,
+        index: usize,
+        instruction: &Instruction,
+    ) -> RuntimeError {
+        match error {
+            RuntimeError::MalformedBytecode(MalformedBytecode::StackUnderflow)
+            | RuntimeError::TypeError { .. } => RuntimeError::InstructionError {
+                index,
+                instruction: format!("{:?}", instruction),
+                backtrace: self.backtrace.iter().cloned().collect(),
+                error: Box::new(error),
+            },
+            RuntimeError::DivisionByZero { pc: None } => {
+                RuntimeError::DivisionByZero { pc: Some(index) }
+            }
+            error => error,
+        }
+    }
+
     fn init_root_frame(
         &mut self,
         input_type: &object_types::DataType,
@@ -153,10 +226,13 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
         Ok(())
     }
 
+    /// Allocates the outputs as public inputs and returns all the public inputs, which are
+    /// the values marked public followed by the outputs.
     fn get_outputs(&mut self) -> Result<Vec<Option<BigInt>>, RuntimeError> {
         let outputs_fr: Vec<_> = self.outputs.iter().map(|f| (*f).clone()).collect();
 
-        let mut outputs_bigint = Vec::with_capacity(outputs_fr.len());
+        let mut outputs_bigint = self.public_inputs.clone();
+        outputs_bigint.reserve(outputs_fr.len());
         for o in outputs_fr.into_iter() {
             let e = self.operations().output(o.clone())?;
             outputs_bigint.push(e.to_bigint());
@@ -197,6 +273,21 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
     }
 }
 
+impl<E: Engine> VirtualMachine<E, ConstantCS> {
+    /// Runs the program computing only the witness values, without generating constraints.
+    ///
+    /// The outputs, debug prints and assertion failures are the same as with a constraint
+    /// system, since they are checked against the values, but the constraints themselves
+    /// are discarded, so their satisfaction is not checked.
+    pub fn run_unconstrained(
+        program: &Program,
+        inputs: &[BigInt],
+    ) -> Result<Vec<Option<BigInt>>, RuntimeError> {
+        let mut vm = Self::new(ConstantCS, true);
+        vm.run(program, Some(inputs), |_, _, _| {}, |_| Ok(()))
+    }
+}
+
 fn data_type_into_scalar_types(dtype: &object_types::DataType) -> Vec<ScalarType> {
     fn internal(types: &mut Vec<ScalarType>, dtype: &object_types::DataType) {
         match dtype {
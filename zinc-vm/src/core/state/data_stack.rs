@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::core::Cell;
 use crate::errors::MalformedBytecode;
@@ -68,6 +68,11 @@ impl<E: Engine> DataStack<E> {
         }
     }
 
+    /// Returns the cell at `address` without failing on the uninitialized memory.
+    pub fn peek(&self, address: usize) -> Option<&Cell<E>> {
+        self.memory.get(address).and_then(Option::as_ref)
+    }
+
     pub fn set(&mut self, address: usize, value: Cell<E>) -> Result<(), RuntimeError> {
         if self.memory.len() <= address {
             let mut extra = vec![None; address + 1 - self.memory.len()];
@@ -161,6 +166,9 @@ impl<E: Engine> DataStack<E> {
     }
 
     /// Conditionally apply one of two deltas.
+    ///
+    /// The cells written by either branch are merged, and a cell written by only one of them
+    /// keeps its value from before the branch on the other side.
     fn merge_pair<CS>(
         &mut self,
         condition: Scalar<E>,
@@ -171,22 +179,31 @@ impl<E: Engine> DataStack<E> {
     where
         CS: ConstraintSystem<E>,
     {
-        for (addr, diff) in delta_then.iter() {
-            let alt = if let Some(diff) = delta_else.get(addr) {
-                Some(diff.new.clone())
-            } else {
-                self.memory[*addr].clone()
+        let addresses: BTreeSet<usize> = delta_then
+            .keys()
+            .chain(delta_else.keys())
+            .copied()
+            .collect();
+
+        for addr in addresses {
+            let new_then = match delta_then.get(&addr) {
+                Some(diff) => Some(diff.new.clone()),
+                None => self.memory[addr].clone(),
+            };
+            let new_else = match delta_else.get(&addr) {
+                Some(diff) => Some(diff.new.clone()),
+                None => self.memory[addr].clone(),
             };
 
-            match (&alt, &diff.new) {
-                (None, _) => {}
-                (Some(Cell::Value(old)), Cell::Value(new)) => {
+            match (&new_then, &new_else) {
+                (Some(Cell::Value(new_then)), Some(Cell::Value(new_else))) => {
                     let cs = ops
                         .constraint_system()
                         .namespace(|| format!("merge address {}", addr));
-                    let value = gadgets::conditional_select(cs, &condition, new, old)?;
-                    self.set(*addr, Cell::Value(value))?;
+                    let value = gadgets::conditional_select(cs, &condition, new_then, new_else)?;
+                    self.set(addr, Cell::Value(value))?;
                 }
+                _ => {}
             }
         }
 
@@ -261,6 +278,25 @@ mod tests {
         ds.merge(condition, &mut ops).unwrap();
         assert_cell_eq(ds.get(4).unwrap(), 42.into());
     }
+
+    #[test]
+    fn test_fork_switch_merge_else_only() {
+        let mut ds = DataStack::new();
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut ops = Gadgets::new(&mut cs);
+        let value = ops.constant_bigint(&42.into(), ScalarType::Field).unwrap();
+        ds.set(4, Cell::Value(value)).unwrap();
+
+        ds.fork();
+        ds.switch_branch().unwrap();
+
+        let value2 = ops.constant_bigint(&13.into(), ScalarType::Field).unwrap();
+        ds.set(4, Cell::Value(value2)).unwrap();
+
+        let condition = Scalar::new_constant_bool(false);
+        ds.merge(condition, &mut ops).unwrap();
+        assert_cell_eq(ds.get(4).unwrap(), 13.into());
+    }
 }
 
 impl<E: Engine> fmt::Display for DataStack<E> {
@@ -40,6 +40,16 @@ impl<E: Engine> EvaluationStack<E> {
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
+    /// Returns the number of values on the stack, including the ones of the forked branches.
+    pub fn depth(&self) -> usize {
+        self.stack.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the values on the stack from the bottom, including the ones of the forked branches.
+    pub fn cells(&self) -> impl Iterator<Item = &Cell<E>> {
+        self.stack.iter().flatten()
+    }
+
     pub fn fork(&mut self) {
         self.stack.push(vec![]);
     }
@@ -82,6 +92,79 @@ impl<E: Engine> EvaluationStack<E> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::{Gadgets, ScalarType};
+
+    use super::*;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    fn fork_sequences(condition: bool) -> Vec<BigInt> {
+        let mut stack = EvaluationStack::new();
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let ops = Gadgets::new(&mut cs);
+
+        stack.fork();
+        for value in [1, 2, 3].iter() {
+            let value = ops
+                .constant_bigint(&(*value).into(), ScalarType::Field)
+                .unwrap();
+            stack.push(Cell::Value(value)).unwrap();
+        }
+        stack.fork();
+        for value in [4, 5, 6].iter() {
+            let value = ops
+                .constant_bigint(&(*value).into(), ScalarType::Field)
+                .unwrap();
+            stack.push(Cell::Value(value)).unwrap();
+        }
+
+        let condition = Scalar::new_constant_bool(condition);
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        stack.merge(cs.namespace(|| "merge"), &condition).unwrap();
+
+        let mut values = Vec::new();
+        for _ in 0..3 {
+            let Cell::Value(value) = stack.pop().unwrap();
+            values.push(value.to_bigint().unwrap());
+        }
+        assert!(stack.pop().is_err());
+        values.reverse();
+        values
+    }
+
+    #[test]
+    fn test_merge_sequence_true() {
+        let values = fork_sequences(true);
+        assert_eq!(values, vec![1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn test_merge_sequence_false() {
+        let values = fork_sequences(false);
+        assert_eq!(values, vec![4.into(), 5.into(), 6.into()]);
+    }
+
+    #[test]
+    fn test_merge_sequence_length_mismatch() {
+        let mut stack = EvaluationStack::new();
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let ops = Gadgets::new(&mut cs);
+
+        stack.fork();
+        let value = ops.constant_bigint(&42.into(), ScalarType::Field).unwrap();
+        stack.push(Cell::Value(value)).unwrap();
+        stack.fork();
+
+        let condition = Scalar::new_constant_bool(true);
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        assert!(stack.merge(cs.namespace(|| "merge"), &condition).is_err());
+    }
+}
+
 impl<E: Engine> fmt::Display for EvaluationStack<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Evaluation Stack:")?;
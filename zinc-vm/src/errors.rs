@@ -39,6 +39,12 @@ pub enum MalformedBytecode {
 
     #[fail(display = "conditional branches produced results of different sizes")]
     BranchStacksDoNotMatch,
+
+    #[fail(
+        display = "`exit` expected {} values on the stack, found {}",
+        expected, found
+    )]
+    ExitStackDepth { expected: usize, found: usize },
 }
 
 #[derive(Debug, Fail)]
@@ -65,6 +71,12 @@ pub enum RuntimeError {
         actual: usize,
     },
 
+    #[fail(
+        display = "address {} is out of range of the stack frame of size {}",
+        address, frame_size
+    )]
+    AddressOutOfRange { address: usize, frame_size: usize },
+
     #[fail(display = "type error: expected {}, got {}", expected, actual)]
     TypeError { expected: String, actual: String },
 
@@ -78,7 +90,7 @@ pub enum RuntimeError {
     UnsatisfiedConstraint,
 
     #[fail(display = "division by zero")]
-    DivisionByZero,
+    DivisionByZero { pc: Option<usize> },
 
     #[fail(display = "inverting zero")]
     ZeroInversion,
@@ -95,8 +107,16 @@ pub enum RuntimeError {
         scalar_type: ScalarType,
     },
 
-    #[fail(display = "using witness as array index is not yet supported")]
-    WitnessArrayIndex,
+    #[fail(display = "value {} does not fit in {} bits", value, bitlength)]
+    ValueOutOfRange { value: BigInt, bitlength: usize },
+
+    #[fail(display = "{}\nat instruction {}: {}", error, index, instruction)]
+    InstructionError {
+        index: usize,
+        instruction: String,
+        backtrace: Vec<String>,
+        error: Box<RuntimeError>,
+    },
 }
 
 impl From<SynthesisError> for RuntimeError {
@@ -9,10 +9,13 @@ use rand::ThreadRng;
 
 use zinc_bytecode::program::Program;
 
-use crate::constraint_systems::{DebugConstraintSystem, DuplicateRemovingCS};
-use crate::core::VirtualMachine;
+use crate::constraint_systems::{
+    ConstantCS, CountingConstraintSystem, DebugConstraintSystem, DuplicateRemovingCS,
+};
+use crate::core::{Debugger, VirtualMachine};
 pub use crate::errors::{MalformedBytecode, Result, RuntimeError, TypeSizeError};
 use crate::gadgets::utils::bigint_to_fr;
+use crate::profile::{ProfileReport, Profiler};
 use crate::Engine;
 use failure::Fail;
 use franklin_crypto::circuit::test::TestConstraintSystem;
@@ -32,7 +35,7 @@ impl<E: Engine> Circuit<E> for VMCircuit<'_> {
         // let cs = LoggingConstraintSystem::new(cs.namespace(|| "logging"));
         let cs = DuplicateRemovingCS::new(cs.namespace(|| "duplicates removing"));
         let mut vm = VirtualMachine::new(cs, false);
-        *self.result = Some(vm.run(self.program, self.inputs, |_| {}, |_| Ok(())));
+        *self.result = Some(vm.run(self.program, self.inputs, |_, _, _| {}, |_| Ok(())));
         Ok(())
     }
 }
@@ -47,7 +50,7 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     let result = vm.run(
         program,
         Some(&inputs_flat),
-        |cs| {
+        |cs, _, _| {
             let num = cs.num_constraints() - num_constraints;
             num_constraints += num;
             log::debug!("Constraints: {}", num);
@@ -71,12 +74,103 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
         .map(|v| v.expect("`run` always computes witness"))
         .collect::<Vec<_>>();
 
-    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-        TypeSizeError::Output {
-            expected: 0,
-            actual: 0,
-        }
-    })?;
+    let value =
+        Value::from_flat_values(&program.public_input_type(), &output_flat).ok_or_else(|| {
+            TypeSizeError::Output {
+                expected: 0,
+                actual: 0,
+            }
+        })?;
+
+    Ok(value)
+}
+
+/// Runs the program without generating constraints, which is much faster than `run`, but
+/// does not check the constraints satisfaction.
+pub fn run_unconstrained<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
+    let inputs_flat = inputs.to_flat_values();
+    let result = VirtualMachine::<Bn256, ConstantCS>::run_unconstrained(program, &inputs_flat)?;
+
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`run_unconstrained` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value =
+        Value::from_flat_values(&program.public_input_type(), &output_flat).ok_or_else(|| {
+            TypeSizeError::Output {
+                expected: 0,
+                actual: 0,
+            }
+        })?;
+
+    Ok(value)
+}
+
+/// Runs the program counting the constraints and allocations made by each instruction.
+///
+/// The constraints are not stored, so their satisfaction is not checked.
+pub fn profile<E: Engine>(program: &Program, inputs: &Value) -> Result<ProfileReport> {
+    let cs = CountingConstraintSystem::<Bn256, _>::new(ConstantCS);
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let mut profiler = Profiler::new();
+    vm.run(
+        program,
+        Some(&inputs_flat),
+        |cs, instruction, location| {
+            profiler.record(
+                instruction,
+                location,
+                cs.num_constraints(),
+                cs.num_allocations(),
+            )
+        },
+        |_| Ok(()),
+    )?;
+
+    Ok(profiler.into_report())
+}
+
+/// Runs the program and returns the number of constraints it has produced.
+///
+/// The constraints are not stored, so their satisfaction is not checked.
+pub fn count_constraints<E: Engine>(program: &Program, inputs: &Value) -> Result<usize> {
+    let cs = CountingConstraintSystem::<Bn256, _>::new(ConstantCS);
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+    vm.run(program, Some(&inputs_flat), |_, _, _| {}, |_| Ok(()))?;
+
+    Ok(vm.constraint_system().num_constraints())
+}
+
+/// Runs the program calling the `debugger` hooks around each instruction.
+pub fn run_with_debugger<E: Engine, D: Debugger>(
+    program: &Program,
+    inputs: &Value,
+    debugger: &mut D,
+) -> Result<Value> {
+    let cs = TestConstraintSystem::<Bn256>::new();
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+    let result = vm.run_with_debugger(program, Some(&inputs_flat), debugger)?;
+
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`run_with_debugger` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value =
+        Value::from_flat_values(&program.public_input_type(), &output_flat).ok_or_else(|| {
+            TypeSizeError::Output {
+                expected: 0,
+                actual: 0,
+            }
+        })?;
 
     Ok(value)
 }
@@ -91,7 +185,7 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     let result = vm.run(
         program,
         Some(&inputs_flat),
-        |cs| {
+        |cs, _, _| {
             let num = cs.num_constraints() - num_constraints;
             num_constraints += num;
             log::debug!("Constraints: {}", num);
@@ -127,12 +221,13 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
         .map(|v| v.expect("`run` always computes witness"))
         .collect::<Vec<_>>();
 
-    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-        TypeSizeError::Output {
-            expected: 0,
-            actual: 0,
-        }
-    })?;
+    let value =
+        Value::from_flat_values(&program.public_input_type(), &output_flat).ok_or_else(|| {
+            TypeSizeError::Output {
+                expected: 0,
+                actual: 0,
+            }
+        })?;
 
     Ok(value)
 }
@@ -188,12 +283,10 @@ pub fn prove<E: Engine>(
                     .map(|v| v.expect("`prove` always computes witness"))
                     .collect();
 
-                let value =
-                    Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-                        TypeSizeError::Output {
-                            expected: 0,
-                            actual: 0,
-                        }
+                let value = Value::from_flat_values(&program.public_input_type(), &output_flat)
+                    .ok_or_else(|| TypeSizeError::Output {
+                        expected: 0,
+                        actual: 0,
                     })?;
 
                 Ok((value, proof))
@@ -0,0 +1,292 @@
+use super::wrapping::{any, integer_type, mul_unsigned, pack, to_unsigned};
+use crate::gadgets::{IntegerType, Scalar};
+use crate::{gadgets, Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+/// Adds the integers `left` and `right` modulo `2^bitlength` of their type.
+///
+/// Returns the wrapped sum and the boolean flag, which is set if the sum overflows the type.
+/// An unsigned sum overflows if its carry bit is set. A signed sum overflows if the operands
+/// have the same sign bit, which differs from the sign bit of the wrapped sum.
+pub fn add_checked<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+
+    let (result, is_overflow) = if int_type.is_signed {
+        let result = gadgets::add_wrapping(cs.namespace(|| "add"), left, right)?;
+
+        let left_sign = sign_bit(cs.namespace(|| "left sign"), left, int_type)?;
+        let right_sign = sign_bit(cs.namespace(|| "right sign"), right, int_type)?;
+        let result_sign = sign_bit(cs.namespace(|| "result sign"), &result, int_type)?;
+
+        let is_sign_different =
+            Boolean::xor(cs.namespace(|| "operands sign"), &left_sign, &right_sign)?;
+        let is_sign_changed =
+            Boolean::xor(cs.namespace(|| "result sign"), &left_sign, &result_sign)?;
+        let is_overflow = Boolean::and(
+            cs.namespace(|| "is overflow"),
+            &is_sign_different.not(),
+            &is_sign_changed,
+        )?;
+
+        (result, is_overflow)
+    } else {
+        let sum = gadgets::add(cs.namespace(|| "add"), left, right)?;
+        let mut bits = sum
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "into_bits"), int_type.bitlength + 1)?;
+        let carry = bits.pop().expect("the sum has more than one bit");
+        let result = pack(cs.namespace(|| "pack"), &bits)?;
+
+        (result.with_type_unchecked(int_type.into()), carry)
+    };
+
+    let is_overflow = Scalar::from_boolean(cs.namespace(|| "overflow"), is_overflow)?;
+    Ok((result, is_overflow))
+}
+
+/// Multiplies the integers `left` and `right` modulo `2^bitlength` of their type.
+///
+/// Returns the wrapped product and the boolean flag, which is set if the product overflows
+/// the type. The flag of a signed product is computed from the unsigned product of the operands
+/// absolute values, which may reach `2^(bitlength - 1)` only if the product is negative.
+pub fn mul_checked<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let bitlength = int_type.bitlength;
+
+    let (result, is_overflow) = if int_type.is_signed {
+        let result = gadgets::mul_wrapping(cs.namespace(|| "mul"), left, right)?;
+
+        let left_abs = gadgets::abs(cs.namespace(|| "left abs"), left)?;
+        let right_abs = gadgets::abs(cs.namespace(|| "right abs"), right)?;
+        let (mut bits, is_unsigned_overflow) =
+            mul_unsigned(cs.namespace(|| "mul abs"), &left_abs, &right_abs, bitlength)?;
+        let top = bits.pop().expect("the product has more than one bit");
+        let is_rest = any(cs.namespace(|| "is rest"), &bits)?;
+
+        let left_sign = sign_bit(cs.namespace(|| "left sign"), left, int_type)?;
+        let right_sign = sign_bit(cs.namespace(|| "right sign"), right, int_type)?;
+        let is_negative = Boolean::xor(cs.namespace(|| "is negative"), &left_sign, &right_sign)?;
+
+        // The absolute value exceeds the maximal positive value, unless it is exactly
+        // `2^(bitlength - 1)` and the product is negative.
+        let is_minimal = Boolean::and(cs.namespace(|| "is minimal"), &is_negative, &is_rest.not())?;
+        let is_exceeding = Boolean::and(cs.namespace(|| "is exceeding"), &top, &is_minimal.not())?;
+        let is_overflow = any(
+            cs.namespace(|| "is overflow"),
+            &[is_unsigned_overflow, is_exceeding],
+        )?;
+
+        (result, is_overflow)
+    } else {
+        let (bits, is_overflow) = mul_unsigned(cs.namespace(|| "mul"), left, right, bitlength)?;
+        let result = pack(cs.namespace(|| "pack"), &bits)?;
+
+        (result.with_type_unchecked(int_type.into()), is_overflow)
+    };
+
+    let is_overflow = Scalar::from_boolean(cs.namespace(|| "overflow"), is_overflow)?;
+    Ok((result, is_overflow))
+}
+
+/// Returns the most significant bit of the two's complement representation of a signed integer.
+fn sign_bit<E, CS>(mut cs: CS, scalar: &Scalar<E>, int_type: IntegerType) -> Result<Boolean>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let unsigned = to_unsigned(cs.namespace(|| "unsigned"), scalar, int_type)?;
+    let mut bits = unsigned
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "into_bits"), int_type.bitlength)?;
+    Ok(bits.pop().expect("the integer has more than one bit"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::utils::bigint_to_fr;
+    use crate::gadgets::ScalarType;
+
+    const U128: IntegerType = IntegerType {
+        is_signed: false,
+        bitlength: 128,
+    };
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: &BigInt,
+        int_type: IntegerType,
+    ) -> Scalar<Bn256> {
+        let fr = bigint_to_fr::<Bn256>(value).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, int_type.into())
+    }
+
+    fn check<F>(operation: F, int_type: IntegerType, cases: Vec<(BigInt, BigInt, BigInt, bool)>)
+    where
+        F: Fn(
+            &mut TestConstraintSystem<Bn256>,
+            &Scalar<Bn256>,
+            &Scalar<Bn256>,
+        ) -> Result<(Scalar<Bn256>, Scalar<Bn256>)>,
+    {
+        for (left, right, expected, is_overflow) in cases.into_iter() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+            let left_scalar = variable(cs.namespace(|| "left"), &left, int_type);
+            let right_scalar = variable(cs.namespace(|| "right"), &right, int_type);
+
+            let (result, flag) = operation(&mut cs, &left_scalar, &right_scalar).unwrap();
+
+            assert!(
+                cs.is_satisfied(),
+                "unsatisfied: {:?}",
+                cs.which_is_unsatisfied()
+            );
+            assert!(!flag.is_constant(), "the flag must be a witness");
+            assert_eq!(result.get_type(), ScalarType::Integer(int_type));
+            assert_eq!(flag.get_type(), ScalarType::Boolean);
+            assert_eq!(result.to_bigint(), Some(expected), "{} and {}", left, right);
+            assert_eq!(
+                flag.to_bigint(),
+                Some(BigInt::from(is_overflow as u8)),
+                "{} and {}",
+                left,
+                right
+            );
+        }
+    }
+
+    fn cases(values: &[(i64, i64, i64, bool)]) -> Vec<(BigInt, BigInt, BigInt, bool)> {
+        values
+            .iter()
+            .map(|(left, right, expected, is_overflow)| {
+                (
+                    BigInt::from(*left),
+                    BigInt::from(*right),
+                    BigInt::from(*expected),
+                    *is_overflow,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_add_checked() {
+        let add =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                add_checked(cs.namespace(|| "add"), left, right)
+            };
+
+        check(
+            add,
+            IntegerType::U8,
+            cases(&[
+                (100, 100, 200, false),
+                (254, 1, 255, false),
+                (255, 1, 0, true),
+                (255, 255, 254, true),
+            ]),
+        );
+        check(
+            add,
+            IntegerType::I8,
+            cases(&[
+                (-100, 50, -50, false),
+                (126, 1, 127, false),
+                (127, 1, -128, true),
+                (-127, -1, -128, false),
+                (-128, -1, 127, true),
+                (-128, 127, -1, false),
+            ]),
+        );
+
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            add,
+            U128,
+            vec![
+                (
+                    max.clone() - BigInt::from(1),
+                    BigInt::from(1),
+                    max.clone(),
+                    false,
+                ),
+                (max, BigInt::from(1), BigInt::from(0), true),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mul_checked() {
+        let mul =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                mul_checked(cs.namespace(|| "mul"), left, right)
+            };
+
+        check(
+            mul,
+            IntegerType::U8,
+            cases(&[
+                (10, 20, 200, false),
+                (15, 17, 255, false),
+                (16, 16, 0, true),
+                (128, 2, 0, true),
+                (255, 255, 1, true),
+                (0, 255, 0, false),
+            ]),
+        );
+        check(
+            mul,
+            IntegerType::I8,
+            cases(&[
+                (-10, 12, -120, false),
+                (-128, 1, -128, false),
+                (-64, 2, -128, false),
+                (-16, -8, -128, true),
+                (64, 2, -128, true),
+                (-128, -1, -128, true),
+                (-65, 2, 126, true),
+                (0, -128, 0, false),
+            ]),
+        );
+
+        let half = BigInt::from(1) << 64;
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            mul,
+            U128,
+            vec![
+                (
+                    half.clone(),
+                    half.clone() - BigInt::from(1),
+                    (half.clone() << 64) - half.clone(),
+                    false,
+                ),
+                (half.clone(), half, BigInt::from(0), true),
+                (max.clone(), max, BigInt::from(1), true),
+            ],
+        );
+    }
+}
@@ -7,6 +7,10 @@ use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::Assignment;
 use zinc_utils::euclidean;
 
+/// Divides `left` by `right` if `condition` is true.
+///
+/// In an untaken branch the nominator is replaced with zero and the denominator with one, so
+/// the quotient and remainder are zero and a zero divisor there does not fail the proof.
 pub fn div_rem_conditional<E, CS>(
     mut cs: CS,
     condition: &Scalar<E>,
@@ -17,6 +21,12 @@ where
     E: Engine,
     CS: ConstraintSystem<E>,
 {
+    let nom = conditional_select(
+        cs.namespace(|| "select nominator"),
+        condition,
+        left,
+        &Scalar::new_constant_int(0, left.get_type()),
+    )?;
     let denom = conditional_select(
         cs.namespace(|| "select denominator"),
         condition,
@@ -24,7 +34,7 @@ where
         &Scalar::new_constant_int(1, right.get_type()),
     )?;
 
-    auto_const!(div_rem_enforce, cs, left, &denom)
+    auto_const!(div_rem_enforce, cs, &nom, &denom)
 }
 
 /// This is enforcing that `right` is not zero.
@@ -47,7 +57,8 @@ where
         let nom_bi = utils::fr_to_bigint(&nom, nominator.is_signed());
         let denom_bi = utils::fr_to_bigint(&denom, denominator.is_signed());
 
-        let (q, r) = euclidean::div_rem(&nom_bi, &denom_bi).ok_or(RuntimeError::DivisionByZero)?;
+        let (q, r) = euclidean::div_rem(&nom_bi, &denom_bi)
+            .ok_or(RuntimeError::DivisionByZero { pc: None })?;
 
         quotient_value = utils::bigint_to_fr::<E>(&q);
         remainder_value = utils::bigint_to_fr::<E>(&r);
@@ -1,15 +1,23 @@
 mod abs;
 mod add;
+mod checked;
 mod div_rem;
 mod field;
 mod mul;
 mod neg;
+mod pow;
+mod saturating;
 mod sub;
+mod wrapping;
 
 pub use abs::*;
 pub use add::*;
+pub use checked::*;
 pub use div_rem::*;
 pub use field::*;
 pub use mul::*;
 pub use neg::*;
+pub use pow::*;
+pub use saturating::*;
 pub use sub::*;
+pub use wrapping::*;
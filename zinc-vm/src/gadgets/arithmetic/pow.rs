@@ -0,0 +1,99 @@
+use crate::gadgets;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
+use crate::{Engine, Result, RuntimeError};
+use franklin_crypto::bellman::ConstraintSystem;
+
+/// Raises `base` to the power of the unsigned `exponent` of `exponent_bits` bits.
+///
+/// The power is computed by squaring and conditionally multiplying the result for each bit of
+/// the exponent from the most significant one, so the number of constraints depends only on
+/// `exponent_bits`. The result is a field element, which must be type checked by the caller.
+pub fn pow<E, CS>(
+    mut cs: CS,
+    base: &Scalar<E>,
+    exponent: &Scalar<E>,
+    exponent_bits: usize,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let bits = exponent_bits_le(cs.namespace(|| "exponent bits"), exponent, exponent_bits)?;
+
+    let base = base.as_field();
+    let one = Scalar::new_constant_int(1, ScalarType::Field);
+
+    let mut result = one.clone();
+    for (index, bit) in bits.iter().enumerate().rev() {
+        let square = gadgets::mul(
+            cs.namespace(|| format!("square {}", index)),
+            &result,
+            &result,
+        )?;
+        let factor = gadgets::conditional_select(
+            cs.namespace(|| format!("factor {}", index)),
+            bit,
+            &base,
+            &one,
+        )?;
+        result = gadgets::mul(
+            cs.namespace(|| format!("multiply {}", index)),
+            &square,
+            &factor,
+        )?;
+    }
+
+    Ok(result)
+}
+
+/// Decomposes the `exponent` into `exponent_bits` little-endian bits.
+///
+/// The bits of a constant exponent are constants, so only the multiplications by a witness
+/// base are constrained.
+fn exponent_bits_le<E, CS>(
+    mut cs: CS,
+    exponent: &Scalar<E>,
+    exponent_bits: usize,
+) -> Result<Vec<Scalar<E>>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    match exponent.get_type() {
+        ScalarType::Integer(IntegerType {
+            is_signed: false, ..
+        }) => {}
+        scalar_type => {
+            return Err(RuntimeError::TypeError {
+                expected: "unsigned integer".to_string(),
+                actual: scalar_type.to_string(),
+            })
+        }
+    }
+
+    if let Some(value) = exponent.to_constant() {
+        if value.bits() > exponent_bits {
+            return Err(RuntimeError::ValueOverflow {
+                value,
+                scalar_type: exponent.get_type(),
+            });
+        }
+
+        let (_sign, bytes) = value.to_bytes_le();
+        return Ok((0..exponent_bits)
+            .map(|index| {
+                let byte = bytes.get(index / 8).copied().unwrap_or(0);
+                Scalar::new_constant_bool((byte >> (index % 8)) & 1 == 1)
+            })
+            .collect());
+    }
+
+    let bits = exponent
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "into_bits"), exponent_bits)?;
+
+    bits.into_iter()
+        .enumerate()
+        .map(|(index, bit)| Scalar::from_boolean(cs.namespace(|| format!("bit {}", index)), bit))
+        .collect()
+}
@@ -0,0 +1,376 @@
+use super::wrapping::integer_type;
+use crate::gadgets::{IntegerType, Scalar};
+use crate::{gadgets, Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use num_bigint::BigInt;
+
+/// Adds the integers `left` and `right`, clamping the result to the range of their type.
+///
+/// The wrapped sum is lesser than `left` only if a non-negative `right` overflows the maximal
+/// value, and greater than `left` only if a negative `right` overflows the minimal value.
+pub fn add_saturating<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let wrapped = gadgets::add_wrapping(cs.namespace(|| "add"), left, right)?;
+    let (min, max) = bounds(int_type)?;
+
+    let is_lesser = gadgets::lt(cs.namespace(|| "is lesser"), &wrapped, left)?;
+    let positive = gadgets::conditional_select(
+        cs.namespace(|| "select positive"),
+        &is_lesser,
+        &max,
+        &wrapped,
+    )?;
+    if !int_type.is_signed {
+        return Ok(positive);
+    }
+
+    let is_greater = gadgets::gt(cs.namespace(|| "is greater"), &wrapped, left)?;
+    let negative = gadgets::conditional_select(
+        cs.namespace(|| "select negative"),
+        &is_greater,
+        &min,
+        &wrapped,
+    )?;
+
+    let is_negative = is_negative(cs.namespace(|| "is negative"), right, int_type)?;
+    gadgets::conditional_select(
+        cs.namespace(|| "select"),
+        &is_negative,
+        &negative,
+        &positive,
+    )
+}
+
+/// Subtracts the integer `right` from `left`, clamping the result to the range of their type.
+///
+/// The wrapped difference is greater than `left` only if a non-negative `right` overflows
+/// the minimal value, and lesser than `left` only if a negative `right` overflows the maximal
+/// value.
+pub fn sub_saturating<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let wrapped = gadgets::sub_wrapping(cs.namespace(|| "sub"), left, right)?;
+    let (min, max) = bounds(int_type)?;
+
+    let is_greater = gadgets::gt(cs.namespace(|| "is greater"), &wrapped, left)?;
+    let positive = gadgets::conditional_select(
+        cs.namespace(|| "select positive"),
+        &is_greater,
+        &min,
+        &wrapped,
+    )?;
+    if !int_type.is_signed {
+        return Ok(positive);
+    }
+
+    let is_lesser = gadgets::lt(cs.namespace(|| "is lesser"), &wrapped, left)?;
+    let negative = gadgets::conditional_select(
+        cs.namespace(|| "select negative"),
+        &is_lesser,
+        &max,
+        &wrapped,
+    )?;
+
+    let is_negative = is_negative(cs.namespace(|| "is negative"), right, int_type)?;
+    gadgets::conditional_select(
+        cs.namespace(|| "select"),
+        &is_negative,
+        &negative,
+        &positive,
+    )
+}
+
+/// Multiplies the integers `left` and `right`, clamping the result to the range of their type.
+///
+/// The product overflows if the absolute value of `left` is greater than the absolute value
+/// limit of the result divided by the absolute value of `right`.
+pub fn mul_saturating<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let wrapped = gadgets::mul_wrapping(cs.namespace(|| "mul"), left, right)?;
+    let (min, max) = bounds(int_type)?;
+
+    let unsigned_type = IntegerType {
+        is_signed: false,
+        bitlength: int_type.bitlength,
+    };
+    let left_abs =
+        gadgets::abs(cs.namespace(|| "left abs"), left)?.with_type_unchecked(unsigned_type.into());
+    let right_abs = gadgets::abs(cs.namespace(|| "right abs"), right)?
+        .with_type_unchecked(unsigned_type.into());
+
+    let (limit, is_negative) = if int_type.is_signed {
+        let is_left_negative = is_negative(cs.namespace(|| "is left negative"), left, int_type)?;
+        let is_right_negative = is_negative(cs.namespace(|| "is right negative"), right, int_type)?;
+        let is_negative = gadgets::ne(
+            cs.namespace(|| "is negative"),
+            &is_left_negative,
+            &is_right_negative,
+        )?;
+
+        let half = BigInt::from(1) << (int_type.bitlength - 1);
+        let negative_limit = Scalar::new_constant_bigint(&half, unsigned_type.into())?;
+        let positive_limit =
+            Scalar::new_constant_bigint(&(half - BigInt::from(1)), unsigned_type.into())?;
+        let limit = gadgets::conditional_select(
+            cs.namespace(|| "select limit"),
+            &is_negative,
+            &negative_limit,
+            &positive_limit,
+        )?;
+        (limit, Some(is_negative))
+    } else {
+        (max.clone(), None)
+    };
+
+    let zero = Scalar::new_constant_int(0, unsigned_type.into());
+    let one = Scalar::new_constant_int(1, unsigned_type.into());
+    let is_right_zero = gadgets::eq(cs.namespace(|| "is right zero"), &right_abs, &zero)?;
+    let divisor = gadgets::conditional_select(
+        cs.namespace(|| "select divisor"),
+        &is_right_zero,
+        &one,
+        &right_abs,
+    )?;
+    let (quotient, _remainder) =
+        gadgets::div_rem_enforce(cs.namespace(|| "div_rem"), &limit, &divisor)?;
+    let is_overflow = gadgets::gt(
+        cs.namespace(|| "is overflow"),
+        &left_abs.as_field(),
+        &quotient,
+    )?;
+
+    let bound = match is_negative {
+        Some(is_negative) => {
+            gadgets::conditional_select(cs.namespace(|| "select bound"), &is_negative, &min, &max)?
+        }
+        None => max,
+    };
+    gadgets::conditional_select(cs.namespace(|| "select"), &is_overflow, &bound, &wrapped)
+}
+
+/// Returns the minimal and maximal values of the integer type.
+fn bounds<E: Engine>(int_type: IntegerType) -> Result<(Scalar<E>, Scalar<E>)> {
+    let (min, max) = if int_type.is_signed {
+        let half = BigInt::from(1) << (int_type.bitlength - 1);
+        (-half.clone(), half - BigInt::from(1))
+    } else {
+        (
+            BigInt::from(0),
+            (BigInt::from(1) << int_type.bitlength) - BigInt::from(1),
+        )
+    };
+
+    Ok((
+        Scalar::new_constant_bigint(&min, int_type.into())?,
+        Scalar::new_constant_bigint(&max, int_type.into())?,
+    ))
+}
+
+/// Checks whether the signed integer is negative.
+fn is_negative<E, CS>(cs: CS, scalar: &Scalar<E>, int_type: IntegerType) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let zero = Scalar::new_constant_int(0, int_type.into());
+    gadgets::lt(cs, scalar, &zero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::ToBigInt;
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::utils::bigint_to_fr;
+    use crate::gadgets::ScalarType;
+
+    const U128: IntegerType = IntegerType {
+        is_signed: false,
+        bitlength: 128,
+    };
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: &BigInt,
+        int_type: IntegerType,
+    ) -> Scalar<Bn256> {
+        let fr = bigint_to_fr::<Bn256>(value).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, int_type.into())
+    }
+
+    fn check<F>(operation: F, int_type: IntegerType, cases: Vec<(BigInt, BigInt, BigInt)>)
+    where
+        F: Fn(
+            &mut TestConstraintSystem<Bn256>,
+            &Scalar<Bn256>,
+            &Scalar<Bn256>,
+        ) -> Result<Scalar<Bn256>>,
+    {
+        for (left, right, expected) in cases.into_iter() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+            let left_scalar = variable(cs.namespace(|| "left"), &left, int_type);
+            let right_scalar = variable(cs.namespace(|| "right"), &right, int_type);
+
+            let result = operation(&mut cs, &left_scalar, &right_scalar).unwrap();
+
+            assert!(
+                cs.is_satisfied(),
+                "unsatisfied: {:?}",
+                cs.which_is_unsatisfied()
+            );
+            assert_eq!(result.get_type(), ScalarType::Integer(int_type));
+            assert_eq!(result.to_bigint(), Some(expected), "{} and {}", left, right);
+        }
+    }
+
+    fn cases(values: &[(i64, i64, i64)]) -> Vec<(BigInt, BigInt, BigInt)> {
+        values
+            .iter()
+            .map(|(left, right, expected)| {
+                (
+                    BigInt::from(*left),
+                    BigInt::from(*right),
+                    BigInt::from(*expected),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_add_saturating() {
+        let add =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                add_saturating(cs.namespace(|| "add"), left, right)
+            };
+
+        check(
+            add,
+            IntegerType::U8,
+            cases(&[
+                (100, 100, 200),
+                (254, 1, 255),
+                (255, 1, 255),
+                (200, 100, 255),
+            ]),
+        );
+        check(
+            add,
+            IntegerType::I8,
+            cases(&[
+                (127, 1, 127),
+                (-128, -1, -128),
+                (100, -100, 0),
+                (-100, -50, -128),
+            ]),
+        );
+
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            add,
+            U128,
+            vec![
+                (max.clone(), BigInt::from(1), max.clone()),
+                (max.clone() - BigInt::from(1), BigInt::from(1), max),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sub_saturating() {
+        let sub =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                sub_saturating(cs.namespace(|| "sub"), left, right)
+            };
+
+        check(
+            sub,
+            IntegerType::U8,
+            cases(&[(10, 5, 5), (1, 1, 0), (0, 1, 0), (5, 10, 0)]),
+        );
+        check(
+            sub,
+            IntegerType::I8,
+            cases(&[
+                (-128, 1, -128),
+                (127, -1, 127),
+                (-1, 127, -128),
+                (0, -128, 127),
+            ]),
+        );
+
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            sub,
+            U128,
+            vec![
+                (BigInt::from(0), BigInt::from(1), BigInt::from(0)),
+                (max.clone(), max, BigInt::from(0)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mul_saturating() {
+        let mul =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                mul_saturating(cs.namespace(|| "mul"), left, right)
+            };
+
+        check(
+            mul,
+            IntegerType::U8,
+            cases(&[
+                (15, 17, 255),
+                (127, 2, 254),
+                (128, 2, 255),
+                (16, 16, 255),
+                (0, 255, 0),
+                (255, 0, 0),
+            ]),
+        );
+        check(
+            mul,
+            IntegerType::I8,
+            cases(&[
+                (-128, -1, 127),
+                (-128, 1, -128),
+                (64, 2, 127),
+                (-64, 2, -128),
+                (-65, 2, -128),
+                (11, -12, -128),
+                (-11, -11, 121),
+                (0, -128, 0),
+            ]),
+        );
+
+        let half = BigInt::from(1) << 64;
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            mul,
+            U128,
+            vec![
+                (half.clone(), half.clone(), max),
+                (
+                    half.clone(),
+                    half.clone() - BigInt::from(1),
+                    (half.clone() << 64) - half,
+                ),
+            ],
+        );
+    }
+}
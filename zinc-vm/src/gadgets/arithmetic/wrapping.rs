@@ -0,0 +1,423 @@
+use crate::gadgets::{IntegerType, Scalar, ScalarType, ScalarTypeExpectation};
+use crate::{gadgets, Engine, Result, RuntimeError};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+/// Adds the integers `left` and `right` modulo `2^bitlength` of their type.
+pub fn add_wrapping<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let left = to_unsigned(cs.namespace(|| "left"), left, int_type)?;
+    let right = to_unsigned(cs.namespace(|| "right"), right, int_type)?;
+
+    let sum = gadgets::add(cs.namespace(|| "add"), &left, &right)?;
+    let result = truncate(
+        cs.namespace(|| "truncate"),
+        &sum,
+        int_type.bitlength + 1,
+        int_type.bitlength,
+    )?;
+
+    from_unsigned(cs.namespace(|| "result"), &result, int_type)
+}
+
+/// Subtracts the integer `right` from `left` modulo `2^bitlength` of their type.
+pub fn sub_wrapping<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let left = to_unsigned(cs.namespace(|| "left"), left, int_type)?;
+    let right = to_unsigned(cs.namespace(|| "right"), right, int_type)?;
+
+    // The modulus keeps the difference non-negative and does not change its lower bits.
+    let modulus =
+        Scalar::new_constant_bigint(&(BigInt::from(1) << int_type.bitlength), ScalarType::Field)?;
+    let offset = gadgets::add(cs.namespace(|| "offset"), &left, &modulus)?;
+    let difference = gadgets::sub(cs.namespace(|| "sub"), &offset, &right)?;
+    let result = truncate(
+        cs.namespace(|| "truncate"),
+        &difference,
+        int_type.bitlength + 1,
+        int_type.bitlength,
+    )?;
+
+    from_unsigned(cs.namespace(|| "result"), &result, int_type)
+}
+
+/// Multiplies the integers `left` and `right` modulo `2^bitlength` of their type.
+pub fn mul_wrapping<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(left, right)?;
+    let left = to_unsigned(cs.namespace(|| "left"), left, int_type)?;
+    let right = to_unsigned(cs.namespace(|| "right"), right, int_type)?;
+
+    let (bits, _is_overflow) =
+        mul_unsigned(cs.namespace(|| "mul"), &left, &right, int_type.bitlength)?;
+    let result = pack(cs.namespace(|| "pack"), &bits)?;
+
+    from_unsigned(cs.namespace(|| "result"), &result, int_type)
+}
+
+/// Multiplies the unsigned integers `left` and `right` of `bitlength` bits.
+///
+/// Returns the lower `bitlength` bits of the product and the flag, which is set if the product
+/// does not fit into `bitlength` bits.
+///
+/// The product of wide integers may not fit into the field, so the operands are split into
+/// the lower and higher halves. The product of the higher halves is a multiple of
+/// `2^bitlength`, so it only affects the flag.
+pub(super) fn mul_unsigned<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+    bitlength: usize,
+) -> Result<(Vec<Boolean>, Boolean)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let half = bitlength / 2;
+
+    let left_bits = left
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "left bits"), bitlength)?;
+    let right_bits = right
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "right bits"), bitlength)?;
+
+    let left_low = pack(cs.namespace(|| "left low"), &left_bits[..half])?;
+    let left_high = pack(cs.namespace(|| "left high"), &left_bits[half..])?;
+    let right_low = pack(cs.namespace(|| "right low"), &right_bits[..half])?;
+    let right_high = pack(cs.namespace(|| "right high"), &right_bits[half..])?;
+
+    let low = gadgets::mul(cs.namespace(|| "low"), &left_low, &right_low)?;
+    let cross_left = gadgets::mul(cs.namespace(|| "cross left"), &left_low, &right_high)?;
+    let cross_right = gadgets::mul(cs.namespace(|| "cross right"), &left_high, &right_low)?;
+    let cross = gadgets::add(cs.namespace(|| "cross"), &cross_left, &cross_right)?;
+    let cross_bits = cross
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "cross bits"), bitlength + 1)?;
+    let cross = pack(
+        cs.namespace(|| "cross low"),
+        &cross_bits[..bitlength - half],
+    )?;
+
+    let shift = Scalar::new_constant_bigint(&(BigInt::from(1) << half), ScalarType::Field)?;
+    let cross = gadgets::mul(cs.namespace(|| "shift cross"), &cross, &shift)?;
+    let product = gadgets::add(cs.namespace(|| "product"), &low, &cross)?;
+    let mut product_bits = product
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "product bits"), bitlength + 1)?;
+    let carry = product_bits
+        .pop()
+        .expect("the product has more than one bit");
+
+    let is_left_high = any(cs.namespace(|| "is left high"), &left_bits[half..])?;
+    let is_right_high = any(cs.namespace(|| "is right high"), &right_bits[half..])?;
+    let is_high_overflow = Boolean::and(
+        cs.namespace(|| "is high overflow"),
+        &is_left_high,
+        &is_right_high,
+    )?;
+    let is_cross_overflow = any(
+        cs.namespace(|| "is cross overflow"),
+        &cross_bits[bitlength - half..],
+    )?;
+    let is_overflow = any(
+        cs.namespace(|| "is overflow"),
+        &[is_high_overflow, is_cross_overflow, carry],
+    )?;
+
+    Ok((product_bits, is_overflow))
+}
+
+/// Checks that both operands are integers of the same type and returns the type.
+pub(super) fn integer_type<E: Engine>(left: &Scalar<E>, right: &Scalar<E>) -> Result<IntegerType> {
+    match ScalarType::expect_same(left.get_type(), right.get_type())? {
+        ScalarType::Integer(int_type) => Ok(int_type),
+        scalar_type => Err(RuntimeError::TypeError {
+            expected: "integer".to_string(),
+            actual: scalar_type.to_string(),
+        }),
+    }
+}
+
+/// Reinterprets a signed integer as the unsigned one with the same two's complement bits.
+pub(super) fn to_unsigned<E, CS>(
+    cs: CS,
+    scalar: &Scalar<E>,
+    int_type: IntegerType,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    if !int_type.is_signed {
+        return Ok(scalar.clone());
+    }
+
+    let unsigned_type = IntegerType {
+        is_signed: false,
+        bitlength: int_type.bitlength,
+    };
+    gadgets::reinterpret_sign(cs, scalar, unsigned_type)
+}
+
+/// Gives the `bitlength` bits of the unsigned result the original integer type.
+fn from_unsigned<E, CS>(cs: CS, scalar: &Scalar<E>, int_type: IntegerType) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    if !int_type.is_signed {
+        return Ok(scalar.with_type_unchecked(int_type.into()));
+    }
+
+    let unsigned_type = IntegerType {
+        is_signed: false,
+        bitlength: int_type.bitlength,
+    };
+    gadgets::reinterpret_sign(
+        cs,
+        &scalar.with_type_unchecked(unsigned_type.into()),
+        int_type,
+    )
+}
+
+/// Decomposes `scalar` into `bits_count` bits and keeps the lower `bitlength` ones.
+///
+/// The decomposition is unsatisfiable if `scalar` does not fit into `bits_count` bits.
+fn truncate<E, CS>(
+    mut cs: CS,
+    scalar: &Scalar<E>,
+    bits_count: usize,
+    bitlength: usize,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let bits = scalar
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "into_bits"), bits_count)?;
+
+    pack(cs.namespace(|| "pack"), &bits[..bitlength])
+}
+
+/// Packs the little-endian `bits` into a field scalar.
+pub(super) fn pack<E, CS>(mut cs: CS, bits: &[Boolean]) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let num = AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), bits)?;
+
+    Ok(Scalar::new_unchecked_variable(
+        num.get_value(),
+        num.get_variable(),
+        ScalarType::Field,
+    ))
+}
+
+/// Checks whether any of the `bits` is set.
+pub(super) fn any<E, CS>(mut cs: CS, bits: &[Boolean]) -> Result<Boolean>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let mut result = Boolean::constant(false);
+    for (index, bit) in bits.iter().enumerate() {
+        result = Boolean::and(
+            cs.namespace(|| format!("bit {}", index)),
+            &result.not(),
+            &bit.not(),
+        )?
+        .not();
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::ToBigInt;
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::utils::bigint_to_fr;
+
+    const U128: IntegerType = IntegerType {
+        is_signed: false,
+        bitlength: 128,
+    };
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: &BigInt,
+        int_type: IntegerType,
+    ) -> Scalar<Bn256> {
+        let fr = bigint_to_fr::<Bn256>(value).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, int_type.into())
+    }
+
+    fn check<F>(operation: F, int_type: IntegerType, cases: Vec<(BigInt, BigInt, BigInt)>)
+    where
+        F: Fn(
+            &mut TestConstraintSystem<Bn256>,
+            &Scalar<Bn256>,
+            &Scalar<Bn256>,
+        ) -> Result<Scalar<Bn256>>,
+    {
+        for (left, right, expected) in cases.into_iter() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+            let left_scalar = variable(cs.namespace(|| "left"), &left, int_type);
+            let right_scalar = variable(cs.namespace(|| "right"), &right, int_type);
+
+            let result = operation(&mut cs, &left_scalar, &right_scalar).unwrap();
+
+            assert!(
+                cs.is_satisfied(),
+                "unsatisfied: {:?}",
+                cs.which_is_unsatisfied()
+            );
+            assert_eq!(result.get_type(), ScalarType::Integer(int_type));
+            assert_eq!(result.to_bigint(), Some(expected), "{} and {}", left, right);
+        }
+    }
+
+    fn cases(values: &[(i64, i64, i64)]) -> Vec<(BigInt, BigInt, BigInt)> {
+        values
+            .iter()
+            .map(|(left, right, expected)| {
+                (
+                    BigInt::from(*left),
+                    BigInt::from(*right),
+                    BigInt::from(*expected),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_add_wrapping() {
+        let add =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                add_wrapping(cs.namespace(|| "add"), left, right)
+            };
+
+        check(
+            add,
+            IntegerType::U8,
+            cases(&[
+                (0, 0, 0),
+                (254, 1, 255),
+                (255, 1, 0),
+                (200, 100, 44),
+                (255, 255, 254),
+            ]),
+        );
+        check(
+            add,
+            IntegerType::I8,
+            cases(&[
+                (127, 1, -128),
+                (-128, -1, 127),
+                (-100, -100, 56),
+                (-1, 1, 0),
+            ]),
+        );
+
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            add,
+            U128,
+            vec![
+                (max.clone(), BigInt::from(1), BigInt::from(0)),
+                (max.clone(), max.clone(), max - BigInt::from(1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sub_wrapping() {
+        let sub =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                sub_wrapping(cs.namespace(|| "sub"), left, right)
+            };
+
+        check(
+            sub,
+            IntegerType::U8,
+            cases(&[(10, 5, 5), (0, 1, 255), (5, 10, 251), (0, 255, 1)]),
+        );
+        check(
+            sub,
+            IntegerType::I8,
+            cases(&[
+                (-128, 1, 127),
+                (127, -1, -128),
+                (0, -128, -128),
+                (-5, 5, -10),
+            ]),
+        );
+
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            sub,
+            U128,
+            vec![
+                (BigInt::from(0), BigInt::from(1), max.clone()),
+                (BigInt::from(0), max, BigInt::from(1)),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mul_wrapping() {
+        let mul =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                mul_wrapping(cs.namespace(|| "mul"), left, right)
+            };
+
+        check(
+            mul,
+            IntegerType::U8,
+            cases(&[(15, 17, 255), (16, 16, 0), (200, 3, 88), (255, 255, 1)]),
+        );
+        check(
+            mul,
+            IntegerType::I8,
+            cases(&[
+                (-128, -1, -128),
+                (64, 2, -128),
+                (-7, 9, -63),
+                (100, 100, 16),
+            ]),
+        );
+
+        let half = BigInt::from(1) << 64;
+        let top = BigInt::from(1) << 127;
+        let max = (BigInt::from(1) << 128) - BigInt::from(1);
+        check(
+            mul,
+            U128,
+            vec![
+                (max.clone(), max, BigInt::from(1)),
+                (half.clone(), half, BigInt::from(0)),
+                (top.clone(), BigInt::from(3), top),
+            ],
+        );
+    }
+}
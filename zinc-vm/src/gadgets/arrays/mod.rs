@@ -1,8 +1,10 @@
+use std::iter;
+
 use crate::gadgets;
 use crate::gadgets::utils::math;
-use crate::gadgets::Scalar;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
 use crate::{Engine, Result};
-use bellman::ConstraintSystem;
+use bellman::{ConstraintSystem, Namespace};
 
 /// Select single value from array based on index bits.
 ///
@@ -45,3 +47,428 @@ where
 
     gadgets::conditional_select(cs.namespace(|| "select"), &index_bits_be[0], &right, &left)
 }
+
+/// Search for the key in the sorted array of constants.
+///
+/// Returns the `found` flag, the index and the element. If there are several elements equal to
+/// the key, the last one is returned. If the key is not found, the index is the insertion point
+/// and the element is zero.
+///
+/// The array is padded with its last element to the power of two, so the search is a fixed tree
+/// of `log2(N)` comparisons. At each level the candidate is selected from the constants by the
+/// bits decided on the previous levels.
+///
+/// **Note**: the array elements must be constants sorted in ascending order.
+pub fn binary_search<E, CS>(
+    mut cs: CS,
+    array: &[Scalar<E>],
+    key: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    assert!(!array.is_empty(), "internal error in binary_search");
+
+    let index_type: ScalarType = IntegerType::U64.into();
+    let last = &array[array.len() - 1];
+
+    let levels = math::log2ceil(array.len());
+    let padded: Vec<Scalar<E>> = array
+        .iter()
+        .chain(iter::repeat(last))
+        .take(1 << levels)
+        .cloned()
+        .collect();
+
+    let mut bits_be = Vec::with_capacity(levels);
+    let mut position = Scalar::new_constant_int(0, index_type);
+    let mut accepted = array[0].clone();
+    for level in 0..levels {
+        let mut cs = cs.namespace(|| format!("level {}", level));
+
+        let step = 1 << (levels - level - 1);
+        let candidates: Vec<Scalar<E>> = (0..1 << level)
+            .map(|prefix| padded[prefix * step * 2 + step - 1].clone())
+            .collect();
+        let candidate = recursive_select(cs.namespace(|| "candidate"), &bits_be, &candidates)?;
+
+        let bit = gadgets::le(cs.namespace(|| "le"), &candidate, key)?;
+        let next_position = gadgets::add(
+            cs.namespace(|| "next_position"),
+            &position,
+            &Scalar::new_constant_int(step, index_type),
+        )?
+        .with_type_unchecked(index_type);
+        position = gadgets::conditional_select(
+            cs.namespace(|| "position"),
+            &bit,
+            &next_position,
+            &position,
+        )?;
+        accepted =
+            gadgets::conditional_select(cs.namespace(|| "accepted"), &bit, &candidate, &accepted)?;
+
+        bits_be.push(bit);
+    }
+
+    // The padding elements are equal to the last one, so they are only accepted
+    // if the whole array is not greater than the key.
+    let is_all_accepted = gadgets::le(cs.namespace(|| "is_all_accepted"), last, key)?;
+    let position = gadgets::conditional_select(
+        cs.namespace(|| "position"),
+        &is_all_accepted,
+        &Scalar::new_constant_int(array.len(), index_type),
+        &position,
+    )?;
+    let accepted = gadgets::conditional_select(
+        cs.namespace(|| "accepted"),
+        &is_all_accepted,
+        last,
+        &accepted,
+    )?;
+
+    let found = gadgets::eq(cs.namespace(|| "found"), &accepted, key)?;
+    let index =
+        gadgets::sub(cs.namespace(|| "index"), &position, &found)?.with_type_unchecked(index_type);
+    let value = gadgets::conditional_select(
+        cs.namespace(|| "value"),
+        &found,
+        &accepted,
+        &Scalar::new_constant_int(0, key.get_type()),
+    )?;
+
+    Ok((found, index, value))
+}
+
+/// Select the array element at the variable `index` by scanning the whole array.
+///
+/// Only the elements of the same type as the first one are scanned, since the index of an
+/// element of another type is never produced by the compiler. The index must be in range.
+pub fn select_by_index<E, CS>(
+    mut cs: CS,
+    array: &[Scalar<E>],
+    index: &Scalar<E>,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    assert!(!array.is_empty(), "internal error in select_by_index");
+
+    let element_type = array[0].get_type();
+    let mut selected = array[0].clone();
+    for (i, element) in array.iter().enumerate().skip(1) {
+        if element.get_type() != element_type {
+            continue;
+        }
+
+        let mut cs = cs.namespace(|| format!("slot {}", i));
+        let slot = Scalar::new_constant_int(i, index.get_type());
+        let is_slot = gadgets::eq(cs.namespace(|| "eq"), index, &slot)?;
+        selected =
+            gadgets::conditional_select(cs.namespace(|| "select"), &is_slot, element, &selected)?;
+    }
+
+    Ok(selected)
+}
+
+/// Replace the array element at the variable `index` with `value` by scanning the whole array.
+///
+/// Every element of the same type as `value` becomes `select(index == slot, value, element)`.
+/// The elements of other types are left untouched.
+pub fn update_by_index<E, CS>(
+    cs: CS,
+    array: &[Scalar<E>],
+    index: &Scalar<E>,
+    value: &Scalar<E>,
+) -> Result<Vec<Scalar<E>>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    map(cs, array, |mut cs, i, element| {
+        if element.get_type() != value.get_type() {
+            return Ok(element.clone());
+        }
+
+        let slot = Scalar::new_constant_int(i, index.get_type());
+        let is_slot = gadgets::eq(cs.namespace(|| "eq"), index, &slot)?;
+        gadgets::conditional_select(cs.namespace(|| "select"), &is_slot, value, element)
+    })
+}
+
+/// Applies `f` to every element of `array`.
+///
+/// The closure receives a namespace named after the element index, so the same names can be
+/// used inside it for every element.
+pub fn map<E, CS, F>(mut cs: CS, array: &[Scalar<E>], mut f: F) -> Result<Vec<Scalar<E>>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+    F: FnMut(Namespace<E, CS::Root>, usize, &Scalar<E>) -> Result<Scalar<E>>,
+{
+    array
+        .iter()
+        .enumerate()
+        .map(|(i, element)| f(cs.namespace(|| format!("element {}", i)), i, element))
+        .collect()
+}
+
+/// Folds the elements of `array` into an accumulator, starting with `init`.
+///
+/// The closure receives a namespace named after the element index, like with `map`.
+pub fn fold<E, CS, T, F>(mut cs: CS, array: &[Scalar<E>], init: T, mut f: F) -> Result<T>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+    F: FnMut(Namespace<E, CS::Root>, T, usize, &Scalar<E>) -> Result<T>,
+{
+    let mut accumulator = init;
+    for (i, element) in array.iter().enumerate() {
+        accumulator = f(
+            cs.namespace(|| format!("element {}", i)),
+            accumulator,
+            i,
+            element,
+        )?;
+    }
+
+    Ok(accumulator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ff::PrimeField;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::{Bn256, Fr};
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: usize,
+        scalar_type: ScalarType,
+    ) -> Scalar<Bn256> {
+        let fr = Fr::from_str(value.to_string().as_str()).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, scalar_type)
+    }
+
+    fn linear_search<CS: ConstraintSystem<Bn256>>(
+        cs: CS,
+        array: &[Scalar<Bn256>],
+        key: &Scalar<Bn256>,
+    ) -> Result<(Scalar<Bn256>, Scalar<Bn256>, Scalar<Bn256>)> {
+        let index_type: ScalarType = IntegerType::U64.into();
+
+        let init = (
+            Scalar::new_constant_bool(false),
+            Scalar::new_constant_int(0, index_type),
+            Scalar::new_constant_int(0, key.get_type()),
+        );
+        fold(
+            cs,
+            array,
+            init,
+            |mut cs, (found, index, value), i, element| {
+                let is_equal = gadgets::eq(cs.namespace(|| "eq"), element, key)?;
+                let found = gadgets::or(cs.namespace(|| "found"), &found, &is_equal)?;
+                let index = gadgets::conditional_select(
+                    cs.namespace(|| "index"),
+                    &is_equal,
+                    &Scalar::new_constant_int(i, index_type),
+                    &index,
+                )?;
+                let value = gadgets::conditional_select(
+                    cs.namespace(|| "value"),
+                    &is_equal,
+                    element,
+                    &value,
+                )?;
+
+                Ok((found, index, value))
+            },
+        )
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let scalar_type: ScalarType = IntegerType::U8.into();
+        let array: Vec<Scalar<Bn256>> = [1, 3, 3, 3, 7, 9]
+            .iter()
+            .map(|value| Scalar::new_constant_int(*value, scalar_type))
+            .collect();
+
+        for (key, found, index, value) in [
+            (1, true, 0, 1),
+            (9, true, 5, 9),
+            (3, true, 3, 3),
+            (7, true, 4, 7),
+            (0, false, 0, 0),
+            (5, false, 4, 0),
+            (10, false, 6, 0),
+        ]
+        .iter()
+        {
+            let mut cs = cs.namespace(|| format!("key {}", key));
+            let key_scalar = variable(cs.namespace(|| "key"), *key, scalar_type);
+
+            let (found_scalar, index_scalar, value_scalar) =
+                binary_search(cs.namespace(|| "binary_search"), &array, &key_scalar).unwrap();
+
+            assert_eq!(
+                found_scalar.get_value().unwrap(),
+                Fr::from_str(if *found { "1" } else { "0" }).unwrap(),
+                "found for key {}",
+                key
+            );
+            assert_eq!(
+                index_scalar.get_value().unwrap(),
+                Fr::from_str(index.to_string().as_str()).unwrap(),
+                "index for key {}",
+                key
+            );
+            assert_eq!(
+                value_scalar.get_value().unwrap(),
+                Fr::from_str(value.to_string().as_str()).unwrap(),
+                "value for key {}",
+                key
+            );
+        }
+
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+    }
+
+    #[test]
+    fn test_binary_search_constraints() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let scalar_type: ScalarType = IntegerType::U16.into();
+        let array: Vec<Scalar<Bn256>> = (0..1024)
+            .map(|value| Scalar::new_constant_int(value * 3, scalar_type))
+            .collect();
+        let key = variable(cs.namespace(|| "key"), 1500, scalar_type);
+
+        let constraints = cs.num_constraints();
+        let (binary_found, binary_index, _value) =
+            binary_search(cs.namespace(|| "binary_search"), &array, &key).unwrap();
+        let binary_constraints = cs.num_constraints() - constraints;
+
+        let constraints = cs.num_constraints();
+        let (linear_found, linear_index, _value) =
+            linear_search(cs.namespace(|| "linear_search"), &array, &key).unwrap();
+        let linear_constraints = cs.num_constraints() - constraints;
+
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+        assert_eq!(binary_found.get_value(), linear_found.get_value());
+        assert_eq!(binary_index.get_value(), linear_index.get_value());
+        assert!(
+            binary_constraints * 2 < linear_constraints,
+            "binary search takes {} constraints, linear search takes {}",
+            binary_constraints,
+            linear_constraints,
+        );
+    }
+
+    #[test]
+    fn test_update_then_select_by_index() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let scalar_type: ScalarType = IntegerType::U8.into();
+        let mut array: Vec<Scalar<Bn256>> = (0..5)
+            .map(|i| {
+                variable(
+                    cs.namespace(|| format!("element {}", i)),
+                    i * 10,
+                    scalar_type,
+                )
+            })
+            .collect();
+
+        for (step, (index, value)) in [(3, 42), (0, 7), (3, 43)].iter().enumerate() {
+            let mut cs = cs.namespace(|| format!("step {}", step));
+            let index = variable(cs.namespace(|| "index"), *index, ScalarType::Field);
+            let value = variable(cs.namespace(|| "value"), *value, scalar_type);
+
+            array = update_by_index(cs.namespace(|| "update"), &array, &index, &value).unwrap();
+            let selected = select_by_index(cs.namespace(|| "select"), &array, &index).unwrap();
+
+            assert_eq!(selected.get_value(), value.get_value());
+            assert_eq!(selected.get_type(), scalar_type);
+        }
+
+        for (i, expected) in [7, 10, 20, 43, 40].iter().enumerate() {
+            assert_eq!(
+                array[i].get_value().unwrap(),
+                Fr::from_str(expected.to_string().as_str()).unwrap(),
+                "element {}",
+                i
+            );
+        }
+
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+    }
+
+    #[test]
+    fn test_update_by_index_skips_other_types() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let array = vec![
+            variable(cs.namespace(|| "integer"), 1, IntegerType::U8.into()),
+            variable(cs.namespace(|| "boolean"), 1, ScalarType::Boolean),
+        ];
+        let index = variable(cs.namespace(|| "index"), 1, ScalarType::Field);
+        let value = variable(cs.namespace(|| "value"), 2, IntegerType::U8.into());
+
+        let updated = update_by_index(cs.namespace(|| "update"), &array, &index, &value).unwrap();
+
+        assert_eq!(updated[0].get_value(), array[0].get_value());
+        assert_eq!(updated[1].get_type(), ScalarType::Boolean);
+        assert_eq!(updated[1].get_value(), array[1].get_value());
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+    }
+
+    fn double<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        element: &Scalar<Bn256>,
+    ) -> Result<Scalar<Bn256>> {
+        gadgets::add(cs.namespace(|| "double"), element, element)
+            .map(|sum| sum.with_type_unchecked(element.get_type()))
+    }
+
+    #[test]
+    fn test_map_namespaces_do_not_collide() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let scalar_type: ScalarType = IntegerType::U8.into();
+        let array: Vec<Scalar<Bn256>> = (0..3)
+            .map(|i| variable(cs.namespace(|| format!("element {}", i)), i, scalar_type))
+            .collect();
+
+        let doubled = map(cs.namespace(|| "first"), &array, |cs, _i, element| {
+            double(cs, element)
+        })
+        .unwrap();
+        let quadrupled = map(cs.namespace(|| "second"), &doubled, |cs, _i, element| {
+            double(cs, element)
+        })
+        .unwrap();
+
+        let sum = fold(
+            cs.namespace(|| "sum"),
+            &quadrupled,
+            Scalar::new_constant_int(0, scalar_type),
+            |mut cs, sum, _i, element| {
+                gadgets::add(cs.namespace(|| "add"), &sum, element)
+                    .map(|sum| sum.with_type_unchecked(scalar_type))
+            },
+        )
+        .unwrap();
+
+        assert_eq!(sum.get_value().unwrap(), Fr::from_str("12").unwrap());
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+    }
+}
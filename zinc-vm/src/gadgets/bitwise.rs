@@ -0,0 +1,224 @@
+use crate::gadgets;
+use crate::gadgets::{IntegerType, Scalar, ScalarType, ScalarTypeExpectation};
+use crate::{Engine, Result, RuntimeError};
+use franklin_crypto::bellman::{ConstraintSystem, Namespace};
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+/// Computes the bitwise AND of the integers `left` and `right`.
+pub fn bit_and<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    bitwise(cs, left, right, |cs, left, right| {
+        Ok(Boolean::and(cs, left, right)?)
+    })
+}
+
+/// Computes the bitwise OR of the integers `left` and `right`.
+pub fn bit_or<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    bitwise(cs, left, right, |cs, left, right| {
+        Ok(Boolean::and(cs, &left.not(), &right.not())?.not())
+    })
+}
+
+/// Computes the bitwise XOR of the integers `left` and `right`.
+pub fn bit_xor<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    bitwise(cs, left, right, |cs, left, right| {
+        Ok(Boolean::xor(cs, left, right)?)
+    })
+}
+
+/// Decomposes both operands into the two's complement bits of their type bit length, combines
+/// each pair of bits with `combine` and packs the result back into the operand type.
+fn bitwise<E, CS, F>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+    combine: F,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+    F: Fn(Namespace<E, CS::Root>, &Boolean, &Boolean) -> Result<Boolean>,
+{
+    let int_type = match ScalarType::expect_same(left.get_type(), right.get_type())? {
+        ScalarType::Integer(int_type) => int_type,
+        scalar_type => {
+            return Err(RuntimeError::TypeError {
+                expected: "integer".to_string(),
+                actual: scalar_type.to_string(),
+            })
+        }
+    };
+    let unsigned_type = IntegerType {
+        is_signed: false,
+        bitlength: int_type.bitlength,
+    };
+
+    let left_bits = unsigned_bits(cs.namespace(|| "left bits"), left, int_type)?;
+    let right_bits = unsigned_bits(cs.namespace(|| "right bits"), right, int_type)?;
+
+    let bits = left_bits
+        .iter()
+        .zip(right_bits.iter())
+        .enumerate()
+        .map(|(index, (left, right))| {
+            combine(cs.namespace(|| format!("bit {}", index)), left, right)
+        })
+        .collect::<Result<Vec<Boolean>>>()?;
+
+    let num = AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack"), &bits)?;
+    let result =
+        Scalar::new_unchecked_variable(num.get_value(), num.get_variable(), unsigned_type.into());
+
+    if int_type.is_signed {
+        gadgets::reinterpret_sign(cs.namespace(|| "reinterpret sign"), &result, int_type)
+    } else {
+        Ok(result)
+    }
+}
+
+/// Decomposes the integer `scalar` into the little-endian bits of its two's complement
+/// representation.
+fn unsigned_bits<E, CS>(
+    mut cs: CS,
+    scalar: &Scalar<E>,
+    int_type: IntegerType,
+) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let unsigned_type = IntegerType {
+        is_signed: false,
+        bitlength: int_type.bitlength,
+    };
+    let unsigned = if int_type.is_signed {
+        gadgets::reinterpret_sign(cs.namespace(|| "reinterpret sign"), scalar, unsigned_type)?
+    } else {
+        scalar.clone()
+    };
+
+    Ok(unsigned
+        .to_expression::<CS>()
+        .into_bits_le_fixed(cs.namespace(|| "into_bits"), int_type.bitlength)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::BigInt;
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::utils::bigint_to_fr;
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: i64,
+        int_type: IntegerType,
+    ) -> Scalar<Bn256> {
+        let fr = bigint_to_fr::<Bn256>(&BigInt::from(value)).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, int_type.into())
+    }
+
+    fn check<F>(operation: F, int_type: IntegerType, cases: &[(i64, i64, i64)])
+    where
+        F: Fn(
+            &mut TestConstraintSystem<Bn256>,
+            &Scalar<Bn256>,
+            &Scalar<Bn256>,
+        ) -> Result<Scalar<Bn256>>,
+    {
+        for (left, right, expected) in cases.iter() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+            let left_scalar = variable(cs.namespace(|| "left"), *left, int_type);
+            let right_scalar = variable(cs.namespace(|| "right"), *right, int_type);
+
+            let result = operation(&mut cs, &left_scalar, &right_scalar).unwrap();
+
+            assert!(
+                cs.is_satisfied(),
+                "unsatisfied: {:?}",
+                cs.which_is_unsatisfied()
+            );
+            assert_eq!(result.get_type(), ScalarType::Integer(int_type));
+            assert_eq!(
+                result.to_bigint(),
+                Some(BigInt::from(*expected)),
+                "{} and {}",
+                left,
+                right
+            );
+        }
+    }
+
+    #[test]
+    fn test_bit_and() {
+        let and =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                bit_and(cs.namespace(|| "and"), left, right)
+            };
+
+        check(
+            and,
+            IntegerType::U8,
+            &[(0b1100_1010, 0b1010_0110, 0b1000_0010), (255, 0, 0)],
+        );
+        check(
+            and,
+            IntegerType::I8,
+            &[(-1, 42, 42), (-128, -1, -128), (-6, 7, 2)],
+        );
+    }
+
+    #[test]
+    fn test_bit_or() {
+        let or =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                bit_or(cs.namespace(|| "or"), left, right)
+            };
+
+        check(
+            or,
+            IntegerType::U8,
+            &[(0b1100_1010, 0b1010_0110, 0b1110_1110), (0, 0, 0)],
+        );
+        check(
+            or,
+            IntegerType::I8,
+            &[(-128, 1, -127), (0, -1, -1), (-6, 1, -5)],
+        );
+    }
+
+    #[test]
+    fn test_bit_xor() {
+        let xor =
+            |cs: &mut TestConstraintSystem<Bn256>, left: &Scalar<Bn256>, right: &Scalar<Bn256>| {
+                bit_xor(cs.namespace(|| "xor"), left, right)
+            };
+
+        check(
+            xor,
+            IntegerType::U8,
+            &[(0b1100_1010, 0b1010_0110, 0b0110_1100), (255, 255, 0)],
+        );
+        check(
+            xor,
+            IntegerType::I8,
+            &[(-1, 0, -1), (-1, 127, -128), (-6, -1, 5)],
+        );
+    }
+}
@@ -178,3 +178,124 @@ where
     let t = eq(cs.namespace(|| "eq"), left, right)?;
     gadgets::not(cs.namespace(|| "not"), &t)
 }
+
+pub fn min<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let is_lt = lt(cs.namespace(|| "lt"), left, right)?;
+    gadgets::conditional_select(cs.namespace(|| "select"), &is_lt, left, right)
+}
+
+pub fn max<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let is_lt = lt(cs.namespace(|| "lt"), left, right)?;
+    gadgets::conditional_select(cs.namespace(|| "select"), &is_lt, right, left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::ToBigInt;
+    use pairing::bn256::Bn256;
+
+    use crate::gadgets::IntegerType;
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: i64,
+        scalar_type: ScalarType,
+    ) -> Scalar<Bn256> {
+        let fr = utils::bigint_to_fr::<Bn256>(&BigInt::from(value)).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, scalar_type)
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let cases = [(-128, 127), (5, -5), (0, 0), (-7, -8)];
+        for (index, (left, right)) in cases.iter().enumerate() {
+            let mut cs = cs.namespace(|| format!("case {}", index));
+            let left = variable(cs.namespace(|| "left"), *left, IntegerType::I8.into());
+            let right = variable(cs.namespace(|| "right"), *right, IntegerType::I8.into());
+            let expected_min = std::cmp::min(left.to_bigint(), right.to_bigint());
+            let expected_max = std::cmp::max(left.to_bigint(), right.to_bigint());
+
+            let minimum = min(cs.namespace(|| "min"), &left, &right).unwrap();
+            let maximum = max(cs.namespace(|| "max"), &left, &right).unwrap();
+
+            assert_eq!(minimum.to_bigint(), expected_min);
+            assert_eq!(maximum.to_bigint(), expected_max);
+            assert_eq!(minimum.get_type(), ScalarType::Integer(IntegerType::I8));
+        }
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+    }
+
+    #[test]
+    fn test_compare_signed() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let cases = [
+            (-1, 1),
+            (1, -1),
+            (-2, -1),
+            (-1, -2),
+            (1, 2),
+            (-128, 127),
+            (127, -128),
+            (0, 0),
+            (-5, -5),
+        ];
+        for (index, (left, right)) in cases.iter().enumerate() {
+            let mut cs = cs.namespace(|| format!("case {}", index));
+            let left_scalar = variable(cs.namespace(|| "left"), *left, IntegerType::I8.into());
+            let right_scalar = variable(cs.namespace(|| "right"), *right, IntegerType::I8.into());
+
+            let results = [
+                gt(cs.namespace(|| "gt"), &left_scalar, &right_scalar).unwrap(),
+                ge(cs.namespace(|| "ge"), &left_scalar, &right_scalar).unwrap(),
+                lt(cs.namespace(|| "lt"), &left_scalar, &right_scalar).unwrap(),
+                le(cs.namespace(|| "le"), &left_scalar, &right_scalar).unwrap(),
+            ];
+            let expected = [left > right, left >= right, left < right, left <= right];
+
+            for (result, expected) in results.iter().zip(expected.iter()) {
+                assert_eq!(
+                    result.to_bigint(),
+                    Some(BigInt::from(*expected as u8)),
+                    "{} and {}",
+                    left,
+                    right
+                );
+            }
+        }
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+    }
+
+    #[test]
+    fn test_min_types_mismatch() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let left = variable(cs.namespace(|| "left"), 1, IntegerType::U8.into());
+        let right = variable(cs.namespace(|| "right"), 2, IntegerType::I8.into());
+
+        assert!(min(cs.namespace(|| "min"), &left, &right).is_err());
+    }
+}
@@ -286,15 +286,11 @@ where
     /// This gadget only enforces 0 <= index < array.len() if condition is true
     pub fn conditional_array_get(
         &mut self,
-        _condition: &Scalar<E>,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: &Scalar<E>,
     ) -> Result<Scalar<E>, RuntimeError> {
-        if !index.is_constant() {
-            return Err(RuntimeError::WitnessArrayIndex);
-        }
-        // let zero = Scalar::new_constant_int(0, index.get_type());
-        // let index = gadgets::conditional_select(self.cs_namespace(), condition, index, &zero)?;
+        let index = self.conditional_index(condition, index)?;
         self.enforcing_array_get(array, &index)
     }
 
@@ -326,33 +322,16 @@ where
                 Ok(array[i].clone())
             }
             _ => {
-                Err(RuntimeError::WitnessArrayIndex)
-                // let mut cs = self.cs_namespace();
-                // let num_bits = math::log2ceil(array.len());
-                // let bits_le = index.to_expression::<CS>().into_bits_le_fixed(
-                //     cs.namespace(|| "into_bits"),
-                //     num_bits
-                // )?;
-                // let bits_be = bits_le
-                //     .into_iter()
-                //     .rev()
-                //     .enumerate()
-                //     .map(|(i, bit)| {
-                //         Scalar::from_boolean(cs.namespace(|| format!("bit {}", i)), bit)
-                //     })
-                //     .collect::<Result<Vec<Scalar<E>>, RuntimeError>>()?;
-
-                // gadgets::recursive_select(
-                //     cs.namespace(|| "recursive_select"),
-                //     &bits_be,
-                //     array
-                // )
+                let cs = self.cs_namespace();
+                gadgets::select_by_index(cs, array, index)
             }
         }
     }
 
-    pub fn array_set(
+    /// This gadget only enforces 0 <= index < array.len() if condition is true
+    pub fn conditional_array_set(
         &mut self,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: Scalar<E>,
         value: Scalar<E>,
@@ -372,22 +351,37 @@ where
                 new_array[i] = value;
             }
             _ => {
-                return Err(RuntimeError::WitnessArrayIndex);
-                // let mut new_array = Vec::new();
-
-                // for (i, p) in array.iter().enumerate() {
-                //     let curr_index = Scalar::new_constant_int(i, ScalarType::Field);
-                //     let is_current_index = self.eq(curr_index, index.clone())?;
-                //     let cs = self.cs_namespace();
-                //     let value = gadgets::conditional_select(cs, &is_current_index, &value, p)?;
-                //     new_array.push(value);
-                // }
+                let index = self.conditional_index(condition, &index)?;
+
+                let mut cs = self.cs_namespace();
+                let length = Scalar::new_constant_bigint(&array.len().into(), index.get_type())?;
+                let lt = gadgets::comparison::lt(cs.namespace(|| "lt"), &index, &length)?;
+                mem::drop(cs);
+                self.assert(lt, Some("index out of bounds"))?;
+
+                let cs = self.cs_namespace();
+                new_array = gadgets::update_by_index(cs, array, &index, &value)?;
             }
         };
 
         Ok(new_array)
     }
 
+    /// Replaces a variable index with zero if condition is false, so the bounds check
+    /// of a branch which is not taken always passes.
+    fn conditional_index(
+        &mut self,
+        condition: &Scalar<E>,
+        index: &Scalar<E>,
+    ) -> Result<Scalar<E>, RuntimeError> {
+        if index.is_constant() {
+            return Ok(index.clone());
+        }
+
+        let zero = Scalar::new_constant_int(0, index.get_type());
+        gadgets::conditional_select(self.cs_namespace(), condition, index, &zero)
+    }
+
     pub fn execute<G: Gadget<E>>(
         &mut self,
         gadget: G,
@@ -1,16 +1,20 @@
 pub mod arithmetic;
 pub mod arrays;
 pub mod auto_const;
+pub mod bitwise;
 pub mod boolean;
 pub mod comparison;
 mod conditional_select;
+pub mod shift;
 pub mod types;
 
 pub use arithmetic::*;
 pub use arrays::*;
+pub use bitwise::*;
 pub use boolean::*;
 pub use comparison::*;
 pub use conditional_select::*;
+pub use shift::*;
 pub use types::*;
 
 mod misc;
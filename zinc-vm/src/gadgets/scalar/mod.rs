@@ -14,6 +14,8 @@ use franklin_crypto::circuit::expression::Expression;
 use franklin_crypto::circuit::num::AllocatedNum;
 use franklin_crypto::circuit::Assignment;
 use num_bigint::{BigInt, ToBigInt};
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::{ScalarValue, Value};
 
 /// Scalar is a primitive value that can be stored on the stack and operated by VM's instructions.
 #[derive(Debug, Clone)]
@@ -142,6 +144,24 @@ impl<E: Engine> Scalar<E> {
         }
     }
 
+    /// Returns the value of the scalar if it is a constant, or `None` if it is a witness variable.
+    pub fn to_constant(&self) -> Option<BigInt> {
+        match &self.variant {
+            ScalarVariant::Constant(constant) => {
+                Some(utils::fr_to_bigint(&constant.value, self.is_signed()))
+            }
+            ScalarVariant::Variable(_) => None,
+        }
+    }
+
+    /// Returns the value of the boolean scalar if it is a constant.
+    pub fn to_constant_bool(&self) -> Option<bool> {
+        match self.scalar_type {
+            ScalarType::Boolean => self.to_constant().map(|value| value != BigInt::from(0)),
+            _ => None,
+        }
+    }
+
     pub fn get_constant_usize(&self) -> Result<usize> {
         let fr = self.get_constant()?;
         let bigint = utils::fr_to_bigint(&fr, false);
@@ -225,6 +245,45 @@ impl<E: Engine> Scalar<E> {
     }
 }
 
+/// Converts the flat `scalars` of a value of `data_type` into the plain data value.
+///
+/// Returns `None` if any of the scalars is a witness variable.
+pub fn to_constant_value<E: Engine>(data_type: &DataType, scalars: &[Scalar<E>]) -> Option<Value> {
+    let flat_values = scalars
+        .iter()
+        .map(Scalar::to_constant)
+        .collect::<Option<Vec<BigInt>>>()?;
+
+    Value::from_flat_values(data_type, &flat_values)
+}
+
+/// Converts the plain data `value` into the flat constant scalars.
+pub fn from_constant_value<E: Engine>(value: &Value) -> Result<Vec<Scalar<E>>> {
+    match value {
+        Value::Unit => Ok(Vec::new()),
+        Value::Scalar(ScalarValue::Field(value)) => {
+            Ok(vec![Scalar::new_constant_bigint(value, ScalarType::Field)?])
+        }
+        Value::Scalar(ScalarValue::Bool(value)) => Ok(vec![Scalar::new_constant_bool(*value)]),
+        Value::Scalar(ScalarValue::Integer(value, int_type)) => {
+            Ok(vec![Scalar::new_constant_bigint(
+                value,
+                (*int_type).into(),
+            )?])
+        }
+        Value::Struct(fields) => fields
+            .iter()
+            .map(|field| from_constant_value(&field.value))
+            .collect::<Result<Vec<Vec<Scalar<E>>>>>()
+            .map(|scalars| scalars.into_iter().flatten().collect()),
+        Value::Array(values) => values
+            .iter()
+            .map(from_constant_value)
+            .collect::<Result<Vec<Vec<Scalar<E>>>>>()
+            .map(|scalars| scalars.into_iter().flatten().collect()),
+    }
+}
+
 //impl<E: Engine> fmt::Debug for Scalar<E> {
 //    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 //        let value_str = self
@@ -284,3 +343,84 @@ impl<E: Engine> From<AllocatedNum<E>> for Scalar<E> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::Bn256;
+    use zinc_bytecode::data::values::StructField;
+
+    fn data_type() -> DataType {
+        DataType::Struct(vec![
+            ("a".to_owned(), DataType::Scalar(IntegerType::I8.into())),
+            (
+                "b".to_owned(),
+                DataType::Array(Box::new(DataType::Scalar(ScalarType::Boolean)), 2),
+            ),
+        ])
+    }
+
+    fn constants() -> Vec<Scalar<Bn256>> {
+        vec![
+            Scalar::new_constant_bigint(&BigInt::from(-5), IntegerType::I8.into()).unwrap(),
+            Scalar::new_constant_bool(true),
+            Scalar::new_constant_bool(false),
+        ]
+    }
+
+    #[test]
+    fn test_to_constant() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let fr = utils::bigint_to_fr::<Bn256>(&BigInt::from(-5)).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        let witness =
+            Scalar::<Bn256>::new_unchecked_variable(Some(fr), variable, IntegerType::I8.into());
+        let constant = Scalar::<Bn256>::new_constant_fr(fr, IntegerType::I8.into());
+
+        assert_eq!(witness.to_constant(), None);
+        assert_eq!(constant.to_constant(), Some(BigInt::from(-5)));
+        assert_eq!(constant.to_constant_bool(), None);
+        assert_eq!(
+            Scalar::<Bn256>::new_constant_bool(true).to_constant_bool(),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_to_constant_value() {
+        let value = to_constant_value(&data_type(), &constants()).unwrap();
+        let expected = Value::Struct(vec![
+            StructField {
+                field: "a".to_owned(),
+                value: Value::Scalar(ScalarValue::Integer(BigInt::from(-5), IntegerType::I8)),
+            },
+            StructField {
+                field: "b".to_owned(),
+                value: Value::Array(vec![
+                    Value::Scalar(ScalarValue::Bool(true)),
+                    Value::Scalar(ScalarValue::Bool(false)),
+                ]),
+            },
+        ]);
+
+        assert_eq!(value, expected);
+
+        let scalars = from_constant_value::<Bn256>(&expected).unwrap();
+        assert!(scalars.iter().all(Scalar::is_constant));
+        assert_eq!(to_constant_value(&data_type(), &scalars), Some(expected));
+    }
+
+    #[test]
+    fn test_to_constant_value_with_witness() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let fr = utils::bigint_to_fr::<Bn256>(&BigInt::from(1)).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+
+        let mut scalars = constants();
+        scalars[2] = Scalar::new_unchecked_variable(Some(fr), variable, ScalarType::Boolean);
+
+        assert!(to_constant_value(&data_type(), &scalars).is_none());
+    }
+}
@@ -0,0 +1,252 @@
+use crate::gadgets;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
+use crate::{Engine, Result, RuntimeError};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+/// Shifts the integer `value` left by `shift` bits.
+///
+/// The bits shifted out of the type bit length are dropped. The sign bit of signed integers
+/// is always cleared, like with the constant shift.
+pub fn shift_left<E, CS>(mut cs: CS, value: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let bits = value_bits(cs.namespace(|| "value bits"), value)?;
+    let bits = barrel_shift(cs.namespace(|| "barrel shift"), bits, shift, true)?;
+    pack(cs.namespace(|| "pack"), bits, value.get_type())
+}
+
+/// Shifts the integer `value` right by `shift` bits.
+///
+/// The sign bit of signed integers is cleared before shifting, so the shift is always logical.
+pub fn shift_right<E, CS>(mut cs: CS, value: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let bits = value_bits(cs.namespace(|| "value bits"), value)?;
+    let bits = barrel_shift(cs.namespace(|| "barrel shift"), bits, shift, false)?;
+    pack(cs.namespace(|| "pack"), bits, value.get_type())
+}
+
+/// Decomposes `value` into the little-endian bits of its two's complement representation,
+/// with the sign bit cleared for signed integers.
+fn value_bits<E, CS>(mut cs: CS, value: &Scalar<E>) -> Result<Vec<Scalar<E>>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let bits = match value.get_type() {
+        ScalarType::Integer(IntegerType {
+            is_signed: true,
+            bitlength,
+        }) => {
+            let base_value = BigInt::from(1) << bitlength;
+            let base = Scalar::new_constant_bigint(&base_value, ScalarType::Field)?;
+            let complement = gadgets::add(cs.namespace(|| "complement"), value, &base)?;
+
+            let mut bits = complement
+                .to_expression::<CS>()
+                .into_bits_le_fixed(cs.namespace(|| "into_bits"), bitlength + 1)?;
+            bits.truncate(bitlength - 1);
+            bits.push(Boolean::constant(false));
+            bits
+        }
+        ScalarType::Integer(IntegerType {
+            is_signed: false,
+            bitlength,
+        }) => value
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "into_bits"), bitlength)?,
+        scalar_type => {
+            return Err(RuntimeError::TypeError {
+                expected: "integer".to_string(),
+                actual: scalar_type.to_string(),
+            })
+        }
+    };
+
+    bits.into_iter()
+        .enumerate()
+        .map(|(index, bit)| Scalar::from_boolean(cs.namespace(|| format!("bit {}", index)), bit))
+        .collect()
+}
+
+/// Shifts `bits` by `shift` positions.
+///
+/// A constant shift only moves the bits. A variable shift is done by a barrel shifter, where
+/// each bit of `shift` conditionally selects the bits shifted by the corresponding power of two.
+fn barrel_shift<E, CS>(
+    mut cs: CS,
+    bits: Vec<Scalar<E>>,
+    shift: &Scalar<E>,
+    is_left: bool,
+) -> Result<Vec<Scalar<E>>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    if shift.is_constant() {
+        return Ok(move_bits(&bits, shift.get_constant_usize()?, is_left));
+    }
+
+    let shift_bits = shift.get_bits_le(cs.namespace(|| "shift bits"))?;
+
+    let mut bits = bits;
+    for (stage, condition) in shift_bits.iter().enumerate() {
+        let amount = 1usize.checked_shl(stage as u32).unwrap_or(std::usize::MAX);
+        let moved = move_bits(&bits, amount, is_left);
+
+        bits = bits
+            .iter()
+            .zip(moved.iter())
+            .enumerate()
+            .map(|(index, (current, moved))| {
+                gadgets::conditional_select(
+                    cs.namespace(|| format!("stage {} bit {}", stage, index)),
+                    condition,
+                    moved,
+                    current,
+                )
+            })
+            .collect::<Result<Vec<Scalar<E>>>>()?;
+    }
+
+    Ok(bits)
+}
+
+/// Moves the little-endian `bits` by `amount` positions, filling the vacated ones with zeros.
+fn move_bits<E: Engine>(bits: &[Scalar<E>], amount: usize, is_left: bool) -> Vec<Scalar<E>> {
+    (0..bits.len())
+        .map(|index| {
+            let source = if is_left {
+                index.checked_sub(amount)
+            } else {
+                index
+                    .checked_add(amount)
+                    .filter(|source| *source < bits.len())
+            };
+
+            match source {
+                Some(source) => bits[source].clone(),
+                None => Scalar::new_constant_bool(false),
+            }
+        })
+        .collect()
+}
+
+/// Packs the little-endian `bits` into a scalar of `scalar_type`.
+fn pack<E, CS>(mut cs: CS, bits: Vec<Scalar<E>>, scalar_type: ScalarType) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let booleans = bits
+        .iter()
+        .enumerate()
+        .map(|(index, bit)| bit.to_boolean(cs.namespace(|| format!("bit {}", index))))
+        .collect::<Result<Vec<Boolean>>>()?;
+
+    let num =
+        AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), &booleans)?;
+
+    Ok(Scalar::new_unchecked_variable(
+        num.get_value(),
+        num.get_variable(),
+        scalar_type,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ff::PrimeField;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::ToBigInt;
+    use pairing::bn256::{Bn256, Fr};
+
+    fn variable<CS: ConstraintSystem<Bn256>>(
+        mut cs: CS,
+        value: usize,
+        scalar_type: ScalarType,
+    ) -> Scalar<Bn256> {
+        let fr = Fr::from_str(value.to_string().as_str()).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        Scalar::new_unchecked_variable(Some(fr), variable, scalar_type)
+    }
+
+    fn shift(value: usize, amount: usize, is_left: bool, is_amount_constant: bool) -> BigInt {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let value = variable(cs.namespace(|| "value"), value, IntegerType::U8.into());
+        let amount = if is_amount_constant {
+            Scalar::new_constant_int(amount, IntegerType::U8.into())
+        } else {
+            variable(cs.namespace(|| "amount"), amount, IntegerType::U8.into())
+        };
+
+        let result = if is_left {
+            shift_left(cs.namespace(|| "shift"), &value, &amount)
+        } else {
+            shift_right(cs.namespace(|| "shift"), &value, &amount)
+        }
+        .unwrap();
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+        result.to_bigint().unwrap()
+    }
+
+    #[test]
+    fn test_shift_left() {
+        for is_amount_constant in [true, false].iter() {
+            for (value, amount, expected) in [
+                (0b1011_0101, 0, 0b1011_0101),
+                (0b1011_0101, 3, 0b1010_1000),
+                (0b1011_0101, 7, 0b1000_0000),
+                (0b1011_0101, 8, 0),
+                (0b1011_0101, 200, 0),
+            ]
+            .iter()
+            {
+                assert_eq!(
+                    shift(*value, *amount, true, *is_amount_constant),
+                    BigInt::from(*expected),
+                    "{} << {}",
+                    value,
+                    amount
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_right() {
+        for is_amount_constant in [true, false].iter() {
+            for (value, amount, expected) in [
+                (0b1011_0101, 0, 0b1011_0101),
+                (0b1011_0101, 3, 0b0001_0110),
+                (0b1011_0101, 7, 0b0000_0001),
+                (0b1011_0101, 8, 0),
+                (0b1011_0101, 200, 0),
+            ]
+            .iter()
+            {
+                assert_eq!(
+                    shift(*value, *amount, false, *is_amount_constant),
+                    BigInt::from(*expected),
+                    "{} >> {}",
+                    value,
+                    amount
+                );
+            }
+        }
+    }
+}
@@ -85,3 +85,106 @@ where
 
     Ok(scalar.with_type_unchecked(int_type.into()))
 }
+
+///
+/// Checks that the unsigned value of `scalar` fits in `bitlength` bits if `condition` is true.
+///
+/// Unlike the type check, the value keeps its type, so it can be used to check the values
+/// which are not known to be produced by a type-checking instruction.
+///
+pub fn conditional_range_check<E, CS>(
+    mut cs: CS,
+    condition: &Scalar<E>,
+    scalar: &Scalar<E>,
+    bitlength: usize,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    condition.get_type().assert_type(ScalarType::Boolean)?;
+
+    // Throw runtime error if value is known.
+    if let (Some(value_fr), Some(condition_fr)) = (scalar.get_value(), condition.get_value()) {
+        let value = utils::fr_to_bigint(&value_fr, false);
+        if !condition_fr.is_zero() && value >= BigInt::from(1) << bitlength {
+            return Err(RuntimeError::ValueOutOfRange { value, bitlength });
+        }
+    }
+
+    if scalar.is_constant() {
+        return Ok(scalar.clone());
+    }
+
+    // If checking inside the false branch, use zero instead to avoid throwing an error.
+    let condition_bool = condition.to_boolean(cs.namespace(|| "to_boolean"))?;
+    let value_to_check = Expression::conditionally_select(
+        cs.namespace(|| "select value to check"),
+        scalar.to_expression::<CS>(),
+        Expression::u64::<CS>(0),
+        &condition_bool,
+    )?;
+
+    // If value is overflowing, `into_bits_le_fixed` will be unsatisfiable.
+    let _bits = value_to_check.into_bits_le_fixed(cs.namespace(|| "into_bits"), bitlength)?;
+
+    Ok(scalar.clone())
+}
+
+///
+/// Reinterprets the bits of an integer as an integer of the same bitlength and the opposite
+/// signedness using the two's complement representation, e.g. `255 as i8 == -1`.
+///
+pub fn reinterpret_sign<E, CS>(
+    mut cs: CS,
+    scalar: &Scalar<E>,
+    int_type: IntegerType,
+) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let modulus = BigInt::from(1) << int_type.bitlength;
+
+    if scalar.is_constant() {
+        let value = utils::fr_to_bigint(&scalar.get_constant()?, !int_type.is_signed);
+        let half = BigInt::from(1) << (int_type.bitlength - 1);
+        let value = if int_type.is_signed && value >= half {
+            value - modulus
+        } else if !int_type.is_signed && value < BigInt::from(0) {
+            value + modulus
+        } else {
+            value
+        };
+        return Scalar::new_constant_bigint(&value, int_type.into());
+    }
+
+    let modulus_fr = utils::bigint_to_fr::<E>(&modulus).expect("invalid integer type length");
+    let value = if int_type.is_signed {
+        // The unsigned value is negative if its most significant bit is set.
+        let bits = scalar
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "into_bits"), int_type.bitlength)?;
+        Expression::conditionally_select(
+            cs.namespace(|| "select value"),
+            scalar.to_expression::<CS>() - Expression::constant::<CS>(modulus_fr),
+            scalar.to_expression::<CS>(),
+            &bits[int_type.bitlength - 1],
+        )?
+    } else {
+        // The signed value is non-negative if the most significant bit of the offset one is set.
+        let offset = BigInt::from(1) << (int_type.bitlength - 1);
+        let offset_fr = utils::bigint_to_fr::<E>(&offset).expect("invalid integer type length");
+        let bits = (scalar.to_expression::<CS>() + Expression::constant::<CS>(offset_fr))
+            .into_bits_le_fixed(cs.namespace(|| "into_bits"), int_type.bitlength)?;
+        Expression::conditionally_select(
+            cs.namespace(|| "select value"),
+            scalar.to_expression::<CS>(),
+            scalar.to_expression::<CS>() + Expression::constant::<CS>(modulus_fr),
+            &bits[int_type.bitlength - 1],
+        )?
+    };
+
+    let num = value.into_number(cs.namespace(|| "into_number"))?;
+    Ok(Scalar::from(num).with_type_unchecked(int_type.into()))
+}
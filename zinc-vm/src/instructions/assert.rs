@@ -64,4 +64,33 @@ mod tests {
             .add(EndIf)
             .test::<i32>(&[])
     }
+
+    fn assert_in_witness_condition(is_taken: bool, value: bool) -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness((is_taken as usize).into(), ScalarType::Boolean)
+            .witness((value as usize).into(), ScalarType::Boolean)
+            .add(Load::new(0))
+            .add(If)
+            .add(Load::new(1))
+            .add(Assert::new(Some("value is false".to_owned())))
+            .add(EndIf)
+            .test::<i32>(&[])
+    }
+
+    #[test]
+    fn test_assert_in_witness_condition() -> Result<(), TestingError> {
+        assert_in_witness_condition(true, true)?;
+        assert_in_witness_condition(false, true)?;
+        assert_in_witness_condition(false, false)
+    }
+
+    #[test]
+    fn test_assert_fail_in_witness_condition() {
+        match assert_in_witness_condition(true, false) {
+            Err(TestingError::RuntimeError(RuntimeError::AssertionError(message))) => {
+                assert_eq!(message, "value is false")
+            }
+            result => panic!("expected an assertion error, got {:?}", result),
+        }
+    }
 }
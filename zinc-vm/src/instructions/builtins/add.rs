@@ -24,12 +24,16 @@ where
 
         let unchecked_sum = gadgets::add(cs.namespace(|| "sum"), &left, &right)?;
 
-        let sum = gadgets::types::conditional_type_check(
-            cs.namespace(|| "type check"),
-            &condition,
-            &unchecked_sum,
-            sum_type,
-        )?;
+        let sum = if self.is_unchecked {
+            unchecked_sum.with_type_unchecked(sum_type)
+        } else {
+            gadgets::types::conditional_type_check(
+                cs.namespace(|| "type check"),
+                &condition,
+                &unchecked_sum,
+                sum_type,
+            )?
+        };
 
         vm.push(Cell::Value(sum))
     }
@@ -37,7 +41,10 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::core::RuntimeError;
+    use crate::errors::MalformedBytecode;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
     use zinc_bytecode::*;
 
     #[test]
@@ -45,7 +52,69 @@ mod tests {
         VMTestRunner::new()
             .add(PushConst::new_field(1.into()))
             .add(PushConst::new_field(2.into()))
-            .add(Add)
+            .add(Add::default())
             .test(&[3])
     }
+
+    #[test]
+    fn test_add_unchecked_constraints() -> Result<(), TestingError> {
+        // The two `u8` inputs take about 20 constraints, and the range check of the sum would
+        // add about 10 more.
+        VMTestRunner::new()
+            .witness(100.into(), IntegerType::U8.into())
+            .witness(27.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Add::new(true))
+            .expect_constraints_at_most(24)
+            .test(&[127])
+    }
+
+    #[test]
+    fn test_add_stack_underflow() {
+        let error = VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(Add::default())
+            .test::<i32>(&[])
+            .expect_err("expected a stack underflow");
+
+        assert_eq!(error.instruction_index(), Some(2));
+        match error {
+            TestingError::RuntimeError(RuntimeError::InstructionError {
+                instruction,
+                backtrace,
+                error,
+                ..
+            }) => {
+                assert_eq!(instruction, "Add(Add { is_unchecked: false })");
+                assert_eq!(backtrace.len(), 3);
+                match *error {
+                    RuntimeError::MalformedBytecode(MalformedBytecode::StackUnderflow) => {}
+                    error => panic!("expected a stack underflow, got {:?}", error),
+                }
+            }
+            error => panic!("expected an instruction error, got {:?}", error),
+        }
+    }
+
+    #[test]
+    fn test_add_type_mismatch() {
+        let error = VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(Add::default())
+            .test::<i32>(&[])
+            .expect_err("expected a type error");
+
+        assert_eq!(error.instruction_index(), Some(3));
+        match error {
+            TestingError::RuntimeError(RuntimeError::InstructionError { error, .. }) => {
+                match *error {
+                    RuntimeError::TypeError { .. } => {}
+                    error => panic!("expected a type error, got {:?}", error),
+                }
+            }
+            error => panic!("expected an instruction error, got {:?}", error),
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::core::{InternalVM, VMInstruction, VirtualMachine};
+use crate::gadgets;
 use crate::{Engine, Result, RuntimeError};
 
 use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
@@ -16,6 +17,12 @@ where
         let right = vm.pop()?.value()?;
         let left = vm.pop()?.value()?;
 
+        if !left.is_constant() || !right.is_constant() {
+            let cs = vm.constraint_system();
+            let result = gadgets::bit_and(cs.namespace(|| "bit_and"), &left, &right)?;
+            return vm.push(result.into());
+        }
+
         let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
 
         let left_value = fr_to_bigint(&left.get_constant()?, scalar_type.is_signed());
@@ -31,3 +38,36 @@ where
         vm.push(result.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_bit_and() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(0b1100_1010.into(), IntegerType::U8.into()))
+            .add(PushConst::new(0b1010_0110.into(), IntegerType::U8.into()))
+            .add(BitAnd)
+            .add(PushConst::new((-6).into(), IntegerType::I8.into()))
+            .add(PushConst::new(7.into(), IntegerType::I8.into()))
+            .add(BitAnd)
+            .test(&[2, 0b1000_0010])
+    }
+
+    #[test]
+    fn test_bit_and_witness() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(0b1100_1010.into(), IntegerType::U8.into())
+            .witness((-6).into(), IntegerType::I8.into())
+            .add(Load::new(0))
+            .add(PushConst::new(0b1010_0110.into(), IntegerType::U8.into()))
+            .add(BitAnd)
+            .add(Load::new(1))
+            .add(PushConst::new(7.into(), IntegerType::I8.into()))
+            .add(BitAnd)
+            .test(&[2, 0b1000_0010])
+    }
+}
@@ -1,12 +1,14 @@
-use crate::core::{InternalVM, VMInstruction, VirtualMachine};
-use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
-use crate::gadgets::{Scalar, ScalarTypeExpectation};
-use crate::{Engine, Result, RuntimeError};
+use crate::auto_const;
+use crate::core::{Cell, InternalVM, VMInstruction, VirtualMachine};
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::{Scalar, ScalarType};
+use crate::{gadgets, Engine, Result, RuntimeError};
 
 use franklin_crypto::bellman::ConstraintSystem;
 use num_bigint::BigInt;
-use num_bigint::Sign;
+use num_traits::One;
 use zinc_bytecode::instructions::BitNot;
+use zinc_bytecode::scalar::IntegerType;
 
 impl<E, CS> VMInstruction<E, CS> for BitNot
 where
@@ -17,21 +19,48 @@ where
         let scalar = vm.pop()?.value()?;
 
         let scalar_type = scalar.get_type();
-        let value = fr_to_bigint(&scalar.get_constant()?, scalar_type.is_signed());
-
-        let mut mask = vec![0xFF; scalar_type.bit_length::<E>() / 8];
-        if scalar_type.is_signed() {
-            mask[0] = 0x7F;
-        }
-
-        let mut result_value = !value;
-        result_value &= &BigInt::from_bytes_le(Sign::Plus, mask.as_slice());
-
-        let result_fr = bigint_to_fr::<E>(&result_value).ok_or(RuntimeError::ValueOverflow {
-            value: result_value,
-            scalar_type,
-        })?;
-        let result = Scalar::new_constant_fr(result_fr, scalar_type);
-        vm.push(result.into())
+        let bitlength = match scalar_type {
+            ScalarType::Integer(IntegerType {
+                is_signed: false,
+                bitlength,
+            }) => bitlength,
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "unsigned integer".into(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+
+        // the inverted value is `MAX - x`, so it is always within the type range
+        let max_value = (BigInt::one() << bitlength) - BigInt::one();
+        let max = Scalar::new_constant_bigint(&max_value, scalar_type)?;
+
+        let cs = vm.constraint_system();
+        let result = auto_const!(
+            gadgets::arithmetic::sub,
+            cs.namespace(|| "bit_not"),
+            &max,
+            &scalar
+        )?;
+
+        vm.push(Cell::Value(result.with_type_unchecked(scalar_type)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_bit_not() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(5.into(), IntegerType::U8.into()))
+            .add(BitNot)
+            .add(PushConst::new(0.into(), IntegerType::U16.into()))
+            .add(BitNot)
+            .test(&[65535, 250])
     }
 }
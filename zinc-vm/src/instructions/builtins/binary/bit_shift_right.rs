@@ -1,4 +1,5 @@
 use crate::core::{InternalVM, VMInstruction, VirtualMachine};
+use crate::gadgets;
 use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
 use crate::gadgets::{Scalar, ScalarTypeExpectation};
 use crate::{Engine, Result, RuntimeError};
@@ -18,6 +19,12 @@ where
         let right = vm.pop()?.value()?;
         let left = vm.pop()?.value()?;
 
+        if !left.is_constant() || !right.is_constant() {
+            let cs = vm.constraint_system();
+            let result = gadgets::shift_right(cs.namespace(|| "shift_right"), &left, &right)?;
+            return vm.push(result.into());
+        }
+
         let scalar_type = left.get_type();
 
         let mut left_value = fr_to_bigint(&left.get_constant()?, scalar_type.is_signed());
@@ -25,7 +32,8 @@ where
 
         let mut mask = vec![0xFF; scalar_type.bit_length::<E>() / 8];
         if scalar_type.is_signed() {
-            mask[0] = 0x7F;
+            let sign_byte = mask.len() - 1;
+            mask[sign_byte] = 0x7F;
         }
 
         left_value &= &BigInt::from_bytes_le(Sign::Plus, mask.as_slice());
@@ -39,3 +47,36 @@ where
         vm.push(result.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_bit_shift_right() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(0b1011_0101.into(), IntegerType::U8.into()))
+            .add(PushConst::new(3.into(), IntegerType::U8.into()))
+            .add(BitShiftRight)
+            .add(PushConst::new(0b1011_0101.into(), IntegerType::U8.into()))
+            .add(PushConst::new(8.into(), IntegerType::U8.into()))
+            .add(BitShiftRight)
+            .test(&[0, 0b0001_0110])
+    }
+
+    #[test]
+    fn test_bit_shift_right_witness() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(0b1011_0101.into(), IntegerType::U8.into())
+            .witness(3.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(PushConst::new(3.into(), IntegerType::U8.into()))
+            .add(BitShiftRight)
+            .add(PushConst::new(0b1011_0101.into(), IntegerType::U8.into()))
+            .add(Load::new(1))
+            .add(BitShiftRight)
+            .test(&[0b0001_0110, 0b0001_0110])
+    }
+}
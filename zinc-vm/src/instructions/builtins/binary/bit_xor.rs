@@ -1,4 +1,5 @@
 use crate::core::{InternalVM, VMInstruction, VirtualMachine};
+use crate::gadgets;
 use crate::{Engine, Result, RuntimeError};
 
 use crate::gadgets::utils::{bigint_to_fr, fr_to_bigint};
@@ -15,6 +16,12 @@ where
         let right = vm.pop()?.value()?;
         let left = vm.pop()?.value()?;
 
+        if !left.is_constant() || !right.is_constant() {
+            let cs = vm.constraint_system();
+            let result = gadgets::bit_xor(cs.namespace(|| "bit_xor"), &left, &right)?;
+            return vm.push(result.into());
+        }
+
         let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
 
         let left_value = fr_to_bigint(&left.get_constant()?, scalar_type.is_signed());
@@ -30,3 +37,36 @@ where
         vm.push(result.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_bit_xor() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(0b1100_1010.into(), IntegerType::U8.into()))
+            .add(PushConst::new(0b1010_0110.into(), IntegerType::U8.into()))
+            .add(BitXor)
+            .add(PushConst::new((-6).into(), IntegerType::I8.into()))
+            .add(PushConst::new((-1).into(), IntegerType::I8.into()))
+            .add(BitXor)
+            .test(&[5, 0b0110_1100])
+    }
+
+    #[test]
+    fn test_bit_xor_witness() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(0b1100_1010.into(), IntegerType::U8.into())
+            .witness((-6).into(), IntegerType::I8.into())
+            .add(Load::new(0))
+            .add(PushConst::new(0b1010_0110.into(), IntegerType::U8.into()))
+            .add(BitXor)
+            .add(Load::new(1))
+            .add(PushConst::new((-1).into(), IntegerType::I8.into()))
+            .add(BitXor)
+            .test(&[5, 0b0110_1100])
+    }
+}
@@ -0,0 +1,92 @@
+use crate::core::{Cell, InternalVM, RuntimeError, VMInstruction, VirtualMachine};
+use crate::gadgets;
+use crate::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use zinc_bytecode::instructions::ConditionalSelectSequence;
+
+impl<E, CS> VMInstruction<E, CS> for ConditionalSelectSequence
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let condition = vm.pop()?.value()?;
+
+        let mut if_false = Vec::with_capacity(self.len);
+        for _ in 0..self.len {
+            if_false.push(vm.pop()?.value()?);
+        }
+        if_false.reverse();
+
+        let mut if_true = Vec::with_capacity(self.len);
+        for _ in 0..self.len {
+            if_true.push(vm.pop()?.value()?);
+        }
+        if_true.reverse();
+
+        for (index, (if_true, if_false)) in if_true.iter().zip(if_false.iter()).enumerate() {
+            let cs = vm.constraint_system();
+            let selected = gadgets::conditional_select(
+                cs.namespace(|| format!("element {}", index)),
+                &condition,
+                if_true,
+                if_false,
+            )?;
+            vm.push(Cell::Value(selected))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+    use zinc_bytecode::*;
+
+    fn push_u8(runner: &mut VMTestRunner, values: &[u8]) {
+        for value in values.iter() {
+            runner.add(PushConst::new((*value).into(), IntegerType::U8.into()));
+        }
+    }
+
+    #[test]
+    fn test_select_sequence_single() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(PushConst::new(1.into(), ScalarType::Boolean))
+            .add(ConditionalSelectSequence::new(1))
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(PushConst::new(0.into(), ScalarType::Boolean))
+            .add(ConditionalSelectSequence::new(1))
+            .test(&[2, 1])
+    }
+
+    #[test]
+    fn test_select_sequence_array() -> Result<(), TestingError> {
+        let mut runner = VMTestRunner::new();
+        push_u8(&mut runner, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        runner
+            .add(PushConst::new(0.into(), ScalarType::Boolean))
+            .add(ConditionalSelectSequence::new(4))
+            .test(&[8, 7, 6, 5])
+    }
+
+    #[test]
+    fn test_select_sequence_array_witness() -> Result<(), TestingError> {
+        for (condition, expected) in vec![(1, [4, 3, 2, 1]), (0, [8, 7, 6, 5])] {
+            let mut runner = VMTestRunner::new();
+            runner.witness(condition.into(), ScalarType::Boolean);
+            push_u8(&mut runner, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            runner
+                .add(Load::new(0))
+                .add(ConditionalSelectSequence::new(4))
+                .test(&expected)?;
+        }
+
+        Ok(())
+    }
+}
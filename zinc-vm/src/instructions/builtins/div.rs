@@ -6,6 +6,7 @@ use crate::core::{RuntimeError, VirtualMachine};
 use crate::gadgets;
 use crate::gadgets::{Scalar, ScalarType, ScalarTypeExpectation};
 use crate::Engine;
+use ff::Field;
 use zinc_bytecode::instructions::Div;
 
 impl<E, CS> VMInstruction<E, CS> for Div
@@ -31,6 +32,9 @@ where
                     &right,
                     &one,
                 )?;
+                if denom.get_value().map_or(false, |value| value.is_zero()) {
+                    return Err(RuntimeError::DivisionByZero { pc: None });
+                }
                 let inverse = gadgets::inverse(cs.namespace(|| "inverse"), &denom)?;
                 gadgets::mul(cs.namespace(|| "div"), &left, &inverse)?
             }
@@ -65,7 +69,7 @@ where
 mod test {
     use super::*;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
-    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
     use zinc_bytecode::*;
 
     #[test]
@@ -85,4 +89,49 @@ mod test {
             .add(Div)
             .test(&[3, -3, -2, 2])
     }
+
+    #[test]
+    fn test_div_by_zero_untaken() -> Result<(), TestingError> {
+        // let b = _;
+        //
+        // if b != 0 { 42 / b } else { 0 }
+        for scalar_type in vec![IntegerType::U8.into(), ScalarType::Field] {
+            for (b, r) in vec![(0, 0), (7, 6)] {
+                VMTestRunner::new()
+                    .witness(b.into(), scalar_type)
+                    .add(Load::new(0))
+                    .add(PushConst::new(0.into(), scalar_type))
+                    .add(Ne)
+                    .add(If)
+                    .add(PushConst::new(42.into(), scalar_type))
+                    .add(Load::new(0))
+                    .add(Div)
+                    .add(Else)
+                    .add(PushConst::new(0.into(), scalar_type))
+                    .add(EndIf)
+                    .test(&[r])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_div_by_zero_taken() {
+        for scalar_type in vec![IntegerType::U8.into(), ScalarType::Field] {
+            let result = VMTestRunner::new()
+                .witness(0.into(), scalar_type)
+                .add(PushConst::new(42.into(), scalar_type))
+                .add(Load::new(0))
+                .add(Div)
+                .test(&[0]);
+
+            match result {
+                Err(TestingError::RuntimeError(RuntimeError::DivisionByZero { pc })) => {
+                    assert_eq!(pc, Some(3))
+                }
+                result => panic!("expected division by zero, got {:?}", result),
+            }
+        }
+    }
 }
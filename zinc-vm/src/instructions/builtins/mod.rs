@@ -2,6 +2,7 @@ pub mod add;
 pub mod div;
 pub mod mul;
 pub mod neg;
+pub mod pow;
 pub mod rem;
 pub mod sub;
 
@@ -17,4 +18,6 @@ pub mod le;
 pub mod lt;
 pub mod ne;
 
+pub mod conditional_select_sequence;
+
 pub mod binary;
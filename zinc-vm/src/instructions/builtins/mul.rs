@@ -30,12 +30,16 @@ where
             &right
         )?;
 
-        let mul = gadgets::types::conditional_type_check(
-            cs.namespace(|| "type check"),
-            &condition,
-            &unchecked_mul,
-            mul_type,
-        )?;
+        let mul = if self.is_unchecked {
+            unchecked_mul.with_type_unchecked(mul_type)
+        } else {
+            gadgets::types::conditional_type_check(
+                cs.namespace(|| "type check"),
+                &condition,
+                &unchecked_mul,
+                mul_type,
+            )?
+        };
 
         vm.push(Cell::Value(mul))
     }
@@ -52,7 +56,7 @@ mod test {
         VMTestRunner::new()
             .add(PushConst::new_field(3.into()))
             .add(PushConst::new_field(4.into()))
-            .add(Mul)
+            .add(Mul::default())
             .test(&[12])
     }
 }
@@ -0,0 +1,130 @@
+extern crate franklin_crypto;
+
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{Cell, InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+use crate::gadgets;
+use crate::gadgets::ScalarType;
+use crate::Engine;
+use zinc_bytecode::instructions::Pow;
+
+impl<E, CS> VMInstruction<E, CS> for Pow
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let exponent = vm.pop()?.value()?;
+        let base = vm.pop()?.value()?;
+
+        let condition = vm.condition_top()?;
+        let cs = vm.constraint_system();
+
+        let scalar_type = base.get_type();
+        let power = match scalar_type {
+            ScalarType::Field => {
+                gadgets::pow(cs.namespace(|| "pow"), &base, &exponent, self.exponent_bits)?
+            }
+            ScalarType::Integer(_) => {
+                let unchecked_power =
+                    gadgets::pow(cs.namespace(|| "pow"), &base, &exponent, self.exponent_bits)?;
+
+                gadgets::conditional_type_check(
+                    cs.namespace(|| "type check"),
+                    &condition,
+                    &unchecked_power,
+                    scalar_type,
+                )?
+            }
+            _ => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer or field".to_string(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+
+        vm.push(Cell::Value(power))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_pow() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(3.into(), IntegerType::U16.into())
+            .witness(7.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Pow::new(8))
+            .add(PushConst::new((-2).into(), IntegerType::I8.into()))
+            .add(PushConst::new(3.into(), IntegerType::U8.into()))
+            .add(Pow::new(8))
+            .test(&[-8, 2187])
+    }
+
+    #[test]
+    fn test_pow_zero_exponent() -> Result<(), TestingError> {
+        for scalar_type in vec![IntegerType::U8.into(), ScalarType::Field] {
+            VMTestRunner::new()
+                .witness(42.into(), scalar_type)
+                .witness(0.into(), IntegerType::U8.into())
+                .add(Load::new(0))
+                .add(Load::new(1))
+                .add(Pow::new(8))
+                .test(&[1])?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pow_max_exponent() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(1.into(), IntegerType::U8.into())
+            .witness(255.into(), IntegerType::U8.into())
+            .witness(2.into(), IntegerType::U16.into())
+            .witness(15.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Pow::new(8))
+            .add(Load::new(2))
+            .add(Load::new(3))
+            .add(Pow::new(4))
+            .test(&[32768, 1])
+    }
+
+    #[test]
+    fn test_pow_overflow() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(2.into(), IntegerType::U8.into())
+            .witness(8.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Pow::new(8))
+            .expect_error(|error| match error {
+                RuntimeError::ValueOverflow { .. } => true,
+                _ => false,
+            })
+            .test(&[0])
+    }
+
+    #[test]
+    fn test_pow_exponent_too_large() {
+        let result = VMTestRunner::new()
+            .witness(2.into(), IntegerType::U16.into())
+            .witness(16.into(), IntegerType::U8.into())
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Pow::new(4))
+            .test(&[0]);
+
+        assert!(result.is_err(), "16 does not fit into 4 bits");
+    }
+}
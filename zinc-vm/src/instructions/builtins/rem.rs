@@ -38,7 +38,7 @@ where
 mod test {
     use super::*;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
-    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
     use zinc_bytecode::*;
 
     #[test]
@@ -60,4 +60,45 @@ mod test {
             .add(Rem)
             .test(&[3, 3, 1, 1])
     }
+
+    #[test]
+    fn test_rem_by_zero_untaken() -> Result<(), TestingError> {
+        // let b = _;
+        //
+        // if b != 0 { 42 % b } else { 0 }
+        for (b, r) in vec![(0, 0), (5, 2)] {
+            VMTestRunner::new()
+                .witness(b.into(), IntegerType::U8.into())
+                .add(Load::new(0))
+                .add(PushConst::new(0.into(), IntegerType::U8.into()))
+                .add(Ne)
+                .add(If)
+                .add(PushConst::new(42.into(), IntegerType::U8.into()))
+                .add(Load::new(0))
+                .add(Rem)
+                .add(Else)
+                .add(PushConst::new(0.into(), IntegerType::U8.into()))
+                .add(EndIf)
+                .test(&[r])?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rem_by_zero_taken() {
+        let result = VMTestRunner::new()
+            .witness(0.into(), IntegerType::U8.into())
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(Load::new(0))
+            .add(Rem)
+            .test(&[0]);
+
+        match result {
+            Err(TestingError::RuntimeError(RuntimeError::DivisionByZero { pc })) => {
+                assert_eq!(pc, Some(3))
+            }
+            result => panic!("expected division by zero, got {:?}", result),
+        }
+    }
 }
@@ -30,12 +30,16 @@ where
             &right
         )?;
 
-        let diff = gadgets::types::conditional_type_check(
-            cs.namespace(|| "type check"),
-            &condition,
-            &unchecked_diff,
-            diff_type,
-        )?;
+        let diff = if self.is_unchecked {
+            unchecked_diff.with_type_unchecked(diff_type)
+        } else {
+            gadgets::types::conditional_type_check(
+                cs.namespace(|| "type check"),
+                &condition,
+                &unchecked_diff,
+                diff_type,
+            )?
+        };
 
         vm.push(Cell::Value(diff))
     }
@@ -52,7 +56,7 @@ mod test {
         VMTestRunner::new()
             .add(PushConst::new_field(2.into()))
             .add(PushConst::new_field(1.into()))
-            .add(Sub)
+            .add(Sub::default())
             .test(&[1])
     }
 }
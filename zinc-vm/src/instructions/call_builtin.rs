@@ -3,6 +3,7 @@ extern crate franklin_crypto;
 use self::franklin_crypto::bellman::ConstraintSystem;
 use crate::core::{InternalVM, VMInstruction};
 use crate::core::{RuntimeError, VirtualMachine};
+use crate::errors::MalformedBytecode;
 use crate::stdlib::crypto::VerifySchnorrSignature;
 use crate::{stdlib, Engine};
 use zinc_bytecode::builtins::BuiltinIdentifier;
@@ -42,6 +43,24 @@ where
             BuiltinIdentifier::ArrayPad => {
                 vm.call_native(stdlib::array::Pad::new(self.inputs_count)?)
             }
+            BuiltinIdentifier::ArrayBinarySearch => {
+                vm.call_native(stdlib::array::BinarySearch::new(self.inputs_count)?)
+            }
+            BuiltinIdentifier::MathMin => vm.call_native(stdlib::math::Min),
+            BuiltinIdentifier::MathMax => vm.call_native(stdlib::math::Max),
+            BuiltinIdentifier::MathAbs => vm.call_native(stdlib::math::Abs),
+            BuiltinIdentifier::OpsWrappingAdd => vm.call_native(stdlib::ops::WrappingAdd),
+            BuiltinIdentifier::OpsWrappingSub => vm.call_native(stdlib::ops::WrappingSub),
+            BuiltinIdentifier::OpsWrappingMul => vm.call_native(stdlib::ops::WrappingMul),
+            BuiltinIdentifier::OpsSaturatingAdd => vm.call_native(stdlib::ops::SaturatingAdd),
+            BuiltinIdentifier::OpsSaturatingSub => vm.call_native(stdlib::ops::SaturatingSub),
+            BuiltinIdentifier::OpsSaturatingMul => vm.call_native(stdlib::ops::SaturatingMul),
+            BuiltinIdentifier::OpsCheckedAdd => vm.call_native(stdlib::ops::CheckedAdd),
+            BuiltinIdentifier::OpsCheckedMul => vm.call_native(stdlib::ops::CheckedMul),
+            BuiltinIdentifier::Pow => Err(MalformedBytecode::InvalidArguments(
+                "`std::pow` is compiled into the `pow` instruction".into(),
+            )
+            .into()),
         }
     }
 }
@@ -4,6 +4,7 @@ use self::franklin_crypto::bellman::ConstraintSystem;
 use crate::core::{Cell, InternalVM, VMInstruction};
 use crate::core::{RuntimeError, VirtualMachine};
 
+use crate::gadgets::ScalarType;
 use crate::{gadgets, Engine};
 use zinc_bytecode::instructions::Cast;
 
@@ -17,12 +18,19 @@ where
 
         let condition = vm.condition_top()?;
         let cs = vm.constraint_system();
-        let new_value = gadgets::conditional_type_check(
-            cs.namespace(|| "type check"),
-            &condition,
-            &old_value,
-            self.scalar_type,
-        )?;
+        let new_value = match (old_value.get_type(), self.scalar_type) {
+            (ScalarType::Integer(from), ScalarType::Integer(to))
+                if from.bitlength == to.bitlength && from.is_signed != to.is_signed =>
+            {
+                gadgets::reinterpret_sign(cs.namespace(|| "reinterpret sign"), &old_value, to)?
+            }
+            _ => gadgets::conditional_type_check(
+                cs.namespace(|| "type check"),
+                &condition,
+                &old_value,
+                self.scalar_type,
+            )?,
+        };
 
         vm.push(Cell::Value(new_value))
     }
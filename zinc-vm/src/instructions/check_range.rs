@@ -0,0 +1,79 @@
+extern crate franklin_crypto;
+
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{Cell, InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+
+use crate::{gadgets, Engine};
+use zinc_bytecode::instructions::CheckRange;
+
+impl<E, CS> VMInstruction<E, CS> for CheckRange
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let value = vm.pop()?.value()?;
+
+        let condition = vm.condition_top()?;
+        let cs = vm.constraint_system();
+        let checked = gadgets::conditional_range_check(
+            cs.namespace(|| "range check"),
+            &condition,
+            &value,
+            self.bitlength,
+        )?;
+
+        vm.push(Cell::Value(checked))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use num_bigint::BigInt;
+    use zinc_bytecode::scalar::ScalarType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_check_range() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .input("value", 0, ScalarType::Field)
+            .add(Load::new(0))
+            .add(CheckRange::new(8))
+            .test_cases(&[
+                (&[("value", 0)], &[0]),
+                (&[("value", 1)], &[1]),
+                (&[("value", 255)], &[255]),
+            ])
+    }
+
+    #[test]
+    fn test_check_range_overflow() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(256.into(), ScalarType::Field)
+            .add(Load::new(0))
+            .add(CheckRange::new(8))
+            .expect_error(|error| match error {
+                RuntimeError::ValueOutOfRange { value, bitlength } => {
+                    *value == BigInt::from(256) && *bitlength == 8
+                }
+                _ => false,
+            })
+            .test(&[256])
+    }
+
+    #[test]
+    fn test_check_range_untaken() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(256.into(), ScalarType::Field)
+            .add(PushConst::new(0.into(), ScalarType::Boolean))
+            .add(If)
+            .add(Load::new(0))
+            .add(CheckRange::new(8))
+            .add(Pop::new(1))
+            .add(EndIf)
+            .test::<i32>(&[])
+    }
+}
@@ -98,12 +98,12 @@ mod tests {
                 .add(If)
                 .add(PushConst::new(1.into(), IntegerType::I8.into()))
                 .add(Load::new(0))
-                .add(Add)
+                .add(Add::default())
                 .add(Store::new(0))
                 .add(Else)
                 .add(Load::new(0))
                 .add(PushConst::new(1.into(), IntegerType::I8.into()))
-                .add(Sub)
+                .add(Sub::default())
                 .add(Store::new(0))
                 .add(EndIf)
                 .add(Load::new(0))
@@ -111,4 +111,157 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_division_by_zero_masked() -> Result<(), TestingError> {
+        // let b = _;
+        //
+        // b != 0 && 42 / b > 2
+        let data = [(0, 0), (7, 1), (21, 0)];
+
+        for (b, r) in data.iter() {
+            VMTestRunner::new()
+                .add(PushConst::new((*b).into(), IntegerType::U8.into()))
+                .add(Store::new(0))
+                .add(Load::new(0))
+                .add(PushConst::new(0.into(), IntegerType::U8.into()))
+                .add(Ne)
+                .add(If)
+                .add(PushConst::new(42.into(), IntegerType::U8.into()))
+                .add(Load::new(0))
+                .add(Div)
+                .add(PushConst::new(2.into(), IntegerType::U8.into()))
+                .add(Gt)
+                .add(Else)
+                .add(PushConst::new(0.into(), ScalarType::Boolean))
+                .add(EndIf)
+                .test(&[*r])?;
+        }
+        Ok(())
+    }
+
+    fn store(runner: &mut VMTestRunner, value: usize) -> &mut VMTestRunner {
+        runner
+            .add(PushConst::new_field(value.into()))
+            .add(Store::new(3))
+    }
+
+    #[test]
+    fn test_nested_storage() -> Result<(), TestingError> {
+        // let a = _;
+        // let b = _;
+        // let c = _;
+        // let mut x = 0;
+        //
+        // if a {
+        //     x = 1;
+        //     if b {
+        //         x = 2;
+        //         if c { x = 3; } else { x = 4; }
+        //     } else {
+        //         x = 5;
+        //         if c { x = 6; }
+        //     }
+        // } else {
+        //     x = 7;
+        //     if b {
+        //         if c { x = 8; } else { x = 9; }
+        //     } else {
+        //         x = 10;
+        //     }
+        // }
+        for case in 0..8 {
+            let (a, b, c) = (case & 4 != 0, case & 2 != 0, case & 1 != 0);
+            let expected = if a {
+                if b {
+                    if c {
+                        3
+                    } else {
+                        4
+                    }
+                } else if c {
+                    6
+                } else {
+                    5
+                }
+            } else if b {
+                if c {
+                    8
+                } else {
+                    9
+                }
+            } else {
+                10
+            };
+
+            let mut runner = VMTestRunner::new();
+            runner
+                .witness((a as usize).into(), ScalarType::Boolean)
+                .witness((b as usize).into(), ScalarType::Boolean)
+                .witness((c as usize).into(), ScalarType::Boolean);
+            store(&mut runner, 0).add(Load::new(0)).add(If);
+            store(&mut runner, 1).add(Load::new(1)).add(If);
+            store(&mut runner, 2).add(Load::new(2)).add(If);
+            store(&mut runner, 3).add(Else);
+            store(&mut runner, 4).add(EndIf).add(Else);
+            store(&mut runner, 5).add(Load::new(2)).add(If);
+            store(&mut runner, 6).add(EndIf).add(EndIf).add(Else);
+            store(&mut runner, 7)
+                .add(Load::new(1))
+                .add(If)
+                .add(Load::new(2))
+                .add(If);
+            store(&mut runner, 8).add(Else);
+            store(&mut runner, 9).add(EndIf).add(Else);
+            store(&mut runner, 10).add(EndIf).add(EndIf);
+            runner
+                .add(Load::new(3))
+                .add(Exit::new(1))
+                .test(&[expected])?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlapping_and_disjoint_storage() -> Result<(), TestingError> {
+        // let c = _;
+        // let (mut x, mut y, mut z) = (0, 0, 0);
+        //
+        // if c {
+        //     x = 1;
+        //     y = 2;
+        // } else {
+        //     y = 3;
+        //     z = 4;
+        // }
+        for (c, expected) in vec![(1, [1, 2, 0]), (0, [0, 3, 4])] {
+            let mut runner = VMTestRunner::new();
+            runner.witness(c.into(), ScalarType::Boolean);
+            for address in 1..=3 {
+                runner
+                    .add(PushConst::new(0.into(), IntegerType::U8.into()))
+                    .add(Store::new(address));
+            }
+            runner
+                .add(Load::new(0))
+                .add(If)
+                .add(PushConst::new(1.into(), IntegerType::U8.into()))
+                .add(Store::new(1))
+                .add(PushConst::new(2.into(), IntegerType::U8.into()))
+                .add(Store::new(2))
+                .add(Else)
+                .add(PushConst::new(3.into(), IntegerType::U8.into()))
+                .add(Store::new(2))
+                .add(PushConst::new(4.into(), IntegerType::U8.into()))
+                .add(Store::new(3))
+                .add(EndIf)
+                .add(Load::new(3))
+                .add(Load::new(2))
+                .add(Load::new(1))
+                .test(&expected)?;
+        }
+
+        Ok(())
+    }
 }
@@ -19,16 +19,36 @@ where
 
 #[cfg(test)]
 mod tests {
-    //    use super::*;
-    //    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
-    //    use zinc_bytecode::*;
-    //
-    //    #[test]
-    //    fn test_exit() -> Result<(), TestingError> {
-    //        VMTestRunner::new()
-    //            .add(PushConst::new_untyped(1.into()))
-    //            .add(Exit::new(0))
-    //            .add(PushConst::new_untyped(2.into()))
-    //            .test(&[1])
-    //    }
+    use crate::core::RuntimeError;
+    use crate::errors::MalformedBytecode;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_exit() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(Exit::new(1))
+            .add(PushConst::new_field(2.into()))
+            .test(&[1])
+    }
+
+    #[test]
+    fn test_exit_stack_depth() {
+        let result = VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(Exit::new(1))
+            .test(&[2]);
+
+        match result {
+            Err(TestingError::RuntimeError(RuntimeError::MalformedBytecode(
+                MalformedBytecode::ExitStackDepth {
+                    expected: 1,
+                    found: 2,
+                },
+            ))) => {}
+            result => panic!("expected a stack depth mismatch, got {:?}", result),
+        }
+    }
 }
@@ -28,6 +28,90 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_call_arguments_by_value() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            // func main
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(Store::new(0))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(Store::new(1))
+            .add(PushConst::new(3.into(), IntegerType::U8.into()))
+            .add(Store::new(2))
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Load::new(2))
+            .add(Call::new(15, 3))
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Load::new(2))
+            .add(Exit::new(4))
+            // func overwrite(mut array: [u8; 3]) -> u8
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(Store::new(0))
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(Store::new(1))
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(Store::new(2))
+            .add(Load::new(0))
+            .add(Return::new(1))
+            .test(&[3, 2, 1, 42])
+    }
+
+    #[test]
+    fn test_call_nested() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            // func main
+            .add(PushConst::new(5.into(), IntegerType::U8.into()))
+            .add(Call::new(4, 1))
+            .add(Exit::new(1))
+            // func increment_double(value: u8) -> u8
+            .add(Load::new(0))
+            .add(Call::new(9, 1))
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(Add::default())
+            .add(Return::new(1))
+            // func double(value: u8) -> u8
+            .add(Load::new(0))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(Mul::default())
+            .add(Return::new(1))
+            .test(&[11])
+    }
+
+    fn call_in_branches(is_taken: bool) -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness((is_taken as usize).into(), ScalarType::Boolean)
+            // func main(condition: bool)
+            .add(Load::new(0))
+            .add(If)
+            .add(PushConst::new(3.into(), IntegerType::U8.into()))
+            .add(Call::new(13, 1))
+            .add(Store::new(1))
+            .add(Else)
+            .add(PushConst::new(4.into(), IntegerType::U8.into()))
+            .add(Call::new(13, 1))
+            .add(Store::new(1))
+            .add(EndIf)
+            .add(Load::new(1))
+            .add(Exit::new(1))
+            // func double(value: u8) -> u8
+            .add(Load::new(0))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(Mul::default())
+            .add(Return::new(1))
+            .test(&[if is_taken { 6 } else { 8 }])
+    }
+
+    #[test]
+    fn test_call_in_branches() -> Result<(), TestingError> {
+        call_in_branches(true)?;
+        call_in_branches(false)
+    }
 
     //    #[test]
     //    fn test_func() -> Result<(), TestingError> {
@@ -43,15 +43,37 @@ mod test {
             .add(LoopBegin::new(10))
             .add(Load::new(0))
             .add(PushConst::new_field(1.into()))
-            .add(Add)
+            .add(Add::default())
             .add(Store::new(0))
             .add(Load::new(0))
             .add(Load::new(1))
-            .add(Add)
+            .add(Add::default())
             .add(Store::new(1))
             .add(LoopEnd)
             .add(Load::new(0))
             .add(Load::new(1))
             .test(&[55, 10])
     }
+
+    #[test]
+    fn test_loop_thousand_iterations() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(0.into()))
+            .add(Store::new(0))
+            .add(PushConst::new_field(0.into()))
+            .add(Store::new(1))
+            .add(LoopBegin::new(1000))
+            .add(Load::new(0))
+            .add(PushConst::new_field(1.into()))
+            .add(Add::default())
+            .add(Store::new(0))
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .add(Add::default())
+            .add(Store::new(1))
+            .add(LoopEnd)
+            .add(Load::new(0))
+            .add(Load::new(1))
+            .test(&[500_500, 1000])
+    }
 }
@@ -0,0 +1,48 @@
+extern crate franklin_crypto;
+
+use self::franklin_crypto::bellman::ConstraintSystem;
+use crate::core::{InternalVM, VMInstruction};
+use crate::core::{RuntimeError, VirtualMachine};
+use crate::Engine;
+use zinc_bytecode::instructions::MarkPublic;
+
+impl<E, CS> VMInstruction<E, CS> for MarkPublic
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        vm.mark_public(self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_mark_public() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(MarkPublic::new(2))
+            .add(Add::default())
+            .add(Exit::new(1))
+            .test(&[3, 2, 1])
+    }
+
+    #[test]
+    fn test_mark_public_order() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(5.into()))
+            .add(MarkPublic::new(1))
+            .add(PushConst::new_field(6.into()))
+            .add(PushConst::new_field(7.into()))
+            .add(MarkPublic::new(2))
+            .add(Add::default())
+            .add(Add::default())
+            .add(Exit::new(1))
+            .test(&[18, 7, 6, 5])
+    }
+}
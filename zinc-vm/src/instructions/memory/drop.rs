@@ -0,0 +1,61 @@
+use crate::core::{InternalVM, VMInstruction, VirtualMachine};
+use crate::{Engine, Result};
+use bellman::ConstraintSystem;
+use zinc_bytecode::instructions::Drop;
+
+impl<E, CS> VMInstruction<E, CS> for Drop
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result {
+        for _ in 0..self.count {
+            vm.pop()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_drop() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(PushConst::new_field(3.into()))
+            .add(Drop::new(2))
+            .add(PushConst::new_field(4.into()))
+            .test(&[4, 1])
+    }
+
+    #[test]
+    fn test_drop_below_swapped() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(PushConst::new_field(3.into()))
+            .add(Swap)
+            .add(Drop::new(1))
+            .add(Swap)
+            .add(Drop::new(1))
+            .test(&[3])
+    }
+
+    #[test]
+    fn test_drop_underflow() {
+        let result = VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(Drop::new(2))
+            .test(&[]);
+
+        assert_eq!(
+            result.err().and_then(|error| error.instruction_index()),
+            Some(2)
+        );
+    }
+}
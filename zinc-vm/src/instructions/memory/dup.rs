@@ -0,0 +1,55 @@
+use crate::core::{InternalVM, VMInstruction, VirtualMachine};
+use crate::{Engine, Result};
+use bellman::ConstraintSystem;
+use zinc_bytecode::instructions::Dup;
+
+impl<E, CS> VMInstruction<E, CS> for Dup
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result {
+        let mut cells = Vec::with_capacity(self.depth + 1);
+        for _ in 0..=self.depth {
+            cells.push(vm.pop()?);
+        }
+
+        let copy = cells[self.depth].clone();
+        for cell in cells.into_iter().rev() {
+            vm.push(cell)?;
+        }
+        vm.push(copy)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_dup() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(PushConst::new_field(3.into()))
+            .add(Dup::new(0))
+            .add(Dup::new(3))
+            .test(&[1, 3, 3, 2, 1])
+    }
+
+    #[test]
+    fn test_dup_underflow() {
+        let result = VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(Dup::new(1))
+            .test(&[1]);
+
+        assert_eq!(
+            result.err().and_then(|error| error.instruction_index()),
+            Some(2)
+        );
+    }
+}
@@ -11,10 +11,46 @@ where
 {
     fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
         for i in 0..self.len {
-            let value = vm.load(self.address + i)?;
+            let value = vm.load(self.address.saturating_add(i))?;
             vm.push(value)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_load_sequence() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(StoreSequence::new(0, 2))
+            .add(LoadSequence::new(0, 2))
+            .test(&[2, 1])
+    }
+
+    #[test]
+    fn test_load_sequence_address_out_of_range() {
+        let result = VMTestRunner::new()
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(StoreSequence::new(0, 2))
+            .add(LoadSequence::new(1, 2))
+            .test::<u8>(&[]);
+
+        match result {
+            Err(TestingError::RuntimeError(RuntimeError::AddressOutOfRange {
+                address: 2,
+                frame_size: 2,
+            })) => {}
+            result => panic!("expected an out of range address, got {:?}", result),
+        }
+    }
+}
@@ -34,3 +34,37 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+    fn load_sequence_by_witness_index(index: usize) -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(index.into(), IntegerType::U8.into())
+            .add(PushConst::new(1.into(), ScalarType::Field))
+            .add(PushConst::new(2.into(), ScalarType::Field))
+            .add(PushConst::new(3.into(), ScalarType::Field))
+            .add(PushConst::new(4.into(), ScalarType::Field))
+            .add(StoreSequence::new(1, 4))
+            .add(Load::new(0))
+            .add(LoadSequenceByIndex::new(1, 4, 2))
+            .test(&[index + 2, index + 1])
+    }
+
+    #[test]
+    fn test_load_sequence_by_witness_index() -> Result<(), TestingError> {
+        load_sequence_by_witness_index(2)
+    }
+
+    #[test]
+    fn test_load_sequence_by_witness_index_out_of_range() {
+        match load_sequence_by_witness_index(4) {
+            Err(TestingError::RuntimeError(RuntimeError::AssertionError(_))) => {}
+            result => panic!("expected an index out of bounds, got {:?}", result),
+        }
+    }
+}
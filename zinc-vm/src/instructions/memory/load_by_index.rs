@@ -27,9 +27,22 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::core::RuntimeError;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
     use zinc_bytecode::instructions::*;
-    use zinc_bytecode::scalar::ScalarType;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+    fn load_by_witness_index(index: usize) -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness(index.into(), IntegerType::U8.into())
+            .add(PushConst::new(10.into(), ScalarType::Field))
+            .add(PushConst::new(20.into(), ScalarType::Field))
+            .add(PushConst::new(30.into(), ScalarType::Field))
+            .add(StoreSequence::new(1, 3))
+            .add(Load::new(0))
+            .add(LoadByIndex::new(1, 3))
+            .test(&[(index + 1) * 10])
+    }
 
     #[test]
     fn test_load_by_index() -> Result<(), TestingError> {
@@ -46,4 +59,17 @@ mod tests {
             .add(LoadByIndex::new(0, 4))
             .test(&[1])
     }
+
+    #[test]
+    fn test_load_by_witness_index() -> Result<(), TestingError> {
+        load_by_witness_index(2)
+    }
+
+    #[test]
+    fn test_load_by_witness_index_out_of_range() {
+        match load_by_witness_index(3) {
+            Err(TestingError::RuntimeError(RuntimeError::AssertionError(_))) => {}
+            result => panic!("expected an index out of bounds, got {:?}", result),
+        }
+    }
 }
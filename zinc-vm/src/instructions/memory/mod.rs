@@ -1,3 +1,5 @@
+pub mod drop;
+pub mod dup;
 pub mod pop;
 pub mod push;
 pub mod slice;
@@ -21,7 +21,11 @@ where
 mod tests {
     use super::*;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::Bn256;
+    use zinc_bytecode::data::types::DataType;
     use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::{Call, InstructionInfo, Program};
 
     #[test]
     fn test_push() -> Result<(), TestingError> {
@@ -33,4 +37,27 @@ mod tests {
             .add(PushConst::new((-1000).into(), IntegerType::I16.into()))
             .test(&[-1000, -1, 0xABCD, 42, 0])
     }
+
+    /// Runs a program pushing the same constant `count` times and returns the number of
+    /// constraints.
+    fn synthesize_constants(count: usize) -> usize {
+        let mut instructions = vec![Call::new(1, 0).wrap()];
+        for _ in 0..count {
+            instructions.push(PushConst::new(255.into(), IntegerType::U8.into()).wrap());
+        }
+        let program = Program::new(DataType::Unit, DataType::Unit, instructions);
+
+        let mut vm = VirtualMachine::new(TestConstraintSystem::<Bn256>::new(), true);
+        vm.run(&program, Some(&[]), |_, _, _| {}, |_| Ok(()))
+            .expect("the program is valid");
+
+        vm.constraint_system().num_constraints()
+    }
+
+    #[test]
+    fn test_push_does_not_allocate() {
+        // Constants are kept in the linear combinations instead of being allocated, so there is
+        // nothing to deduplicate between the repeated ones.
+        assert_eq!(synthesize_constants(100), synthesize_constants(1));
+    }
 }
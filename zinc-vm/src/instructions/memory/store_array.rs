@@ -10,11 +10,44 @@ where
     CS: ConstraintSystem<E>,
 {
     fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
-        for i in 0..self.len {
+        for i in (0..self.len).rev() {
             let value = vm.pop()?;
-            vm.store(self.address + self.len - i - 1, value)?;
+            vm.store(self.address.saturating_add(i), value)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::RuntimeError;
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::scalar::IntegerType;
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_store_sequence() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(StoreSequence::new(0, 2))
+            .add(Load::new(1))
+            .add(Load::new(0))
+            .test(&[1, 2])
+    }
+
+    #[test]
+    fn test_store_sequence_address_out_of_range() {
+        let result = VMTestRunner::new()
+            .add(PushConst::new(1.into(), IntegerType::U8.into()))
+            .add(PushConst::new(2.into(), IntegerType::U8.into()))
+            .add(StoreSequence::new(std::usize::MAX, 2))
+            .test::<u8>(&[]);
+
+        match result {
+            Err(TestingError::RuntimeError(RuntimeError::AddressOutOfRange { .. })) => {}
+            result => panic!("expected an out of range address, got {:?}", result),
+        }
+    }
+}
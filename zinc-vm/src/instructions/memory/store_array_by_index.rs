@@ -26,13 +26,17 @@ where
 
         let index = vm.pop()?.value()?;
 
+        let condition = vm.condition_top()?;
         for (i, value) in values.into_iter().enumerate() {
             let cs = vm.constraint_system();
             let offset = Scalar::new_constant_bigint(&i.into(), index.get_type())?;
             let address = gadgets::add(cs.namespace(|| format!("address {}", i)), &index, &offset)?;
-            array = vm
-                .operations()
-                .array_set(array.as_slice(), address, value)?;
+            array = vm.operations().conditional_array_set(
+                &condition,
+                array.as_slice(),
+                address,
+                value,
+            )?;
         }
 
         for (i, value) in array.into_iter().enumerate() {
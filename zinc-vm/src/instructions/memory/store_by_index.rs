@@ -18,7 +18,10 @@ where
             array.push(vm.load(self.address + i)?.value()?);
         }
 
-        let new_array = vm.operations().array_set(array.as_slice(), index, value)?;
+        let condition = vm.condition_top()?;
+        let new_array =
+            vm.operations()
+                .conditional_array_set(&condition, array.as_slice(), index, value)?;
 
         for (i, value) in new_array.into_iter().enumerate() {
             vm.store(self.address + i, Cell::Value(value))?;
@@ -27,3 +30,42 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+    fn store_by_witness_index_in_branch(
+        is_taken: bool,
+        index: usize,
+        expected: &[usize],
+    ) -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .witness((is_taken as usize).into(), ScalarType::Boolean)
+            .witness(index.into(), IntegerType::U8.into())
+            .add(PushConst::new(10.into(), ScalarType::Field))
+            .add(PushConst::new(20.into(), ScalarType::Field))
+            .add(PushConst::new(30.into(), ScalarType::Field))
+            .add(StoreSequence::new(2, 3))
+            .add(Load::new(0))
+            .add(If)
+            .add(Load::new(1))
+            .add(PushConst::new(99.into(), ScalarType::Field))
+            .add(StoreByIndex::new(2, 3))
+            .add(EndIf)
+            .add(LoadSequence::new(2, 3))
+            .test(expected)
+    }
+
+    #[test]
+    fn test_store_by_witness_index() -> Result<(), TestingError> {
+        store_by_witness_index_in_branch(true, 1, &[30, 99, 10])
+    }
+
+    #[test]
+    fn test_store_by_witness_index_in_false_branch() -> Result<(), TestingError> {
+        store_by_witness_index_in_branch(false, 1, &[30, 20, 10])
+    }
+}
@@ -16,3 +16,19 @@ where
         vm.push(b)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+    use zinc_bytecode::*;
+
+    #[test]
+    fn test_swap() -> Result<(), TestingError> {
+        VMTestRunner::new()
+            .add(PushConst::new_field(1.into()))
+            .add(PushConst::new_field(2.into()))
+            .add(PushConst::new_field(3.into()))
+            .add(Swap)
+            .test(&[2, 3, 1])
+    }
+}
@@ -8,6 +8,8 @@ pub mod memory;
 pub mod assert;
 pub mod call_builtin;
 pub mod cast;
+pub mod check_range;
 pub mod dbg;
+pub mod mark_public;
 pub mod markers;
 pub mod noop;
@@ -6,7 +6,7 @@ use failure::Fail;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::test::TestConstraintSystem;
 use num_bigint::{BigInt, ToBigInt};
-use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::{DataType, ScalarType};
 use zinc_bytecode::{Call, Instruction, InstructionInfo, Program};
 
 type TestVirtualMachine = VirtualMachine<Bn256, TestConstraintSystem<Bn256>>;
@@ -38,6 +38,19 @@ where
     }
 }
 
+fn assert_outputs_eq<BI>(outputs: &[Option<BigInt>], expected_stack: &[BI])
+where
+    BI: Into<BigInt> + Copy,
+{
+    let expected: Vec<Option<BigInt>> = expected_stack
+        .iter()
+        .rev()
+        .map(|expected| Some((*expected).into()))
+        .collect();
+
+    assert_eq!(outputs, expected.as_slice(), "wrong outputs");
+}
+
 #[derive(Debug, Fail)]
 pub enum TestingError {
     #[fail(display = "{}", _0)]
@@ -48,24 +61,104 @@ pub enum TestingError {
 
     #[fail(display = "unsatisfied")]
     Unsatisfied,
+
+    #[fail(display = "expected an error, but the run has succeeded")]
+    ErrorExpected,
+
+    #[fail(display = "{} constraints exceed the limit of {}", count, limit)]
+    TooManyConstraints { limit: usize, count: usize },
+}
+
+impl TestingError {
+    /// Returns the index of the instruction, which caused a stack underflow or type error.
+    pub fn instruction_index(&self) -> Option<usize> {
+        match self {
+            TestingError::RuntimeError(RuntimeError::InstructionError { index, .. }) => {
+                Some(*index)
+            }
+            _ => None,
+        }
+    }
 }
 
 pub struct VMTestRunner {
     instructions: Vec<Instruction>,
+    witness: Vec<(Option<String>, BigInt, ScalarType)>,
+    expected_error: Option<fn(&RuntimeError) -> bool>,
+    max_constraints: Option<usize>,
 }
 
 impl VMTestRunner {
     pub fn new() -> Self {
         Self {
             instructions: vec![Call::new(1, 0).wrap()],
+            witness: Vec::new(),
+            expected_error: None,
+            max_constraints: None,
         }
     }
 
+    /// Adds a witness input, which is passed as the next argument of the tested function.
+    pub fn witness(&mut self, value: BigInt, scalar_type: ScalarType) -> &mut Self {
+        self.push_witness(None, value, scalar_type)
+    }
+
+    /// Adds a named witness input like `witness`, whose value can be rebound by `test_cases`.
+    pub fn input<T: Into<BigInt>>(
+        &mut self,
+        name: &str,
+        value: T,
+        scalar_type: ScalarType,
+    ) -> &mut Self {
+        self.push_witness(Some(name.to_owned()), value.into(), scalar_type)
+    }
+
+    /// Makes the test pass only if the run fails with an error accepted by `matcher`.
+    ///
+    /// The stack underflow and type errors are wrapped into `RuntimeError::InstructionError`.
+    pub fn expect_error(&mut self, matcher: fn(&RuntimeError) -> bool) -> &mut Self {
+        self.expected_error = Some(matcher);
+        self
+    }
+
+    /// Makes the test fail if the run produces more than `limit` constraints.
+    pub fn expect_constraints_at_most(&mut self, limit: usize) -> &mut Self {
+        self.max_constraints = Some(limit);
+        self
+    }
+
     pub fn add<I: InstructionInfo>(&mut self, instruction: I) -> &mut Self {
         self.instructions.push(instruction.wrap());
         self
     }
 
+    /// Runs the test for each case of the named input values and the expected stack values.
+    ///
+    /// The inputs not listed in a case keep their previous values.
+    pub fn test_cases<T: Into<BigInt> + Copy>(
+        &mut self,
+        cases: &[(&[(&str, T)], &[T])],
+    ) -> Result<(), TestingError> {
+        for (inputs, expected_stack) in cases.iter() {
+            for (name, value) in inputs.iter() {
+                let witness = self
+                    .witness
+                    .iter_mut()
+                    .find(|(witness_name, _, _)| witness_name.as_deref() == Some(*name))
+                    .unwrap_or_else(|| panic!("input `{}` is not declared", name));
+                witness.1 = (*value).into();
+            }
+
+            self.test(expected_stack)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the instructions and checks the `expected_stack` values, listed from the top.
+    ///
+    /// If the program exits with outputs, they are checked instead of the stack, with the last
+    /// output at the top.
     pub fn test<T: Into<BigInt> + Copy>(
         &mut self,
         expected_stack: &[T],
@@ -85,24 +178,64 @@ impl VMTestRunner {
     ) -> Result<(), TestingError> {
         let mut vm = new_test_constrained_vm();
 
-        let program = Program::new(DataType::Unit, DataType::Unit, self.instructions.clone());
-
-        vm.run(&program, Some(&[]), |_| {}, |_| Ok(()))
-            .map_err(TestingError::RuntimeError)?;
+        let input = DataType::Tuple(
+            self.witness
+                .iter()
+                .map(|(_name, _value, scalar_type)| DataType::Scalar(*scalar_type))
+                .collect(),
+        );
+        let inputs: Vec<BigInt> = self
+            .witness
+            .iter()
+            .map(|(_name, value, _)| value.clone())
+            .collect();
+        let program = Program::new(input, DataType::Unit, self.instructions.clone());
+
+        let result = vm.run(&program, Some(&inputs), |_, _, _| {}, |_| Ok(()));
+        if let Some(matcher) = self.expected_error {
+            return match result {
+                Err(error) if matcher(&error) => Ok(()),
+                Err(error) => Err(TestingError::RuntimeError(error)),
+                Ok(_) => Err(TestingError::ErrorExpected),
+            };
+        }
+        let outputs = result.map_err(TestingError::RuntimeError)?;
 
         let cs = vm.constraint_system();
 
         let unconstrained = cs.find_unconstrained();
         let satisfied = cs.is_satisfied();
+        let constraints = cs.num_constraints();
 
-        assert_stack_eq(&mut vm, expected_stack);
+        if outputs.is_empty() {
+            assert_stack_eq(&mut vm, expected_stack);
+        } else {
+            assert_outputs_eq(&outputs, expected_stack);
+        }
 
         if !unconstrained.is_empty() {
             Err(TestingError::Unconstrained(unconstrained))
         } else if !satisfied {
             Err(TestingError::Unsatisfied)
         } else {
-            Ok(())
+            match self.max_constraints {
+                Some(limit) if constraints > limit => Err(TestingError::TooManyConstraints {
+                    limit,
+                    count: constraints,
+                }),
+                _ => Ok(()),
+            }
         }
     }
+
+    fn push_witness(
+        &mut self,
+        name: Option<String>,
+        value: BigInt,
+        scalar_type: ScalarType,
+    ) -> &mut Self {
+        self.witness.push((name, value, scalar_type));
+        self.instructions[0] = Call::new(1, self.witness.len()).wrap();
+        self
+    }
 }
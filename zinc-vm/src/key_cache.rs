@@ -0,0 +1,137 @@
+//!
+//! The proving key cache.
+//!
+//! The keys are stored under the hash of the program bytecode, prefixed with the proving system
+//! and curve identifiers, so a changed program or another curve never reuses a stale key.
+//! The keys are encoded as artifacts, so a corrupted or foreign key is rejected on loading.
+//!
+
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use failure::Fail;
+
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::program::Program;
+
+use crate::artifacts::{Artifact, ArtifactError, Proof, ProvingKey};
+use crate::errors::RuntimeError;
+use crate::Engine;
+
+/// The proving system identifier in the cache keys.
+const BACKEND: &str = "groth16";
+
+#[derive(Debug, Fail)]
+pub enum KeyCacheError {
+    #[fail(display = "key storage: {}", _0)]
+    Storage(io::Error),
+
+    #[fail(display = "cached key: {}", _0)]
+    Artifact(ArtifactError),
+
+    #[fail(display = "{}", _0)]
+    Runtime(RuntimeError),
+}
+
+///
+/// The storage of the encoded keys.
+///
+pub trait KeyStorage {
+    /// Returns the bytes stored under `key`, or `None` if there are none.
+    fn load(&self, key: &str) -> io::Result<Option<Vec<u8>>>;
+
+    fn store(&self, key: &str, bytes: &[u8]) -> io::Result<()>;
+}
+
+///
+/// The storage keeping each key in a file of the directory.
+///
+pub struct FileKeyStorage {
+    directory: PathBuf,
+}
+
+impl FileKeyStorage {
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+impl KeyStorage for FileKeyStorage {
+    fn load(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.directory.join(key)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn store(&self, key: &str, bytes: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        fs::write(self.directory.join(key), bytes)
+    }
+}
+
+///
+/// Runs the setup of a program only if its proving key is not stored yet.
+///
+pub struct KeyCache<E: Engine, S: KeyStorage> {
+    storage: S,
+    _pd: PhantomData<E>,
+}
+
+impl<E: Engine, S: KeyStorage> KeyCache<E, S> {
+    pub fn new(storage: S) -> Self {
+        Self {
+            storage,
+            _pd: PhantomData,
+        }
+    }
+
+    ///
+    /// Loads the proving key of `program`, or runs the setup and stores the key if it is missing.
+    ///
+    pub fn proving_key(&self, program: &Program) -> Result<ProvingKey<E>, KeyCacheError> {
+        let key = Self::key(program);
+
+        if let Some(bytes) = self.storage.load(&key).map_err(KeyCacheError::Storage)? {
+            return ProvingKey::<E>::from_artifact_bytes(bytes.as_slice())
+                .map_err(KeyCacheError::Artifact);
+        }
+
+        let params = crate::facade::setup::<E>(program).map_err(KeyCacheError::Runtime)?;
+        self.storage
+            .store(&key, params.to_artifact_bytes().as_slice())
+            .map_err(KeyCacheError::Storage)?;
+
+        Ok(params)
+    }
+
+    ///
+    /// Proves the program execution like `zinc_vm::prove` with the cached proving key.
+    ///
+    pub fn prove(
+        &self,
+        program: &Program,
+        witness: &Value,
+    ) -> Result<(Value, Proof<E>), KeyCacheError> {
+        let params = self.proving_key(program)?;
+        crate::facade::prove::<E>(program, &params, witness).map_err(KeyCacheError::Runtime)
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    fn key(program: &Program) -> String {
+        format!(
+            "{}-{}-{}.pk",
+            BACKEND,
+            E::curve_id(),
+            crate::session::circuit_hash(program.to_bytes().as_slice())
+        )
+    }
+}
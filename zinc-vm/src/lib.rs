@@ -1,5 +1,6 @@
 pub mod constraint_systems;
 mod core;
+pub use crate::core::{Debugger, StateView, TraceRecorder, TraceStep};
 mod errors;
 pub mod gadgets;
 mod instructions;
@@ -11,6 +12,18 @@ mod tests;
 mod facade;
 pub use facade::*;
 
+mod session;
+pub use session::*;
+
+mod artifacts;
+pub use artifacts::*;
+
+mod key_cache;
+pub use key_cache::*;
+
+mod profile;
+pub use profile::{ProfileEntry, ProfileReport};
+
 use franklin_crypto::alt_babyjubjub::{AltJubjubBn256, JubjubEngine};
 use lazy_static::lazy_static;
 use pairing::bn256::Bn256;
@@ -18,6 +31,9 @@ use std::fmt::Debug;
 
 pub trait Engine: JubjubEngine + Debug {
     fn jubjub_params<'a>() -> &'a Self::Params;
+
+    /// The curve identifier written into the artifact headers.
+    fn curve_id() -> u8;
 }
 
 lazy_static! {
@@ -28,4 +44,8 @@ impl Engine for Bn256 {
     fn jubjub_params<'a>() -> &'a Self::Params {
         &JUBJUB_BN256_PARAMS
     }
+
+    fn curve_id() -> u8 {
+        1
+    }
 }
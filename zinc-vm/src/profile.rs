@@ -0,0 +1,180 @@
+//!
+//! The constraint profile of a program run.
+//!
+//! Each executed instruction is charged with the constraints and allocations made while it was
+//! executed. The charges are aggregated by the instruction opcode and by the source line taken
+//! from the debug markers. The constraints made before the first instruction, like the input
+//! allocations, are charged to the first instruction, which is the entry function call.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::json;
+
+use zinc_bytecode::Instruction;
+
+use crate::core::location::CodeLocation;
+
+/// The aggregated charges of an opcode or a source line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    pub name: String,
+    pub executions: usize,
+    pub constraints: usize,
+    pub allocations: usize,
+}
+
+impl ProfileEntry {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            executions: 0,
+            constraints: 0,
+            allocations: 0,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.name,
+            "executions": self.executions,
+            "constraints": self.constraints,
+            "allocations": self.allocations,
+        })
+    }
+}
+
+/// The profile entries sorted by the number of constraints, the most expensive first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileReport {
+    pub constraints: usize,
+    pub allocations: usize,
+    pub by_instruction: Vec<ProfileEntry>,
+    pub by_line: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "constraints": self.constraints,
+            "allocations": self.allocations,
+            "by_instruction": self.by_instruction.iter().map(ProfileEntry::to_json).collect::<Vec<_>>(),
+            "by_line": self.by_line.iter().map(ProfileEntry::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "total: {} constraints, {} allocations",
+            self.constraints, self.allocations
+        )?;
+
+        for (title, entries) in [
+            ("instruction", &self.by_instruction),
+            ("line", &self.by_line),
+        ]
+        .iter()
+        {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>12}  {}",
+                "constraints", "allocations", "executions", title
+            )?;
+            for entry in entries.iter() {
+                writeln!(
+                    f,
+                    "{:>12} {:>12} {:>12}  {}",
+                    entry.constraints, entry.allocations, entry.executions, entry.name
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects the charges of the executed instructions.
+pub(crate) struct Profiler {
+    constraints: usize,
+    allocations: usize,
+    by_instruction: HashMap<String, ProfileEntry>,
+    by_line: HashMap<String, ProfileEntry>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self {
+            constraints: 0,
+            allocations: 0,
+            by_instruction: HashMap::new(),
+            by_line: HashMap::new(),
+        }
+    }
+
+    /// Charges the `instruction` executed at `location` with the difference between the current
+    /// totals of the constraint system and the previously recorded ones.
+    pub fn record(
+        &mut self,
+        instruction: &Instruction,
+        location: &CodeLocation,
+        constraints: usize,
+        allocations: usize,
+    ) {
+        let constraints_delta = constraints - self.constraints;
+        let allocations_delta = allocations - self.allocations;
+        self.constraints = constraints;
+        self.allocations = allocations;
+
+        let opcode = format!("{:?}", instruction);
+        let opcode = opcode.split('(').next().unwrap_or("").to_owned();
+        let line = format!(
+            "{}:{}",
+            location
+                .file
+                .as_ref()
+                .map(String::as_str)
+                .unwrap_or("<unknown file>"),
+            location
+                .line
+                .map(|line| line.to_string())
+                .unwrap_or_else(|| "<unknown line>".into())
+        );
+
+        for (entries, name) in vec![
+            (&mut self.by_instruction, opcode),
+            (&mut self.by_line, line),
+        ] {
+            let entry = entries
+                .entry(name.clone())
+                .or_insert_with(|| ProfileEntry::new(name));
+            entry.executions += 1;
+            entry.constraints += constraints_delta;
+            entry.allocations += allocations_delta;
+        }
+    }
+
+    pub fn into_report(self) -> ProfileReport {
+        ProfileReport {
+            constraints: self.constraints,
+            allocations: self.allocations,
+            by_instruction: Self::sorted(self.by_instruction),
+            by_line: Self::sorted(self.by_line),
+        }
+    }
+
+    fn sorted(entries: HashMap<String, ProfileEntry>) -> Vec<ProfileEntry> {
+        let mut entries: Vec<ProfileEntry> = entries.into_iter().map(|(_, entry)| entry).collect();
+        entries.sort_by(|left, right| {
+            right
+                .constraints
+                .cmp(&left.constraints)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        entries
+    }
+}
@@ -0,0 +1,222 @@
+//!
+//! The per-entry proving session.
+//!
+//! A build directory contains one compiled program per entry point, named `<entry>.znb`,
+//! and the entry's proving key, named `<entry>.pk`, written by `Parameters::write`.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use failure::Fail;
+use franklin_crypto::bellman::groth16::{Parameters, Proof};
+use sha2::Digest;
+use sha2::Sha256;
+
+use zinc_bytecode::data::values::{JsonValueError, Value};
+use zinc_bytecode::program::Program;
+
+use crate::errors::RuntimeError;
+use crate::facade::VerificationError;
+use crate::Engine;
+
+pub const PROGRAM_FILE_EXTENSION: &str = "znb";
+pub const PROVING_KEY_FILE_EXTENSION: &str = "pk";
+
+#[derive(Debug, Fail)]
+pub enum SessionError {
+    #[fail(display = "{}: {}", path, error)]
+    Io { path: String, error: io::Error },
+
+    #[fail(display = "entry `{}` program decoding: {}", entry, error)]
+    ProgramDecoding { entry: String, error: String },
+
+    #[fail(display = "entry `{}` proving key is missing", _0)]
+    ProvingKeyNotFound(String),
+
+    #[fail(display = "entry `{}` not found", _0)]
+    EntryNotFound(String),
+
+    #[fail(display = "entry `{}` input: {}", entry, error)]
+    InvalidInput {
+        entry: String,
+        error: JsonValueError,
+    },
+
+    #[fail(
+        display = "entry `{}` circuit hash mismatch: expected {}, found {}",
+        entry, expected, found
+    )]
+    CircuitHashMismatch {
+        entry: String,
+        expected: String,
+        found: String,
+    },
+
+    #[fail(display = "{}", _0)]
+    Runtime(RuntimeError),
+
+    #[fail(display = "{}", _0)]
+    Verification(VerificationError),
+}
+
+///
+/// The proof of a single entry execution with the data required to verify it.
+///
+pub struct ProofBundle<E: Engine> {
+    pub entry: String,
+    pub circuit_hash: String,
+    pub public_input: Value,
+    pub proof: Proof<E>,
+}
+
+struct Entry<E: Engine> {
+    program: Program,
+    params: Parameters<E>,
+    circuit_hash: String,
+}
+
+pub struct Session<E: Engine> {
+    entries: HashMap<String, Entry<E>>,
+}
+
+impl<E: Engine> Session<E> {
+    ///
+    /// Loads every entry program with its keys and ABI from the build directory.
+    ///
+    pub fn open<P: AsRef<Path>>(build_dir: P) -> Result<Self, SessionError> {
+        let build_dir = build_dir.as_ref();
+        let directory = fs::read_dir(build_dir).map_err(|error| SessionError::Io {
+            path: build_dir.to_string_lossy().to_string(),
+            error,
+        })?;
+
+        let mut entries = HashMap::new();
+        for file in directory {
+            let path = file
+                .map_err(|error| SessionError::Io {
+                    path: build_dir.to_string_lossy().to_string(),
+                    error,
+                })?
+                .path();
+
+            if path.extension().and_then(|extension| extension.to_str())
+                != Some(PROGRAM_FILE_EXTENSION)
+            {
+                continue;
+            }
+            let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(name) => name.to_owned(),
+                None => continue,
+            };
+
+            let bytes = fs::read(&path).map_err(|error| SessionError::Io {
+                path: path.to_string_lossy().to_string(),
+                error,
+            })?;
+            let program = Program::from_bytes(bytes.as_slice()).map_err(|error| {
+                SessionError::ProgramDecoding {
+                    entry: name.clone(),
+                    error,
+                }
+            })?;
+
+            let key_path = path.with_extension(PROVING_KEY_FILE_EXTENSION);
+            if !key_path.exists() {
+                return Err(SessionError::ProvingKeyNotFound(name));
+            }
+            let params = fs::File::open(&key_path)
+                .and_then(|file| Parameters::<E>::read(file, true))
+                .map_err(|error| SessionError::Io {
+                    path: key_path.to_string_lossy().to_string(),
+                    error,
+                })?;
+
+            entries.insert(
+                name,
+                Entry {
+                    program,
+                    params,
+                    circuit_hash: circuit_hash(bytes.as_slice()),
+                },
+            );
+        }
+
+        Ok(Self { entries })
+    }
+
+    ///
+    /// Validates the JSON inputs against the entry input type, executes the entry and
+    /// returns the proof bundle.
+    ///
+    pub fn prove(
+        &self,
+        entry: &str,
+        inputs: &serde_json::Value,
+    ) -> Result<ProofBundle<E>, SessionError> {
+        let loaded = self.entry(entry)?;
+
+        let witness = Value::from_typed_json(inputs, &loaded.program.input).map_err(|error| {
+            SessionError::InvalidInput {
+                entry: entry.to_owned(),
+                error,
+            }
+        })?;
+
+        let (public_input, proof) =
+            crate::facade::prove::<E>(&loaded.program, &loaded.params, &witness)
+                .map_err(SessionError::Runtime)?;
+
+        Ok(ProofBundle {
+            entry: entry.to_owned(),
+            circuit_hash: loaded.circuit_hash.clone(),
+            public_input,
+            proof,
+        })
+    }
+
+    ///
+    /// Verifies the bundle against the entry it was produced by.
+    ///
+    /// The circuit hash is checked before the pairing, so a bundle produced by another
+    /// version of the entry fails early with `SessionError::CircuitHashMismatch`.
+    ///
+    pub fn verify(&self, bundle: &ProofBundle<E>) -> Result<bool, SessionError> {
+        let loaded = self.entry(bundle.entry.as_str())?;
+
+        if loaded.circuit_hash != bundle.circuit_hash {
+            return Err(SessionError::CircuitHashMismatch {
+                entry: bundle.entry.clone(),
+                expected: loaded.circuit_hash.clone(),
+                found: bundle.circuit_hash.clone(),
+            });
+        }
+
+        crate::facade::verify(&loaded.params.vk, &bundle.proof, &bundle.public_input)
+            .map_err(SessionError::Verification)
+    }
+
+    ///
+    /// The names of the loaded entries.
+    ///
+    pub fn entries(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.entries.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    fn entry(&self, name: &str) -> Result<&Entry<E>, SessionError> {
+        self.entries
+            .get(name)
+            .ok_or_else(|| SessionError::EntryNotFound(name.to_owned()))
+    }
+}
+
+///
+/// The hex-encoded SHA-256 digest of the serialized program.
+///
+pub fn circuit_hash(program_bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(program_bytes))
+}
@@ -0,0 +1,65 @@
+use crate::core::EvaluationStack;
+use crate::gadgets;
+use crate::gadgets::utils;
+use crate::stdlib::NativeFunction;
+use crate::{Engine, MalformedBytecode, Result};
+use bellman::ConstraintSystem;
+use num_bigint::BigInt;
+
+pub struct BinarySearch {
+    array_length: usize,
+}
+
+impl BinarySearch {
+    pub fn new(inputs_count: usize) -> Result<Self> {
+        inputs_count
+            .checked_sub(1)
+            .filter(|array_length| *array_length > 0)
+            .map(|array_length| Self { array_length })
+            .ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(
+                    "array::binary_search expects at least 2 arguments".into(),
+                )
+                .into()
+            })
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for BinarySearch {
+    fn execute<CS: ConstraintSystem<E>>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result {
+        let key = stack.pop()?.value()?;
+
+        let mut array = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            let value = stack.pop()?.value()?;
+            array.push(value);
+        }
+        array.reverse();
+
+        let constants = array
+            .iter()
+            .map(|value| {
+                value
+                    .get_constant()
+                    .map(|constant| utils::fr_to_bigint(&constant, value.is_signed()))
+            })
+            .collect::<Result<Vec<BigInt>>>()
+            .map_err(|_| {
+                MalformedBytecode::InvalidArguments(
+                    "array::binary_search expects a constant array".into(),
+                )
+            })?;
+        if constants.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(MalformedBytecode::InvalidArguments(
+                "array::binary_search expects a sorted array".into(),
+            )
+            .into());
+        }
+
+        let (found, index, value) = gadgets::binary_search(cs, &array, &key)?;
+
+        stack.push(found.into())?;
+        stack.push(index.into())?;
+        stack.push(value.into())
+    }
+}
@@ -6,3 +6,6 @@ pub use truncate::*;
 
 mod reverse;
 pub use reverse::*;
+
+mod binary_search;
+pub use binary_search::*;
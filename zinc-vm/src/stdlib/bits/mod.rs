@@ -9,3 +9,75 @@ pub use unsigned_from_bits::*;
 
 mod signed_from_bits;
 pub use signed_from_bits::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    use crate::core::{Cell, EvaluationStack};
+    use crate::gadgets::utils::bigint_to_fr;
+    use crate::gadgets::{IntegerType, Scalar};
+    use crate::stdlib::NativeFunction;
+
+    fn round_trip(value: BigInt, is_signed: bool, bitlength: usize) -> BigInt {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut stack = EvaluationStack::new();
+
+        let fr = bigint_to_fr::<Bn256>(&value).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        let scalar_type = IntegerType {
+            is_signed,
+            bitlength,
+        }
+        .into();
+        let scalar = Scalar::new_unchecked_variable(Some(fr), variable, scalar_type);
+        stack.push(Cell::Value(scalar)).unwrap();
+
+        ToBits
+            .execute(cs.namespace(|| "to_bits"), &mut stack)
+            .unwrap();
+        if is_signed {
+            SignedFromBits::new(bitlength)
+                .execute(cs.namespace(|| "from_bits"), &mut stack)
+                .unwrap();
+        } else {
+            UnsignedFromBits::new(bitlength)
+                .execute(cs.namespace(|| "from_bits"), &mut stack)
+                .unwrap();
+        }
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+
+        let Cell::Value(result) = stack.pop().unwrap();
+        assert!(stack.pop().is_err(), "the bits are not consumed");
+        result.to_bigint().unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_unsigned() {
+        for (value, bitlength) in [(0, 8), (201, 8), (1000, 12), (65535, 16), (12345, 24)].iter() {
+            let value = BigInt::from(*value);
+            assert_eq!(round_trip(value.clone(), false, *bitlength), value);
+        }
+
+        let max = (BigInt::from(1) << 248) - 1;
+        assert_eq!(round_trip(max.clone(), false, 248), max);
+    }
+
+    #[test]
+    fn test_round_trip_signed() {
+        for (value, bitlength) in [(0, 8), (-128, 8), (127, 8), (-1000, 12), (-12345, 24)].iter() {
+            let value = BigInt::from(*value);
+            assert_eq!(round_trip(value.clone(), true, *bitlength), value);
+        }
+    }
+}
@@ -45,3 +45,62 @@ impl<E: Engine> NativeFunction<E> for Pedersen {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use pairing::bn256::Bn256;
+
+    /// Checks the gadget digest of `bits` against the native hash computed outside the VM.
+    fn check_digest(bits: &[bool]) {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut stack = EvaluationStack::new();
+
+        for bit in bits.iter() {
+            stack.push(Scalar::new_constant_bool(*bit).into()).unwrap();
+        }
+
+        Pedersen::new(bits.len())
+            .unwrap()
+            .execute(cs.namespace(|| "pedersen"), &mut stack)
+            .unwrap();
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+
+        let y = stack.pop().unwrap().value().unwrap().get_value();
+        let x = stack.pop().unwrap().value().unwrap().get_value();
+
+        let (expected_x, expected_y) = franklin_crypto::pedersen_hash::pedersen_hash::<Bn256, _>(
+            Personalization::NoteCommitment,
+            bits.iter().cloned(),
+            Bn256::jubjub_params(),
+        )
+        .into_xy();
+
+        assert_eq!(x, Some(expected_x));
+        assert_eq!(y, Some(expected_y));
+    }
+
+    #[test]
+    fn test_pedersen_abc() {
+        let bits = b"abc"
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| byte & (1 << i) != 0))
+            .collect::<Vec<_>>();
+
+        check_digest(bits.as_slice());
+    }
+
+    #[test]
+    fn test_pedersen_max_length() {
+        let bits = (0..512).map(|i| i % 3 == 0).collect::<Vec<_>>();
+
+        check_digest(bits.as_slice());
+    }
+}
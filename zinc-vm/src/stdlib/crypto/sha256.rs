@@ -44,11 +44,84 @@ impl<E: Engine> NativeFunction<E> for Sha256 {
 
         assert_eq!(digest_bits.len(), 256);
 
-        for bit in digest_bits {
-            let scalar = Scalar::from_boolean(cs.namespace(|| "from_boolean"), bit)?;
+        for (i, bit) in digest_bits.into_iter().enumerate() {
+            let scalar = Scalar::from_boolean(cs.namespace(|| format!("from_boolean {}", i)), bit)?;
             stack.push(scalar.into())?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    /// Returns the digest bits of `message`, hashed by the gadget.
+    fn digest(message: &[u8]) -> Vec<bool> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut stack = EvaluationStack::new();
+
+        for byte in message.iter() {
+            for i in (0..8).rev() {
+                let bit = Scalar::new_constant_bool(byte & (1 << i) != 0);
+                stack.push(bit.into()).unwrap();
+            }
+        }
+
+        Sha256::new(message.len() * 8)
+            .unwrap()
+            .execute(cs.namespace(|| "sha256"), &mut stack)
+            .unwrap();
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+
+        let mut bits = Vec::new();
+        for _ in 0..256 {
+            let bit = stack.pop().unwrap().value().unwrap().to_bigint();
+            bits.push(bit == Some(BigInt::from(1)));
+        }
+        bits.reverse();
+        bits
+    }
+
+    fn hex_to_bits(hex: &str) -> Vec<bool> {
+        let mut bits = Vec::new();
+        for i in (0..hex.len()).step_by(2) {
+            let byte = u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+            for j in (0..8).rev() {
+                bits.push(byte & (1 << j) != 0);
+            }
+        }
+        bits
+    }
+
+    #[test]
+    fn test_sha256_empty() {
+        assert_eq!(
+            digest(b""),
+            hex_to_bits("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+    }
+
+    #[test]
+    fn test_sha256_abc() {
+        assert_eq!(
+            digest(b"abc"),
+            hex_to_bits("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+    }
+
+    #[test]
+    fn test_sha256_length_not_multiple_of_8() {
+        assert!(Sha256::new(12).is_err());
+    }
+}
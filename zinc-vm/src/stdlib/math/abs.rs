@@ -0,0 +1,85 @@
+use bellman::ConstraintSystem;
+
+use crate::core::EvaluationStack;
+use crate::gadgets::{IntegerType, ScalarType};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result, RuntimeError};
+
+/// Computes the absolute value of an integer.
+///
+/// The result is unsigned and has the same bitlength as the operand, so the absolute value
+/// of the minimal signed value, e.g. `128` for `-128_i8`, is representable and never overflows.
+pub struct Abs;
+
+impl<E: Engine> NativeFunction<E> for Abs {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let scalar = stack.pop()?.value()?;
+        let bitlength = match scalar.get_type() {
+            ScalarType::Integer(int_type) => int_type.bitlength,
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer".to_string(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+
+        let abs = gadgets::abs(cs, &scalar)?;
+        let result_type = IntegerType {
+            is_signed: false,
+            bitlength,
+        };
+        stack.push(abs.with_type_unchecked(result_type.into()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::{BigInt, ToBigInt};
+    use pairing::bn256::Bn256;
+
+    use crate::core::Cell;
+    use crate::gadgets::utils::bigint_to_fr;
+    use crate::gadgets::Scalar;
+
+    fn abs(value: i64, scalar_type: IntegerType) -> Scalar<Bn256> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut stack = EvaluationStack::new();
+
+        let fr = bigint_to_fr::<Bn256>(&BigInt::from(value)).unwrap();
+        let variable = cs.alloc(|| "variable", || Ok(fr)).unwrap();
+        let scalar = Scalar::new_unchecked_variable(Some(fr), variable, scalar_type.into());
+        stack.push(Cell::Value(scalar)).unwrap();
+
+        Abs.execute(cs.namespace(|| "abs"), &mut stack).unwrap();
+
+        assert!(
+            cs.is_satisfied(),
+            "unsatisfied: {:?}",
+            cs.which_is_unsatisfied()
+        );
+        stack.pop().unwrap().value().unwrap()
+    }
+
+    #[test]
+    fn test_abs() {
+        for (value, expected) in [(0, 0), (42, 42), (-42, 42), (127, 127), (-128, 128)].iter() {
+            let result = abs(*value, IntegerType::I8);
+            assert_eq!(result.to_bigint(), Some(BigInt::from(*expected)));
+            assert_eq!(result.get_type(), ScalarType::Integer(IntegerType::U8));
+        }
+    }
+
+    #[test]
+    fn test_abs_unsigned() {
+        let result = abs(255, IntegerType::U8);
+        assert_eq!(result.to_bigint(), Some(BigInt::from(255)));
+        assert_eq!(result.get_type(), ScalarType::Integer(IntegerType::U8));
+    }
+}
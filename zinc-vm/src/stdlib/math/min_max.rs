@@ -0,0 +1,33 @@
+use bellman::ConstraintSystem;
+
+use crate::core::EvaluationStack;
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result};
+
+pub struct Min;
+
+impl<E: Engine> NativeFunction<E> for Min {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let min = gadgets::min(cs, &left, &right)?;
+        stack.push(min.into())
+    }
+}
+
+pub struct Max;
+
+impl<E: Engine> NativeFunction<E> for Max {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let max = gadgets::max(cs, &left, &right)?;
+        stack.push(max.into())
+    }
+}
@@ -0,0 +1,5 @@
+mod abs;
+pub use self::abs::*;
+
+mod min_max;
+pub use self::min_max::*;
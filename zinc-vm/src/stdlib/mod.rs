@@ -2,6 +2,8 @@ pub mod array;
 pub mod bits;
 pub mod crypto;
 pub mod ff;
+pub mod math;
+pub mod ops;
 
 use crate::core::EvaluationStack;
 use crate::{Engine, Result};
@@ -0,0 +1,35 @@
+use bellman::ConstraintSystem;
+
+use crate::core::EvaluationStack;
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result};
+
+pub struct CheckedAdd;
+
+impl<E: Engine> NativeFunction<E> for CheckedAdd {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let (result, is_overflow) = gadgets::add_checked(cs, &left, &right)?;
+        stack.push(result.into())?;
+        stack.push(is_overflow.into())
+    }
+}
+
+pub struct CheckedMul;
+
+impl<E: Engine> NativeFunction<E> for CheckedMul {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let (result, is_overflow) = gadgets::mul_checked(cs, &left, &right)?;
+        stack.push(result.into())?;
+        stack.push(is_overflow.into())
+    }
+}
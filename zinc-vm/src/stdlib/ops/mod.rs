@@ -0,0 +1,2 @@
+mod checked;
+pub use self::checked::*;
@@ -0,0 +1,47 @@
+use bellman::ConstraintSystem;
+
+use crate::core::EvaluationStack;
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result};
+
+pub struct SaturatingAdd;
+
+impl<E: Engine> NativeFunction<E> for SaturatingAdd {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::add_saturating(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
+
+pub struct SaturatingSub;
+
+impl<E: Engine> NativeFunction<E> for SaturatingSub {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::sub_saturating(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
+
+pub struct SaturatingMul;
+
+impl<E: Engine> NativeFunction<E> for SaturatingMul {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::mul_saturating(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
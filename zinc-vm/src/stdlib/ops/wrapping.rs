@@ -0,0 +1,47 @@
+use bellman::ConstraintSystem;
+
+use crate::core::EvaluationStack;
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result};
+
+pub struct WrappingAdd;
+
+impl<E: Engine> NativeFunction<E> for WrappingAdd {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::add_wrapping(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
+
+pub struct WrappingSub;
+
+impl<E: Engine> NativeFunction<E> for WrappingSub {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::sub_wrapping(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
+
+pub struct WrappingMul;
+
+impl<E: Engine> NativeFunction<E> for WrappingMul {
+    fn execute<CS>(&self, cs: CS, stack: &mut EvaluationStack<E>) -> Result
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+        let result = gadgets::mul_wrapping(cs, &left, &right)?;
+        stack.push(result.into())
+    }
+}
@@ -0,0 +1,122 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::{estimate_cost, InstructionInfo};
+
+/// The allowed relative difference between the estimated and the measured constraint counts.
+const TOLERANCE: f64 = 0.25;
+
+fn assert_estimate(program: &Program, inputs: serde_json::Value) {
+    let inputs = Value::from_typed_json(&inputs, &program.input).expect("invalid inputs");
+
+    let measured =
+        crate::count_constraints::<Bn256>(program, &inputs).expect("the program is valid");
+    let estimate = estimate_cost(program);
+
+    assert_eq!(estimate.unknown, 0);
+    let difference = (estimate.constraints as f64 - measured as f64).abs();
+    assert!(
+        difference <= measured as f64 * TOLERANCE,
+        "estimated {} constraints, measured {}",
+        estimate.constraints,
+        measured,
+    );
+}
+
+/// Sums the `u32` input 50 times.
+#[test]
+fn sum_in_loop() {
+    let u32_type = DataType::Scalar(IntegerType::U32.into());
+    let program = Program::new(
+        u32_type.clone(),
+        u32_type,
+        vec![
+            Call::new(2, 1).wrap(),
+            Exit::new(1).wrap(),
+            PushConst::new(0.into(), IntegerType::U32.into()).wrap(),
+            Store::new(1).wrap(),
+            LoopBegin::new(50).wrap(),
+            Load::new(1).wrap(),
+            Load::new(0).wrap(),
+            Add::default().wrap(),
+            Store::new(1).wrap(),
+            LoopEnd.wrap(),
+            Load::new(1).wrap(),
+            Return::new(1).wrap(),
+        ],
+    );
+
+    assert_estimate(&program, json!("3"));
+}
+
+/// Either increments or doubles the `u16` accumulator 20 times depending on the witness flag.
+#[test]
+fn witness_branches_in_loop() {
+    let u16_type = DataType::Scalar(IntegerType::U16.into());
+    let program = Program::new(
+        DataType::Struct(vec![
+            ("x".into(), u16_type.clone()),
+            ("flag".into(), DataType::Scalar(ScalarType::Boolean)),
+        ]),
+        u16_type,
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            PushConst::new(1.into(), IntegerType::U16.into()).wrap(),
+            Store::new(2).wrap(),
+            LoopBegin::new(20).wrap(),
+            Load::new(1).wrap(),
+            If.wrap(),
+            Load::new(2).wrap(),
+            Load::new(0).wrap(),
+            Add::default().wrap(),
+            Store::new(2).wrap(),
+            Else.wrap(),
+            Load::new(2).wrap(),
+            PushConst::new(2.into(), IntegerType::U16.into()).wrap(),
+            Mul::default().wrap(),
+            Store::new(2).wrap(),
+            EndIf.wrap(),
+            LoopEnd.wrap(),
+            Load::new(2).wrap(),
+            Return::new(1).wrap(),
+        ],
+    );
+
+    assert_estimate(&program, json!({ "x": "1", "flag": true }));
+}
+
+/// Computes `acc = acc * x + 1` in two nested loops of 10 iterations over the field.
+#[test]
+fn nested_field_loops() {
+    let field_type = DataType::Scalar(ScalarType::Field);
+    let program = Program::new(
+        field_type.clone(),
+        field_type,
+        vec![
+            Call::new(2, 1).wrap(),
+            Exit::new(1).wrap(),
+            PushConst::new(0.into(), ScalarType::Field).wrap(),
+            Store::new(1).wrap(),
+            LoopBegin::new(10).wrap(),
+            LoopBegin::new(10).wrap(),
+            Load::new(1).wrap(),
+            Load::new(0).wrap(),
+            Mul::default().wrap(),
+            PushConst::new(1.into(), ScalarType::Field).wrap(),
+            Add::default().wrap(),
+            Store::new(1).wrap(),
+            LoopEnd.wrap(),
+            LoopEnd.wrap(),
+            Load::new(1).wrap(),
+            Return::new(1).wrap(),
+        ],
+    );
+
+    assert_estimate(&program, json!("5"));
+}
@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::InstructionInfo;
+
+use super::binary_program;
+use crate::{FileKeyStorage, KeyCache, KeyStorage};
+
+/// Counts the stored keys, which is the number of the setups run by the cache.
+struct CountingStorage {
+    inner: FileKeyStorage,
+    stores: Cell<usize>,
+}
+
+impl KeyStorage for CountingStorage {
+    fn load(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        self.inner.load(key)
+    }
+
+    fn store(&self, key: &str, bytes: &[u8]) -> io::Result<()> {
+        self.stores.set(self.stores.get() + 1);
+        self.inner.store(key, bytes)
+    }
+}
+
+fn program(is_sum: bool) -> Program {
+    binary_program(if is_sum {
+        Add::default().wrap()
+    } else {
+        Mul::default().wrap()
+    })
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("zinc-vm-key-cache-{}", name));
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+#[test]
+fn ok_second_run_skips_setup() {
+    let cache = KeyCache::<Bn256, _>::new(CountingStorage {
+        inner: FileKeyStorage::new(cache_dir("reuse")),
+        stores: Cell::new(0),
+    });
+    let program = program(true);
+    let witness = Value::from_typed_json(&json!({ "a": "2", "b": "3" }), &program.input)
+        .expect("invalid inputs");
+
+    let (output, _proof) = cache.prove(&program, &witness).expect("first proof");
+    assert_eq!(output.to_json(), json!("5"));
+    assert_eq!(cache.storage().stores.get(), 1);
+
+    let (output, proof) = cache.prove(&program, &witness).expect("second proof");
+    assert_eq!(output.to_json(), json!("5"));
+    assert_eq!(cache.storage().stores.get(), 1);
+
+    let params = cache.proving_key(&program).expect("cached key");
+    let verified = crate::verify(&params.vk, &proof, &output).expect("verification");
+    assert!(
+        verified,
+        "the proof made with the cached key must be accepted"
+    );
+}
+
+#[test]
+fn ok_changed_program_invalidates_key() {
+    let cache = KeyCache::<Bn256, _>::new(CountingStorage {
+        inner: FileKeyStorage::new(cache_dir("invalidate")),
+        stores: Cell::new(0),
+    });
+    let witness_type = program(true).input;
+    let witness = Value::from_typed_json(&json!({ "a": "2", "b": "3" }), &witness_type)
+        .expect("invalid inputs");
+
+    cache.prove(&program(true), &witness).expect("sum proof");
+    let (output, _proof) = cache
+        .prove(&program(false), &witness)
+        .expect("product proof");
+    assert_eq!(output.to_json(), json!("6"));
+    assert_eq!(cache.storage().stores.get(), 2);
+}
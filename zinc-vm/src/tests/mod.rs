@@ -1 +1,40 @@
+mod cost;
+mod key_cache;
+mod namespaces;
 mod overflow;
+mod profile;
+mod public;
+mod session;
+mod unchecked;
+mod unconstrained;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{Instruction, InstructionInfo};
+
+fn u8_type() -> DataType {
+    DataType::Scalar(IntegerType::U8.into())
+}
+
+/// The `{ a: u8, b: u8 }` input of the test programs.
+fn ab_input_type() -> DataType {
+    DataType::Struct(vec![("a".into(), u8_type()), ("b".into(), u8_type())])
+}
+
+/// Applies the binary `operation` to the `a` and `b` inputs and returns the `u8` result.
+fn binary_program(operation: Instruction) -> Program {
+    Program::new(
+        ab_input_type(),
+        u8_type(),
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            Load::new(0).wrap(),
+            Load::new(1).wrap(),
+            operation,
+            Return::new(1).wrap(),
+        ],
+    )
+}
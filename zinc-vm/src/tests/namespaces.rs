@@ -0,0 +1,32 @@
+use crate::instructions::testing_utils::{TestingError, VMTestRunner};
+use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+#[test]
+fn repeated_operation_in_loop() -> Result<(), TestingError> {
+    VMTestRunner::new()
+        .witness(1.into(), IntegerType::U16.into())
+        .add(PushConst::new(0.into(), IntegerType::U16.into()))
+        .add(LoopBegin::new(100))
+        .add(Load::new(0))
+        .add(Add::default())
+        .add(LoopEnd)
+        .test(&[100])
+}
+
+#[test]
+fn repeated_native_function_outputs() -> Result<(), TestingError> {
+    let mut runner = VMTestRunner::new();
+    for _ in 0..8 {
+        runner.witness(1.into(), ScalarType::Boolean);
+    }
+    for address in 0..8 {
+        runner.add(Load::new(address));
+    }
+
+    runner
+        .add(CallBuiltin::new(BuiltinIdentifier::CryptoSha256, 8, 256))
+        .add(Pop::new(256))
+        .test::<i32>(&[])
+}
@@ -1,14 +1,14 @@
 use crate::instructions::testing_utils::{TestingError, VMTestRunner};
 use crate::RuntimeError;
 use zinc_bytecode::instructions::*;
-use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
 
 #[test]
 fn unsigned_positive_overflow_fail() {
     let res = VMTestRunner::new()
         .add(PushConst::new(255.into(), IntegerType::U8.into()))
         .add(PushConst::new(1.into(), IntegerType::U8.into()))
-        .add(Add)
+        .add(Add::default())
         .test(&[256]);
 
     match res.err().expect("expected overflow error") {
@@ -22,7 +22,7 @@ fn unsigned_negative_overflow_fail() {
     let res = VMTestRunner::new()
         .add(PushConst::new(254.into(), IntegerType::U8.into()))
         .add(PushConst::new(255.into(), IntegerType::U8.into()))
-        .add(Sub)
+        .add(Sub::default())
         .test(&[-1]);
 
     match res.err().expect("expected overflow error") {
@@ -36,7 +36,7 @@ fn signed_positive_overflow_fail() {
     let res = VMTestRunner::new()
         .add(PushConst::new(127.into(), IntegerType::I8.into()))
         .add(PushConst::new(1.into(), IntegerType::I8.into()))
-        .add(Add)
+        .add(Add::default())
         .test(&[128]);
 
     match res.err().expect("expected overflow error") {
@@ -50,7 +50,7 @@ fn signed_negative_overflow_fail() {
     let res = VMTestRunner::new()
         .add(PushConst::new((-128).into(), IntegerType::I8.into()))
         .add(PushConst::new(1.into(), IntegerType::I8.into()))
-        .add(Sub)
+        .add(Sub::default())
         .test(&[-129]);
 
     match res.err().expect("expected overflow error") {
@@ -64,7 +64,7 @@ fn unsigned_positive_overflow_ok() -> Result<(), TestingError> {
     VMTestRunner::new()
         .add(PushConst::new(254.into(), IntegerType::U8.into()))
         .add(PushConst::new(1.into(), IntegerType::U8.into()))
-        .add(Add)
+        .add(Add::default())
         .test(&[255])
 }
 
@@ -73,7 +73,7 @@ fn unsigned_negative_overflow_ok() -> Result<(), TestingError> {
     VMTestRunner::new()
         .add(PushConst::new(255.into(), IntegerType::U8.into()))
         .add(PushConst::new(255.into(), IntegerType::U8.into()))
-        .add(Sub)
+        .add(Sub::default())
         .test(&[0])
 }
 
@@ -82,7 +82,7 @@ fn signed_positive_overflow_ok() -> Result<(), TestingError> {
     VMTestRunner::new()
         .add(PushConst::new(126.into(), IntegerType::I8.into()))
         .add(PushConst::new(1.into(), IntegerType::I8.into()))
-        .add(Add)
+        .add(Add::default())
         .test(&[127])
 }
 
@@ -91,6 +91,50 @@ fn signed_negative_overflow_ok() -> Result<(), TestingError> {
     VMTestRunner::new()
         .add(PushConst::new((-127).into(), IntegerType::I8.into()))
         .add(PushConst::new(1.into(), IntegerType::I8.into()))
-        .add(Sub)
+        .add(Sub::default())
         .test(&[-128])
 }
+
+#[test]
+fn witness_input_overflow_fail() {
+    let res = VMTestRunner::new()
+        .witness(256.into(), IntegerType::U8.into())
+        .add(Load::new(0))
+        .test(&[256]);
+
+    match res.err().expect("expected overflow error") {
+        TestingError::RuntimeError(RuntimeError::ValueOverflow { .. }) => {}
+        err => panic!("expected overflow error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn witness_input_overflow_ok() -> Result<(), TestingError> {
+    VMTestRunner::new()
+        .witness(255.into(), IntegerType::U8.into())
+        .add(Load::new(0))
+        .test(&[255])
+}
+
+#[test]
+fn narrowing_cast_overflow_fail() {
+    let res = VMTestRunner::new()
+        .witness(256.into(), ScalarType::Field)
+        .add(Load::new(0))
+        .add(Cast::new(IntegerType::U8.into()))
+        .test(&[256]);
+
+    match res.err().expect("expected overflow error") {
+        TestingError::RuntimeError(RuntimeError::ValueOverflow { .. }) => {}
+        err => panic!("expected overflow error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn narrowing_cast_overflow_ok() -> Result<(), TestingError> {
+    VMTestRunner::new()
+        .witness(255.into(), ScalarType::Field)
+        .add(Load::new(0))
+        .add(Cast::new(IntegerType::U8.into()))
+        .test(&[255])
+}
@@ -0,0 +1,65 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::InstructionInfo;
+
+use super::{ab_input_type, u8_type};
+
+/// Computes `a * b * b * b` on the line 2 and adds `a` to the product on the line 3.
+fn program() -> Program {
+    Program::new(
+        ab_input_type(),
+        u8_type(),
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            FileMarker::new("main.zn".into()).wrap(),
+            LineMarker::new(2).wrap(),
+            Load::new(0).wrap(),
+            Load::new(1).wrap(),
+            Mul::default().wrap(),
+            Load::new(1).wrap(),
+            Mul::default().wrap(),
+            Load::new(1).wrap(),
+            Mul::default().wrap(),
+            Store::new(2).wrap(),
+            LineMarker::new(3).wrap(),
+            Load::new(2).wrap(),
+            Load::new(0).wrap(),
+            Add::default().wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+#[test]
+fn multiplication_line_dominates() {
+    let program = program();
+    let inputs = Value::from_typed_json(&json!({ "a": "3", "b": "2" }), &program.input)
+        .expect("invalid inputs");
+
+    let report = crate::profile::<Bn256>(&program, &inputs).expect("profiling failed");
+
+    let line = &report.by_line[0];
+    assert_eq!(line.name, "main.zn:2");
+    for other in report.by_line[1..].iter() {
+        assert!(line.constraints > other.constraints, "{}", report);
+    }
+
+    let instruction = &report.by_instruction[0];
+    assert_eq!(instruction.name, "Mul");
+    assert_eq!(instruction.executions, 3);
+
+    assert_eq!(
+        report
+            .by_line
+            .iter()
+            .map(|entry| entry.constraints)
+            .sum::<usize>(),
+        report.constraints
+    );
+    assert_eq!(report.to_json()["by_line"][0]["name"], json!("main.zn:2"));
+}
@@ -0,0 +1,58 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::InstructionInfo;
+
+use super::{ab_input_type, u8_type};
+
+/// Marks `b` and then `a` public and returns `a + b`.
+fn program() -> Program {
+    Program::new(
+        ab_input_type(),
+        u8_type(),
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            Load::new(1).wrap(),
+            MarkPublic::new(1).wrap(),
+            Load::new(0).wrap(),
+            MarkPublic::new(1).wrap(),
+            Add::default().wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+    .with_public(DataType::Tuple(vec![u8_type(), u8_type()]))
+}
+
+#[test]
+fn ok_public_input_order() {
+    let program = program();
+    let inputs = Value::from_typed_json(&json!({ "a": "2", "b": "3" }), &program.input)
+        .expect("invalid inputs");
+
+    let params = crate::setup::<Bn256>(&program).expect("setup");
+    let (public_input, proof) = crate::prove::<Bn256>(&program, &params, &inputs).expect("proof");
+    assert_eq!(public_input.to_json(), json!([["3", "2"], "5"]));
+
+    let verified = crate::verify(&params.vk, &proof, &public_input).expect("verification");
+    assert!(
+        verified,
+        "the public inputs in the marking order must be accepted"
+    );
+
+    let swapped = Value::from_typed_json(&json!([["2", "3"], "5"]), &program.public_input_type())
+        .expect("invalid public input");
+    let verified = crate::verify(&params.vk, &proof, &swapped).expect("verification");
+    assert!(!verified, "the swapped public inputs must be rejected");
+}
+
+#[test]
+fn ok_unmarked_public_input_is_output() {
+    let program = Program::new(DataType::Unit, u8_type(), vec![]);
+
+    assert_eq!(program.public_input_type(), u8_type());
+}
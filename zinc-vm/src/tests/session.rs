@@ -0,0 +1,117 @@
+use std::fs;
+use std::path::PathBuf;
+
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::InstructionInfo;
+
+use super::{binary_program, u8_type};
+use crate::{Session, SessionError, PROGRAM_FILE_EXTENSION, PROVING_KEY_FILE_EXTENSION};
+
+fn double_program() -> Program {
+    Program::new(
+        DataType::Struct(vec![("value".into(), u8_type())]),
+        u8_type(),
+        vec![
+            Call::new(2, 1).wrap(),
+            Exit::new(1).wrap(),
+            Load::new(0).wrap(),
+            Load::new(0).wrap(),
+            Add::default().wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+fn write_entry(build_dir: &PathBuf, name: &str, program: &Program) {
+    let path = build_dir.join(name);
+
+    fs::write(
+        path.with_extension(PROGRAM_FILE_EXTENSION),
+        program.to_bytes(),
+    )
+    .expect("program writing");
+
+    let params = crate::setup::<Bn256>(program).expect("setup");
+    let file =
+        fs::File::create(path.with_extension(PROVING_KEY_FILE_EXTENSION)).expect("key creating");
+    params.write(file).expect("key writing");
+}
+
+fn build_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("zinc-vm-session-{}", name));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).expect("build directory creating");
+
+    write_entry(&path, "add", &binary_program(Add::default().wrap()));
+    write_entry(&path, "double", &double_program());
+
+    path
+}
+
+#[test]
+fn ok_two_entries() {
+    let path = build_dir("two-entries");
+    let session = Session::<Bn256>::open(&path).expect("session opening");
+
+    assert_eq!(session.entries(), vec!["add", "double"]);
+
+    let bundle = session
+        .prove("add", &json!({ "a": "2", "b": "3" }))
+        .expect("proving `add`");
+    assert_eq!(bundle.entry, "add");
+    assert_eq!(bundle.public_input.to_json(), json!("5"));
+    assert!(session.verify(&bundle).expect("verifying `add`"));
+
+    let bundle = session
+        .prove("double", &json!({ "value": "21" }))
+        .expect("proving `double`");
+    assert_eq!(bundle.entry, "double");
+    assert_eq!(bundle.public_input.to_json(), json!("42"));
+    assert!(session.verify(&bundle).expect("verifying `double`"));
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_circuit_hash_mismatch() {
+    let path = build_dir("hash-mismatch");
+    let session = Session::<Bn256>::open(&path).expect("session opening");
+
+    let mut bundle = session
+        .prove("add", &json!({ "a": "2", "b": "3" }))
+        .expect("proving `add`");
+    bundle.entry = "double".to_owned();
+
+    match session.verify(&bundle) {
+        Err(SessionError::CircuitHashMismatch { entry, .. }) => assert_eq!(entry, "double"),
+        Err(error) => panic!("expected circuit hash mismatch, got {:?} instead", error),
+        Ok(_) => panic!("expected circuit hash mismatch"),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_invalid_input() {
+    let path = build_dir("invalid-input");
+    let session = Session::<Bn256>::open(&path).expect("session opening");
+
+    match session.prove("double", &json!({ "a": "2", "b": "3" })) {
+        Err(SessionError::InvalidInput { entry, .. }) => assert_eq!(entry, "double"),
+        Err(error) => panic!("expected invalid input, got {:?} instead", error),
+        Ok(_) => panic!("expected invalid input"),
+    }
+
+    match session.prove("withdraw", &json!({})) {
+        Err(SessionError::EntryNotFound(entry)) => assert_eq!(entry, "withdraw"),
+        Err(error) => panic!("expected entry not found, got {:?} instead", error),
+        Ok(_) => panic!("expected entry not found"),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
@@ -0,0 +1,64 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::InstructionInfo;
+
+/// Sums eight `u8` values into a `u16` accumulator, which cannot overflow.
+fn program(is_unchecked: bool) -> Program {
+    Program::new(
+        DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 8),
+        DataType::Scalar(IntegerType::U16.into()),
+        vec![
+            Call::new(2, 8).wrap(),
+            Exit::new(1).wrap(),
+            PushConst::new(0.into(), IntegerType::U16.into()).wrap(),
+            Store::new(8).wrap(),
+            PushConst::new(0.into(), ScalarType::Field).wrap(),
+            Store::new(9).wrap(),
+            LoopBegin::new(8).wrap(),
+            Load::new(8).wrap(),
+            Load::new(9).wrap(),
+            LoadByIndex::new(0, 8).wrap(),
+            Cast::new(IntegerType::U16.into()).wrap(),
+            Add::new(is_unchecked).wrap(),
+            Store::new(8).wrap(),
+            Load::new(9).wrap(),
+            PushConst::new(1.into(), ScalarType::Field).wrap(),
+            Add::default().wrap(),
+            Store::new(9).wrap(),
+            LoopEnd.wrap(),
+            Load::new(8).wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+#[test]
+fn unchecked_accumulation() {
+    let checked = program(false);
+    let unchecked = program(true);
+
+    let inputs = json!(["255", "255", "255", "255", "255", "255", "255", "1"]);
+    let inputs = Value::from_typed_json(&inputs, &checked.input).expect("invalid inputs");
+
+    let checked_output = crate::run::<Bn256>(&checked, &inputs).expect("checked run failed");
+    let unchecked_output = crate::run::<Bn256>(&unchecked, &inputs).expect("unchecked run failed");
+    assert_eq!(checked_output, unchecked_output);
+    assert_eq!(unchecked_output.to_json(), json!("1786"));
+
+    let checked_constraints =
+        crate::count_constraints::<Bn256>(&checked, &inputs).expect("checked run failed");
+    let unchecked_constraints =
+        crate::count_constraints::<Bn256>(&unchecked, &inputs).expect("unchecked run failed");
+    assert!(
+        unchecked_constraints + 8 * 16 <= checked_constraints,
+        "{} constraints are not fewer than {} by the range checks",
+        unchecked_constraints,
+        checked_constraints,
+    );
+}
@@ -0,0 +1,106 @@
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::program::Program;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::InstructionInfo;
+
+use crate::RuntimeError;
+
+fn u16_type() -> DataType {
+    DataType::Scalar(IntegerType::U16.into())
+}
+
+/// Sums `x` ten times, then either triples or decreases the sum by 7 depending on `flag`,
+/// and asserts that the result is positive.
+fn program() -> Program {
+    Program::new(
+        DataType::Struct(vec![
+            ("x".into(), u16_type()),
+            ("flag".into(), DataType::Scalar(ScalarType::Boolean)),
+        ]),
+        u16_type(),
+        vec![
+            Call::new(2, 2).wrap(),
+            Exit::new(1).wrap(),
+            PushConst::new(0.into(), IntegerType::U16.into()).wrap(),
+            Store::new(2).wrap(),
+            LoopBegin::new(10).wrap(),
+            Load::new(2).wrap(),
+            Load::new(0).wrap(),
+            Add::default().wrap(),
+            Store::new(2).wrap(),
+            LoopEnd.wrap(),
+            Load::new(1).wrap(),
+            If.wrap(),
+            Load::new(2).wrap(),
+            PushConst::new(3.into(), IntegerType::U16.into()).wrap(),
+            Mul::default().wrap(),
+            Store::new(2).wrap(),
+            Else.wrap(),
+            Load::new(2).wrap(),
+            PushConst::new(7.into(), IntegerType::U16.into()).wrap(),
+            Sub::default().wrap(),
+            Store::new(2).wrap(),
+            EndIf.wrap(),
+            Load::new(2).wrap(),
+            PushConst::new(0.into(), IntegerType::U16.into()).wrap(),
+            Gt.wrap(),
+            Assert::new(Some("the result is not positive".into())).wrap(),
+            Load::new(2).wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+fn run_both(program: &Program, inputs: serde_json::Value) -> (Value, Value) {
+    let inputs = Value::from_typed_json(&inputs, &program.input).expect("invalid inputs");
+
+    let constrained = crate::run::<Bn256>(program, &inputs).expect("constrained run failed");
+    let unconstrained =
+        crate::run_unconstrained::<Bn256>(program, &inputs).expect("unconstrained run failed");
+
+    (constrained, unconstrained)
+}
+
+#[test]
+fn unconstrained_outputs_match() {
+    let program = program();
+
+    for (inputs, expected) in vec![
+        (json!({ "x": "100", "flag": true }), json!("3000")),
+        (json!({ "x": "100", "flag": false }), json!("993")),
+        (json!({ "x": "1", "flag": false }), json!("3")),
+    ] {
+        let (constrained, unconstrained) = run_both(&program, inputs);
+
+        assert_eq!(constrained, unconstrained);
+        assert_eq!(unconstrained.to_json(), expected);
+    }
+}
+
+#[test]
+fn unconstrained_errors_match() {
+    let program = program();
+
+    for inputs in vec![
+        json!({ "x": "0", "flag": true }),
+        json!({ "x": "0", "flag": false }),
+    ] {
+        let inputs = Value::from_typed_json(&inputs, &program.input).expect("invalid inputs");
+
+        let constrained = crate::run::<Bn256>(&program, &inputs);
+        let unconstrained = crate::run_unconstrained::<Bn256>(&program, &inputs);
+
+        match (constrained, unconstrained) {
+            (Err(RuntimeError::AssertionError(left)), Err(RuntimeError::AssertionError(right))) => {
+                assert_eq!(left, right)
+            }
+            (Err(RuntimeError::ValueOverflow { .. }), Err(RuntimeError::ValueOverflow { .. })) => {}
+            results => panic!("expected the same errors, got {:?}", results),
+        }
+    }
+}